@@ -7,16 +7,20 @@
 use directories::BaseDirs;
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::credential::CredentialStore;
 use super::error::{ConfigError, Result};
+use super::lock::{ConfigLock, DEFAULT_LOCK_TIMEOUT};
 
 /// Main configuration structure
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Config {
+    /// Config schema version (absent in files written before versioning)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<u32>,
     /// Default profile for enterprise commands
     #[serde(default, rename = "default_enterprise")]
     pub default_enterprise: Option<String>,
@@ -30,9 +34,49 @@ pub struct Config {
     /// Can be overridden per-profile. Supports keyring: prefix for secure storage.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub files_api_key: Option<String>,
+    /// Global resilience settings, used by every profile unless it overrides them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resilience: Option<super::ResilienceConfig>,
+    /// Timestamp normalization defaults for command output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamps: Option<TimestampConfig>,
+    /// Boolean coercion defaults for command output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub booleans: Option<BooleanConfig>,
     /// Map of profile name -> profile configuration
     #[serde(default)]
     pub profiles: HashMap<String, Profile>,
+    /// Inheritance problems found while loading (missing base, cycle), by profile name
+    ///
+    /// Such a profile is loaded with only its own fields, or left out of
+    /// `profiles` when those don't make a complete profile.
+    #[serde(skip)]
+    pub inheritance_errors: BTreeMap<String, String>,
+    /// Profiles left out of `profiles`, written back unchanged on save
+    #[serde(skip)]
+    unresolved_profiles: toml::Table,
+}
+
+/// `[timestamps]` section: rewrite timestamp fields in output as ISO 8601 UTC
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct TimestampConfig {
+    /// Normalize timestamps without passing `--normalize-timestamps`
+    #[serde(default)]
+    pub normalize: bool,
+    /// Field names treated as timestamps (replaces the built-in list when set)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+/// `[booleans]` section: rewrite boolean-ish fields in output as JSON booleans
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct BooleanConfig {
+    /// Coerce booleans without passing `--coerce-bools`
+    #[serde(default)]
+    pub coerce: bool,
+    /// Field names treated as booleans (replaces the built-in list when set)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
 }
 
 /// Individual profile configuration
@@ -47,12 +91,21 @@ pub struct Profile {
     /// Supports keyring: prefix for secure storage.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub files_api_key: Option<String>,
-    /// Resilience configuration for this profile
+    /// Resilience settings for this profile (overrides the global section field by field)
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub resilience: Option<super::ResilienceConfig>,
+    pub resilience: Option<super::ResilienceOverrides>,
     /// Tags for organizing profiles
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    /// Read Enterprise credentials from a mounted operator secret instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kubernetes: Option<super::KubernetesSource>,
+    /// Output format used when `--output` is not given (e.g. "json", "table")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Profile to inherit unset fields from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
 }
 
 /// Supported deployment types
@@ -177,22 +230,16 @@ impl Profile {
                 // Resolve each credential with environment variable fallback
                 let resolved_key = store
                     .get_credential(api_key, Some("REDIS_CLOUD_API_KEY"))
-                    .map_err(|e| {
-                        ConfigError::CredentialError(format!("Failed to resolve API key: {}", e))
-                    })?;
+                    .map_err(credential_context("API key"))?;
                 let resolved_secret = store
                     .get_credential_with_env_vars(
                         api_secret,
                         vec!["REDIS_CLOUD_SECRET_KEY", "REDIS_CLOUD_API_SECRET"],
                     )
-                    .map_err(|e| {
-                        ConfigError::CredentialError(format!("Failed to resolve API secret: {}", e))
-                    })?;
+                    .map_err(credential_context("API secret"))?;
                 let resolved_url = store
                     .get_credential(api_url, Some("REDIS_CLOUD_API_URL"))
-                    .map_err(|e| {
-                        ConfigError::CredentialError(format!("Failed to resolve API URL: {}", e))
-                    })?;
+                    .map_err(credential_context("API URL"))?;
 
                 Ok(Some((resolved_key, resolved_secret, resolved_url)))
             }
@@ -213,30 +260,33 @@ impl Profile {
                 insecure,
                 ca_cert,
             } => {
+                if let Some(kubernetes) = &self.kubernetes {
+                    // Read on every resolution so rotated secrets take effect
+                    let secret = kubernetes.read_secret()?;
+                    return Ok(Some((
+                        secret.url.unwrap_or_else(|| url.clone()),
+                        secret.username,
+                        Some(secret.password),
+                        *insecure,
+                        ca_cert.clone().or(secret.ca_cert),
+                    )));
+                }
+
                 let store = CredentialStore::new();
 
                 // Resolve each credential with environment variable fallback
                 let resolved_url = store
                     .get_credential(url, Some("REDIS_ENTERPRISE_URL"))
-                    .map_err(|e| {
-                        ConfigError::CredentialError(format!("Failed to resolve URL: {}", e))
-                    })?;
+                    .map_err(credential_context("URL"))?;
                 let resolved_username = store
                     .get_credential(username, Some("REDIS_ENTERPRISE_USER"))
-                    .map_err(|e| {
-                        ConfigError::CredentialError(format!("Failed to resolve username: {}", e))
-                    })?;
+                    .map_err(credential_context("username"))?;
                 let resolved_password = password
                     .as_ref()
                     .map(|p| {
                         store
                             .get_credential(p, Some("REDIS_ENTERPRISE_PASSWORD"))
-                            .map_err(|e| {
-                                ConfigError::CredentialError(format!(
-                                    "Failed to resolve password: {}",
-                                    e
-                                ))
-                            })
+                            .map_err(credential_context("password"))
                     })
                     .transpose()?;
 
@@ -292,28 +342,18 @@ impl Profile {
                 let store = CredentialStore::new();
 
                 // Resolve each credential with environment variable fallback
-                let resolved_host =
-                    store
-                        .get_credential(host, Some("REDIS_HOST"))
-                        .map_err(|e| {
-                            ConfigError::CredentialError(format!("Failed to resolve host: {}", e))
-                        })?;
+                let resolved_host = store
+                    .get_credential(host, Some("REDIS_HOST"))
+                    .map_err(credential_context("host"))?;
                 let resolved_username = store
                     .get_credential(username, Some("REDIS_USERNAME"))
-                    .map_err(|e| {
-                        ConfigError::CredentialError(format!("Failed to resolve username: {}", e))
-                    })?;
+                    .map_err(credential_context("username"))?;
                 let resolved_password = password
                     .as_ref()
                     .map(|p| {
                         store
                             .get_credential(p, Some("REDIS_PASSWORD"))
-                            .map_err(|e| {
-                                ConfigError::CredentialError(format!(
-                                    "Failed to resolve password: {}",
-                                    e
-                                ))
-                            })
+                            .map_err(credential_context("password"))
                     })
                     .transpose()?;
 
@@ -531,6 +571,22 @@ impl Config {
         }
     }
 
+    /// Effective resilience settings for a profile
+    ///
+    /// Starts from the global `[resilience]` section (or built-in defaults) and
+    /// applies the profile's own `resilience` overrides on top. Unknown or
+    /// unspecified profiles get the global settings.
+    pub fn resilience_for_profile(&self, profile_name: Option<&str>) -> super::ResilienceConfig {
+        let global = self.resilience.clone().unwrap_or_default();
+        match profile_name
+            .and_then(|name| self.profiles.get(name))
+            .and_then(|profile| profile.resilience.as_ref())
+        {
+            Some(overrides) => global.with_overrides(overrides),
+            None => global,
+        }
+    }
+
     /// Load configuration from the standard location
     pub fn load() -> Result<Self> {
         let config_path = Self::config_path()?;
@@ -551,8 +607,32 @@ impl Config {
         // Expand environment variables in the config content
         let expanded_content = Self::expand_env_vars(&content);
 
-        let config: Config = toml::from_str(&expanded_content)?;
+        Self::from_toml_str(&expanded_content)
+    }
 
+    /// Parse config file content, filling in each profile's inherited fields
+    /// from its `base` profile
+    ///
+    /// A broken `base` doesn't fail the load; it is recorded in
+    /// [`Config::inheritance_errors`] so other profiles stay usable.
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        let mut doc: toml::Table = toml::from_str(content)?;
+        let mut inheritance_errors = BTreeMap::new();
+        let mut unresolved_profiles = toml::Table::new();
+        if let Some(toml::Value::Table(profiles)) = doc.get_mut("profiles") {
+            inheritance_errors = resolve_profile_bases(profiles);
+            for name in inheritance_errors.keys() {
+                let incomplete = profiles
+                    .get(name)
+                    .is_some_and(|own| own.clone().try_into::<Profile>().is_err());
+                if incomplete && let Some(own) = profiles.remove(name) {
+                    unresolved_profiles.insert(name.clone(), own);
+                }
+            }
+        }
+        let mut config: Config = toml::Value::Table(doc).try_into()?;
+        config.inheritance_errors = inheritance_errors;
+        config.unresolved_profiles = unresolved_profiles;
         Ok(config)
     }
 
@@ -563,7 +643,50 @@ impl Config {
     }
 
     /// Save configuration to a specific path
+    ///
+    /// Takes the config file lock (see [`ConfigLock`]) and replaces the file
+    /// atomically, so concurrent writers never leave a partially written file.
+    /// Use [`Config::update_at_path`] for read-modify-write changes, which also
+    /// avoids overwriting another process's update.
     pub fn save_to_path(&self, config_path: &Path) -> Result<()> {
+        let _lock = ConfigLock::acquire(config_path, DEFAULT_LOCK_TIMEOUT)?;
+        self.write_atomic(config_path)
+    }
+
+    /// Apply `update` to the config file at the standard location under the file lock
+    pub fn update<T>(update: impl FnOnce(&mut Config) -> T) -> Result<T> {
+        let config_path = Self::config_path()?;
+        Self::update_at_path(&config_path, update)
+    }
+
+    /// Apply `update` to the config file at `config_path` under the file lock.
+    ///
+    /// The file is re-read after the lock is taken, so changes written by other
+    /// processes since this one started are preserved. Environment variable
+    /// references in the file are kept as written.
+    pub fn update_at_path<T>(
+        config_path: &Path,
+        update: impl FnOnce(&mut Config) -> T,
+    ) -> Result<T> {
+        let _lock = ConfigLock::acquire(config_path, DEFAULT_LOCK_TIMEOUT)?;
+
+        let mut config = if config_path.exists() {
+            let content = fs::read_to_string(config_path).map_err(|e| ConfigError::LoadError {
+                path: config_path.display().to_string(),
+                source: e,
+            })?;
+            Self::from_toml_str(&content)?
+        } else {
+            Config::default()
+        };
+
+        let result = update(&mut config);
+        config.write_atomic(config_path)?;
+        Ok(result)
+    }
+
+    /// Write to a temporary file next to `config_path` and rename it into place
+    fn write_atomic(&self, config_path: &Path) -> Result<()> {
         // Create parent directories if they don't exist
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent).map_err(|e| ConfigError::SaveError {
@@ -572,14 +695,22 @@ impl Config {
             })?;
         }
 
-        let content = toml::to_string_pretty(self)?;
-
-        fs::write(config_path, content).map_err(|e| ConfigError::SaveError {
-            path: config_path.display().to_string(),
-            source: e,
-        })?;
-
-        Ok(())
+        let mut doc = toml::Table::try_from(self)?;
+        if let Some(toml::Value::Table(profiles)) = doc.get_mut("profiles") {
+            strip_inherited_fields(profiles);
+        }
+        if !self.unresolved_profiles.is_empty() {
+            let profiles = doc
+                .entry("profiles")
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if let toml::Value::Table(profiles) = profiles {
+                for (name, own) in &self.unresolved_profiles {
+                    profiles.entry(name.clone()).or_insert_with(|| own.clone());
+                }
+            }
+        }
+        let content = toml::to_string_pretty(&doc)?;
+        replace_file(config_path, &content)
     }
 
     /// Set or update a profile
@@ -599,6 +730,8 @@ impl Config {
         if self.default_database.as_deref() == Some(name) {
             self.default_database = None;
         }
+        self.unresolved_profiles.remove(name);
+        self.inheritance_errors.remove(name);
         self.profiles.remove(name)
     }
 
@@ -671,6 +804,173 @@ impl Config {
     }
 }
 
+/// Write `content` to a temporary file next to `path` and rename it into place
+pub(super) fn replace_file(path: &Path, content: &str) -> Result<()> {
+    let save_error = |e| ConfigError::SaveError {
+        path: path.display().to_string(),
+        source: e,
+    };
+
+    let mut tmp_name = path
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_else(|| "config.toml".into());
+    tmp_name.push(format!(".tmp.{}", std::process::id()));
+    let tmp_path = path.with_file_name(tmp_name);
+
+    fs::write(&tmp_path, content).map_err(save_error)?;
+    // Keep the permissions of the file being replaced (it may hold credentials)
+    if let Ok(metadata) = fs::metadata(path) {
+        fs::set_permissions(&tmp_path, metadata.permissions()).map_err(save_error)?;
+    }
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        save_error(e)
+    })?;
+
+    Ok(())
+}
+
+/// Fill in every profile's unset fields from its `base`, recursively
+///
+/// Profiles whose inheritance can't be resolved keep only their own fields;
+/// their errors are returned by profile name.
+fn resolve_profile_bases(profiles: &mut toml::Table) -> BTreeMap<String, String> {
+    let names: Vec<String> = profiles.keys().cloned().collect();
+    let mut resolved = HashMap::new();
+    let mut errors = BTreeMap::new();
+    for name in &names {
+        match resolve_profile(profiles, name, &mut Vec::new(), &mut resolved) {
+            Ok(table) => {
+                profiles.insert(name.clone(), toml::Value::Table(table));
+            }
+            Err(e) => {
+                errors.insert(name.clone(), e.to_string());
+            }
+        }
+    }
+    errors
+}
+
+/// The profile `name` with its inherited fields filled in
+///
+/// `chain` holds the profiles being resolved, so a profile that is reached
+/// again is reported as a cycle.
+fn resolve_profile(
+    profiles: &toml::Table,
+    name: &str,
+    chain: &mut Vec<String>,
+    resolved: &mut HashMap<String, toml::Table>,
+) -> Result<toml::Table> {
+    if let Some(table) = resolved.get(name) {
+        return Ok(table.clone());
+    }
+    if chain.iter().any(|n| n == name) {
+        chain.push(name.to_string());
+        return Err(ConfigError::InheritanceError(format!(
+            "profile inheritance cycle: {}",
+            chain.join(" -> ")
+        )));
+    }
+
+    let own = match profiles.get(name) {
+        Some(toml::Value::Table(table)) => table.clone(),
+        _ => {
+            let child = chain.last().cloned().unwrap_or_default();
+            return Err(ConfigError::InheritanceError(format!(
+                "profile '{}' has base '{}', which does not exist",
+                child, name
+            )));
+        }
+    };
+
+    let table = match own.get("base") {
+        None => own,
+        Some(toml::Value::String(base)) => {
+            chain.push(name.to_string());
+            let mut inherited = resolve_profile(profiles, base, chain, resolved)?;
+            chain.pop();
+            inherited.remove("base");
+            merge_tables(&mut inherited, own);
+            inherited
+        }
+        Some(_) => {
+            return Err(ConfigError::InheritanceError(format!(
+                "profile '{}' has a 'base' that is not a profile name",
+                name
+            )));
+        }
+    };
+
+    resolved.insert(name.to_string(), table.clone());
+    Ok(table)
+}
+
+/// Overlay `own` on `inherited`; nested tables are merged field by field
+fn merge_tables(inherited: &mut toml::Table, own: toml::Table) {
+    for (key, value) in own {
+        match (inherited.get_mut(&key), value) {
+            (Some(toml::Value::Table(target)), toml::Value::Table(table)) => {
+                merge_tables(target, table)
+            }
+            (_, value) => {
+                inherited.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Drop fields a profile would inherit unchanged from its base, so a saved
+/// config keeps only what each profile overrides. A profile whose base no
+/// longer exists keeps all its fields and loses the `base` key.
+fn strip_inherited_fields(profiles: &mut toml::Table) {
+    let snapshot = profiles.clone();
+    for (name, value) in profiles.iter_mut() {
+        let toml::Value::Table(table) = value else {
+            continue;
+        };
+        let Some(toml::Value::String(base)) = table.get("base") else {
+            continue;
+        };
+        match snapshot.get(base) {
+            Some(toml::Value::Table(base_table)) if base != name => {
+                remove_equal_fields(table, base_table)
+            }
+            _ => {
+                table.remove("base");
+            }
+        }
+    }
+}
+
+/// Remove entries of `table` that `base` has with the same value
+fn remove_equal_fields(table: &mut toml::Table, base: &toml::Table) {
+    table.retain(|key, value| {
+        if key == "base" {
+            return true;
+        }
+        match (value, base.get(key)) {
+            (toml::Value::Table(nested), Some(toml::Value::Table(base_nested))) => {
+                remove_equal_fields(nested, base_nested);
+                !nested.is_empty()
+            }
+            (value, Some(base_value)) => value != base_value,
+            (_, None) => true,
+        }
+    });
+}
+
+/// Wrap a credential resolution error with what was being resolved.
+///
+/// Keyring-unavailable errors pass through unchanged so their recovery
+/// guidance reaches the user.
+fn credential_context(what: &'static str) -> impl Fn(ConfigError) -> ConfigError {
+    move |e| match e {
+        ConfigError::KeyringUnavailable { .. } => e,
+        e => ConfigError::CredentialError(format!("Failed to resolve {}: {}", what, e)),
+    }
+}
+
 fn default_cloud_url() -> String {
     "https://api.redislabs.com/v1".to_string()
 }
@@ -693,6 +993,9 @@ mod tests {
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
 
         config.set_profile("test".to_string(), cloud_profile);
@@ -717,6 +1020,9 @@ mod tests {
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
 
         let (key, secret, url) = cloud_profile.cloud_credentials().unwrap();
@@ -854,6 +1160,9 @@ api_url = "${REDIS_TEST_URL:-https://api.redislabs.com/v1}"
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
         config.set_profile("ent1".to_string(), enterprise_profile);
 
@@ -886,6 +1195,9 @@ api_url = "${REDIS_TEST_URL:-https://api.redislabs.com/v1}"
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
         config.set_profile("cloud1".to_string(), cloud_profile);
 
@@ -918,6 +1230,9 @@ api_url = "${REDIS_TEST_URL:-https://api.redislabs.com/v1}"
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
         config.set_profile("cloud1".to_string(), cloud_profile.clone());
         config.set_profile("cloud2".to_string(), cloud_profile);
@@ -935,6 +1250,9 @@ api_url = "${REDIS_TEST_URL:-https://api.redislabs.com/v1}"
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
         config.set_profile("ent1".to_string(), enterprise_profile.clone());
         config.set_profile("ent2".to_string(), enterprise_profile);
@@ -976,6 +1294,9 @@ api_url = "${REDIS_TEST_URL:-https://api.redislabs.com/v1}"
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
         config.set_profile("cloud1".to_string(), cloud_profile);
 
@@ -1002,6 +1323,9 @@ api_url = "${REDIS_TEST_URL:-https://api.redislabs.com/v1}"
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
 
         config.set_profile("myredis".to_string(), db_profile);
@@ -1044,6 +1368,9 @@ api_url = "${REDIS_TEST_URL:-https://api.redislabs.com/v1}"
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         };
         config.set_profile("db1".to_string(), db_profile);
 
@@ -1093,6 +1420,9 @@ port = 12345
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         }
     }
 
@@ -1109,6 +1439,9 @@ port = 12345
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         }
     }
 
@@ -1203,6 +1536,9 @@ port = 12345
                 files_api_key: None,
                 resilience: None,
                 tags: vec![],
+                kubernetes: None,
+                output: None,
+                base: None,
             },
         );
 
@@ -1210,4 +1546,226 @@ port = 12345
         let err = config.resolve_profile_deployment(None).unwrap_err();
         assert!(err.to_string().contains("No cloud or enterprise"));
     }
+
+    #[test]
+    fn test_profile_resilience_overrides_global() {
+        let toml = r#"
+[resilience.retry]
+max_attempts = 2
+backoff_ms = 250
+
+[resilience.rate_limit]
+enabled = true
+requests_per_minute = 60
+
+[profiles.staging]
+deployment_type = "enterprise"
+url = "https://staging:9443"
+username = "admin"
+
+[profiles.staging.resilience.retry]
+max_attempts = 10
+
+[profiles.staging.resilience.circuit_breaker]
+track = true
+
+[profiles.prod]
+deployment_type = "enterprise"
+url = "https://prod:9443"
+username = "admin"
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+
+        let staging = config.resilience_for_profile(Some("staging"));
+        assert_eq!(staging.retry.max_attempts, 10);
+        // Unset fields fall through to the global section, then to defaults
+        assert_eq!(staging.retry.backoff_ms, 250);
+        assert_eq!(staging.retry.max_backoff_ms, 5000);
+        assert!(staging.rate_limit.enabled);
+        assert_eq!(staging.rate_limit.requests_per_minute, 60);
+        assert!(staging.circuit_breaker.track);
+        assert_eq!(staging.circuit_breaker.window_size, 20);
+
+        let prod = config.resilience_for_profile(Some("prod"));
+        assert_eq!(prod.retry.max_attempts, 2);
+        assert!(!prod.circuit_breaker.track);
+
+        // Overrides round-trip without picking up the merged values
+        let serialized = toml::to_string(&config).unwrap();
+        let reparsed: Config = toml::from_str(&serialized).unwrap();
+        let overrides = reparsed.profiles["staging"].resilience.as_ref().unwrap();
+        assert_eq!(overrides.retry.as_ref().unwrap().max_attempts, Some(10));
+        assert_eq!(overrides.retry.as_ref().unwrap().backoff_ms, None);
+    }
+
+    #[test]
+    fn test_timestamps_section() {
+        let toml = r#"
+[timestamps]
+normalize = true
+fields = ["created_time", "lastSeen"]
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let timestamps = config.timestamps.as_ref().unwrap();
+        assert!(timestamps.normalize);
+        assert_eq!(timestamps.fields, vec!["created_time", "lastSeen"]);
+
+        // Absent section stays absent when written back
+        let serialized = toml::to_string(&Config::default()).unwrap();
+        assert!(!serialized.contains("timestamps"));
+    }
+
+    #[test]
+    fn test_booleans_section() {
+        let toml = r#"
+[booleans]
+coerce = true
+fields = ["replication", "eviction"]
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let booleans = config.booleans.as_ref().unwrap();
+        assert!(booleans.coerce);
+        assert_eq!(booleans.fields, vec!["replication", "eviction"]);
+
+        let serialized = toml::to_string(&Config::default()).unwrap();
+        assert!(!serialized.contains("booleans"));
+    }
+
+    const INHERITING: &str = r#"
+[profiles.ent-base]
+deployment_type = "enterprise"
+url = "https://base:9443"
+username = "admin@redis.local"
+password = "base-secret"
+insecure = true
+ca_cert = "/etc/ca.crt"
+tags = ["prod"]
+
+[profiles.ent-base.resilience.retry]
+max_attempts = 5
+backoff_ms = 200
+
+[profiles.east]
+base = "ent-base"
+url = "https://east:9443"
+
+[profiles.east-slow]
+base = "east"
+
+[profiles.east-slow.resilience.retry]
+max_attempts = 9
+"#;
+
+    #[test]
+    fn test_profile_inherits_from_base() {
+        let config = Config::from_toml_str(INHERITING).unwrap();
+
+        let east = &config.profiles["east"];
+        let (url, username, password, insecure, ca_cert) = east.enterprise_credentials().unwrap();
+        assert_eq!(url, "https://east:9443");
+        assert_eq!(username, "admin@redis.local");
+        assert_eq!(password, Some("base-secret"));
+        assert!(insecure);
+        assert_eq!(ca_cert, Some("/etc/ca.crt"));
+        assert_eq!(east.tags, vec!["prod"]);
+        assert_eq!(east.base.as_deref(), Some("ent-base"));
+
+        // Inheritance is recursive and nested tables merge field by field
+        let slow = config.resilience_for_profile(Some("east-slow"));
+        assert_eq!(slow.retry.max_attempts, 9);
+        assert_eq!(slow.retry.backoff_ms, 200);
+        let (url, ..) = config.profiles["east-slow"]
+            .enterprise_credentials()
+            .unwrap();
+        assert_eq!(url, "https://east:9443");
+    }
+
+    #[test]
+    fn test_cloud_profile_inherits_credentials() {
+        let toml = r#"
+[profiles.cloud-base]
+deployment_type = "cloud"
+api_key = "key"
+api_secret = "secret"
+
+[profiles.staging]
+base = "cloud-base"
+api_url = "https://staging.example.com/v1"
+"#;
+        let config = Config::from_toml_str(toml).unwrap();
+        assert_eq!(
+            config.profiles["staging"].cloud_credentials(),
+            Some(("key", "secret", "https://staging.example.com/v1"))
+        );
+    }
+
+    #[test]
+    fn test_profile_inheritance_errors() {
+        let missing = r#"
+[profiles.a]
+base = "nope"
+
+[profiles.ok]
+deployment_type = "database"
+host = "localhost"
+port = 6379
+"#;
+        let config = Config::from_toml_str(missing).unwrap();
+        // The other profiles still load
+        assert!(config.profiles.contains_key("ok"));
+        assert!(!config.profiles.contains_key("a"));
+        let err = &config.inheritance_errors["a"];
+        assert!(
+            err.contains("profile 'a' has base 'nope', which does not exist"),
+            "got: {err}"
+        );
+
+        let cycle = "[profiles.a]\nbase = \"b\"\n\n[profiles.b]\nbase = \"a\"\n";
+        let config = Config::from_toml_str(cycle).unwrap();
+        let err = &config.inheritance_errors["a"];
+        assert!(err.contains("cycle: a -> b -> a"), "got: {err}");
+        assert!(config.inheritance_errors.contains_key("b"));
+    }
+
+    #[test]
+    fn test_broken_profile_survives_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, "[profiles.a]\nbase = \"nope\"\n").unwrap();
+
+        Config::update_at_path(&path, |config| config.default_cloud = Some("x".into())).unwrap();
+
+        let written: toml::Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["profiles"]["a"]["base"].as_str(), Some("nope"));
+    }
+
+    #[test]
+    fn test_saved_profile_keeps_only_overrides() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        Config::from_toml_str(INHERITING)
+            .unwrap()
+            .save_to_path(&path)
+            .unwrap();
+
+        let written: toml::Table = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let east = written["profiles"]["east"].as_table().unwrap();
+        let mut keys: Vec<&str> = east.keys().map(String::as_str).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["base", "url"]);
+
+        let slow = written["profiles"]["east-slow"].as_table().unwrap();
+        assert_eq!(
+            slow["resilience"]["retry"].as_table().unwrap().len(),
+            1,
+            "got: {slow:?}"
+        );
+
+        // Reloading gives the same effective profiles
+        let reloaded = Config::load_from_path(&path).unwrap();
+        assert_eq!(
+            reloaded.profiles["east"].enterprise_credentials(),
+            Config::from_toml_str(INHERITING).unwrap().profiles["east"].enterprise_credentials()
+        );
+    }
 }
@@ -5,9 +5,15 @@
 //! - OS keyring (when feature enabled)
 //! - Plaintext storage (fallback)
 //! - Environment variable override
+//!
+//! When a credential lives in the keyring but the keyring is unavailable or
+//! locked (common on headless servers), lookups fall back to the environment
+//! variable and then to a registered [`KeyringFallback`] (the CLI prompts),
+//! before failing with [`ConfigError::KeyringUnavailable`].
 
 use super::error::{ConfigError, Result};
 use std::env;
+use std::sync::OnceLock;
 
 /// Prefix that indicates a value should be retrieved from the keyring
 const KEYRING_PREFIX: &str = "keyring:";
@@ -16,6 +22,15 @@ const KEYRING_PREFIX: &str = "keyring:";
 #[cfg(feature = "secure-storage")]
 const SERVICE_NAME: &str = "redisctl";
 
+/// Supplies a credential when the keyring cannot be read.
+///
+/// Called with the keyring key and the reason the keyring is unavailable;
+/// returns `None` to give up.
+pub type KeyringFallback = fn(key: &str, reason: &str) -> Option<String>;
+
+/// Process-wide fallback for unreadable keyring credentials
+static KEYRING_FALLBACK: OnceLock<KeyringFallback> = OnceLock::new();
+
 /// Storage backend for credentials
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -63,6 +78,44 @@ impl CredentialStore {
         }
     }
 
+    /// Register a fallback used when a keyring credential cannot be read
+    /// because the keyring is unavailable or locked.
+    ///
+    /// Only the first call takes effect.
+    pub fn set_keyring_fallback(fallback: KeyringFallback) {
+        let _ = KEYRING_FALLBACK.set(fallback);
+    }
+
+    /// Whether a keyring error means the keyring itself is unusable (no
+    /// Secret Service, locked collection, no session bus), as opposed to a
+    /// problem with one entry.
+    #[cfg(feature = "secure-storage")]
+    fn is_keyring_unavailable(err: &keyring::Error) -> bool {
+        matches!(
+            err,
+            keyring::Error::PlatformFailure(_) | keyring::Error::NoStorageAccess(_)
+        )
+    }
+
+    /// Resolve a keyring credential the keyring could not provide, via the
+    /// registered fallback, or fail with guidance.
+    #[cfg_attr(not(feature = "secure-storage"), allow(dead_code))]
+    fn keyring_unavailable(key: &str, env_var: Option<&str>, reason: String) -> Result<String> {
+        tracing::warn!(
+            "OS keyring unavailable ({}); falling back for credential '{}'",
+            reason,
+            key
+        );
+        if let Some(value) = KEYRING_FALLBACK.get().and_then(|f| f(key, &reason)) {
+            return Ok(value);
+        }
+        Err(ConfigError::KeyringUnavailable {
+            key: key.to_string(),
+            env_var: env_var.map(str::to_string),
+            reason,
+        })
+    }
+
     /// Check if keyring is available on this system
     #[cfg(feature = "secure-storage")]
     fn is_keyring_available() -> bool {
@@ -87,10 +140,18 @@ impl CredentialStore {
                     let entry = keyring::Entry::new(SERVICE_NAME, key)
                         .map_err(|e| ConfigError::KeyringError(e.to_string()))?;
                     entry.set_password(value).map_err(|e| {
-                        ConfigError::KeyringError(format!(
-                            "Failed to store credential in keyring: {}",
-                            e
-                        ))
+                        if Self::is_keyring_unavailable(&e) {
+                            ConfigError::KeyringUnavailable {
+                                key: key.to_string(),
+                                env_var: None,
+                                reason: e.to_string(),
+                            }
+                        } else {
+                            ConfigError::KeyringError(format!(
+                                "Failed to store credential in keyring: {}",
+                                e
+                            ))
+                        }
                     })?;
                     // Return the reference string that will be stored in config
                     Ok(format!("{}{}", KEYRING_PREFIX, key))
@@ -119,8 +180,12 @@ impl CredentialStore {
     /// Retrieve a credential value with support for multiple environment variable aliases.
     ///
     /// Environment variables are checked in order, and the first set value wins.
+    ///
+    /// If the value is a keyring reference and the keyring is unavailable or
+    /// locked, the registered [`KeyringFallback`] is tried before failing with
+    /// [`ConfigError::KeyringUnavailable`].
     pub fn get_credential_with_env_vars(&self, value: &str, env_vars: Vec<&str>) -> Result<String> {
-        for var in env_vars {
+        for var in &env_vars {
             if let Ok(env_value) = env::var(var) {
                 return Ok(env_value);
             }
@@ -131,14 +196,24 @@ impl CredentialStore {
             #[cfg(feature = "secure-storage")]
             {
                 let key = value.trim_start_matches(KEYRING_PREFIX);
-                let entry = keyring::Entry::new(SERVICE_NAME, key)
-                    .map_err(|e| ConfigError::KeyringError(e.to_string()))?;
-                entry.get_password().map_err(|e| {
-                    ConfigError::KeyringError(format!(
+                let env_var = env_vars.first().copied();
+                let entry = match keyring::Entry::new(SERVICE_NAME, key) {
+                    Ok(entry) => entry,
+                    Err(e) if Self::is_keyring_unavailable(&e) => {
+                        return Self::keyring_unavailable(key, env_var, e.to_string());
+                    }
+                    Err(e) => return Err(ConfigError::KeyringError(e.to_string())),
+                };
+                match entry.get_password() {
+                    Ok(password) => Ok(password),
+                    Err(e) if Self::is_keyring_unavailable(&e) => {
+                        Self::keyring_unavailable(key, env_var, e.to_string())
+                    }
+                    Err(e) => Err(ConfigError::KeyringError(format!(
                         "Failed to retrieve credential '{}' from keyring: {}",
                         key, e
-                    ))
-                })
+                    ))),
+                }
             }
             #[cfg(not(feature = "secure-storage"))]
             {
@@ -276,6 +351,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_keyring_unavailable_message_names_env_var() {
+        let err = ConfigError::KeyringUnavailable {
+            key: "prod-password".to_string(),
+            env_var: Some("REDIS_ENTERPRISE_PASSWORD".to_string()),
+            reason: "collection is locked".to_string(),
+        };
+        let message = err.to_string();
+        assert!(message.contains("unavailable or locked (collection is locked)"));
+        assert!(message.contains("'prod-password'"));
+        assert!(message.contains("Set REDIS_ENTERPRISE_PASSWORD"));
+    }
+
     #[test]
     fn test_keyring_reference_detection() {
         assert!(CredentialStore::is_keyring_reference("keyring:my-key"));
@@ -41,12 +41,42 @@ pub enum ConfigError {
     #[error("Keyring error: {0}")]
     KeyringError(String),
 
+    #[error(
+        "OS keyring is unavailable or locked ({reason}), so credential '{key}' cannot be read. {}",
+        keyring_guidance(env_var.as_deref())
+    )]
+    KeyringUnavailable {
+        key: String,
+        env_var: Option<String>,
+        reason: String,
+    },
+
     #[error("Environment variable expansion failed: {0}")]
     EnvExpansionError(String),
 
     #[error("ambiguous deployment type — both cloud and enterprise profiles exist")]
     AmbiguousDeployment,
 
+    #[error(
+        "Timed out after {timeout_secs}s waiting for the lock on {path}; another redisctl process is modifying the config"
+    )]
+    LockTimeout { path: String, timeout_secs: u64 },
+
+    #[error("Config migration failed: {0}")]
+    MigrationError(String),
+
+    #[error("Invalid {field}: {reason}")]
+    InvalidField {
+        /// The flag or config key holding the bad value (e.g. `--url`)
+        field: String,
+        reason: String,
+        /// How to correct the value
+        suggestion: String,
+    },
+
+    #[error("Invalid profile inheritance: {0}")]
+    InheritanceError(String),
+
     #[error("Failed to determine config directory")]
     ConfigDirError,
 
@@ -54,5 +84,16 @@ pub enum ConfigError {
     IoError(#[from] std::io::Error),
 }
 
+/// Recovery hint for [`ConfigError::KeyringUnavailable`]
+fn keyring_guidance(env_var: Option<&str>) -> String {
+    let unlock = "unlock the keyring for this session (on headless Linux, start a Secret Service \
+                  daemon such as gnome-keyring-daemon --unlock), or re-save the profile without \
+                  --use-keyring";
+    match env_var {
+        Some(var) => format!("Set {var} to provide it, {unlock}"),
+        None => format!("To fix, {unlock}"),
+    }
+}
+
 /// Result type for configuration operations
 pub type Result<T> = std::result::Result<T, ConfigError>;
@@ -0,0 +1,177 @@
+//! Enterprise credentials from a mounted Redis Enterprise operator secret
+//!
+//! The operator keeps the cluster admin credentials in a Kubernetes secret
+//! that pods can mount as files: `username`, `password`, and optionally `url`
+//! and `ca.crt`. A profile with a `kubernetes` section reads those files every
+//! time its credentials are resolved instead of storing them in the config,
+//! so rotated secrets are picked up without editing the profile:
+//!
+//! ```toml
+//! [profiles.in-cluster]
+//! deployment_type = "enterprise"
+//! url = "https://rec.redis.svc.cluster.local:9443"
+//! username = "admin@redis.local"
+//!
+//! [profiles.in-cluster.kubernetes]
+//! secret_path = "/var/run/secrets/redis-enterprise"
+//! namespace = "redis"
+//! cluster = "rec"
+//! ```
+//!
+//! The `url` and `username` stored in the profile are only used when the
+//! secret does not provide them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::error::{ConfigError, Result};
+
+/// Default mount point for the operator admin secret
+pub const DEFAULT_SECRET_PATH: &str = "/var/run/secrets/redis-enterprise";
+
+/// Namespace file mounted into every pod with a service account
+pub const SERVICE_ACCOUNT_NAMESPACE_FILE: &str =
+    "/var/run/secrets/kubernetes.io/serviceaccount/namespace";
+
+/// Where a profile finds its operator-managed credentials
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct KubernetesSource {
+    /// Directory the admin secret is mounted at (default `/var/run/secrets/redis-enterprise`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_path: Option<String>,
+    /// Namespace of the RedisEnterpriseCluster (defaults to the pod's namespace)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// RedisEnterpriseCluster name, used to derive the API URL when the
+    /// secret has no `url` file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<String>,
+}
+
+/// Credentials read from an operator secret
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperatorSecret {
+    /// From the secret's `url` file, or derived from the cluster name and namespace
+    pub url: Option<String>,
+    pub username: String,
+    pub password: String,
+    /// Path to the secret's `ca.crt`, when present
+    pub ca_cert: Option<String>,
+}
+
+impl KubernetesSource {
+    /// Directory holding the mounted secret
+    pub fn secret_dir(&self) -> PathBuf {
+        PathBuf::from(self.secret_path.as_deref().unwrap_or(DEFAULT_SECRET_PATH))
+    }
+
+    /// Configured namespace, or the namespace of the pod this runs in
+    pub fn namespace(&self) -> Option<String> {
+        self.namespace
+            .clone()
+            .or_else(|| read_trimmed(Path::new(SERVICE_ACCOUNT_NAMESPACE_FILE)))
+    }
+
+    /// Read the secret as it is mounted right now
+    pub fn read_secret(&self) -> Result<OperatorSecret> {
+        read_operator_secret(
+            &self.secret_dir(),
+            self.cluster.as_deref(),
+            self.namespace().as_deref(),
+        )
+    }
+}
+
+/// Read an operator secret from `secret_dir`, deriving the in-cluster service
+/// URL from `cluster` and `namespace` when the secret has no `url` file
+pub fn read_operator_secret(
+    secret_dir: &Path,
+    cluster: Option<&str>,
+    namespace: Option<&str>,
+) -> Result<OperatorSecret> {
+    let required = |file: &str| {
+        read_trimmed(&secret_dir.join(file)).ok_or_else(|| {
+            ConfigError::CredentialError(format!(
+                "operator secret at {} has no '{}' file",
+                secret_dir.display(),
+                file
+            ))
+        })
+    };
+    let username = required("username")?;
+    let password = required("password")?;
+
+    let url = read_trimmed(&secret_dir.join("url")).or_else(|| match (cluster, namespace) {
+        (Some(cluster), Some(namespace)) => Some(format!(
+            "https://{}.{}.svc.cluster.local:9443",
+            cluster, namespace
+        )),
+        _ => None,
+    });
+
+    let ca_path = secret_dir.join("ca.crt");
+    let ca_cert = ca_path
+        .is_file()
+        .then(|| ca_path.to_string_lossy().into_owned());
+
+    Ok(OperatorSecret {
+        url,
+        username,
+        password,
+        ca_cert,
+    })
+}
+
+/// Read a secret file, ignoring surrounding whitespace; empty files count as absent
+fn read_trimmed(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_secret_picks_up_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("username"), "admin@redis.local\n").unwrap();
+        fs::write(dir.path().join("password"), "first").unwrap();
+
+        let source = KubernetesSource {
+            secret_path: Some(dir.path().to_string_lossy().into_owned()),
+            namespace: Some("redis".to_string()),
+            cluster: Some("rec".to_string()),
+        };
+        let secret = source.read_secret().unwrap();
+        assert_eq!(secret.username, "admin@redis.local");
+        assert_eq!(secret.password, "first");
+        assert_eq!(
+            secret.url.as_deref(),
+            Some("https://rec.redis.svc.cluster.local:9443")
+        );
+        assert_eq!(secret.ca_cert, None);
+
+        // The operator rewrites the mounted files when the secret rotates
+        fs::write(dir.path().join("password"), "second").unwrap();
+        fs::write(dir.path().join("url"), "https://rec-api.example.com:9443").unwrap();
+        let secret = source.read_secret().unwrap();
+        assert_eq!(secret.password, "second");
+        assert_eq!(
+            secret.url.as_deref(),
+            Some("https://rec-api.example.com:9443")
+        );
+    }
+
+    #[test]
+    fn test_read_secret_requires_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("username"), "admin").unwrap();
+
+        let err = read_operator_secret(dir.path(), None, None).unwrap_err();
+        assert!(err.to_string().contains("'password'"), "got: {err}");
+    }
+}
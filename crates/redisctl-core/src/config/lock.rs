@@ -0,0 +1,116 @@
+//! Advisory locking for config file writes
+//!
+//! Writers take an exclusive OS lock on a sidecar `<config>.lock` file before
+//! touching the config, so concurrent `redisctl` processes (for example parallel
+//! `profile set` calls in CI) serialize instead of interleaving writes. The
+//! sidecar is used rather than the config file itself because saves replace the
+//! config file by renaming a temporary file over it.
+//!
+//! The lock is advisory: it only coordinates processes that also take it.
+
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::error::{ConfigError, Result};
+
+/// How long a writer waits for another process to release the config lock
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Interval between lock attempts while waiting
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An exclusive lock on a config file, released when dropped
+#[derive(Debug)]
+pub struct ConfigLock {
+    // Closing the file releases the OS lock
+    _file: File,
+    path: PathBuf,
+}
+
+impl ConfigLock {
+    /// Lock `config_path`, waiting up to `timeout` for other holders to finish
+    pub fn acquire(config_path: &Path, timeout: Duration) -> Result<Self> {
+        let path = lock_path(config_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| ConfigError::SaveError {
+                path: parent.display().to_string(),
+                source: e,
+            })?;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(|e| ConfigError::SaveError {
+                path: path.display().to_string(),
+                source: e,
+            })?;
+
+        let start = Instant::now();
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(Self { _file: file, path }),
+                Err(TryLockError::WouldBlock) if start.elapsed() < timeout => {
+                    thread::sleep(LOCK_RETRY_INTERVAL);
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(ConfigError::LockTimeout {
+                        path: config_path.display().to_string(),
+                        timeout_secs: timeout.as_secs(),
+                    });
+                }
+                Err(TryLockError::Error(e)) => {
+                    return Err(ConfigError::SaveError {
+                        path: path.display().to_string(),
+                        source: e,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Path of the sidecar lock file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Sidecar lock file for `config_path`, e.g. `config.toml.lock`
+fn lock_path(config_path: &Path) -> PathBuf {
+    let mut name = config_path
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_else(|| "config.toml".into());
+    name.push(".lock");
+    config_path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_path() {
+        assert_eq!(
+            lock_path(Path::new("/tmp/redisctl/config.toml")),
+            PathBuf::from("/tmp/redisctl/config.toml.lock")
+        );
+    }
+
+    #[test]
+    fn test_contended_lock_times_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.toml");
+
+        let held = ConfigLock::acquire(&config_path, DEFAULT_LOCK_TIMEOUT).unwrap();
+        let err = ConfigLock::acquire(&config_path, Duration::from_millis(100)).unwrap_err();
+        assert!(matches!(err, ConfigError::LockTimeout { .. }));
+
+        drop(held);
+        assert!(ConfigLock::acquire(&config_path, Duration::from_millis(100)).is_ok());
+    }
+}
@@ -0,0 +1,246 @@
+//! Configuration file schema migrations
+//!
+//! Config files carry an optional top-level `version` key. Files without one are
+//! classified by their layout: legacy files (using keys that have since been
+//! replaced) are version 0, anything else is treated as version 1.
+//!
+//! Migrations operate on the raw TOML document rather than on [`Config`] so that
+//! keys the current schema no longer understands can still be read and rewritten.
+//! Environment variable references such as `${REDIS_CLOUD_API_KEY}` are left
+//! untouched.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use toml::{Table, Value};
+
+use super::config::Config;
+use super::error::{ConfigError, Result};
+
+/// The config schema version written by this release
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
+/// A single step in the migration chain, upgrading `from` to `from + 1`
+struct Migration {
+    from: u32,
+    description: &'static str,
+    apply: fn(&mut Table) -> Result<()>,
+}
+
+/// Ordered migration chain; each entry upgrades the document by one version
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    description: "Replace legacy 'default_profile' with type-specific default_cloud/default_enterprise/default_database",
+    apply: migrate_v0_default_profile,
+}];
+
+/// Outcome of a config migration
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationReport {
+    /// Schema version detected in the original file
+    pub from_version: u32,
+    /// Schema version after migration
+    pub to_version: u32,
+    /// Descriptions of the migrations that were applied, in order
+    pub applied: Vec<String>,
+    /// Where the original file was backed up, if a backup was written
+    pub backup_path: Option<PathBuf>,
+}
+
+impl MigrationReport {
+    /// Whether any migration was applied
+    pub fn changed(&self) -> bool {
+        !self.applied.is_empty()
+    }
+}
+
+/// Detect the schema version of a raw config document
+pub fn detect_version(doc: &Table) -> u32 {
+    if let Some(version) = doc.get("version").and_then(Value::as_integer) {
+        return u32::try_from(version).unwrap_or(0);
+    }
+
+    if doc.contains_key("default_profile") {
+        0
+    } else {
+        1
+    }
+}
+
+/// Apply all pending migrations to a raw config document in place
+pub fn migrate_document(doc: &mut Table) -> Result<MigrationReport> {
+    let from_version = detect_version(doc);
+    if from_version > CURRENT_CONFIG_VERSION {
+        return Err(ConfigError::MigrationError(format!(
+            "config version {} is newer than this release supports ({}); upgrade redisctl",
+            from_version, CURRENT_CONFIG_VERSION
+        )));
+    }
+
+    let mut applied = Vec::new();
+    let mut version = from_version;
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= from_version) {
+        (migration.apply)(doc)?;
+        applied.push(migration.description.to_string());
+        version = migration.from + 1;
+    }
+
+    if !applied.is_empty() {
+        doc.insert("version".to_string(), Value::Integer(version.into()));
+    }
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: version,
+        applied,
+        backup_path: None,
+    })
+}
+
+/// v0 -> v1: a single `default_profile` was split into per-deployment defaults
+fn migrate_v0_default_profile(doc: &mut Table) -> Result<()> {
+    let Some(legacy) = doc.remove("default_profile") else {
+        return Ok(());
+    };
+    let Some(name) = legacy.as_str() else {
+        return Err(ConfigError::MigrationError(
+            "'default_profile' must be a string".to_string(),
+        ));
+    };
+
+    let deployment_type = doc
+        .get("profiles")
+        .and_then(|p| p.get(name))
+        .and_then(|p| p.get("deployment_type"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            ConfigError::MigrationError(format!(
+                "'default_profile' refers to unknown profile '{}'",
+                name
+            ))
+        })?;
+
+    let key = format!("default_{}", deployment_type);
+    if !doc.contains_key(&key) {
+        doc.insert(key, Value::String(name.to_string()));
+    }
+
+    Ok(())
+}
+
+impl Config {
+    /// Migrate the config file at `path` to the current schema version.
+    ///
+    /// When migrations are needed the original file is copied to
+    /// `<path>.v<version>.bak` (if `backup` is set) before the upgraded file
+    /// is written. With `dry_run` nothing is written and the report describes
+    /// what would change.
+    pub fn migrate_file(path: &Path, backup: bool, dry_run: bool) -> Result<MigrationReport> {
+        let content = fs::read_to_string(path).map_err(|e| ConfigError::LoadError {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        let mut doc: Table = toml::from_str(&content)?;
+
+        let mut report = migrate_document(&mut doc)?;
+        if !report.changed() || dry_run {
+            return Ok(report);
+        }
+
+        // Make sure the result is loadable before touching the file
+        let migrated = toml::to_string_pretty(&doc)?;
+        Config::from_toml_str(&migrated)?;
+
+        if backup {
+            let backup_path =
+                PathBuf::from(format!("{}.v{}.bak", path.display(), report.from_version));
+            fs::copy(path, &backup_path).map_err(|e| ConfigError::SaveError {
+                path: backup_path.display().to_string(),
+                source: e,
+            })?;
+            report.backup_path = Some(backup_path);
+        }
+
+        fs::write(path, migrated).map_err(|e| ConfigError::SaveError {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEGACY: &str = r#"
+default_profile = "prod"
+
+[profiles.prod]
+deployment_type = "enterprise"
+url = "https://cluster:9443"
+username = "admin"
+password = "${RE_PASSWORD}"
+"#;
+
+    #[test]
+    fn test_detect_version() {
+        let legacy: Table = toml::from_str(LEGACY).unwrap();
+        assert_eq!(detect_version(&legacy), 0);
+
+        let current: Table = toml::from_str("default_cloud = \"c\"").unwrap();
+        assert_eq!(detect_version(&current), 1);
+
+        let explicit: Table = toml::from_str("version = 1").unwrap();
+        assert_eq!(detect_version(&explicit), 1);
+    }
+
+    #[test]
+    fn test_migrate_legacy_default_profile() {
+        let mut doc: Table = toml::from_str(LEGACY).unwrap();
+        let report = migrate_document(&mut doc).unwrap();
+
+        assert_eq!(report.from_version, 0);
+        assert_eq!(report.to_version, CURRENT_CONFIG_VERSION);
+        assert_eq!(report.applied.len(), 1);
+        assert!(!doc.contains_key("default_profile"));
+        assert_eq!(doc["default_enterprise"].as_str(), Some("prod"));
+        assert_eq!(doc["version"].as_integer(), Some(1));
+    }
+
+    #[test]
+    fn test_migrate_current_is_noop() {
+        let mut doc: Table = toml::from_str("default_cloud = \"c\"").unwrap();
+        let report = migrate_document(&mut doc).unwrap();
+        assert!(!report.changed());
+        assert!(!doc.contains_key("version"));
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_version() {
+        let mut doc: Table = toml::from_str("version = 99").unwrap();
+        assert!(migrate_document(&mut doc).is_err());
+    }
+
+    #[test]
+    fn test_migrate_file_writes_backup_and_preserves_env_refs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, LEGACY).unwrap();
+
+        let report = Config::migrate_file(&path, true, false).unwrap();
+
+        let backup = report.backup_path.unwrap();
+        assert_eq!(fs::read_to_string(backup).unwrap(), LEGACY);
+
+        let migrated = fs::read_to_string(&path).unwrap();
+        assert!(migrated.contains("${RE_PASSWORD}"));
+        assert!(!migrated.contains("default_profile"));
+
+        let config = Config::load_from_path(&path).unwrap();
+        assert_eq!(config.default_enterprise.as_deref(), Some("prod"));
+        assert_eq!(config.version, Some(1));
+    }
+}
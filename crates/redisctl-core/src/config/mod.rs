@@ -18,10 +18,21 @@
 pub mod config;
 pub mod credential;
 pub mod error;
+pub mod kubernetes;
+pub mod lock;
+pub mod migration;
 pub mod resilience;
 
 // Re-export main types for convenience
-pub use config::{Config, DeploymentType, Profile, ProfileCredentials};
-pub use credential::{CredentialStorage, CredentialStore};
+pub use config::{
+    BooleanConfig, Config, DeploymentType, Profile, ProfileCredentials, TimestampConfig,
+};
+pub use credential::{CredentialStorage, CredentialStore, KeyringFallback};
 pub use error::{ConfigError, Result};
-pub use resilience::ResilienceConfig;
+pub use kubernetes::{KubernetesSource, OperatorSecret};
+pub use lock::ConfigLock;
+pub use migration::{CURRENT_CONFIG_VERSION, MigrationReport};
+pub use resilience::{
+    CircuitBreakerMetrics, CircuitBreakerState, CircuitBreakerStore, CircuitState,
+    ResilienceConfig, ResilienceOverrides,
+};
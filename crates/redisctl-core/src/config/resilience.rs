@@ -3,8 +3,17 @@
 //! This module defines configuration structures for resilience patterns
 //! (circuit breaker, retry, rate limiting) that can be stored in profiles.
 
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
 
+use super::config::replace_file;
+use super::error::{ConfigError, Result};
+use super::lock::{ConfigLock, DEFAULT_LOCK_TIMEOUT};
+
 /// Configuration for resilience patterns
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResilienceConfig {
@@ -39,6 +48,11 @@ pub struct CircuitBreakerConfig {
     /// Duration in seconds to wait before attempting to close the circuit
     #[serde(default = "default_reset_timeout")]
     pub reset_timeout_secs: u64,
+
+    /// Record call outcomes across invocations so `profile show` can report
+    /// the breaker state (off unless set)
+    #[serde(default)]
+    pub track: bool,
 }
 
 impl Default for CircuitBreakerConfig {
@@ -48,6 +62,7 @@ impl Default for CircuitBreakerConfig {
             failure_threshold: 0.5,
             window_size: 20,
             reset_timeout_secs: 60,
+            track: false,
         }
     }
 }
@@ -83,6 +98,29 @@ impl Default for RetryConfig {
     }
 }
 
+impl RetryConfig {
+    /// Delay before retry number `retry` (1-based): the initial backoff,
+    /// doubled for every retry after the first and capped at `max_backoff_ms`
+    pub fn backoff_delay(&self, retry: u32) -> Duration {
+        let factor = 1u64
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u64::MAX);
+        let delay = self
+            .backoff_ms
+            .saturating_mul(factor)
+            .min(self.max_backoff_ms);
+        Duration::from_millis(delay)
+    }
+
+    /// Delays between attempts. `max_attempts` counts the first call, so a
+    /// request is retried `max_attempts - 1` times.
+    pub fn backoff_schedule(&self) -> Vec<Duration> {
+        (1..self.max_attempts)
+            .map(|retry| self.backoff_delay(retry))
+            .collect()
+    }
+}
+
 /// Rate limiting configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimitConfig {
@@ -136,3 +174,419 @@ fn default_max_backoff_ms() -> u64 {
 fn default_requests_per_minute() -> u32 {
     100
 }
+
+/// Per-profile resilience settings layered over the global `[resilience]` section
+///
+/// Every field is optional; anything left unset falls through to the global
+/// value, so a profile only needs to name what it changes:
+///
+/// ```toml
+/// [profiles.staging.resilience.retry]
+/// max_attempts = 10
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResilienceOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuit_breaker: Option<CircuitBreakerOverrides>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryOverrides>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitOverrides>,
+}
+
+/// Optional circuit breaker settings for a profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreakerOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_threshold: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_size: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub track: Option<bool>,
+}
+
+/// Optional retry settings for a profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RetryOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backoff_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_backoff_ms: Option<u64>,
+}
+
+/// Optional rate limiting settings for a profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requests_per_minute: Option<u32>,
+}
+
+impl ResilienceConfig {
+    /// Return a copy of this config with the profile's overrides applied
+    pub fn with_overrides(&self, overrides: &ResilienceOverrides) -> Self {
+        let mut merged = self.clone();
+
+        if let Some(cb) = &overrides.circuit_breaker {
+            let target = &mut merged.circuit_breaker;
+            target.enabled = cb.enabled.unwrap_or(target.enabled);
+            target.failure_threshold = cb.failure_threshold.unwrap_or(target.failure_threshold);
+            target.window_size = cb.window_size.unwrap_or(target.window_size);
+            target.reset_timeout_secs = cb.reset_timeout_secs.unwrap_or(target.reset_timeout_secs);
+            target.track = cb.track.unwrap_or(target.track);
+        }
+
+        if let Some(retry) = &overrides.retry {
+            let target = &mut merged.retry;
+            target.enabled = retry.enabled.unwrap_or(target.enabled);
+            target.max_attempts = retry.max_attempts.unwrap_or(target.max_attempts);
+            target.backoff_ms = retry.backoff_ms.unwrap_or(target.backoff_ms);
+            target.max_backoff_ms = retry.max_backoff_ms.unwrap_or(target.max_backoff_ms);
+        }
+
+        if let Some(rl) = &overrides.rate_limit {
+            let target = &mut merged.rate_limit;
+            target.enabled = rl.enabled.unwrap_or(target.enabled);
+            target.requests_per_minute =
+                rl.requests_per_minute.unwrap_or(target.requests_per_minute);
+        }
+
+        merged
+    }
+}
+
+/// Position of a circuit breaker
+///
+/// The breaker only observes: an open circuit is reported, but commands are
+/// still sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CircuitState {
+    /// Outcomes are counted
+    Closed,
+    /// Too many recent calls failed; outcomes are ignored until the reset
+    /// timeout has passed
+    Open,
+    /// The reset timeout has passed; the next call decides whether to close
+    HalfOpen,
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half-open"),
+        }
+    }
+}
+
+/// Recorded call outcomes for one profile's circuit breaker
+///
+/// Each CLI invocation is its own process, so the state lives in a file next
+/// to the config (see [`CircuitBreakerStore`]) instead of in memory. Times are
+/// Unix seconds.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreakerState {
+    /// Most recent outcomes, oldest first; `true` marks a failure
+    #[serde(default)]
+    pub window: Vec<bool>,
+    /// When the circuit opened, while it is open or half-open
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opened_at: Option<u64>,
+    /// When the circuit last opened
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_trip: Option<u64>,
+    /// How many times the circuit has opened
+    #[serde(default)]
+    pub trips: u32,
+}
+
+/// Snapshot of a circuit breaker, as reported by `profile show`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CircuitBreakerMetrics {
+    pub state: CircuitState,
+    /// Failures among the calls in the window
+    pub failures: u32,
+    /// Calls in the window
+    pub calls: u32,
+    pub failure_rate: f32,
+    pub trips: u32,
+    pub last_trip: Option<u64>,
+    /// Seconds until an open circuit lets a trial call through
+    pub retry_in_secs: Option<u64>,
+}
+
+impl CircuitBreakerState {
+    /// Current position, given the time now
+    pub fn state(&self, config: &CircuitBreakerConfig, now: u64) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened) if now.saturating_sub(opened) >= config.reset_timeout_secs => {
+                CircuitState::HalfOpen
+            }
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    /// Record the outcome of a call
+    ///
+    /// The circuit opens once a full window reaches the failure threshold. A
+    /// trial call while half-open closes it again on success and reopens it
+    /// on failure.
+    pub fn record(&mut self, failed: bool, config: &CircuitBreakerConfig, now: u64) {
+        match self.state(config, now) {
+            CircuitState::Open => return,
+            CircuitState::HalfOpen if failed => {
+                self.trip(now);
+                return;
+            }
+            CircuitState::HalfOpen => {
+                self.opened_at = None;
+                self.window.clear();
+            }
+            CircuitState::Closed => {}
+        }
+
+        let size = config.window_size.max(1) as usize;
+        self.window.push(failed);
+        if self.window.len() > size {
+            let excess = self.window.len() - size;
+            self.window.drain(..excess);
+        }
+        if self.window.len() == size && self.failure_rate() >= config.failure_threshold {
+            self.trip(now);
+        }
+    }
+
+    /// Metrics for display and monitoring
+    pub fn metrics(&self, config: &CircuitBreakerConfig, now: u64) -> CircuitBreakerMetrics {
+        let state = self.state(config, now);
+        let retry_in_secs = match (state, self.opened_at) {
+            (CircuitState::Open, Some(opened)) => {
+                Some((opened + config.reset_timeout_secs).saturating_sub(now))
+            }
+            _ => None,
+        };
+        CircuitBreakerMetrics {
+            state,
+            failures: self.failures(),
+            calls: self.window.len() as u32,
+            failure_rate: self.failure_rate(),
+            trips: self.trips,
+            last_trip: self.last_trip,
+            retry_in_secs,
+        }
+    }
+
+    fn failures(&self) -> u32 {
+        self.window.iter().filter(|failed| **failed).count() as u32
+    }
+
+    fn failure_rate(&self) -> f32 {
+        if self.window.is_empty() {
+            0.0
+        } else {
+            self.failures() as f32 / self.window.len() as f32
+        }
+    }
+
+    fn trip(&mut self, now: u64) {
+        self.opened_at = Some(now);
+        self.last_trip = Some(now);
+        self.trips += 1;
+    }
+}
+
+/// Circuit breaker states for every profile, kept in `circuit-breakers.json`
+/// next to the config file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CircuitBreakerStore {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, CircuitBreakerState>,
+}
+
+impl CircuitBreakerStore {
+    /// State file for the config at `config_path`
+    pub fn path_for(config_path: &Path) -> PathBuf {
+        config_path.with_file_name("circuit-breakers.json")
+    }
+
+    /// Load the store; a missing or unreadable file starts every breaker closed
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Apply `update` to the store kept next to the config at `config_path`
+    ///
+    /// Runs under the config file lock and replaces the state file atomically,
+    /// so concurrent commands neither lose each other's outcomes nor leave a
+    /// partially written file.
+    pub fn update(config_path: &Path, update: impl FnOnce(&mut Self)) -> Result<()> {
+        let _lock = ConfigLock::acquire(config_path, DEFAULT_LOCK_TIMEOUT)?;
+        let path = Self::path_for(config_path);
+        let mut store = Self::load(&path);
+        update(&mut store);
+        let content = serde_json::to_string_pretty(&store).map_err(|e| ConfigError::SaveError {
+            path: path.display().to_string(),
+            source: std::io::Error::other(e),
+        })?;
+        replace_file(&path, &content)
+    }
+
+    /// State for `profile`, closed if nothing has been recorded
+    pub fn get(&self, profile: &str) -> CircuitBreakerState {
+        self.profiles.get(profile).cloned().unwrap_or_default()
+    }
+}
+
+/// Current Unix time in seconds
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker_config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            window_size: 4,
+            failure_threshold: 0.5,
+            reset_timeout_secs: 60,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_circuit_opens_on_full_window_over_threshold() {
+        let config = breaker_config();
+        let mut state = CircuitBreakerState::default();
+
+        state.record(true, &config, 100);
+        state.record(true, &config, 101);
+        // Two failures, but the window is not full yet
+        assert_eq!(state.state(&config, 102), CircuitState::Closed);
+
+        state.record(false, &config, 102);
+        state.record(false, &config, 103);
+        assert_eq!(state.state(&config, 103), CircuitState::Open);
+
+        let metrics = state.metrics(&config, 113);
+        assert_eq!(metrics.failures, 2);
+        assert_eq!(metrics.calls, 4);
+        assert_eq!(metrics.trips, 1);
+        assert_eq!(metrics.last_trip, Some(103));
+        assert_eq!(metrics.retry_in_secs, Some(50));
+    }
+
+    #[test]
+    fn test_half_open_trial_call() {
+        let config = breaker_config();
+        let mut state = CircuitBreakerState {
+            window: vec![true; 4],
+            opened_at: Some(100),
+            last_trip: Some(100),
+            trips: 1,
+        };
+        assert_eq!(state.state(&config, 160), CircuitState::HalfOpen);
+
+        // A failed trial reopens the circuit
+        state.record(true, &config, 160);
+        assert_eq!(state.state(&config, 161), CircuitState::Open);
+        assert_eq!(state.trips, 2);
+
+        // A successful trial closes it and starts a fresh window
+        state.record(false, &config, 220);
+        assert_eq!(state.state(&config, 220), CircuitState::Closed);
+        assert_eq!(state.metrics(&config, 220).calls, 1);
+    }
+
+    #[test]
+    fn test_store_update_writes_state_next_to_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.toml");
+        let config = breaker_config();
+
+        for now in [100, 101] {
+            CircuitBreakerStore::update(&config_path, |store| {
+                store
+                    .profiles
+                    .entry("prod".to_string())
+                    .or_default()
+                    .record(true, &config, now);
+            })
+            .unwrap();
+        }
+
+        let store = CircuitBreakerStore::load(&CircuitBreakerStore::path_for(&config_path));
+        assert_eq!(store.get("prod").window, vec![true, true]);
+        assert!(!config.track, "tracking is opt-in");
+    }
+
+    #[test]
+    fn test_backoff_schedule_doubles_up_to_cap() {
+        let retry = RetryConfig {
+            max_attempts: 6,
+            backoff_ms: 200,
+            max_backoff_ms: 1000,
+            ..Default::default()
+        };
+        let delays: Vec<u128> = retry
+            .backoff_schedule()
+            .iter()
+            .map(Duration::as_millis)
+            .collect();
+        assert_eq!(delays, vec![200, 400, 800, 1000, 1000]);
+
+        // Large retry counts saturate instead of overflowing
+        assert_eq!(retry.backoff_delay(200), Duration::from_millis(1000));
+        assert!(
+            RetryConfig {
+                max_attempts: 1,
+                ..Default::default()
+            }
+            .backoff_schedule()
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = CircuitBreakerStore::path_for(&dir.path().join("config.toml"));
+        assert_eq!(
+            CircuitBreakerStore::load(&path),
+            CircuitBreakerStore::default()
+        );
+
+        let mut store = CircuitBreakerStore::default();
+        store.profiles.insert(
+            "prod".to_string(),
+            CircuitBreakerState {
+                window: vec![false, true],
+                ..Default::default()
+            },
+        );
+        store.save(&path).unwrap();
+        assert_eq!(CircuitBreakerStore::load(&path), store);
+        assert_eq!(store.get("staging"), CircuitBreakerState::default());
+    }
+}
@@ -10,6 +10,11 @@
 //!
 //! - `poll_action` - Generic action polling with progress callbacks
 //! - `upgrade_database_and_wait` - Upgrade a database and wait for completion
+//! - `export_database_and_wait` - Export a database and wait for completion
+//! - `wait_for_database_active` - Wait for a database to become active
+//! - `join_cluster_and_wait` - Join a node to a cluster and wait until it is active
+//! - `collect_debug_info_and_wait` - Collect debug info and wait for the download
+//! - `cluster_summary` - Condensed cluster overview (counts, license, alerts)
 //!
 //! ## Example
 //!
@@ -31,11 +36,17 @@
 //! ```
 
 pub mod progress;
+pub mod summary;
 pub mod workflows;
 
 // Re-export key types for convenience
-pub use progress::{EnterpriseProgressCallback, EnterpriseProgressEvent, poll_action};
+pub use progress::{
+    EnterpriseProgressCallback, EnterpriseProgressEvent, EtaEstimator, poll_action,
+};
+pub use summary::cluster_summary;
 pub use workflows::{
-    DEFAULT_INTERVAL, DEFAULT_TIMEOUT, backup_database_and_wait, flush_database_and_wait,
-    import_database_and_wait, upgrade_database_and_wait, upgrade_module_and_wait,
+    DEFAULT_INTERVAL, DEFAULT_TIMEOUT, backup_database_and_wait, collect_debug_info_and_wait,
+    ensure_node_not_clustered, export_database_and_wait, flush_database_and_wait,
+    import_database_and_wait, join_cluster_and_wait, upgrade_database_and_wait,
+    upgrade_module_and_wait, wait_for_database_active,
 };
@@ -20,6 +20,8 @@ pub enum EnterpriseProgressEvent {
         status: String,
         progress: Option<f32>,
         elapsed: Duration,
+        /// Estimated time remaining, once the reported progress has advanced
+        eta: Option<Duration>,
     },
     /// Action completed successfully
     Completed { action_uid: String },
@@ -84,6 +86,7 @@ pub async fn poll_action(
 ) -> Result<Action> {
     let start = Instant::now();
     let handler = client.actions();
+    let mut estimator = EtaEstimator::default();
 
     emit(
         &on_progress,
@@ -108,6 +111,9 @@ pub async fn poll_action(
                 status: status.clone(),
                 progress: action.progress,
                 elapsed,
+                eta: action
+                    .progress
+                    .and_then(|pct| estimator.update(pct, elapsed)),
             },
         );
 
@@ -144,9 +150,67 @@ pub async fn poll_action(
     }
 }
 
+/// Estimates the time left for an action from how fast its progress advances
+///
+/// The rate is measured from the first sample that reported progress, so time
+/// the action spent queued before it started moving does not skew the estimate.
+#[derive(Debug, Clone, Default)]
+pub struct EtaEstimator {
+    baseline: Option<(f32, Duration)>,
+}
+
+impl EtaEstimator {
+    /// Record a progress percentage seen at `elapsed` and return the estimated
+    /// time remaining, or `None` until progress has moved past the first sample
+    pub fn update(&mut self, progress: f32, elapsed: Duration) -> Option<Duration> {
+        let progress = progress.clamp(0.0, 100.0);
+        let (start_pct, start_elapsed) = *self.baseline.get_or_insert((progress, elapsed));
+        let advanced = progress - start_pct;
+        if advanced <= 0.0 {
+            return None;
+        }
+        let spent = elapsed.saturating_sub(start_elapsed).as_secs_f64();
+        let remaining = spent * f64::from(100.0 - progress) / f64::from(advanced);
+        Some(Duration::from_secs_f64(remaining))
+    }
+}
+
 /// Helper to emit progress events
 fn emit(callback: &Option<EnterpriseProgressCallback>, event: EnterpriseProgressEvent) {
     if let Some(cb) = callback {
         cb(event);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eta_waits_for_progress_to_move() {
+        let mut estimator = EtaEstimator::default();
+        assert_eq!(estimator.update(0.0, Duration::from_secs(5)), None);
+        // Still queued: no rate yet
+        assert_eq!(estimator.update(0.0, Duration::from_secs(10)), None);
+        // 25% in 20s since the first sample leaves 60s for the remaining 75%
+        assert_eq!(
+            estimator.update(25.0, Duration::from_secs(25)),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            estimator.update(100.0, Duration::from_secs(85)),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_eta_ignores_time_before_first_sample() {
+        let mut estimator = EtaEstimator::default();
+        // The action had been queued for a minute before it reported 50%
+        assert_eq!(estimator.update(50.0, Duration::from_secs(60)), None);
+        assert_eq!(
+            estimator.update(60.0, Duration::from_secs(70)),
+            Some(Duration::from_secs(40))
+        );
+    }
+}
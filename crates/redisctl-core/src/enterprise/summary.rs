@@ -0,0 +1,118 @@
+//! Condensed cluster overview shared by the CLI and the MCP server
+
+use crate::error::Result;
+use redis_enterprise::EnterpriseClient;
+use serde_json::Value;
+
+/// Fetch a condensed cluster summary: identity, version, node and database
+/// counts, license status, and the number of raised cluster alerts
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redisctl_core::enterprise::cluster_summary;
+///
+/// let summary = cluster_summary(&client).await?;
+/// println!("{} databases", summary["databases"]);
+/// ```
+pub async fn cluster_summary(client: &EnterpriseClient) -> Result<Value> {
+    let cluster = client.get_raw("/v1/cluster").await?;
+    let nodes = client.get_raw("/v1/nodes").await?;
+    let databases = client.get_raw("/v1/bdbs").await?;
+    let license = client.get_raw("/v1/license").await?;
+    let alerts = client.get_raw("/v1/cluster/alerts").await?;
+
+    Ok(build_cluster_summary(
+        &cluster, &nodes, &databases, &license, &alerts,
+    ))
+}
+
+/// Compose the summary from the individual API responses
+fn build_cluster_summary(
+    cluster: &Value,
+    nodes: &Value,
+    databases: &Value,
+    license: &Value,
+    alerts: &Value,
+) -> Value {
+    let node_list = nodes.as_array().map(Vec::as_slice).unwrap_or_default();
+
+    // The cluster object may not carry a version; fall back to the first node's
+    let version = cluster
+        .get("software_version")
+        .or_else(|| node_list.first().and_then(|n| n.get("software_version")))
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    let nodes_active = node_list
+        .iter()
+        .filter(|n| n["status"].as_str() == Some("active"))
+        .count();
+
+    serde_json::json!({
+        "name": cluster.get("name"),
+        "version": version,
+        "nodes": node_list.len(),
+        "nodes_active": nodes_active,
+        "databases": databases.as_array().map(|d| d.len()).unwrap_or(0),
+        "license_expired": license
+            .get("expired")
+            .or_else(|| cluster.get("license_expired")),
+        "license_expiration": license.get("expiration_date"),
+        "active_alerts": count_raised_alerts(alerts),
+    })
+}
+
+/// Count raised alerts. Cluster alerts come back as a map of alert name to
+/// settings, where only entries whose `state` is true are raised; a list
+/// counts every entry not explicitly cleared.
+fn count_raised_alerts(alerts: &Value) -> usize {
+    match alerts {
+        Value::Array(list) => list
+            .iter()
+            .filter(|a| a["state"].as_bool() != Some(false))
+            .count(),
+        Value::Object(map) => map
+            .values()
+            .filter(|a| a["state"].as_bool() == Some(true))
+            .count(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_cluster_summary() {
+        let summary = build_cluster_summary(
+            &json!({"name": "prod", "license_expired": false}),
+            &json!([
+                {"uid": 1, "status": "active", "software_version": "7.4.2"},
+                {"uid": 2, "status": "down"}
+            ]),
+            &json!([{"uid": 1}, {"uid": 2}, {"uid": 3}]),
+            &json!({"expired": true, "expiration_date": "2026-01-01T00:00:00Z"}),
+            &json!({
+                "node_failed": {"state": true},
+                "high_memory": {"state": false, "threshold": "80"}
+            }),
+        );
+
+        assert_eq!(summary["name"], "prod");
+        assert_eq!(summary["version"], "7.4.2");
+        assert_eq!(summary["nodes"], 2);
+        assert_eq!(summary["nodes_active"], 1);
+        assert_eq!(summary["databases"], 3);
+        assert_eq!(summary["license_expired"], true);
+        assert_eq!(summary["active_alerts"], 1);
+    }
+
+    #[test]
+    fn test_count_raised_alerts_in_list() {
+        let alerts = json!([{"name": "a"}, {"name": "b", "state": false}]);
+        assert_eq!(count_raised_alerts(&alerts), 1);
+    }
+}
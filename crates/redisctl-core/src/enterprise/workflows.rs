@@ -5,11 +5,17 @@
 //! - Validate inputs before making API calls
 //! - Progress reporting for long-running operations
 
-use crate::enterprise::progress::{EnterpriseProgressCallback, poll_action};
-use crate::error::Result;
+use crate::enterprise::progress::{
+    EnterpriseProgressCallback, EnterpriseProgressEvent, poll_action,
+};
+use crate::error::{CoreError, Result};
+use crate::progress::{PollStatus, StatusCallback, poll_until};
 use redis_enterprise::bdb::DatabaseUpgradeRequest;
+use redis_enterprise::cluster::ClusterHandler;
+use redis_enterprise::debuginfo::{DebugInfoHandler, DebugInfoRequest};
 use redis_enterprise::{Database, EnterpriseClient};
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Default timeout for Enterprise async operations (10 minutes)
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(600);
@@ -182,6 +188,61 @@ pub async fn import_database_and_wait(
     Ok(())
 }
 
+/// Export an Enterprise database and wait for completion
+///
+/// # Arguments
+///
+/// * `client` - The Enterprise API client
+/// * `bdb_uid` - The database UID to export
+/// * `export_location` - The location to export to (S3, FTP, SFTP, or local path)
+/// * `timeout` - Maximum time to wait for completion
+/// * `on_progress` - Optional callback for progress updates
+///
+/// # Errors
+///
+/// If the export action fails or is cancelled, returns
+/// [`CoreError::TaskFailed`] carrying the action's error description.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redisctl_core::enterprise::export_database_and_wait;
+/// use std::time::Duration;
+///
+/// export_database_and_wait(
+///     &client,
+///     1,
+///     "s3://bucket/backup.rdb",
+///     Duration::from_secs(600),
+///     None,
+/// ).await?;
+/// ```
+pub async fn export_database_and_wait(
+    client: &EnterpriseClient,
+    bdb_uid: u32,
+    export_location: &str,
+    timeout: Duration,
+    on_progress: Option<EnterpriseProgressCallback>,
+) -> Result<()> {
+    // Start export
+    let response = client.databases().export(bdb_uid, export_location).await?;
+
+    // Poll until completion if we got an action_uid
+    if let Some(action_uid) = response.action_uid {
+        poll_action(client, &action_uid, timeout, DEFAULT_INTERVAL, on_progress)
+            .await
+            .map_err(|e| match e {
+                CoreError::TaskFailed(error) => CoreError::TaskFailed(format!(
+                    "Export of database {} failed: {}",
+                    bdb_uid, error
+                )),
+                other => other,
+            })?;
+    }
+
+    Ok(())
+}
+
 /// Flush all data from an Enterprise database and wait for completion
 ///
 /// WARNING: This permanently deletes all data in the database!
@@ -213,3 +274,374 @@ pub async fn flush_database_and_wait(
 
     Ok(())
 }
+
+/// Classify a database status for [`wait_for_database_active`]
+fn database_readiness(status: &str) -> PollStatus<()> {
+    match status {
+        "active" => PollStatus::Ready(()),
+        "creation-failed" => PollStatus::Failed("database creation failed".to_string()),
+        "delete-pending" => PollStatus::Failed("database is being deleted".to_string()),
+        "recovery" => PollStatus::Failed(
+            "database is in recovery state and needs 'database recover'".to_string(),
+        ),
+        // 'pending', 'active-change-pending', 'import-pending', ...
+        other => PollStatus::Pending(other.to_string()),
+    }
+}
+
+/// Wait for a database to reach `active` status
+///
+/// Useful when the database was created or changed by another tool. Fails
+/// early if the database enters a state it cannot become active from.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redisctl_core::enterprise::wait_for_database_active;
+/// use std::time::Duration;
+///
+/// let db = wait_for_database_active(
+///     &client,
+///     1,
+///     Duration::from_secs(300),
+///     Duration::from_secs(5),
+///     None,
+/// ).await?;
+/// ```
+pub async fn wait_for_database_active(
+    client: &EnterpriseClient,
+    bdb_uid: u32,
+    timeout: Duration,
+    interval: Duration,
+    on_pending: Option<StatusCallback>,
+) -> Result<Database> {
+    poll_until(
+        timeout,
+        interval,
+        || async move {
+            let db = client.databases().get(bdb_uid).await?;
+            let status = db.status.as_deref().unwrap_or("unknown");
+            Ok(match database_readiness(status) {
+                PollStatus::Ready(()) => PollStatus::Ready(db),
+                PollStatus::Failed(e) => PollStatus::Failed(e),
+                PollStatus::Pending(s) => PollStatus::Pending(s),
+            })
+        },
+        on_pending,
+    )
+    .await
+}
+
+/// Classify a node's `GET /v1/bootstrap` response while it joins a cluster
+fn bootstrap_readiness(bootstrap: &serde_json::Value) -> PollStatus<()> {
+    let status = &bootstrap["bootstrap_status"];
+    match status["state"].as_str().unwrap_or("unknown") {
+        "completed" => PollStatus::Ready(()),
+        "error" => PollStatus::Failed(
+            status["error"]
+                .as_str()
+                .unwrap_or("node bootstrap failed")
+                .to_string(),
+        ),
+        // 'idle', 'initiated', 'in_progress', ...
+        other => PollStatus::Pending(other.to_string()),
+    }
+}
+
+/// Classify a cluster's `GET /v1/nodes` response: ready once every node is active
+fn nodes_readiness(nodes: &serde_json::Value) -> PollStatus<()> {
+    let nodes = nodes.as_array().map(Vec::as_slice).unwrap_or_default();
+    let active = nodes
+        .iter()
+        .filter(|n| n["status"].as_str() == Some("active"))
+        .count();
+    if !nodes.is_empty() && active == nodes.len() {
+        PollStatus::Ready(())
+    } else {
+        PollStatus::Pending(format!("{} of {} nodes active", active, nodes.len()))
+    }
+}
+
+/// Fail if the node behind `client` has already been bootstrapped
+///
+/// A node that created or joined a cluster reports bootstrap state
+/// `completed`. Nodes whose bootstrap status cannot be read are let through;
+/// the join request itself reports any remaining problem.
+pub async fn ensure_node_not_clustered(client: &EnterpriseClient) -> Result<()> {
+    if let Ok(bootstrap) = client.get_raw("/v1/bootstrap").await
+        && bootstrap["bootstrap_status"]["state"].as_str() == Some("completed")
+    {
+        return Err(CoreError::Validation(
+            "Node already belongs to a cluster; remove it from that cluster before joining another"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Join a node to an existing cluster and wait until the node is active
+///
+/// `client` must point at the node being joined (not at the existing
+/// cluster). This workflow:
+/// 1. Fails early if the node already belongs to a cluster
+/// 2. Submits the join request with the cluster's address and admin credentials
+/// 3. Polls the returned action, or the node's bootstrap status if the
+///    cluster returned none, until the join completes
+/// 4. Waits until the cluster reports every node, including the new one, active
+///
+/// Returns the cluster's node list.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redisctl_core::enterprise::join_cluster_and_wait;
+/// use std::time::Duration;
+///
+/// let nodes = join_cluster_and_wait(
+///     &new_node_client,
+///     "10.0.0.1",
+///     "admin@example.com",
+///     "password",
+///     Duration::from_secs(600),
+///     None,
+/// ).await?;
+/// ```
+pub async fn join_cluster_and_wait(
+    client: &EnterpriseClient,
+    cluster_address: &str,
+    username: &str,
+    password: &str,
+    timeout: Duration,
+    on_progress: Option<EnterpriseProgressCallback>,
+) -> Result<serde_json::Value> {
+    let deadline = Instant::now() + timeout;
+    let on_progress: Option<Arc<dyn Fn(EnterpriseProgressEvent) + Send + Sync>> =
+        on_progress.map(Arc::from);
+
+    ensure_node_not_clustered(client).await?;
+
+    let response = ClusterHandler::new(client.clone())
+        .join_node(cluster_address, username, password)
+        .await?;
+
+    let emit_polling = |phase: &'static str| -> Option<StatusCallback> {
+        on_progress.clone().map(|cb| {
+            Box::new(move |state: &str, elapsed: Duration| {
+                cb(EnterpriseProgressEvent::Polling {
+                    action_uid: phase.to_string(),
+                    status: state.to_string(),
+                    progress: None,
+                    elapsed,
+                    eta: None,
+                })
+            }) as StatusCallback
+        })
+    };
+
+    match response["action_uid"].as_str() {
+        Some(action_uid) => {
+            let forward = on_progress.clone().map(|cb| {
+                Box::new(move |event: EnterpriseProgressEvent| cb(event))
+                    as EnterpriseProgressCallback
+            });
+            poll_action(
+                client,
+                action_uid,
+                deadline.saturating_duration_since(Instant::now()),
+                DEFAULT_INTERVAL,
+                forward,
+            )
+            .await?;
+        }
+        None => {
+            poll_until(
+                deadline.saturating_duration_since(Instant::now()),
+                DEFAULT_INTERVAL,
+                || async move {
+                    // The node's API restarts while it joins; keep polling through errors
+                    Ok(match client.get_raw("/v1/bootstrap").await {
+                        Ok(bootstrap) => bootstrap_readiness(&bootstrap),
+                        Err(_) => PollStatus::Pending("restarting".to_string()),
+                    })
+                },
+                emit_polling("join"),
+            )
+            .await?;
+        }
+    }
+
+    poll_until(
+        deadline.saturating_duration_since(Instant::now()),
+        DEFAULT_INTERVAL,
+        || async move {
+            Ok(match client.get_raw("/v1/nodes").await {
+                Ok(nodes) => match nodes_readiness(&nodes) {
+                    PollStatus::Ready(()) => PollStatus::Ready(nodes),
+                    PollStatus::Failed(e) => PollStatus::Failed(e),
+                    PollStatus::Pending(s) => PollStatus::Pending(s),
+                },
+                Err(_) => PollStatus::Pending("waiting for cluster API".to_string()),
+            })
+        },
+        emit_polling("nodes"),
+    )
+    .await
+    .map_err(|e| match e {
+        CoreError::TaskFailed(error) => {
+            CoreError::TaskFailed(format!("Node join failed: {}", error))
+        }
+        other => other,
+    })
+}
+
+/// Classify a `GET /v1/debuginfo/{task_id}` response
+fn debug_info_readiness(status: &serde_json::Value) -> PollStatus<()> {
+    match status["status"].as_str().unwrap_or("unknown") {
+        "completed" => PollStatus::Ready(()),
+        "failed" | "error" => PollStatus::Failed(
+            status["error"]
+                .as_str()
+                .unwrap_or("debug info collection failed")
+                .to_string(),
+        ),
+        other => match status["progress"].as_f64() {
+            Some(progress) => PollStatus::Pending(format!("{} ({:.0}%)", other, progress)),
+            None => PollStatus::Pending(other.to_string()),
+        },
+    }
+}
+
+/// Start a debug info collection and wait for it to finish
+///
+/// This workflow:
+/// 1. Submits the collection request
+/// 2. Polls the task status until it completes or fails
+///
+/// Returns the final task status, including `download_url` when the cluster
+/// provides one.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redisctl_core::enterprise::collect_debug_info_and_wait;
+/// use redis_enterprise::debuginfo::DebugInfoRequest;
+/// use std::time::Duration;
+///
+/// let status = collect_debug_info_and_wait(
+///     &client,
+///     request,
+///     Duration::from_secs(600),
+///     None,
+/// ).await?;
+/// println!("{}", status["download_url"]);
+/// ```
+pub async fn collect_debug_info_and_wait(
+    client: &EnterpriseClient,
+    request: DebugInfoRequest,
+    timeout: Duration,
+    on_pending: Option<StatusCallback>,
+) -> Result<serde_json::Value> {
+    let handler = DebugInfoHandler::new(client.clone());
+    let created = serde_json::to_value(handler.create(request).await?).unwrap_or_default();
+    let task_id = created["task_id"]
+        .as_str()
+        .ok_or_else(|| {
+            CoreError::TaskFailed("Debug info collection returned no task_id".to_string())
+        })?
+        .to_string();
+
+    let handler = &handler;
+    let task_id = task_id.as_str();
+    poll_until(
+        timeout,
+        DEFAULT_INTERVAL,
+        || async move {
+            let status = serde_json::to_value(handler.status(task_id).await?).unwrap_or_default();
+            Ok(match debug_info_readiness(&status) {
+                PollStatus::Ready(()) => PollStatus::Ready(status),
+                PollStatus::Failed(e) => PollStatus::Failed(e),
+                PollStatus::Pending(s) => PollStatus::Pending(s),
+            })
+        },
+        on_pending,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_info_readiness() {
+        assert_eq!(
+            debug_info_readiness(&serde_json::json!({"status": "completed"})),
+            PollStatus::Ready(())
+        );
+        assert_eq!(
+            debug_info_readiness(&serde_json::json!({"status": "running", "progress": 45.0})),
+            PollStatus::Pending("running (45%)".to_string())
+        );
+        assert_eq!(
+            debug_info_readiness(&serde_json::json!({"status": "failed", "error": "disk full"})),
+            PollStatus::Failed("disk full".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bootstrap_readiness() {
+        let state = |s: &str| serde_json::json!({"bootstrap_status": {"state": s}});
+        assert_eq!(
+            bootstrap_readiness(&state("completed")),
+            PollStatus::Ready(())
+        );
+        assert!(matches!(
+            bootstrap_readiness(&state("initiated")),
+            PollStatus::Pending(_)
+        ));
+        assert_eq!(
+            bootstrap_readiness(&serde_json::json!({
+                "bootstrap_status": {"state": "error", "error": "invalid credentials"}
+            })),
+            PollStatus::Failed("invalid credentials".to_string())
+        );
+    }
+
+    #[test]
+    fn test_nodes_readiness() {
+        let nodes = serde_json::json!([
+            {"uid": 1, "status": "active"},
+            {"uid": 2, "status": "provisioning"}
+        ]);
+        assert_eq!(
+            nodes_readiness(&nodes),
+            PollStatus::Pending("1 of 2 nodes active".to_string())
+        );
+        let nodes = serde_json::json!([
+            {"uid": 1, "status": "active"},
+            {"uid": 2, "status": "active"}
+        ]);
+        assert_eq!(nodes_readiness(&nodes), PollStatus::Ready(()));
+        assert!(matches!(
+            nodes_readiness(&serde_json::json!([])),
+            PollStatus::Pending(_)
+        ));
+    }
+
+    #[test]
+    fn test_database_readiness() {
+        assert_eq!(database_readiness("active"), PollStatus::Ready(()));
+        assert!(matches!(
+            database_readiness("active-change-pending"),
+            PollStatus::Pending(_)
+        ));
+        assert!(matches!(
+            database_readiness("pending"),
+            PollStatus::Pending(_)
+        ));
+        assert!(matches!(
+            database_readiness("creation-failed"),
+            PollStatus::Failed(_)
+        ));
+    }
+}
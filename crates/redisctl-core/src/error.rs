@@ -40,6 +40,10 @@ pub enum CoreError {
     #[error("Task timed out after {0:?}")]
     TaskTimeout(Duration),
 
+    /// Polling gave up after the configured number of attempts
+    #[error("Gave up after {attempts} poll attempts; last status: {last_status}")]
+    PollLimitExceeded { attempts: u32, last_status: String },
+
     /// Task failed during async operation
     #[error("Task failed: {0}")]
     TaskFailed(String),
@@ -93,7 +97,7 @@ impl CoreError {
         match self {
             CoreError::Cloud(e) => e.is_timeout(),
             CoreError::Enterprise(e) => e.is_timeout(),
-            CoreError::TaskTimeout(_) => true,
+            CoreError::TaskTimeout(_) | CoreError::PollLimitExceeded { .. } => true,
             _ => false,
         }
     }
@@ -135,7 +139,8 @@ impl CoreError {
         match self {
             CoreError::Cloud(e) => e.is_retryable(),
             CoreError::Enterprise(e) => e.is_retryable(),
-            CoreError::TaskTimeout(_) => true, // Timeout might succeed on retry
+            // Timeout might succeed on retry
+            CoreError::TaskTimeout(_) | CoreError::PollLimitExceeded { .. } => true,
             _ => false,
         }
     }
@@ -7,6 +7,7 @@
 //! - **Progress callbacks** - For Cloud's async task polling
 //! - **Module resolution** - Validate Enterprise modules before creation
 //! - **Workflows** - Multi-step operations (create + wait, etc.)
+//! - **Test support** - Mock servers and mock-backed clients (`test-support` feature)
 //!
 //! ## Philosophy
 //!
@@ -65,6 +66,13 @@
 //!     })),
 //! ).await?;
 //! ```
+//!
+//! # Testing
+//!
+//! Enable the `test-support` feature in `[dev-dependencies]` to test code built
+//! on this crate without a live deployment. The `testing` module re-exports the
+//! Layer 1 mock servers and fixtures, and provides `cloud_client` /
+//! `enterprise_client` to point a client at any mock HTTP server.
 
 pub mod config;
 pub mod error;
@@ -73,14 +81,21 @@ pub mod progress;
 pub mod cloud;
 pub mod enterprise;
 
+#[cfg(feature = "test-support")]
+pub mod testing;
+
 // Re-export commonly used items
 pub use error::{CoreError, Result};
-pub use progress::{ProgressCallback, ProgressEvent, poll_task};
+pub use progress::{
+    PollOptions, PollSchedule, PollStatus, ProgressCallback, ProgressEvent, StatusCallback,
+    poll_task, poll_task_with_options, poll_until,
+};
 
 // Re-export config types for convenience
 pub use config::{
-    Config, ConfigError, CredentialStorage, CredentialStore, DeploymentType, Profile,
-    ProfileCredentials, ResilienceConfig,
+    BooleanConfig, CircuitBreakerMetrics, CircuitState, Config, ConfigError, CredentialStorage,
+    CredentialStore, DeploymentType, KubernetesSource, Profile, ProfileCredentials,
+    ResilienceConfig, ResilienceOverrides, TimestampConfig,
 };
 
 // Re-export Layer 1 for convenience (but consumers can also import directly)
@@ -7,6 +7,7 @@
 use crate::error::{CoreError, Result};
 use redis_cloud::tasks::TaskStateUpdate;
 use redis_cloud::{CloudClient, TaskHandler};
+use std::future::Future;
 use std::time::{Duration, Instant};
 
 /// Progress events emitted during async operations
@@ -19,6 +20,8 @@ pub enum ProgressEvent {
         task_id: String,
         status: String,
         elapsed: Duration,
+        /// Wait before the next check if the task is still running
+        next_interval: Duration,
     },
     /// Task completed successfully
     Completed {
@@ -35,6 +38,104 @@ pub enum ProgressEvent {
 /// MCP typically doesn't need this.
 pub type ProgressCallback = Box<dyn Fn(ProgressEvent) + Send + Sync>;
 
+/// How long to wait between polling attempts
+///
+/// A plain [`Duration`] converts into [`PollSchedule::Fixed`], so existing
+/// callers passing an interval keep working.
+///
+/// # Example
+///
+/// ```rust
+/// use redisctl_core::PollSchedule;
+/// use std::time::Duration;
+///
+/// // 2s, 4s, 8s, ... capped at 30s
+/// let schedule = PollSchedule::Exponential {
+///     base: Duration::from_secs(2),
+///     factor: 2.0,
+///     max: Duration::from_secs(30),
+/// };
+/// assert_eq!(schedule.interval(1), Duration::from_secs(2));
+/// assert_eq!(schedule.interval(3), Duration::from_secs(8));
+/// assert_eq!(schedule.interval(10), Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PollSchedule {
+    /// Wait the same time between every attempt
+    Fixed(Duration),
+    /// Start at `base`, multiply by `factor` after each attempt, never exceed `max`
+    Exponential {
+        base: Duration,
+        factor: f64,
+        max: Duration,
+    },
+}
+
+impl PollSchedule {
+    /// Wait after the `attempt`-th check (1-based)
+    pub fn interval(&self, attempt: u32) -> Duration {
+        match *self {
+            PollSchedule::Fixed(interval) => interval,
+            PollSchedule::Exponential { base, factor, max } => {
+                let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+                let secs = base.as_secs_f64() * factor.max(1.0).powi(exponent);
+                if secs.is_finite() && secs < max.as_secs_f64() {
+                    Duration::from_secs_f64(secs)
+                } else {
+                    max
+                }
+            }
+        }
+    }
+}
+
+impl From<Duration> for PollSchedule {
+    fn from(interval: Duration) -> Self {
+        PollSchedule::Fixed(interval)
+    }
+}
+
+/// Limits for [`poll_task_with_options`]
+///
+/// # Example
+///
+/// ```rust
+/// use redisctl_core::PollOptions;
+/// use std::time::Duration;
+///
+/// // Poll every 10s for up to 10 minutes, but never more than 30 times
+/// let options = PollOptions::new(Duration::from_secs(600), Duration::from_secs(10))
+///     .max_attempts(30);
+/// assert_eq!(options.max_attempts, Some(30));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollOptions {
+    /// Maximum time to wait for completion
+    pub timeout: Duration,
+    /// Time between polling attempts
+    pub interval: PollSchedule,
+    /// Maximum number of status checks; `None` bounds polling by `timeout` only
+    pub max_attempts: Option<u32>,
+}
+
+impl PollOptions {
+    /// Poll on `interval` until `timeout`, with no attempt limit
+    pub fn new(timeout: Duration, interval: impl Into<PollSchedule>) -> Self {
+        Self {
+            timeout,
+            interval: interval.into(),
+            max_attempts: None,
+        }
+    }
+
+    /// Stop after `attempts` status checks, whichever of this and the timeout comes first
+    #[must_use]
+    pub fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+}
+
 /// Poll a Cloud task until completion
 ///
 /// # Arguments
@@ -42,7 +143,7 @@ pub type ProgressCallback = Box<dyn Fn(ProgressEvent) + Send + Sync>;
 /// * `client` - The Cloud API client
 /// * `task_id` - The task ID to poll
 /// * `timeout` - Maximum time to wait for completion
-/// * `interval` - Time between polling attempts
+/// * `interval` - Time between polling attempts: a [`Duration`] or a [`PollSchedule`]
 /// * `on_progress` - Optional callback for progress updates
 ///
 /// # Returns
@@ -82,10 +183,37 @@ pub async fn poll_task(
     client: &CloudClient,
     task_id: &str,
     timeout: Duration,
-    interval: Duration,
+    interval: impl Into<PollSchedule>,
     on_progress: Option<ProgressCallback>,
 ) -> Result<TaskStateUpdate> {
+    poll_task_with_options(
+        client,
+        task_id,
+        PollOptions::new(timeout, interval),
+        on_progress,
+    )
+    .await
+}
+
+/// Poll a Cloud task until completion, with limits from [`PollOptions`]
+///
+/// Behaves like [`poll_task`], but when `options.max_attempts` is set it stops
+/// after that many status checks with [`CoreError::PollLimitExceeded`], which
+/// records the last observed status. Each attempt still emits
+/// [`ProgressEvent::Polling`].
+pub async fn poll_task_with_options(
+    client: &CloudClient,
+    task_id: &str,
+    options: PollOptions,
+    on_progress: Option<ProgressCallback>,
+) -> Result<TaskStateUpdate> {
+    let PollOptions {
+        timeout,
+        interval,
+        max_attempts,
+    } = options;
     let start = Instant::now();
+    let mut attempts: u32 = 0;
     let handler = TaskHandler::new(client.clone());
 
     emit(
@@ -103,6 +231,8 @@ pub async fn poll_task(
 
         let task = handler.get_task_by_id(task_id.to_string()).await?;
         let status = task.status.clone().unwrap_or_default();
+        attempts += 1;
+        let next_interval = interval.interval(attempts);
 
         emit(
             &on_progress,
@@ -110,6 +240,7 @@ pub async fn poll_task(
                 task_id: task_id.to_string(),
                 status: status.clone(),
                 elapsed,
+                next_interval,
             },
         );
 
@@ -144,19 +275,95 @@ pub async fn poll_task(
                 );
                 return Err(CoreError::TaskFailed(error));
             }
-            // Cancelled state
-            "cancelled" => {
+            // Cancelled or aborted states
+            "cancelled" | "aborted" => {
+                let error = format!("Task was {}", status.to_lowercase());
                 emit(
                     &on_progress,
                     ProgressEvent::Failed {
                         task_id: task_id.to_string(),
-                        error: "Task was cancelled".to_string(),
+                        error: error.clone(),
                     },
                 );
-                return Err(CoreError::TaskFailed("Task was cancelled".to_string()));
+                return Err(CoreError::TaskFailed(error));
             }
             _ => {
+                if max_attempts.is_some_and(|max| attempts >= max) {
+                    return Err(CoreError::PollLimitExceeded {
+                        attempts,
+                        last_status: status,
+                    });
+                }
                 // Still processing, wait and try again
+                tokio::time::sleep(next_interval).await;
+            }
+        }
+    }
+}
+
+/// Result of a single check made by [`poll_until`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PollStatus<T> {
+    /// The condition holds; polling stops and returns this value
+    Ready(T),
+    /// The condition can no longer be met; polling stops with this error
+    Failed(String),
+    /// Not there yet; the string describes the current state
+    Pending(String),
+}
+
+/// Callback invoked with the current state and elapsed time on each pending check
+pub type StatusCallback = Box<dyn Fn(&str, Duration) + Send + Sync>;
+
+/// Poll an arbitrary condition until it is met, fails, or times out
+///
+/// Use this for waits that are not tied to a Cloud task or Enterprise action,
+/// such as waiting for a resource to reach a given status.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use redisctl_core::{PollStatus, poll_until};
+/// use std::time::Duration;
+///
+/// let db = poll_until(
+///     Duration::from_secs(300),
+///     Duration::from_secs(5),
+///     || async {
+///         let db = client.databases().get(1).await?;
+///         Ok(match db.status.as_deref() {
+///             Some("active") => PollStatus::Ready(db),
+///             Some(other) => PollStatus::Pending(other.to_string()),
+///             None => PollStatus::Pending("unknown".to_string()),
+///         })
+///     },
+///     None,
+/// ).await?;
+/// ```
+pub async fn poll_until<T, F, Fut>(
+    timeout: Duration,
+    interval: Duration,
+    mut check: F,
+    on_pending: Option<StatusCallback>,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<PollStatus<T>>>,
+{
+    let start = Instant::now();
+
+    loop {
+        match check().await? {
+            PollStatus::Ready(value) => return Ok(value),
+            PollStatus::Failed(error) => return Err(CoreError::TaskFailed(error)),
+            PollStatus::Pending(state) => {
+                let elapsed = start.elapsed();
+                if let Some(cb) = &on_pending {
+                    cb(&state, elapsed);
+                }
+                if elapsed + interval > timeout {
+                    return Err(CoreError::TaskTimeout(timeout));
+                }
                 tokio::time::sleep(interval).await;
             }
         }
@@ -169,3 +376,177 @@ fn emit(callback: &Option<ProgressCallback>, event: ProgressEvent) {
         cb(event);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn mock_task(status: &str) -> wiremock::ResponseTemplate {
+        wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "taskId": "task-1",
+            "status": status,
+        }))
+    }
+
+    async fn mock_cloud(status: &str) -> (wiremock::MockServer, CloudClient) {
+        use wiremock::matchers::{method, path};
+
+        let server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/tasks/task-1"))
+            .respond_with(mock_task(status))
+            .mount(&server)
+            .await;
+        let client = CloudClient::builder()
+            .api_key("key")
+            .api_secret("secret")
+            .base_url(server.uri())
+            .build()
+            .unwrap();
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn test_poll_task_stops_at_max_attempts() {
+        let (server, client) = mock_cloud("processing-in-progress").await;
+        let polls = std::sync::Arc::new(AtomicU32::new(0));
+        let counter = polls.clone();
+
+        let result = poll_task_with_options(
+            &client,
+            "task-1",
+            PollOptions::new(Duration::from_secs(60), Duration::from_millis(1)).max_attempts(3),
+            Some(Box::new(move |event| {
+                if let ProgressEvent::Polling { .. } = event {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }
+            })),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(CoreError::PollLimitExceeded { attempts: 3, ref last_status })
+                if last_status == "processing-in-progress"
+        ));
+        assert_eq!(polls.load(Ordering::SeqCst), 3);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_poll_schedule_exponential_caps_at_max() {
+        let schedule = PollSchedule::Exponential {
+            base: Duration::from_secs(5),
+            factor: 1.5,
+            max: Duration::from_secs(60),
+        };
+        let waits: Vec<u64> = (1..=9).map(|n| schedule.interval(n).as_secs()).collect();
+        assert_eq!(waits, [5, 7, 11, 16, 25, 37, 56, 60, 60]);
+        assert_eq!(schedule.interval(u32::MAX), Duration::from_secs(60));
+
+        let fixed: PollSchedule = Duration::from_secs(10).into();
+        assert_eq!(fixed.interval(1), fixed.interval(50));
+    }
+
+    #[tokio::test]
+    async fn test_poll_task_reports_next_interval() {
+        let (_server, client) = mock_cloud("processing-in-progress").await;
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = seen.clone();
+
+        let _ = poll_task_with_options(
+            &client,
+            "task-1",
+            PollOptions::new(
+                Duration::from_secs(60),
+                PollSchedule::Exponential {
+                    base: Duration::from_millis(1),
+                    factor: 2.0,
+                    max: Duration::from_millis(3),
+                },
+            )
+            .max_attempts(3),
+            Some(Box::new(move |event| {
+                if let ProgressEvent::Polling { next_interval, .. } = event {
+                    sink.lock().unwrap().push(next_interval.as_millis());
+                }
+            })),
+        )
+        .await;
+
+        assert_eq!(*seen.lock().unwrap(), [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_poll_task_completes_within_max_attempts() {
+        let (_server, client) = mock_cloud("processing-completed").await;
+        let task = poll_task_with_options(
+            &client,
+            "task-1",
+            PollOptions::new(Duration::from_secs(60), Duration::from_millis(1)).max_attempts(1),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(task.status.as_deref(), Some("processing-completed"));
+    }
+
+    #[tokio::test]
+    async fn test_poll_task_fails_on_aborted() {
+        let (_server, client) = mock_cloud("aborted").await;
+        let result = poll_task_with_options(
+            &client,
+            "task-1",
+            PollOptions::new(Duration::from_secs(60), Duration::from_millis(1)).max_attempts(5),
+            None,
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(CoreError::TaskFailed(ref msg)) if msg == "Task was aborted"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_ready() {
+        let calls = &AtomicU32::new(0);
+        let result = poll_until(
+            Duration::from_secs(5),
+            Duration::from_millis(1),
+            move || async move {
+                let n = calls.fetch_add(1, Ordering::SeqCst);
+                Ok(if n < 2 {
+                    PollStatus::Pending("creating".to_string())
+                } else {
+                    PollStatus::Ready(n)
+                })
+            },
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[tokio::test]
+    async fn test_poll_until_failed_and_timeout() {
+        let failed = poll_until::<(), _, _>(
+            Duration::from_secs(5),
+            Duration::from_millis(1),
+            || async { Ok(PollStatus::Failed("creation-failed".to_string())) },
+            None,
+        )
+        .await;
+        assert!(matches!(failed, Err(CoreError::TaskFailed(e)) if e == "creation-failed"));
+
+        let timed_out = poll_until::<(), _, _>(
+            Duration::from_millis(20),
+            Duration::from_millis(5),
+            || async { Ok(PollStatus::Pending("pending".to_string())) },
+            None,
+        )
+        .await;
+        assert!(matches!(timed_out, Err(CoreError::TaskTimeout(_))));
+    }
+}
@@ -0,0 +1,132 @@
+//! Test support for downstream crates
+//!
+//! Enabled with the `test-support` feature. Lets consumers of redisctl-core
+//! exercise code built on the Layer 1 clients against a local mock server
+//! instead of a real Cloud account or Enterprise cluster.
+//!
+//! The Layer 1 mock servers and fixtures are re-exported as [`cloud`] and
+//! [`enterprise`]. For tests that bring their own HTTP mock (for example a
+//! plain `wiremock::MockServer`), [`cloud_client`] and [`enterprise_client`]
+//! build clients whose transport points at an arbitrary base URL.
+//!
+//! # Example
+//!
+//! ```toml
+//! [dev-dependencies]
+//! redisctl-core = { version = "0.10.1", features = ["test-support"] }
+//! ```
+//!
+//! ```rust,ignore
+//! use redisctl_core::testing::cloud::{MockCloudServer, SubscriptionFixture};
+//!
+//! #[tokio::test]
+//! async fn lists_subscriptions() {
+//!     let server = MockCloudServer::start().await;
+//!     server
+//!         .mock_subscriptions_list(vec![SubscriptionFixture::new(1, "prod").build()])
+//!         .await;
+//!
+//!     // Hand the mock-backed client to the code under test
+//!     let client = server.client();
+//!     assert_eq!(my_crate::count_subscriptions(&client).await.unwrap(), 1);
+//! }
+//! ```
+//!
+//! ```rust,ignore
+//! use redisctl_core::testing::enterprise_client;
+//! use wiremock::MockServer;
+//!
+//! let server = MockServer::start().await;
+//! // ... register mocks on `server` ...
+//! let client = enterprise_client(&server.uri())?;
+//! ```
+
+use redis_cloud::CloudClient;
+use redis_enterprise::EnterpriseClient;
+
+use crate::error::Result;
+
+/// Redis Cloud mock server and fixtures
+pub use redis_cloud::testing as cloud;
+
+/// Redis Enterprise mock server and fixtures
+pub use redis_enterprise::testing as enterprise;
+
+/// API key used by [`cloud_client`]
+pub const TEST_CLOUD_API_KEY: &str = "test-api-key";
+
+/// API secret used by [`cloud_client`]
+pub const TEST_CLOUD_API_SECRET: &str = "test-api-secret";
+
+/// Username used by [`enterprise_client`]
+pub const TEST_ENTERPRISE_USERNAME: &str = "admin@test.local";
+
+/// Password used by [`enterprise_client`]
+pub const TEST_ENTERPRISE_PASSWORD: &str = "test-password";
+
+/// Build a Cloud client that sends every request to `base_url`
+///
+/// Uses [`TEST_CLOUD_API_KEY`] / [`TEST_CLOUD_API_SECRET`] so mocks can
+/// match on the auth headers if they need to.
+pub fn cloud_client(base_url: &str) -> Result<CloudClient> {
+    Ok(CloudClient::builder()
+        .api_key(TEST_CLOUD_API_KEY)
+        .api_secret(TEST_CLOUD_API_SECRET)
+        .base_url(base_url)
+        .build()?)
+}
+
+/// Build an Enterprise client that sends every request to `base_url`
+///
+/// Uses [`TEST_ENTERPRISE_USERNAME`] / [`TEST_ENTERPRISE_PASSWORD`] and
+/// disables certificate verification, since mock servers are plain HTTP or
+/// self-signed.
+pub fn enterprise_client(base_url: &str) -> Result<EnterpriseClient> {
+    Ok(EnterpriseClient::builder()
+        .base_url(base_url)
+        .username(TEST_ENTERPRISE_USERNAME)
+        .password(TEST_ENTERPRISE_PASSWORD)
+        .insecure(true)
+        .build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_cloud_client_targets_mock_server() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/subscriptions"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"subscriptions": []})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = cloud_client(&server.uri()).unwrap();
+        let body = client.get_raw("/subscriptions").await.unwrap();
+        assert_eq!(body["subscriptions"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_enterprise_client_targets_mock_server() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/cluster"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"name": "mock"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = enterprise_client(&server.uri()).unwrap();
+        let body = client.get_raw("/v1/cluster").await.unwrap();
+        assert_eq!(body["name"], "mock");
+    }
+}
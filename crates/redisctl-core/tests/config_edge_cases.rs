@@ -245,6 +245,9 @@ fn tags_round_trip_save_and_reload() {
             files_api_key: None,
             resilience: None,
             tags: vec!["prod".to_string(), "us-east".to_string()],
+            kubernetes: None,
+            output: None,
+            base: None,
         },
     );
 
@@ -281,6 +284,9 @@ fn empty_tags_not_serialized() {
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         },
     );
 
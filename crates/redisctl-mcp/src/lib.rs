@@ -56,6 +56,7 @@
 
 pub mod audit;
 pub mod error;
+pub mod overrides;
 pub mod policy;
 pub mod presets;
 pub mod prompts;
@@ -211,6 +212,7 @@ mod tests {
         let _ = tools::cloud::list_subscriptions(state.clone());
         let _ = tools::cloud::get_subscription(state.clone());
         let _ = tools::cloud::list_databases(state.clone());
+        let _ = tools::cloud::list_all_cloud_databases(state.clone());
         let _ = tools::cloud::get_database(state.clone());
         let _ = tools::cloud::get_backup_status(state.clone());
         let _ = tools::cloud::get_slow_log(state.clone());
@@ -234,6 +236,7 @@ mod tests {
         // Write operations
         let _ = tools::cloud::create_database(state.clone());
         let _ = tools::cloud::update_database(state.clone());
+        let _ = tools::cloud::update_cloud_database(state.clone());
         let _ = tools::cloud::delete_database(state.clone());
         let _ = tools::cloud::backup_database(state.clone());
         let _ = tools::cloud::import_database(state.clone());
@@ -261,6 +264,7 @@ mod tests {
         // Verify all enterprise tools build successfully
         // Cluster
         let _ = tools::enterprise::get_cluster(state.clone());
+        let _ = tools::enterprise::get_cluster_summary(state.clone());
         // License
         let _ = tools::enterprise::get_license(state.clone());
         let _ = tools::enterprise::get_license_usage(state.clone());
@@ -21,6 +21,7 @@ use tracing_subscriber::{EnvFilter, fmt, prelude::*};
 
 mod audit;
 mod error;
+mod overrides;
 mod policy;
 mod presets;
 mod prompts;
@@ -30,6 +31,7 @@ mod state;
 mod tools;
 
 use audit::AuditLayer;
+use overrides::ToolOverrides;
 use policy::{Policy, PolicyConfig, SafetyTier, ToolsetKind};
 use presets::{ToolVisibility, ToolsConfig};
 use state::{AppState, CredentialSource};
@@ -188,6 +190,11 @@ struct Args {
     #[arg(long, env = "REDIS_CLIENT_NAME", default_value = "redisctl-mcp")]
     client_name: Option<String>,
 
+    /// Ask the user to approve each destructive tool call before it runs.
+    /// Uses MCP elicitation; calls are refused if the client cannot prompt.
+    #[arg(long, env = "REDISCTL_MCP_REQUIRE_CONFIRMATION")]
+    require_confirmation: bool,
+
     /// Toolsets to enable (default: all compiled-in).
     /// Use bare names for all sub-modules: cloud,enterprise,database,app.
     /// Use colon syntax for specific sub-modules: cloud:subscriptions,cloud:networking.
@@ -229,6 +236,11 @@ struct Args {
     #[arg(long, default_value = "30")]
     request_timeout_secs: u64,
 
+    /// Path to a TOML file that overrides tool descriptions or disables tools.
+    /// Every tool named in it must exist.
+    #[arg(long, env = "REDISCTL_MCP_TOOL_CONFIG")]
+    tool_config: Option<PathBuf>,
+
     // --- Skills ---
     /// Directory containing SKILL.md files to load as MCP prompts.
     /// Each subdirectory should contain a SKILL.md with YAML frontmatter.
@@ -540,6 +552,11 @@ async fn main() -> Result<()> {
         "Starting redisctl-mcp server"
     );
 
+    let tool_overrides = match &args.tool_config {
+        Some(path) => ToolOverrides::load(path)?,
+        None => ToolOverrides::default(),
+    };
+
     // Determine credential source
     let credential_source = if args.oauth {
         CredentialSource::OAuth {
@@ -564,13 +581,17 @@ async fn main() -> Result<()> {
     ));
 
     // Build application state
-    let state = Arc::new(AppState::new(
-        credential_source,
-        policy.clone(),
-        args.database_url.clone(),
-        args.cluster,
-        args.client_name.clone(),
-    )?);
+    let state = Arc::new(
+        AppState::new(
+            credential_source,
+            policy.clone(),
+            args.database_url.clone(),
+            args.cluster,
+            args.client_name.clone(),
+        )?
+        .with_require_confirmation(args.require_confirmation)
+        .with_tool_overrides(tool_overrides),
+    );
 
     // Resolve skills directory
     let skills_dir = resolve_skills_dir(&args);
@@ -794,6 +815,19 @@ fn build_tool_toolset_mapping(enabled: &EnabledToolsets) -> HashMap<String, Tool
     mapping
 }
 
+/// Every tool name compiled into this binary, including the system tools.
+fn compiled_tool_names() -> HashSet<String> {
+    let mut names: HashSet<String> = tools::profile::tool_names().into_iter().collect();
+    #[cfg(feature = "cloud")]
+    names.extend(tools::cloud::tool_names());
+    #[cfg(feature = "enterprise")]
+    names.extend(tools::enterprise::tool_names());
+    #[cfg(feature = "database")]
+    names.extend(tools::redis::tool_names());
+    names.extend(presets::SYSTEM_TOOLS.iter().map(|s| (*s).to_string()));
+    names
+}
+
 /// Merge a single toolset's router(s) into the main router, respecting sub-module selection.
 fn merge_toolset_router(
     router: McpRouter,
@@ -841,6 +875,15 @@ fn build_router(
     tool_toolset: &HashMap<String, ToolsetKind>,
     skills_dir: Option<&std::path::Path>,
 ) -> Result<McpRouter> {
+    // Tool config names are checked against every compiled-in tool, so a
+    // config shared between servers with different toolsets stays valid
+    let overrides = state.tool_overrides();
+    overrides.validate(&compiled_tool_names())?;
+    let disabled = overrides.disabled();
+    if !disabled.is_empty() {
+        info!(count = disabled.len(), "Tools disabled by tool config");
+    }
+
     let router = McpRouter::new().server_info("redisctl-mcp", env!("CARGO_PKG_VERSION"));
 
     // Enable dynamic prompts for skill loading
@@ -866,11 +909,12 @@ fn build_router(
     }
 
     // Register the show_policy tool (always available, bypasses visibility)
-    router = router.tool(policy::show_policy_tool(policy.clone()));
+    router = router.tool(overrides.apply(policy::show_policy_tool(policy.clone())));
 
     // Resolve tool visibility from preset config
     let all_tools: HashSet<String> = tool_toolset.keys().cloned().collect();
-    let visible_set = presets::resolve_visible_tools(&tools_config, &all_tools, tool_toolset);
+    let mut visible_set = presets::resolve_visible_tools(&tools_config, &all_tools, tool_toolset);
+    visible_set.retain(|name| !disabled.contains(name));
     let is_preset_active = !tools_config.is_all();
 
     if is_preset_active {
@@ -888,7 +932,7 @@ fn build_router(
         all_tools: tool_toolset.clone(),
         config: tools_config,
     });
-    router = router.tool(presets::list_available_tools_tool(visibility));
+    router = router.tool(overrides.apply(presets::list_available_tools_tool(visibility)));
 
     // Build instructions with policy description
     let mut prefix = format!(
@@ -917,15 +961,19 @@ fn build_router(
     // Apply combined visibility + policy filter
     // System tools (show_policy, list_available_tools) bypass visibility.
     // All other tools must pass both visibility and policy checks.
+    // Tools disabled by the tool config are hidden either way.
     let policy_for_filter = policy.clone();
     let visible_for_filter = Arc::new(visible_set);
+    let disabled_for_filter = Arc::new(disabled);
     info!(tier = %policy.global_tier(), "Applying policy filter");
     let router = router.tool_filter(
         CapabilityFilter::<Tool>::new(move |_session, tool: &Tool| {
             let name = tool.name.as_str();
             let is_system = presets::SYSTEM_TOOLS.contains(&name);
             let is_visible = is_system || visible_for_filter.contains(name);
-            is_visible && policy_for_filter.is_tool_allowed(tool)
+            is_visible
+                && !disabled_for_filter.contains(name)
+                && policy_for_filter.is_tool_allowed(tool)
         })
         .denial_behavior(DenialBehavior::Unauthorized),
     );
@@ -1000,6 +1048,9 @@ mod tests {
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         }
     }
 
@@ -1016,6 +1067,9 @@ mod tests {
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         }
     }
 
@@ -1033,6 +1087,9 @@ mod tests {
             files_api_key: None,
             resilience: None,
             tags: vec![],
+            kubernetes: None,
+            output: None,
+            base: None,
         }
     }
 
@@ -1188,6 +1245,18 @@ mod tests {
         );
     }
 
+    /// Helper: assert whether repeating a tool's call with the same input is a no-op
+    fn assert_idempotent(tool: &Tool, name: &str, idempotent: bool) {
+        let ann = tool
+            .annotations
+            .as_ref()
+            .unwrap_or_else(|| panic!("{name}: missing annotations"));
+        assert_eq!(
+            ann.idempotent_hint, idempotent,
+            "{name}: idempotent_hint should be {idempotent}"
+        );
+    }
+
     /// Helper: assert a tool is destructive (explicit annotations with destructive_hint=true)
     fn assert_destructive(tool: &Tool, name: &str) {
         let ann = tool
@@ -1270,6 +1339,10 @@ mod tests {
                 &tools::cloud::list_subscriptions(state.clone()),
                 "list_subscriptions",
             );
+            assert_read_only(
+                &tools::cloud::list_all_cloud_databases(state.clone()),
+                "list_all_cloud_databases",
+            );
             assert_read_only(&tools::cloud::get_account(state.clone()), "get_account");
             assert_read_only(
                 &tools::cloud::list_fixed_subscriptions(state.clone()),
@@ -1296,6 +1369,10 @@ mod tests {
                 &tools::cloud::update_database(state.clone()),
                 "update_database",
             );
+            assert_non_destructive_write(
+                &tools::cloud::update_cloud_database(state.clone()),
+                "update_cloud_database",
+            );
             assert_non_destructive_write(
                 &tools::cloud::backup_database(state.clone()),
                 "backup_database",
@@ -1318,6 +1395,50 @@ mod tests {
             );
         }
 
+        #[test]
+        fn cloud_write_tools_idempotency() {
+            let state = test_state();
+            for (tool, name, idempotent) in [
+                (
+                    tools::cloud::update_database(state.clone()),
+                    "update_database",
+                    true,
+                ),
+                (
+                    tools::cloud::update_cloud_database(state.clone()),
+                    "update_cloud_database",
+                    true,
+                ),
+                (
+                    tools::cloud::update_subscription_cidr_allowlist(state.clone()),
+                    "update_subscription_cidr_allowlist",
+                    true,
+                ),
+                (
+                    tools::cloud::create_database(state.clone()),
+                    "create_database",
+                    false,
+                ),
+                (
+                    tools::cloud::backup_database(state.clone()),
+                    "backup_database",
+                    false,
+                ),
+                (
+                    tools::cloud::delete_database(state.clone()),
+                    "delete_database",
+                    false,
+                ),
+                (
+                    tools::cloud::flush_database(state.clone()),
+                    "flush_database",
+                    false,
+                ),
+            ] {
+                assert_idempotent(&tool, name, idempotent);
+            }
+        }
+
         #[test]
         fn cloud_raw_api_is_destructive() {
             let state = test_state();
@@ -1335,6 +1456,15 @@ mod tests {
                 &tools::cloud::delete_subscription(state.clone()),
                 "delete_subscription",
             );
+            // Imports overwrite existing keys
+            assert_destructive(
+                &tools::cloud::import_database(state.clone()),
+                "import_database",
+            );
+            assert_destructive(
+                &tools::cloud::import_fixed_database(state.clone()),
+                "import_fixed_database",
+            );
             assert_destructive(
                 &tools::cloud::flush_database(state.clone()),
                 "flush_database",
@@ -1387,6 +1517,10 @@ mod tests {
                 &tools::enterprise::get_cluster(state.clone()),
                 "get_cluster",
             );
+            assert_read_only(
+                &tools::enterprise::get_cluster_summary(state.clone()),
+                "get_cluster_summary",
+            );
             assert_read_only(
                 &tools::enterprise::list_databases(state.clone()),
                 "list_enterprise_databases",
@@ -1418,6 +1552,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn enterprise_write_tools_idempotency() {
+            let state = test_state();
+            assert_idempotent(
+                &tools::enterprise::update_cluster(state.clone()),
+                "update_enterprise_cluster",
+                true,
+            );
+            assert_idempotent(
+                &tools::enterprise::update_enterprise_database(state.clone()),
+                "update_enterprise_database",
+                true,
+            );
+            assert_idempotent(
+                &tools::enterprise::create_enterprise_database(state.clone()),
+                "create_enterprise_database",
+                false,
+            );
+            assert_idempotent(
+                &tools::enterprise::flush_enterprise_database(state.clone()),
+                "flush_enterprise_database",
+                false,
+            );
+        }
+
         #[test]
         fn enterprise_raw_api_is_destructive() {
             let state = test_state();
@@ -1450,6 +1609,14 @@ mod tests {
                 &tools::enterprise::delete_enterprise_acl(state.clone()),
                 "delete_enterprise_acl",
             );
+            assert_destructive(
+                &tools::enterprise::import_enterprise_database(state.clone()),
+                "import_enterprise_database",
+            );
+            assert_destructive(
+                &tools::enterprise::restore_enterprise_database(state.clone()),
+                "restore_enterprise_database",
+            );
         }
     }
 
@@ -1465,6 +1632,7 @@ mod tests {
             assert_read_only(&tools::redis::ping(state.clone()), "redis_ping");
             assert_read_only(&tools::redis::info(state.clone()), "redis_info");
             assert_read_only(&tools::redis::keys(state.clone()), "redis_keys");
+            assert_read_only(&tools::redis::scan_count(state.clone()), "redis_scan_count");
             assert_read_only(&tools::redis::get(state.clone()), "redis_get");
             assert_read_only(&tools::redis::hgetall(state.clone()), "redis_hgetall");
             assert_read_only(
@@ -1482,6 +1650,7 @@ mod tests {
             );
             assert_non_destructive_write(&tools::redis::set(state.clone()), "redis_set");
             assert_non_destructive_write(&tools::redis::expire(state.clone()), "redis_expire");
+            assert_non_destructive_write(&tools::redis::persist(state.clone()), "redis_persist");
             assert_non_destructive_write(&tools::redis::hset(state.clone()), "redis_hset");
             assert_non_destructive_write(&tools::redis::lpush(state.clone()), "redis_lpush");
             assert_non_destructive_write(&tools::redis::xadd(state.clone()), "redis_xadd");
@@ -1563,6 +1732,49 @@ mod tests {
             build_router(state, policy, &enabled, tools_config, &tool_toolset, None).unwrap();
     }
 
+    #[test]
+    fn tool_config_must_name_existing_tools() {
+        let enabled = EnabledToolsets::all_of([Toolset::App]);
+        let tool_toolset = build_tool_toolset_mapping(&enabled);
+
+        let known: ToolOverrides = toml::from_str(
+            r#"
+            [tools.profile_list]
+            description = "List profiles"
+
+            [tools.profile_delete]
+            disabled = true
+            "#,
+        )
+        .unwrap();
+        let state = Arc::new((*test_state()).clone().with_tool_overrides(known));
+        assert!(
+            build_router(
+                state,
+                test_policy_arc(SafetyTier::Full),
+                &enabled,
+                ToolsConfig::default(),
+                &tool_toolset,
+                None,
+            )
+            .is_ok()
+        );
+
+        let unknown: ToolOverrides =
+            toml::from_str("[tools.profile_lst]\ndisabled = true\n").unwrap();
+        let state = Arc::new((*test_state()).clone().with_tool_overrides(unknown));
+        let err = build_router(
+            state,
+            test_policy_arc(SafetyTier::Full),
+            &enabled,
+            ToolsConfig::default(),
+            &tool_toolset,
+            None,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("profile_lst"), "got: {err}");
+    }
+
     #[test]
     fn policy_disabled_toolset_removes_from_enabled() {
         use policy::ToolsetPolicy;
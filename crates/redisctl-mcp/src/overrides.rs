@@ -0,0 +1,171 @@
+//! Per-tool description overrides and disabling from a `--tool-config` file.
+//!
+//! Different agent setups want different tool descriptions (terse for small
+//! context windows, verbose for weaker tool selection). Operators can tune
+//! the tool surface without recompiling:
+//!
+//! ```toml
+//! [tools.redis_keys]
+//! description = "List keys by pattern. Use redis_scan instead on large databases."
+//!
+//! [tools.flush_database]
+//! disabled = true
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use tower_mcp::Tool;
+
+/// Override settings for a single tool.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolOverride {
+    /// Replacement description shown to the client.
+    pub description: Option<String>,
+    /// Hide the tool and refuse calls to it.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// Contents of a `--tool-config` file, keyed by tool name.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolOverrides {
+    #[serde(default)]
+    pub tools: BTreeMap<String, ToolOverride>,
+}
+
+impl ToolOverrides {
+    /// Load overrides from a TOML file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tool config: {}", path.display()))?;
+        let overrides: Self = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse tool config: {}", path.display()))?;
+        tracing::info!(
+            path = %path.display(),
+            tools = overrides.tools.len(),
+            "Loaded tool config"
+        );
+        Ok(overrides)
+    }
+
+    /// Fail if any configured tool name is not in `known`.
+    pub fn validate(&self, known: &HashSet<String>) -> Result<()> {
+        let unknown: Vec<&str> = self
+            .tools
+            .keys()
+            .filter(|name| !known.contains(*name))
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            bail!("Unknown tool(s) in tool config: {}", unknown.join(", "));
+        }
+        Ok(())
+    }
+
+    /// Names of tools marked `disabled = true`.
+    pub fn disabled(&self) -> HashSet<String> {
+        self.tools
+            .iter()
+            .filter(|(_, o)| o.disabled)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Replace the tool's description if one is configured for it.
+    pub fn apply(&self, mut tool: Tool) -> Tool {
+        if let Some(description) = self
+            .tools
+            .get(tool.name.as_str())
+            .and_then(|o| o.description.as_ref())
+        {
+            tool.description = Some(description.clone());
+        }
+        tool
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_mcp::ToolBuilder;
+
+    fn parse(toml_str: &str) -> ToolOverrides {
+        toml::from_str(toml_str).unwrap()
+    }
+
+    fn test_tool(name: &str) -> Tool {
+        ToolBuilder::new(name)
+            .description("Original description")
+            .read_only_safe()
+            .handler(|_: serde_json::Value| async { Ok(tower_mcp::CallToolResult::text("ok")) })
+            .build()
+    }
+
+    #[test]
+    fn test_apply_replaces_description() {
+        let overrides = parse(
+            r#"
+            [tools.redis_keys]
+            description = "Terse"
+            "#,
+        );
+
+        let tool = overrides.apply(test_tool("redis_keys"));
+        assert_eq!(tool.description.as_deref(), Some("Terse"));
+
+        let untouched = overrides.apply(test_tool("redis_scan"));
+        assert_eq!(
+            untouched.description.as_deref(),
+            Some("Original description")
+        );
+    }
+
+    #[test]
+    fn test_disabled_tools() {
+        let overrides = parse(
+            r#"
+            [tools.flush_database]
+            disabled = true
+
+            [tools.redis_keys]
+            description = "Terse"
+            "#,
+        );
+        let disabled = overrides.disabled();
+        assert_eq!(disabled.len(), 1);
+        assert!(disabled.contains("flush_database"));
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_tools() {
+        let overrides = parse(
+            r#"
+            [tools.redis_keys]
+            disabled = true
+
+            [tools.no_such_tool]
+            description = "?"
+            "#,
+        );
+        let known: HashSet<String> = ["redis_keys".to_string()].into_iter().collect();
+        let err = overrides.validate(&known).unwrap_err().to_string();
+        assert!(err.contains("no_such_tool"), "got: {err}");
+        assert!(!err.contains("redis_keys"), "got: {err}");
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        let result: std::result::Result<ToolOverrides, _> = toml::from_str(
+            r#"
+            [tools.redis_keys]
+            descripton = "typo"
+            "#,
+        );
+        assert!(result.is_err());
+    }
+}
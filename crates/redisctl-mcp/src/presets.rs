@@ -55,6 +55,7 @@ pub const CLOUD_ESSENTIALS: &[&str] = &[
     "list_subscriptions",
     "get_subscription",
     "list_databases",
+    "list_all_cloud_databases",
     "get_database",
     "get_account",
     "get_regions",
@@ -77,6 +78,7 @@ pub const CLOUD_ESSENTIALS: &[&str] = &[
 /// Enterprise essentials: cluster, nodes, databases, RBAC, observability.
 pub const ENTERPRISE_ESSENTIALS: &[&str] = &[
     "get_cluster",
+    "get_cluster_summary",
     "get_license",
     "list_nodes",
     "get_node",
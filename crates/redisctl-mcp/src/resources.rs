@@ -78,12 +78,12 @@ pub fn help_resource() -> Resource {
 
 ### Redis Cloud
 - **Subscriptions**: list_subscriptions, get_subscription
-- **Databases**: list_databases, get_database, get_backup_status, get_slow_log
+- **Databases**: list_databases, list_all_cloud_databases, get_database, get_backup_status, get_slow_log
 - **Account**: get_account, list_account_users
 - **Tasks**: list_tasks, get_task
 
 ### Redis Enterprise
-- **Cluster**: get_cluster, get_cluster_stats
+- **Cluster**: get_cluster_summary, get_cluster, get_cluster_stats
 - **License**: get_license, get_license_usage
 - **Databases**: list_enterprise_databases, get_enterprise_database
 - **Nodes**: list_nodes, get_node, get_node_stats
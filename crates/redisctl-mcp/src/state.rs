@@ -1,6 +1,7 @@
 //! Application state and credential resolution
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[cfg(any(feature = "cloud", feature = "enterprise", feature = "database"))]
@@ -13,6 +14,7 @@ use redis_enterprise::EnterpriseClient;
 use redisctl_core::Config;
 use tokio::sync::RwLock;
 
+use crate::overrides::ToolOverrides;
 use crate::policy::{Policy, SafetyTier};
 
 #[cfg(feature = "cloud")]
@@ -58,8 +60,14 @@ pub struct AppState {
     pub cluster: bool,
     /// Client name for CLIENT SETNAME (identifies connections in CLIENT LIST)
     pub client_name: Option<String>,
-    /// redisctl config (for profile-based auth)
-    config: Option<Config>,
+    /// Ask the user to approve destructive tool calls via elicitation
+    require_confirmation: bool,
+    /// Description overrides and disabled tools from `--tool-config`
+    tool_overrides: Arc<ToolOverrides>,
+    /// redisctl config (for profile-based auth), reloaded when profile tools change it
+    config: std::sync::RwLock<Option<Config>>,
+    /// Config file to reload from; the default location when unset
+    config_path: Option<PathBuf>,
     /// Configured profiles (for multi-cluster support)
     profiles: Vec<String>,
     /// Cached API clients (keyed by profile name, "_default" for default)
@@ -97,7 +105,10 @@ impl AppState {
             database_url,
             cluster,
             client_name,
-            config,
+            require_confirmation: false,
+            tool_overrides: Arc::default(),
+            config: std::sync::RwLock::new(config),
+            config_path: None,
             profiles,
             clients: RwLock::new(CachedClients {
                 #[cfg(feature = "cloud")]
@@ -173,8 +184,9 @@ impl AppState {
         // Create new client
         let client = self.create_enterprise_client(profile).await?;
 
-        // Cache it
-        {
+        // Cache it, unless its credentials come from a Kubernetes secret that
+        // may rotate underneath us
+        if !self.enterprise_profile_uses_kubernetes(profile) {
             let mut clients = self.clients.write().await;
             clients.enterprise.insert(cache_key, client.clone());
         }
@@ -182,6 +194,24 @@ impl AppState {
         Ok(client)
     }
 
+    /// Whether the Enterprise profile reads its credentials from a mounted operator secret
+    #[cfg(feature = "enterprise")]
+    fn enterprise_profile_uses_kubernetes(&self, profile: Option<&str>) -> bool {
+        let (CredentialSource::Profiles(profiles), Some(config)) =
+            (&self.credential_source, self.config())
+        else {
+            return false;
+        };
+        let profile_to_use = profile
+            .map(|s| s.to_string())
+            .or_else(|| profiles.first().cloned());
+        config
+            .resolve_enterprise_profile(profile_to_use.as_deref())
+            .ok()
+            .and_then(|name| config.profiles.get(&name))
+            .is_some_and(|p| p.kubernetes.is_some())
+    }
+
     /// Get or create Enterprise API client (uses default profile)
     #[cfg(feature = "enterprise")]
     #[allow(dead_code)]
@@ -189,15 +219,56 @@ impl AppState {
         self.enterprise_client_for_profile(None).await
     }
 
+    /// Snapshot of the redisctl config, if one is loaded
+    fn config(&self) -> Option<Config> {
+        self.config.read().ok().and_then(|config| config.clone())
+    }
+
+    /// Read the config file again, keeping the current config if it can't be loaded
+    fn reload_config(&self) {
+        if !matches!(self.credential_source, CredentialSource::Profiles(_)) {
+            return;
+        }
+        let loaded = match &self.config_path {
+            Some(path) => Config::load_from_path(path),
+            None => Config::load(),
+        };
+        match loaded {
+            Ok(config) => {
+                if let Ok(mut current) = self.config.write() {
+                    *current = Some(config);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to reload redisctl config: {}", e),
+        }
+    }
+
+    /// Reload the config and drop the cached API clients for `profile` and for
+    /// the default profile
+    ///
+    /// Profile tools call this after changing the config, so the next tool call
+    /// builds its client from the updated profile instead of reusing a stale one.
+    #[cfg_attr(
+        not(any(feature = "cloud", feature = "enterprise")),
+        allow(unused_mut, unused_variables)
+    )]
+    pub async fn invalidate_profile_clients(&self, profile: &str) {
+        self.reload_config();
+        let mut clients = self.clients.write().await;
+        for key in [profile, "_default"] {
+            #[cfg(feature = "cloud")]
+            clients.cloud.remove(key);
+            #[cfg(feature = "enterprise")]
+            clients.enterprise.remove(key);
+        }
+    }
+
     /// Create a new Cloud client from credentials
     #[cfg(feature = "cloud")]
     async fn create_cloud_client(&self, profile: Option<&str>) -> Result<CloudClient> {
         match &self.credential_source {
             CredentialSource::Profiles(profiles) => {
-                let config = self
-                    .config
-                    .as_ref()
-                    .context("No redisctl config available")?;
+                let config = self.config().context("No redisctl config available")?;
 
                 // Use specified profile, first configured profile, or let config resolve default
                 let profile_to_use = profile
@@ -247,10 +318,7 @@ impl AppState {
     async fn create_enterprise_client(&self, profile: Option<&str>) -> Result<EnterpriseClient> {
         match &self.credential_source {
             CredentialSource::Profiles(profiles) => {
-                let config = self
-                    .config
-                    .as_ref()
-                    .context("No redisctl config available")?;
+                let config = self.config().context("No redisctl config available")?;
 
                 // Use specified profile, first configured profile, or let config resolve default
                 let profile_to_use = profile
@@ -319,10 +387,7 @@ impl AppState {
     /// If profile is `None`, uses the first configured profile or default from config
     #[cfg(feature = "database")]
     pub fn database_url_for_profile(&self, profile: Option<&str>) -> Result<String> {
-        let config = self
-            .config
-            .as_ref()
-            .context("No redisctl config available")?;
+        let config = self.config().context("No redisctl config available")?;
 
         let profile_to_use = profile
             .map(|s| s.to_string())
@@ -461,6 +526,28 @@ impl AppState {
         matches!(self.policy.global_tier(), SafetyTier::Full)
     }
 
+    /// Require human approval (via elicitation) before destructive tools run
+    pub fn with_require_confirmation(mut self, require: bool) -> Self {
+        self.require_confirmation = require;
+        self
+    }
+
+    /// Whether destructive tools must be approved by the user before running
+    pub fn require_confirmation(&self) -> bool {
+        self.require_confirmation
+    }
+
+    /// Apply tool description overrides and disabling from `--tool-config`
+    pub fn with_tool_overrides(mut self, overrides: ToolOverrides) -> Self {
+        self.tool_overrides = Arc::new(overrides);
+        self
+    }
+
+    /// Tool overrides loaded from `--tool-config` (empty when not set)
+    pub fn tool_overrides(&self) -> &ToolOverrides {
+        &self.tool_overrides
+    }
+
     /// Store a named command alias (session-scoped, in-memory only).
     #[cfg(feature = "database")]
     pub async fn set_alias(&self, name: String, commands: Vec<Vec<String>>) {
@@ -501,7 +588,10 @@ impl Clone for AppState {
             database_url: self.database_url.clone(),
             cluster: self.cluster,
             client_name: self.client_name.clone(),
-            config: self.config.clone(),
+            require_confirmation: self.require_confirmation,
+            tool_overrides: self.tool_overrides.clone(),
+            config: std::sync::RwLock::new(self.config()),
+            config_path: self.config_path.clone(),
             profiles: self.profiles.clone(),
             clients: RwLock::new(CachedClients {
                 #[cfg(feature = "cloud")]
@@ -540,7 +630,10 @@ impl AppState {
             database_url: None,
             cluster: false,
             client_name: None,
-            config: None,
+            require_confirmation: false,
+            tool_overrides: Arc::default(),
+            config: std::sync::RwLock::new(None),
+            config_path: None,
             profiles: vec![],
             clients: RwLock::new(CachedClients {
                 cloud,
@@ -565,7 +658,10 @@ impl AppState {
             database_url: None,
             cluster: false,
             client_name: None,
-            config: None,
+            require_confirmation: false,
+            tool_overrides: Arc::default(),
+            config: std::sync::RwLock::new(None),
+            config_path: None,
             profiles: vec![],
             clients: RwLock::new(CachedClients {
                 #[cfg(feature = "cloud")]
@@ -592,7 +688,10 @@ impl AppState {
             database_url: None,
             cluster: false,
             client_name: None,
-            config: None,
+            require_confirmation: false,
+            tool_overrides: Arc::default(),
+            config: std::sync::RwLock::new(None),
+            config_path: None,
             profiles: vec![],
             clients: RwLock::new(CachedClients {
                 cloud: cloud_map,
@@ -643,4 +742,122 @@ mod tests {
             std::env::remove_var("REDIS_CLOUD_API_SECRET");
         }
     }
+
+    #[cfg(feature = "cloud")]
+    #[tokio::test]
+    async fn test_cloud_client_is_cached_per_profile() {
+        let mut config = redisctl_core::Config::default();
+        config.set_profile(
+            "test".to_string(),
+            redisctl_core::Profile {
+                deployment_type: redisctl_core::DeploymentType::Cloud,
+                credentials: redisctl_core::ProfileCredentials::Cloud {
+                    api_key: "key".to_string(),
+                    api_secret: "secret".to_string(),
+                    api_url: "https://api.redislabs.com/v1".to_string(),
+                },
+                files_api_key: None,
+                resilience: None,
+                tags: Vec::new(),
+                kubernetes: None,
+                output: None,
+                base: None,
+            },
+        );
+        let mut state = super::AppState::new(
+            super::CredentialSource::Profiles(vec!["test".to_string()]),
+            super::AppState::test_policy(),
+            None,
+            false,
+            None,
+        )
+        .unwrap();
+        *state.config.write().unwrap() = Some(config);
+
+        state.cloud_client_for_profile(Some("test")).await.unwrap();
+        // Without a config the second call can only succeed from the cache
+        *state.config.write().unwrap() = None;
+        state.cloud_client_for_profile(Some("test")).await.unwrap();
+        assert_eq!(state.clients.read().await.cloud.len(), 1);
+
+        state.invalidate_profile_clients("test").await;
+        assert!(state.cloud_client_for_profile(Some("test")).await.is_err());
+    }
+
+    #[cfg(feature = "enterprise")]
+    #[tokio::test]
+    async fn test_changed_profile_is_reloaded_from_disk() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        async fn cluster(name: &str) -> MockServer {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/v1/cluster"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!({"name": name})),
+                )
+                .mount(&server)
+                .await;
+            server
+        }
+        fn write_profile(path: &std::path::Path, url: &str) {
+            let config = format!(
+                "[profiles.test]\ndeployment_type = \"enterprise\"\nurl = \"{}\"\n\
+                 username = \"admin\"\npassword = \"secret\"\n",
+                url
+            );
+            std::fs::write(path, config).unwrap();
+        }
+
+        let (old, new) = (cluster("old").await, cluster("new").await);
+        let config_path =
+            std::env::temp_dir().join(format!("redisctl-mcp-state-{}.toml", std::process::id()));
+        write_profile(&config_path, &old.uri());
+
+        let mut state = super::AppState::new(
+            super::CredentialSource::Profiles(vec!["test".to_string()]),
+            super::AppState::test_policy(),
+            None,
+            false,
+            None,
+        )
+        .unwrap();
+        state.config_path = Some(config_path.clone());
+        state.reload_config();
+
+        let client = state
+            .enterprise_client_for_profile(Some("test"))
+            .await
+            .unwrap();
+        assert_eq!(client.get_raw("/v1/cluster").await.unwrap()["name"], "old");
+
+        // A profile tool edits the file, then invalidates the profile's clients
+        write_profile(&config_path, &new.uri());
+        state.invalidate_profile_clients("test").await;
+        let client = state
+            .enterprise_client_for_profile(Some("test"))
+            .await
+            .unwrap();
+        assert_eq!(client.get_raw("/v1/cluster").await.unwrap()["name"], "new");
+
+        let _ = std::fs::remove_file(&config_path);
+    }
+
+    #[test]
+    fn test_require_confirmation_defaults_off() {
+        let state = super::AppState::new(
+            super::CredentialSource::Profiles(vec![]),
+            super::AppState::test_policy(),
+            None,
+            false,
+            None,
+        )
+        .unwrap();
+        assert!(!state.require_confirmation());
+
+        let state = state.with_require_confirmation(true);
+        assert!(state.require_confirmation());
+        assert!(state.clone().require_confirmation());
+    }
 }
@@ -11,6 +11,9 @@ use redis_cloud::{
     AccountHandler, AclHandler, CloudAccountHandler, CostReportCreateRequest, CostReportHandler,
     TaskHandler, UserHandler,
 };
+use redisctl_core::{CoreError, ProgressCallback, ProgressEvent, poll_task};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tower_mcp::{CallToolResult, ResultExt};
 
 use crate::tools::macros::{cloud_tool, mcp_module};
@@ -44,6 +47,17 @@ fn default_task_interval() -> u64 {
     5
 }
 
+/// Progress metadata collected while waiting on a task
+#[derive(Debug, Clone, Default, serde::Serialize)]
+struct TaskWaitProgress {
+    /// Number of status polls performed
+    polls: u32,
+    /// Seconds elapsed at the last poll
+    elapsed_seconds: u64,
+    /// Distinct statuses observed, in order
+    status_history: Vec<String>,
+}
+
 mcp_module! {
     get_account => "get_account",
     get_system_logs => "get_system_logs",
@@ -200,7 +214,7 @@ cloud_tool!(read_only, get_account_user, "get_account_user",
     }
 );
 
-cloud_tool!(write, update_account_user, "update_account_user",
+cloud_tool!(idempotent_write, update_account_user, "update_account_user",
     "Update an account user's name or role.",
     {
         /// Account user ID to update
@@ -332,7 +346,7 @@ cloud_tool!(write, create_acl_user, "create_acl_user",
     }
 );
 
-cloud_tool!(write, update_acl_user, "update_acl_user",
+cloud_tool!(idempotent_write, update_acl_user, "update_acl_user",
     "Update an ACL user's role or password.",
     {
         /// ACL user ID to update
@@ -414,7 +428,7 @@ cloud_tool!(write, create_acl_role, "create_acl_role",
     }
 );
 
-cloud_tool!(write, update_acl_role, "update_acl_role",
+cloud_tool!(idempotent_write, update_acl_role, "update_acl_role",
     "Update an ACL role's name or Redis rule assignments.",
     {
         /// ACL role ID to update
@@ -497,7 +511,7 @@ cloud_tool!(write, create_redis_rule, "create_redis_rule",
     }
 );
 
-cloud_tool!(write, update_redis_rule, "update_redis_rule",
+cloud_tool!(idempotent_write, update_redis_rule, "update_redis_rule",
     "Update a Redis ACL rule's name or pattern.",
     {
         /// Redis ACL rule ID to update
@@ -637,18 +651,133 @@ cloud_tool!(read_only, list_payment_methods, "list_payment_methods",
 // ============================================================================
 
 cloud_tool!(read_only, list_tasks, "list_tasks",
-    "List all async tasks.",
-    {} => |client, _input| {
+    "List async tasks, newest first. Supports filtering by status (processing, completed, \
+     failed) and by age: `since` is compared against each task's timestamp, so tasks without \
+     a timestamp are left out when it is set. An invalid `since` is reported as an error.",
+    {
+        /// Optional filter by task state: "processing", "completed", or "failed"
+        #[serde(default)]
+        pub status_filter: Option<String>,
+        /// Only tasks with a timestamp at or after this time (ISO 8601, e.g. "2024-01-15T10:00:00Z" or "2024-01-15")
+        #[serde(default)]
+        pub since: Option<String>,
+        /// Maximum number of tasks to return
+        #[serde(default)]
+        pub limit: Option<usize>,
+    } => |client, input| {
+        let status = input
+            .status_filter
+            .as_deref()
+            .map(TaskStatusFilter::parse)
+            .transpose()
+            .map_err(tower_mcp::Error::tool)?;
+        let since = input
+            .since
+            .as_deref()
+            .map(parse_since)
+            .transpose()
+            .map_err(tower_mcp::Error::tool)?;
+
         let handler = TaskHandler::new(client);
         let tasks = handler
             .get_all_tasks()
             .await
             .tool_context("Failed to list tasks")?;
+        let tasks = serde_json::to_value(&tasks)
+            .map_err(|e| tower_mcp::Error::tool(format!("Failed to serialize tasks: {}", e)))?;
 
-        CallToolResult::from_list("tasks", &tasks)
+        let filtered = filter_tasks(tasks, status, since, input.limit);
+        CallToolResult::from_list("tasks", &filtered)
     }
 );
 
+/// Task states accepted by `list_tasks`' `status_filter`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskStatusFilter {
+    Processing,
+    Completed,
+    Failed,
+}
+
+impl TaskStatusFilter {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "processing" => Ok(Self::Processing),
+            "completed" => Ok(Self::Completed),
+            "failed" => Ok(Self::Failed),
+            other => Err(format!(
+                "Invalid status_filter '{}': expected processing, completed, or failed",
+                other
+            )),
+        }
+    }
+
+    /// Whether a Cloud task status (e.g. "processing-completed") is in this state
+    fn matches(self, status: &str) -> bool {
+        match self {
+            Self::Processing => matches!(
+                status,
+                "received" | "initialized" | "processing-in-progress"
+            ),
+            Self::Completed => status == "processing-completed",
+            Self::Failed => status == "processing-error",
+        }
+    }
+}
+
+/// Parse `since` as an RFC 3339 timestamp or a date (midnight UTC)
+fn parse_since(value: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    let value = value.trim();
+    if let Ok(ts) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Ok(ts.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|ts| ts.and_utc())
+        .ok_or_else(|| {
+            format!(
+                "Invalid since '{}': expected an ISO 8601 timestamp such as 2024-01-15T10:00:00Z or a date such as 2024-01-15",
+                value
+            )
+        })
+}
+
+fn task_timestamp(task: &serde_json::Value) -> Option<chrono::DateTime<chrono::Utc>> {
+    task["timestamp"]
+        .as_str()
+        .and_then(|ts| parse_since(ts).ok())
+}
+
+/// Apply `list_tasks`' filters to the serialized task list, newest first
+fn filter_tasks(
+    tasks: serde_json::Value,
+    status: Option<TaskStatusFilter>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    limit: Option<usize>,
+) -> Vec<serde_json::Value> {
+    let mut tasks: Vec<_> = match tasks {
+        serde_json::Value::Array(tasks) => tasks,
+        _ => Vec::new(),
+    }
+    .into_iter()
+    .filter(|task| match status {
+        Some(filter) => task["status"].as_str().is_some_and(|s| filter.matches(s)),
+        None => true,
+    })
+    .filter(|task| match since {
+        Some(since) => task_timestamp(task).is_some_and(|ts| ts >= since),
+        None => true,
+    })
+    .collect();
+
+    tasks.sort_by_key(|task| std::cmp::Reverse(task_timestamp(task)));
+    if let Some(limit) = limit {
+        tasks.truncate(limit);
+    }
+    tasks
+}
+
 cloud_tool!(read_only, get_task, "get_task",
     "Get task status by ID.",
     {
@@ -666,7 +795,10 @@ cloud_tool!(read_only, get_task, "get_task",
 );
 
 cloud_tool!(read_only, wait_for_cloud_task, "wait_for_cloud_task",
-    "Poll an async task until it reaches a terminal state. Useful for multi-step workflows.",
+    "Poll an async task until it reaches a terminal state and return the outcome. \
+     The response includes whether the task succeeded, the resource ID it produced, \
+     the final task object, and progress metadata (poll count, elapsed time, status history). \
+     Use after any tool that returns a task ID to block on completion in one call.",
     {
         /// Task ID to wait for
         pub task_id: String,
@@ -677,46 +809,70 @@ cloud_tool!(read_only, wait_for_cloud_task, "wait_for_cloud_task",
         #[serde(default = "default_task_interval")]
         pub interval_seconds: u64,
     } => |client, input| {
-        let handler = TaskHandler::new(client);
-        let deadline =
-            tokio::time::Instant::now() + std::time::Duration::from_secs(input.timeout_seconds);
-        let interval = std::time::Duration::from_secs(input.interval_seconds);
-
-        loop {
-            let task = handler
-                .get_task_by_id(input.task_id.clone())
-                .await
-                .tool_context("Failed to get task status")?;
-
-            // Check for terminal states
-            if let Some(ref status) = task.status {
-                let s = status.to_lowercase();
-                if matches!(
-                    s.as_str(),
-                    "completed"
-                        | "failed"
-                        | "error"
-                        | "success"
-                        | "cancelled"
-                        | "aborted"
-                ) {
-                    return CallToolResult::from_serialize(&task);
+        let progress = Arc::new(Mutex::new(TaskWaitProgress::default()));
+        let recorder = Arc::clone(&progress);
+        let on_progress: ProgressCallback = Box::new(move |event| {
+            if let ProgressEvent::Polling { status, elapsed, .. } = event
+                && let Ok(mut p) = recorder.lock()
+            {
+                p.polls += 1;
+                p.elapsed_seconds = elapsed.as_secs();
+                if p.status_history.last() != Some(&status) {
+                    p.status_history.push(status);
                 }
             }
+        });
 
-            // Check timeout
-            if tokio::time::Instant::now() >= deadline {
-                return CallToolResult::from_serialize(&serde_json::json!({
+        let result = poll_task(
+            &client,
+            &input.task_id,
+            Duration::from_secs(input.timeout_seconds),
+            Duration::from_secs(input.interval_seconds),
+            Some(on_progress),
+        )
+        .await;
+        let progress = progress.lock().map(|p| p.clone()).unwrap_or_default();
+
+        match result {
+            Ok(task) => CallToolResult::from_serialize(&serde_json::json!({
+                "task_id": input.task_id,
+                "succeeded": true,
+                "status": task.status,
+                "resource_id": task.response.as_ref().and_then(|r| r.resource_id),
+                "progress": progress,
+                "task": task,
+            })),
+            Err(CoreError::TaskTimeout(_)) => {
+                let last_status = TaskHandler::new(client.clone())
+                    .get_task_by_id(input.task_id.clone())
+                    .await
+                    .ok();
+                CallToolResult::from_serialize(&serde_json::json!({
+                    "task_id": input.task_id,
+                    "succeeded": false,
                     "timeout": true,
                     "message": format!(
                         "Task {} did not complete within {} seconds",
                         input.task_id, input.timeout_seconds
                     ),
-                    "last_status": task,
-                }));
+                    "progress": progress,
+                    "last_status": last_status,
+                }))
             }
-
-            tokio::time::sleep(interval).await;
+            Err(CoreError::TaskFailed(error)) => {
+                let last_status = TaskHandler::new(client.clone())
+                    .get_task_by_id(input.task_id.clone())
+                    .await
+                    .ok();
+                CallToolResult::from_serialize(&serde_json::json!({
+                    "task_id": input.task_id,
+                    "succeeded": false,
+                    "error": error,
+                    "progress": progress,
+                    "last_status": last_status,
+                }))
+            }
+            Err(e) => Err(e).tool_context("Failed to get task status"),
         }
     }
 );
@@ -794,7 +950,7 @@ cloud_tool!(write, create_cloud_account, "create_cloud_account",
     }
 );
 
-cloud_tool!(write, update_cloud_account, "update_cloud_account",
+cloud_tool!(idempotent_write, update_cloud_account, "update_cloud_account",
     "Update a cloud provider account (BYOC) configuration.",
     {
         /// Cloud account ID to update
@@ -849,3 +1005,53 @@ cloud_tool!(destructive, delete_cloud_account, "delete_cloud_account",
         CallToolResult::from_serialize(&result)
     }
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tasks() -> serde_json::Value {
+        json!([
+            {"taskId": "a", "status": "processing-completed", "timestamp": "2024-01-10T08:00:00Z"},
+            {"taskId": "b", "status": "processing-error", "timestamp": "2024-01-15T09:00:00Z"},
+            {"taskId": "c", "status": "processing-in-progress", "timestamp": "2024-01-16T10:00:00Z"},
+            {"taskId": "d", "status": "received"}
+        ])
+    }
+
+    fn ids(tasks: &[serde_json::Value]) -> Vec<&str> {
+        tasks.iter().filter_map(|t| t["taskId"].as_str()).collect()
+    }
+
+    #[test]
+    fn test_filter_tasks_by_status_since_and_limit() {
+        let all = filter_tasks(tasks(), None, None, None);
+        assert_eq!(ids(&all), ["c", "b", "a", "d"]);
+
+        let processing = filter_tasks(tasks(), Some(TaskStatusFilter::Processing), None, None);
+        assert_eq!(ids(&processing), ["c", "d"]);
+
+        let since = parse_since("2024-01-15").unwrap();
+        let recent = filter_tasks(tasks(), None, Some(since), None);
+        assert_eq!(ids(&recent), ["c", "b"]);
+
+        let latest = filter_tasks(tasks(), None, None, Some(1));
+        assert_eq!(ids(&latest), ["c"]);
+    }
+
+    #[test]
+    fn test_task_filter_inputs_are_validated() {
+        assert_eq!(
+            TaskStatusFilter::parse("Failed"),
+            Ok(TaskStatusFilter::Failed)
+        );
+        assert!(TaskStatusFilter::parse("stuck").is_err());
+        assert!(parse_since("2024-01-15T10:00:00+02:00").is_ok());
+        assert!(
+            parse_since("last tuesday")
+                .unwrap_err()
+                .contains("ISO 8601")
+        );
+    }
+}
@@ -120,7 +120,7 @@ cloud_tool!(write, create_fixed_subscription, "create_fixed_subscription",
     }
 );
 
-cloud_tool!(write, update_fixed_subscription, "update_fixed_subscription",
+cloud_tool!(idempotent_write, update_fixed_subscription, "update_fixed_subscription",
     "Update a Fixed/Essentials subscription.",
     {
         /// Fixed subscription ID to update
@@ -369,7 +369,7 @@ cloud_tool!(write, create_fixed_database, "create_fixed_database",
     }
 );
 
-cloud_tool!(write, update_fixed_database, "update_fixed_database",
+cloud_tool!(idempotent_write, update_fixed_database, "update_fixed_database",
     "Update a database in a Fixed/Essentials subscription.",
     {
         /// Fixed subscription ID
@@ -525,9 +525,8 @@ cloud_tool!(read_only, get_fixed_database_import_status, "get_fixed_database_imp
     }
 );
 
-cloud_tool!(write, import_fixed_database, "import_fixed_database",
-    "Import data into a database from an external source. \
-     WARNING: This will overwrite existing data.",
+cloud_tool!(destructive, import_fixed_database, "import_fixed_database",
+    "DANGEROUS: Import data into a database from an external source, overwriting existing data.",
     {
         /// Fixed subscription ID
         pub subscription_id: i32,
@@ -626,7 +625,7 @@ cloud_tool!(write, create_fixed_database_tag, "create_fixed_database_tag",
     }
 );
 
-cloud_tool!(write, update_fixed_database_tag, "update_fixed_database_tag",
+cloud_tool!(idempotent_write, update_fixed_database_tag, "update_fixed_database_tag",
     "Update a tag value on a database.",
     {
         /// Fixed subscription ID
@@ -681,7 +680,7 @@ cloud_tool!(destructive, delete_fixed_database_tag, "delete_fixed_database_tag",
     }
 );
 
-cloud_tool!(write, update_fixed_database_tags, "update_fixed_database_tags",
+cloud_tool!(idempotent_write, update_fixed_database_tags, "update_fixed_database_tags",
     "Update all tags on a database (replaces existing tags).",
     {
         /// Fixed subscription ID
@@ -139,7 +139,7 @@ cloud_tool!(write, create_vpc_peering, "create_vpc_peering",
     }
 );
 
-cloud_tool!(write, update_vpc_peering, "update_vpc_peering",
+cloud_tool!(idempotent_write, update_vpc_peering, "update_vpc_peering",
     "Update a VPC peering connection.",
     {
         /// Subscription ID
@@ -283,7 +283,7 @@ cloud_tool!(write, create_aa_vpc_peering, "create_aa_vpc_peering",
     }
 );
 
-cloud_tool!(write, update_aa_vpc_peering, "update_aa_vpc_peering",
+cloud_tool!(idempotent_write, update_aa_vpc_peering, "update_aa_vpc_peering",
     "Update an Active-Active VPC peering connection.",
     {
         /// Subscription ID
@@ -458,7 +458,7 @@ cloud_tool!(write, create_tgw_attachment, "create_tgw_attachment",
     }
 );
 
-cloud_tool!(write, update_tgw_attachment_cidrs, "update_tgw_attachment_cidrs",
+cloud_tool!(idempotent_write, update_tgw_attachment_cidrs, "update_tgw_attachment_cidrs",
     "Update CIDRs for a Transit Gateway attachment.",
     {
         /// Subscription ID
@@ -630,7 +630,7 @@ cloud_tool!(write, create_aa_tgw_attachment, "create_aa_tgw_attachment",
     }
 );
 
-cloud_tool!(write, update_aa_tgw_attachment_cidrs, "update_aa_tgw_attachment_cidrs",
+cloud_tool!(idempotent_write, update_aa_tgw_attachment_cidrs, "update_aa_tgw_attachment_cidrs",
     "Update CIDRs for an Active-Active Transit Gateway attachment.",
     {
         /// Subscription ID
@@ -804,7 +804,7 @@ cloud_tool!(write, create_psc_endpoint, "create_psc_endpoint",
     }
 );
 
-cloud_tool!(write, update_psc_endpoint, "update_psc_endpoint",
+cloud_tool!(idempotent_write, update_psc_endpoint, "update_psc_endpoint",
     "Update a Private Service Connect (PSC) endpoint.",
     {
         /// Subscription ID
@@ -1010,7 +1010,7 @@ cloud_tool!(write, create_aa_psc_endpoint, "create_aa_psc_endpoint",
     }
 );
 
-cloud_tool!(write, update_aa_psc_endpoint, "update_aa_psc_endpoint",
+cloud_tool!(idempotent_write, update_aa_psc_endpoint, "update_aa_psc_endpoint",
     "Update an Active-Active PSC endpoint.",
     {
         /// Subscription ID
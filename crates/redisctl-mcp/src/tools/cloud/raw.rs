@@ -5,7 +5,7 @@ use std::sync::Arc;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
-use tower_mcp::extract::{Json, State};
+use tower_mcp::extract::{Context, Json, State};
 use tower_mcp::{CallToolResult, Error as McpError, McpRouter, ResultExt, Tool, ToolBuilder};
 
 use crate::state::AppState;
@@ -49,7 +49,9 @@ pub fn cloud_raw_api(state: Arc<AppState>) -> Tool {
         .destructive()
         .extractor_handler(
             state,
-            |State(state): State<Arc<AppState>>, Json(input): Json<CloudRawApiInput>| async move {
+            |State(state): State<Arc<AppState>>,
+             ctx: Context,
+             Json(input): Json<CloudRawApiInput>| async move {
                 // Method-based tier gating
                 match input.method {
                     HttpMethod::Get => {
@@ -85,6 +87,11 @@ pub fn cloud_raw_api(state: Arc<AppState>) -> Tool {
                     return CallToolResult::from_serialize(&preview);
                 }
 
+                if !matches!(input.method, HttpMethod::Get) {
+                    crate::tools::confirm_destructive(&state, &ctx, "cloud_raw_api", &input)
+                        .await?;
+                }
+
                 let client = state
                     .cloud_client_for_profile(input.profile.as_deref())
                     .await
@@ -133,5 +140,5 @@ pub(super) const TOOL_NAMES: &[&str] = &["cloud_raw_api"];
 
 /// Build a sub-router containing the raw Cloud API tool.
 pub fn router(state: Arc<AppState>) -> McpRouter {
-    McpRouter::new().tool(cloud_raw_api(state))
+    McpRouter::new().tool(state.tool_overrides().apply(cloud_raw_api(state.clone())))
 }
@@ -1,7 +1,9 @@
 //! Subscription and database tools for Redis Cloud
 
+use std::sync::Arc;
 use std::time::Duration;
 
+use redis_cloud::CloudClient;
 use redis_cloud::databases::DatabaseCreateRequest;
 use redis_cloud::flexible::{DatabaseHandler, SubscriptionHandler};
 use redisctl_core::cloud::{
@@ -9,6 +11,10 @@ use redisctl_core::cloud::{
     delete_subscription_and_wait, flush_database_and_wait, import_database_and_wait,
     update_database_and_wait,
 };
+use redisctl_core::poll_task;
+use serde_json::{Value, json};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 use tower_mcp::{CallToolResult, ResultExt};
 
 use crate::tools::macros::{cloud_tool, mcp_module};
@@ -45,6 +51,56 @@ fn default_subscription_timeout() -> u64 {
     1800 // Subscriptions can take a while
 }
 
+/// Subscriptions queried at once by `list_all_cloud_databases`
+const LIST_ALL_DATABASES_CONCURRENCY: usize = 4;
+
+/// Databases requested per page from `/subscriptions/{id}/databases`
+const LIST_DATABASES_PAGE_SIZE: usize = 100;
+
+/// Databases from a `/subscriptions/{id}/databases` response
+///
+/// The API wraps them as `{"subscription": [{"databases": [...]}]}`; a single
+/// object instead of an array is accepted too.
+fn databases_from_response(response: &Value) -> Vec<Value> {
+    let subscription = &response["subscription"];
+    let entries = match subscription {
+        Value::Array(entries) => entries.iter().collect::<Vec<_>>(),
+        Value::Object(_) => vec![subscription],
+        _ => Vec::new(),
+    };
+    entries
+        .into_iter()
+        .filter_map(|entry| entry["databases"].as_array())
+        .flatten()
+        .cloned()
+        .collect()
+}
+
+/// Fetch every database of a subscription, following `offset`/`limit` paging
+/// until a short page comes back
+async fn fetch_subscription_databases(
+    client: &CloudClient,
+    subscription_id: i64,
+) -> Result<Vec<Value>, redis_cloud::CloudError> {
+    let mut databases = Vec::new();
+    loop {
+        let response = client
+            .get_raw(&format!(
+                "/subscriptions/{}/databases?offset={}&limit={}",
+                subscription_id,
+                databases.len(),
+                LIST_DATABASES_PAGE_SIZE
+            ))
+            .await?;
+        let page = databases_from_response(&response);
+        let page_len = page.len();
+        databases.extend(page);
+        if page_len < LIST_DATABASES_PAGE_SIZE {
+            return Ok(databases);
+        }
+    }
+}
+
 // ============================================================================
 // Helper structs used as field types in tool inputs
 // ============================================================================
@@ -84,6 +140,7 @@ mcp_module! {
     list_subscriptions => "list_subscriptions",
     get_subscription => "get_subscription",
     list_databases => "list_databases",
+    list_all_cloud_databases => "list_all_cloud_databases",
     get_database => "get_database",
     get_backup_status => "get_backup_status",
     get_slow_log => "get_slow_log",
@@ -91,6 +148,7 @@ mcp_module! {
     get_database_certificate => "get_database_certificate",
     create_database => "create_database",
     update_database => "update_database",
+    update_cloud_database => "update_cloud_database",
     delete_database => "delete_database",
     backup_database => "backup_database",
     import_database => "import_database",
@@ -168,6 +226,98 @@ cloud_tool!(read_only, list_databases, "list_databases",
     }
 );
 
+cloud_tool!(read_only, list_all_cloud_databases, "list_all_cloud_databases",
+    "List databases across all Pro subscriptions in one call. Each database is tagged \
+     with subscriptionId and subscriptionName. Optionally filter by name or status.",
+    {
+        /// Only include databases whose name contains this text (case-insensitive)
+        #[serde(default)]
+        pub name: Option<String>,
+        /// Only include databases with this status (e.g., "active")
+        #[serde(default)]
+        pub status: Option<String>,
+    } => |client, input| {
+        let subscriptions = client
+            .get_raw("/subscriptions")
+            .await
+            .tool_context("Failed to list subscriptions")?;
+        let subscriptions: Vec<(i64, String)> = subscriptions["subscriptions"]
+            .as_array()
+            .map(|subs| {
+                subs.iter()
+                    .filter_map(|sub| {
+                        let id = sub["id"].as_i64()?;
+                        let name = sub["name"].as_str().unwrap_or_default().to_string();
+                        Some((id, name))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let permits = Arc::new(Semaphore::new(LIST_ALL_DATABASES_CONCURRENCY));
+        let mut tasks = JoinSet::new();
+        for (index, (id, name)) in subscriptions.iter().cloned().enumerate() {
+            let client = client.clone();
+            let permits = permits.clone();
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let result = fetch_subscription_databases(&client, id).await;
+                (index, id, name, result)
+            });
+        }
+
+        let mut per_subscription = Vec::with_capacity(subscriptions.len());
+        while let Some(joined) = tasks.join_next().await {
+            per_subscription.push(joined.tool_context("Database listing task failed")?);
+        }
+        // Keep subscription order stable regardless of completion order
+        per_subscription.sort_by_key(|(index, ..)| *index);
+
+        let name_filter = input.name.as_deref().map(str::to_lowercase);
+        let mut databases = Vec::new();
+        let mut errors = Vec::new();
+        for (_, id, name, result) in per_subscription {
+            let subscription_databases = match result {
+                Ok(subscription_databases) => subscription_databases,
+                Err(e) => {
+                    errors.push(json!({
+                        "subscriptionId": id,
+                        "subscriptionName": name,
+                        "error": e.to_string(),
+                    }));
+                    continue;
+                }
+            };
+            for mut db in subscription_databases {
+                let db_name = db["name"].as_str().unwrap_or_default().to_lowercase();
+                if name_filter.as_ref().is_some_and(|f| !db_name.contains(f.as_str())) {
+                    continue;
+                }
+                if input.status.as_ref().is_some_and(|s| {
+                    !db["status"].as_str().is_some_and(|st| st.eq_ignore_ascii_case(s))
+                }) {
+                    continue;
+                }
+                if let Value::Object(map) = &mut db {
+                    map.insert("subscriptionId".to_string(), json!(id));
+                    map.insert("subscriptionName".to_string(), json!(name));
+                }
+                databases.push(db);
+            }
+        }
+
+        let mut result = json!({
+            "total": databases.len(),
+            "subscriptions_scanned": subscriptions.len(),
+            "databases": databases,
+        });
+        if !errors.is_empty() {
+            result["errors"] = Value::Array(errors);
+        }
+        CallToolResult::from_serialize(&result)
+    }
+);
+
 cloud_tool!(read_only, get_database, "get_database",
     "Get database details by ID.",
     {
@@ -481,7 +631,7 @@ cloud_tool!(write, create_database, "create_database",
     }
 );
 
-cloud_tool!(write, update_database, "update_database",
+cloud_tool!(idempotent_write, update_database, "update_database",
     "Update a database configuration.",
     {
         /// Subscription ID containing the database
@@ -545,6 +695,115 @@ cloud_tool!(write, update_database, "update_database",
     }
 );
 
+cloud_tool!(idempotent_write, update_cloud_database, "update_cloud_database",
+    "Change selected settings of a database and wait for the update task to finish. \
+     Only the fields provided are sent. Returns the task outcome and the refreshed \
+     database so the change can be confirmed.",
+    {
+        /// Subscription ID containing the database
+        pub subscription_id: i32,
+        /// Database ID to update
+        pub database_id: i32,
+        /// New memory limit in GB
+        #[serde(default)]
+        pub memory_limit_gb: Option<f64>,
+        /// Throughput measurement: "operations-per-second" or "number-of-shards"
+        /// (requires throughput_value)
+        #[serde(default)]
+        pub throughput_by: Option<String>,
+        /// Throughput value in the unit given by throughput_by
+        #[serde(default)]
+        pub throughput_value: Option<i64>,
+        /// Enable or disable replication
+        #[serde(default)]
+        pub replication: Option<bool>,
+        /// Data persistence: "none", "aof-every-1-second", "aof-every-write", "snapshot-every-1-hour", etc.
+        #[serde(default)]
+        pub data_persistence: Option<String>,
+        /// New password for the default user
+        #[serde(default)]
+        pub password: Option<String>,
+        /// Timeout in seconds (default: 600)
+        #[serde(default = "default_timeout")]
+        pub timeout_seconds: u64,
+    } => |client, input| {
+        let mut body = serde_json::Map::new();
+        if let Some(memory) = input.memory_limit_gb {
+            body.insert("memoryLimitInGb".to_string(), json!(memory));
+        }
+        match (&input.throughput_by, input.throughput_value) {
+            (Some(by), Some(value)) => {
+                body.insert(
+                    "throughputMeasurement".to_string(),
+                    json!({"by": by, "value": value}),
+                );
+            }
+            (None, None) => {}
+            _ => {
+                return Err(tower_mcp::Error::tool(
+                    "throughput_by and throughput_value must be given together",
+                ));
+            }
+        }
+        if let Some(replication) = input.replication {
+            body.insert("replication".to_string(), json!(replication));
+        }
+        if let Some(persistence) = &input.data_persistence {
+            body.insert("dataPersistence".to_string(), json!(persistence));
+        }
+        if let Some(password) = &input.password {
+            body.insert("password".to_string(), json!(password));
+        }
+        if body.is_empty() {
+            return Err(tower_mcp::Error::tool(
+                "At least one update field is required",
+            ));
+        }
+        // Field names only, so the password is not echoed back
+        let updated_fields: Vec<String> = body.keys().cloned().collect();
+
+        let response = client
+            .put_raw(
+                &format!(
+                    "/subscriptions/{}/databases/{}",
+                    input.subscription_id, input.database_id
+                ),
+                Value::Object(body),
+            )
+            .await
+            .tool_context("Failed to update database")?;
+        let task_id = response
+            .get("taskId")
+            .and_then(Value::as_str)
+            .ok_or_else(|| tower_mcp::Error::tool("Database update returned no task ID"))?;
+
+        let task = poll_task(
+            &client,
+            task_id,
+            Duration::from_secs(input.timeout_seconds),
+            Duration::from_secs(10),
+            None,
+        )
+        .await
+        .tool_context("Database update did not complete")?;
+
+        let database = DatabaseHandler::new(client)
+            .get_subscription_database_by_id(input.subscription_id, input.database_id)
+            .await
+            .tool_context("Failed to get updated database")?;
+
+        CallToolResult::from_serialize(&json!({
+            "updated_fields": updated_fields,
+            "task": {
+                "task_id": task_id,
+                "succeeded": true,
+                "status": task.status,
+            },
+            "database": database,
+        }))
+    }
+);
+
 cloud_tool!(write, backup_database, "backup_database",
     "Trigger a manual backup of a database.",
     {
@@ -579,8 +838,8 @@ cloud_tool!(write, backup_database, "backup_database",
     }
 );
 
-cloud_tool!(write, import_database, "import_database",
-    "Import data into a database from an external source. WARNING: This will overwrite existing data.",
+cloud_tool!(destructive, import_database, "import_database",
+    "DANGEROUS: Import data into a database from an external source, overwriting existing data.",
     {
         /// Subscription ID containing the database
         pub subscription_id: i32,
@@ -705,7 +964,7 @@ cloud_tool!(write, create_subscription, "create_subscription",
     }
 );
 
-cloud_tool!(write, update_subscription, "update_subscription",
+cloud_tool!(idempotent_write, update_subscription, "update_subscription",
     "Update a subscription.",
     {
         /// Subscription ID to update
@@ -728,7 +987,7 @@ cloud_tool!(write, update_subscription, "update_subscription",
     }
 );
 
-cloud_tool!(write, update_subscription_cidr_allowlist, "update_subscription_cidr_allowlist",
+cloud_tool!(idempotent_write, update_subscription_cidr_allowlist, "update_subscription_cidr_allowlist",
     "Update the CIDR allowlist for a subscription.",
     {
         /// Subscription ID
@@ -759,7 +1018,7 @@ cloud_tool!(write, update_subscription_cidr_allowlist, "update_subscription_cidr
     }
 );
 
-cloud_tool!(write, update_subscription_maintenance_windows, "update_subscription_maintenance_windows",
+cloud_tool!(idempotent_write, update_subscription_maintenance_windows, "update_subscription_maintenance_windows",
     "Update maintenance windows for a subscription.",
     {
         /// Subscription ID
@@ -912,7 +1171,7 @@ cloud_tool!(write, create_database_tag, "create_database_tag",
     }
 );
 
-cloud_tool!(write, update_database_tag, "update_database_tag",
+cloud_tool!(idempotent_write, update_database_tag, "update_database_tag",
     "Update a tag on a database.",
     {
         /// Subscription ID
@@ -949,7 +1208,7 @@ cloud_tool!(write, update_database_tag, "update_database_tag",
     }
 );
 
-cloud_tool!(write, update_database_tags, "update_database_tags",
+cloud_tool!(idempotent_write, update_database_tags, "update_database_tags",
     "Update all tags on a database (replaces existing tags).",
     {
         /// Subscription ID
@@ -988,7 +1247,7 @@ cloud_tool!(write, update_database_tags, "update_database_tags",
     }
 );
 
-cloud_tool!(write, update_crdb_local_properties, "update_crdb_local_properties",
+cloud_tool!(idempotent_write, update_crdb_local_properties, "update_crdb_local_properties",
     "Update local properties of an Active-Active (CRDB) database.",
     {
         /// Subscription ID
@@ -11,6 +11,7 @@ use crate::tools::macros::{enterprise_tool, mcp_module};
 
 mcp_module! {
     get_cluster => "get_cluster",
+    get_cluster_summary => "get_cluster_summary",
     get_cluster_stats => "get_cluster_stats",
     update_cluster => "update_enterprise_cluster",
     get_cluster_policy => "get_enterprise_cluster_policy",
@@ -53,6 +54,19 @@ enterprise_tool!(read_only, get_cluster, "get_cluster",
     }
 );
 
+enterprise_tool!(read_only, get_cluster_summary, "get_cluster_summary",
+    "Get a compact cluster overview in one call: name, version, node and database \
+     counts, license status, and active alert count. Prefer this over get_cluster \
+     for orientation.",
+    {} => |client, _input| {
+        let summary = redisctl_core::enterprise::cluster_summary(&client)
+            .await
+            .tool_context("Failed to get cluster summary")?;
+
+        CallToolResult::from_serialize(&summary)
+    }
+);
+
 enterprise_tool!(read_only, get_cluster_stats, "get_cluster_stats",
     "Get cluster-level statistics. Optionally specify interval and time range \
      for historical data.",
@@ -94,7 +108,7 @@ enterprise_tool!(read_only, get_cluster_stats, "get_cluster_stats",
     }
 );
 
-enterprise_tool!(write, update_cluster, "update_enterprise_cluster",
+enterprise_tool!(idempotent_write, update_cluster, "update_enterprise_cluster",
     "Update cluster configuration settings. Pass fields to update as JSON.",
     {
         /// JSON object with cluster settings to update (e.g., {"name": "my-cluster", "email_alerts": true})
@@ -124,7 +138,7 @@ enterprise_tool!(read_only, get_cluster_policy, "get_enterprise_cluster_policy",
     }
 );
 
-enterprise_tool!(write, update_cluster_policy, "update_enterprise_cluster_policy",
+enterprise_tool!(idempotent_write, update_cluster_policy, "update_enterprise_cluster_policy",
     "Update cluster policy settings. Pass fields to update as JSON.",
     {
         /// JSON object with policy settings to update
@@ -211,7 +225,7 @@ enterprise_tool!(write, rotate_cluster_certificates, "rotate_enterprise_cluster_
     }
 );
 
-enterprise_tool!(write, update_cluster_certificates, "update_enterprise_cluster_certificates",
+enterprise_tool!(idempotent_write, update_cluster_certificates, "update_enterprise_cluster_certificates",
     "Update a specific certificate. Provide the certificate name (proxy, syncer, api), \
      PEM-encoded certificate, and PEM-encoded private key.",
     {
@@ -268,7 +282,7 @@ enterprise_tool!(read_only, get_license_usage, "get_license_usage",
     }
 );
 
-enterprise_tool!(write, update_license, "update_enterprise_license",
+enterprise_tool!(idempotent_write, update_license, "update_enterprise_license",
     "Apply a new license key to the cluster.",
     {
         /// The license key string to install
@@ -468,7 +482,7 @@ enterprise_tool!(write, drain_node, "drain_enterprise_node",
 // Node Update/Remove Operations
 // ============================================================================
 
-enterprise_tool!(write, update_enterprise_node, "update_enterprise_node",
+enterprise_tool!(idempotent_write, update_enterprise_node, "update_enterprise_node",
     "Update a node's configuration. Pass fields to update as JSON.",
     {
         /// Node UID
@@ -7,7 +7,8 @@ use redis_enterprise::bdb::{CreateDatabaseRequest, DatabaseHandler, DatabaseUpgr
 use redis_enterprise::crdb::CrdbHandler;
 use redis_enterprise::stats::{StatsHandler, StatsQuery};
 use redisctl_core::enterprise::{
-    backup_database_and_wait, flush_database_and_wait, import_database_and_wait,
+    backup_database_and_wait, export_database_and_wait, flush_database_and_wait,
+    import_database_and_wait,
 };
 use serde_json::Value;
 use tower_mcp::{CallToolResult, ResultExt};
@@ -208,9 +209,9 @@ enterprise_tool!(write, backup_enterprise_database, "backup_enterprise_database"
     }
 );
 
-enterprise_tool!(write, import_enterprise_database, "import_enterprise_database",
-    "Import data into a database from an external source and wait for completion. \
-     WARNING: If flush is true, existing data will be deleted before import.",
+enterprise_tool!(destructive, import_enterprise_database, "import_enterprise_database",
+    "DANGEROUS: Import data into a database from an external source and wait for completion. \
+     Imported keys overwrite existing ones; if flush is true, all existing data is deleted first.",
     {
         /// Database UID to import into
         pub bdb_uid: u32,
@@ -295,7 +296,7 @@ enterprise_tool!(write, create_enterprise_database, "create_enterprise_database"
     }
 );
 
-enterprise_tool!(write, update_enterprise_database, "update_enterprise_database",
+enterprise_tool!(idempotent_write, update_enterprise_database, "update_enterprise_database",
     "Update database configuration. Pass fields to update as JSON.",
     {
         /// Database UID to update
@@ -359,25 +360,37 @@ enterprise_tool!(destructive, flush_enterprise_database, "flush_enterprise_datab
 );
 
 enterprise_tool!(write, export_enterprise_database, "export_enterprise_database",
-    "Export a database to a specified location (e.g., S3, FTP).",
+    "Export a database to a specified location (e.g., S3, FTP) and wait for completion.",
     {
         /// Database UID to export
         pub uid: u32,
         /// Export location (e.g., S3 URL or FTP path)
         pub export_location: String,
+        /// Timeout in seconds (default: 600)
+        #[serde(default = "default_enterprise_timeout")]
+        pub timeout_seconds: u64,
     } => |client, input| {
-        let handler = DatabaseHandler::new(client);
-        let response = handler
-            .export(input.uid, &input.export_location)
-            .await
-            .tool_context("Failed to export database")?;
+        // Use Layer 2 workflow
+        export_database_and_wait(
+            &client,
+            input.uid,
+            &input.export_location,
+            Duration::from_secs(input.timeout_seconds),
+            None,
+        )
+        .await
+        .tool_context("Failed to export database")?;
 
-        CallToolResult::from_serialize(&response)
+        CallToolResult::from_serialize(&serde_json::json!({
+            "message": "Export completed successfully",
+            "uid": input.uid,
+            "export_location": input.export_location
+        }))
     }
 );
 
-enterprise_tool!(write, restore_enterprise_database, "restore_enterprise_database",
-    "Restore a database from a backup.",
+enterprise_tool!(destructive, restore_enterprise_database, "restore_enterprise_database",
+    "DANGEROUS: Restore a database from a backup, replacing its current data.",
     {
         /// Database UID to restore
         pub uid: u32,
@@ -488,7 +501,7 @@ enterprise_tool!(write, create_enterprise_crdb, "create_enterprise_crdb",
     }
 );
 
-enterprise_tool!(write, update_enterprise_crdb, "update_enterprise_crdb",
+enterprise_tool!(idempotent_write, update_enterprise_crdb, "update_enterprise_crdb",
     "Update an Active-Active (CRDB) database. Pass fields to update as JSON.",
     {
         /// CRDB GUID (globally unique identifier)
@@ -1,7 +1,10 @@
 //! Alerts, logs, aggregate stats, shards, debug info, and module tools
 
+use std::time::Duration;
+
 use redis_enterprise::debuginfo::{DebugInfoHandler, DebugInfoRequest};
 use redis_enterprise::logs::{LogsHandler, LogsQuery};
+use redisctl_core::enterprise::collect_debug_info_and_wait;
 use tower_mcp::{CallToolResult, ResultExt};
 
 use crate::tools::macros::{enterprise_tool, mcp_module};
@@ -38,7 +41,7 @@ enterprise_tool!(read_only, list_alerts, "list_alerts",
     }
 );
 
-enterprise_tool!(write, acknowledge_enterprise_alert, "acknowledge_enterprise_alert",
+enterprise_tool!(idempotent_write, acknowledge_enterprise_alert, "acknowledge_enterprise_alert",
     "Acknowledge (clear) a specific alert by ID.",
     {
         /// Alert UID to acknowledge
@@ -272,8 +275,14 @@ enterprise_tool!(read_only, get_debug_info_status, "get_debug_info_status",
     }
 );
 
+fn default_debug_info_timeout() -> u64 {
+    600
+}
+
 enterprise_tool!(write, create_debug_info, "create_debug_info",
-    "Start a debug info collection task. Optionally scope to specific nodes or databases.",
+    "Start a debug info collection task and return its task_id. Optionally scope to specific \
+     nodes or databases. Set wait=true to poll until the collection finishes and return the \
+     final status with its download_url; otherwise poll with get_debug_info_status.",
     {
         /// List of node UIDs to collect debug info from (if not specified, collects from all nodes)
         #[serde(default)]
@@ -281,6 +290,12 @@ enterprise_tool!(write, create_debug_info, "create_debug_info",
         /// List of database UIDs to collect debug info for (if not specified, collects for all databases)
         #[serde(default)]
         pub bdb_uids: Option<Vec<u32>>,
+        /// Wait for the collection to finish (default: false)
+        #[serde(default)]
+        pub wait: bool,
+        /// Timeout in seconds when waiting (default: 600)
+        #[serde(default = "default_debug_info_timeout")]
+        pub timeout_seconds: u64,
     } => |client, input| {
         let request = DebugInfoRequest {
             node_uids: input.node_uids,
@@ -291,6 +306,19 @@ enterprise_tool!(write, create_debug_info, "create_debug_info",
             time_range: None,
         };
 
+        if input.wait {
+            let status = collect_debug_info_and_wait(
+                &client,
+                request,
+                Duration::from_secs(input.timeout_seconds),
+                None,
+            )
+            .await
+            .tool_context("Debug info collection did not complete")?;
+
+            return CallToolResult::from_serialize(&status);
+        }
+
         let handler = DebugInfoHandler::new(client);
         let status = handler
             .create(request)
@@ -57,7 +57,7 @@ enterprise_tool!(read_only, get_proxy_stats, "get_enterprise_proxy_stats",
     }
 );
 
-enterprise_tool!(write, update_proxy, "update_enterprise_proxy",
+enterprise_tool!(idempotent_write, update_proxy, "update_enterprise_proxy",
     "Update a proxy's configuration. Pass fields to update as JSON.",
     {
         /// Proxy UID to update
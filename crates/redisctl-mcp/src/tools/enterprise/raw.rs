@@ -5,7 +5,7 @@ use std::sync::Arc;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
-use tower_mcp::extract::{Json, State};
+use tower_mcp::extract::{Context, Json, State};
 use tower_mcp::{CallToolResult, Error as McpError, McpRouter, ResultExt, Tool, ToolBuilder};
 
 use crate::state::AppState;
@@ -50,6 +50,7 @@ pub fn enterprise_raw_api(state: Arc<AppState>) -> Tool {
         .extractor_handler(
             state,
             |State(state): State<Arc<AppState>>,
+             ctx: Context,
              Json(input): Json<EnterpriseRawApiInput>| async move {
                 // Method-based tier gating
                 match input.method {
@@ -60,10 +61,7 @@ pub fn enterprise_raw_api(state: Arc<AppState>) -> Tool {
                             ));
                         }
                     }
-                    HttpMethod::Post
-                    | HttpMethod::Put
-                    | HttpMethod::Patch
-                    | HttpMethod::Delete => {
+                    HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch | HttpMethod::Delete => {
                         if !state.is_destructive_allowed() {
                             return Err(McpError::tool(
                                 "enterprise_raw_api mutating methods require full tier",
@@ -89,6 +87,11 @@ pub fn enterprise_raw_api(state: Arc<AppState>) -> Tool {
                     return CallToolResult::from_serialize(&preview);
                 }
 
+                if !matches!(input.method, HttpMethod::Get) {
+                    crate::tools::confirm_destructive(&state, &ctx, "enterprise_raw_api", &input)
+                        .await?;
+                }
+
                 let client = state
                     .enterprise_client_for_profile(input.profile.as_deref())
                     .await
@@ -137,5 +140,9 @@ pub(super) const TOOL_NAMES: &[&str] = &["enterprise_raw_api"];
 
 /// Build a sub-router containing the raw Enterprise API tool.
 pub fn router(state: Arc<AppState>) -> McpRouter {
-    McpRouter::new().tool(enterprise_raw_api(state))
+    McpRouter::new().tool(
+        state
+            .tool_overrides()
+            .apply(enterprise_raw_api(state.clone())),
+    )
 }
@@ -104,7 +104,7 @@ enterprise_tool!(write, create_enterprise_user, "create_enterprise_user",
     }
 );
 
-enterprise_tool!(write, update_enterprise_user, "update_enterprise_user",
+enterprise_tool!(idempotent_write, update_enterprise_user, "update_enterprise_user",
     "Update an existing user. Only specified fields will be modified.",
     {
         /// User UID to update
@@ -247,7 +247,7 @@ enterprise_tool!(write, create_enterprise_role, "create_enterprise_role",
     }
 );
 
-enterprise_tool!(write, update_enterprise_role, "update_enterprise_role",
+enterprise_tool!(idempotent_write, update_enterprise_role, "update_enterprise_role",
     "Update an existing role.",
     {
         /// Role UID to update
@@ -374,7 +374,7 @@ enterprise_tool!(write, create_enterprise_acl, "create_enterprise_acl",
     }
 );
 
-enterprise_tool!(write, update_enterprise_acl, "update_enterprise_acl",
+enterprise_tool!(idempotent_write, update_enterprise_acl, "update_enterprise_acl",
     "Update an existing Redis ACL.",
     {
         /// ACL UID to update
@@ -470,7 +470,7 @@ enterprise_tool!(read_only, get_enterprise_ldap_config, "get_enterprise_ldap_con
     }
 );
 
-enterprise_tool!(write, update_enterprise_ldap_config, "update_enterprise_ldap_config",
+enterprise_tool!(idempotent_write, update_enterprise_ldap_config, "update_enterprise_ldap_config",
     "Update the LDAP configuration. Pass LDAP settings as JSON.",
     {
         /// LDAP configuration as a JSON object. Fields: enabled (bool), servers (array of {host, port, use_tls, starttls}),
@@ -60,7 +60,7 @@ enterprise_tool!(read_only, get_service_status, "get_enterprise_service_status",
     }
 );
 
-enterprise_tool!(write, update_service, "update_enterprise_service",
+enterprise_tool!(idempotent_write, update_service, "update_enterprise_service",
     "Update a service's configuration. Pass fields as JSON.",
     {
         /// Service ID to update
@@ -80,7 +80,7 @@ enterprise_tool!(write, update_service, "update_enterprise_service",
     }
 );
 
-enterprise_tool!(write, start_service, "start_enterprise_service",
+enterprise_tool!(idempotent_write, start_service, "start_enterprise_service",
     "Start a stopped service.",
     {
         /// Service ID
@@ -96,7 +96,7 @@ enterprise_tool!(write, start_service, "start_enterprise_service",
     }
 );
 
-enterprise_tool!(write, stop_service, "stop_enterprise_service",
+enterprise_tool!(idempotent_write, stop_service, "stop_enterprise_service",
     "Stop a running service.",
     {
         /// Service ID
@@ -16,7 +16,8 @@
 ///
 /// - `read_only` — `.read_only_safe()`, no permission guard
 /// - `write` — `.non_destructive()`, checks `state.is_write_allowed()`
-/// - `destructive` — `.destructive()`, checks `state.is_destructive_allowed()`
+/// - `destructive` — `.destructive()`, checks `state.is_destructive_allowed()`, then
+///   asks the client for approval when `--require-confirmation` is set
 ///
 /// # Example
 ///
@@ -52,6 +53,16 @@ macro_rules! database_tool {
         }
     };
 
+    // --- Human approval (only destructive tools, only with --require-confirmation) ---
+
+    (@confirm destructive_guard $state:ident $ctx:ident $tool_name:literal $input:ident) => {
+        crate::tools::confirm_destructive(&$state, &$ctx, $tool_name, &$input).await?;
+    };
+
+    (@confirm $guard:ident $state:ident $ctx:ident $tool_name:literal $input:ident) => {
+        let _ = &$ctx;
+    };
+
     // --- Main implementation ---
 
     (@impl $safety_method:ident, $guard:ident, $fn_name:ident, $tool_name:literal, $description:expr,
@@ -80,8 +91,10 @@ macro_rules! database_tool {
                     .extractor_handler(
                         state,
                         |tower_mcp::extract::State(state): tower_mcp::extract::State<std::sync::Arc<crate::state::AppState>>,
+                         ctx: tower_mcp::extract::Context,
                          tower_mcp::extract::Json(mut $input): tower_mcp::extract::Json<[<$fn_name:camel Input>]>| async move {
                             database_tool!(@guard $guard state);
+                            database_tool!(@confirm $guard state ctx $tool_name $input);
                             #[allow(unused_mut)]
                             let mut $conn = super::get_connection(
                                 $input.url.take(), $input.profile.as_deref(), &state
@@ -124,8 +137,10 @@ macro_rules! database_tool {
                     .extractor_handler(
                         state,
                         |tower_mcp::extract::State($state): tower_mcp::extract::State<std::sync::Arc<crate::state::AppState>>,
+                         ctx: tower_mcp::extract::Context,
                          tower_mcp::extract::Json(mut $input): tower_mcp::extract::Json<[<$fn_name:camel Input>]>| async move {
                             database_tool!(@guard $guard $state);
+                            database_tool!(@confirm $guard $state ctx $tool_name $input);
                             #[allow(unused_mut)]
                             let mut $conn = super::get_connection(
                                 $input.url.take(), $input.profile.as_deref(), &$state
@@ -205,7 +220,17 @@ macro_rules! cloud_tool {
         }
     };
 
-    (@impl $safety_method:ident, $guard:ident, $fn_name:ident, $tool_name:literal, $description:expr,
+    // --- Human approval (only destructive tools, only with --require-confirmation) ---
+
+    (@confirm destructive_guard $state:ident $ctx:ident $tool_name:literal $input:ident) => {
+        crate::tools::confirm_destructive(&$state, &$ctx, $tool_name, &$input).await?;
+    };
+
+    (@confirm $guard:ident $state:ident $ctx:ident $tool_name:literal $input:ident) => {
+        let _ = &$ctx;
+    };
+
+    (@impl [$($safety_method:ident),+], $guard:ident, $fn_name:ident, $tool_name:literal, $description:expr,
      { $($(#[$field_meta:meta])* pub $field_name:ident : $field_type:ty),* $(,)? }
      => |$client:ident, $input:ident| $body:block
     ) => {
@@ -224,12 +249,14 @@ macro_rules! cloud_tool {
             pub fn $fn_name(state: std::sync::Arc<crate::state::AppState>) -> tower_mcp::Tool {
                 tower_mcp::ToolBuilder::new($tool_name)
                     .description($description)
-                    .$safety_method()
+                    $(.$safety_method())+
                     .extractor_handler(
                         state,
                         |tower_mcp::extract::State(state): tower_mcp::extract::State<std::sync::Arc<crate::state::AppState>>,
+                         ctx: tower_mcp::extract::Context,
                          tower_mcp::extract::Json($input): tower_mcp::extract::Json<[<$fn_name:camel Input>]>| async move {
                             cloud_tool!(@guard $guard state);
+                            cloud_tool!(@confirm $guard state ctx $tool_name $input);
                             let $client = state
                                 .cloud_client_for_profile($input.profile.as_deref())
                                 .await
@@ -245,13 +272,17 @@ macro_rules! cloud_tool {
     };
 
     (read_only, $($rest:tt)*) => {
-        cloud_tool!(@impl read_only_safe, no_guard, $($rest)*);
+        cloud_tool!(@impl [read_only_safe], no_guard, $($rest)*);
     };
     (write, $($rest:tt)*) => {
-        cloud_tool!(@impl non_destructive, write_guard, $($rest)*);
+        cloud_tool!(@impl [non_destructive], write_guard, $($rest)*);
+    };
+    // A write that leaves the same state when repeated with the same input
+    (idempotent_write, $($rest:tt)*) => {
+        cloud_tool!(@impl [non_destructive, idempotent], write_guard, $($rest)*);
     };
     (destructive, $($rest:tt)*) => {
-        cloud_tool!(@impl destructive, destructive_guard, $($rest)*);
+        cloud_tool!(@impl [destructive], destructive_guard, $($rest)*);
     };
 }
 #[allow(unused_imports)]
@@ -298,7 +329,17 @@ macro_rules! enterprise_tool {
         }
     };
 
-    (@impl $safety_method:ident, $guard:ident, $fn_name:ident, $tool_name:literal, $description:expr,
+    // --- Human approval (only destructive tools, only with --require-confirmation) ---
+
+    (@confirm destructive_guard $state:ident $ctx:ident $tool_name:literal $input:ident) => {
+        crate::tools::confirm_destructive(&$state, &$ctx, $tool_name, &$input).await?;
+    };
+
+    (@confirm $guard:ident $state:ident $ctx:ident $tool_name:literal $input:ident) => {
+        let _ = &$ctx;
+    };
+
+    (@impl [$($safety_method:ident),+], $guard:ident, $fn_name:ident, $tool_name:literal, $description:expr,
      { $($(#[$field_meta:meta])* pub $field_name:ident : $field_type:ty),* $(,)? }
      => |$client:ident, $input:ident| $body:block
     ) => {
@@ -317,12 +358,14 @@ macro_rules! enterprise_tool {
             pub fn $fn_name(state: std::sync::Arc<crate::state::AppState>) -> tower_mcp::Tool {
                 tower_mcp::ToolBuilder::new($tool_name)
                     .description($description)
-                    .$safety_method()
+                    $(.$safety_method())+
                     .extractor_handler(
                         state,
                         |tower_mcp::extract::State(state): tower_mcp::extract::State<std::sync::Arc<crate::state::AppState>>,
+                         ctx: tower_mcp::extract::Context,
                          tower_mcp::extract::Json($input): tower_mcp::extract::Json<[<$fn_name:camel Input>]>| async move {
                             enterprise_tool!(@guard $guard state);
+                            enterprise_tool!(@confirm $guard state ctx $tool_name $input);
                             let $client = state
                                 .enterprise_client_for_profile($input.profile.as_deref())
                                 .await
@@ -338,13 +381,17 @@ macro_rules! enterprise_tool {
     };
 
     (read_only, $($rest:tt)*) => {
-        enterprise_tool!(@impl read_only_safe, no_guard, $($rest)*);
+        enterprise_tool!(@impl [read_only_safe], no_guard, $($rest)*);
     };
     (write, $($rest:tt)*) => {
-        enterprise_tool!(@impl non_destructive, write_guard, $($rest)*);
+        enterprise_tool!(@impl [non_destructive], write_guard, $($rest)*);
+    };
+    // A write that leaves the same state when repeated with the same input
+    (idempotent_write, $($rest:tt)*) => {
+        enterprise_tool!(@impl [non_destructive, idempotent], write_guard, $($rest)*);
     };
     (destructive, $($rest:tt)*) => {
-        enterprise_tool!(@impl destructive, destructive_guard, $($rest)*);
+        enterprise_tool!(@impl [destructive], destructive_guard, $($rest)*);
     };
 }
 #[allow(unused_imports)]
@@ -369,11 +416,14 @@ pub(crate) use enterprise_tool;
 ///
 /// pub fn router(state: Arc<AppState>) -> McpRouter {
 ///     McpRouter::new()
-///         .tool(ping(state.clone()))
-///         .tool(info(state.clone()))
-///         .tool(dbsize(state.clone()))
+///         .tool(state.tool_overrides().apply(ping(state.clone())))
+///         .tool(state.tool_overrides().apply(info(state.clone())))
+///         .tool(state.tool_overrides().apply(dbsize(state.clone())))
 /// }
 /// ```
+///
+/// Each tool passes through the `--tool-config` overrides so configured
+/// descriptions replace the built-in ones.
 macro_rules! mcp_module {
     { $( $fn_name:ident => $tool_name:literal ),* $(,)? } => {
         pub(super) const TOOL_NAMES: &[&str] = &[$($tool_name),*];
@@ -381,7 +431,7 @@ macro_rules! mcp_module {
         pub fn router(state: std::sync::Arc<crate::state::AppState>) -> tower_mcp::McpRouter {
             tower_mcp::McpRouter::new()
             $(
-                .tool($fn_name(state.clone()))
+                .tool(state.tool_overrides().apply($fn_name(state.clone())))
             )*
         }
     };
@@ -25,6 +25,39 @@ pub mod profile;
 #[cfg(feature = "database")]
 pub mod redis;
 
+/// Ask the human behind the client to approve a destructive tool call.
+///
+/// A no-op unless the server was started with `--require-confirmation`. The
+/// request goes out as an MCP elicitation; a decline, a cancel, or a client
+/// without elicitation support all refuse the call rather than run it.
+pub async fn confirm_destructive(
+    state: &crate::state::AppState,
+    ctx: &tower_mcp::extract::Context,
+    tool_name: &str,
+    input: &impl std::fmt::Debug,
+) -> Result<(), tower_mcp::Error> {
+    if !state.require_confirmation() {
+        return Ok(());
+    }
+
+    let message = format!(
+        "Allow destructive tool '{}' to run with these arguments?\n\n{:#?}",
+        tool_name, input
+    );
+    match ctx.confirm(message).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(tower_mcp::Error::tool(format!(
+            "{} was not approved by the user",
+            tool_name
+        ))),
+        Err(e) => Err(tower_mcp::Error::tool(format!(
+            "{} requires user approval (--require-confirmation), but the client \
+             could not be asked: {}",
+            tool_name, e
+        ))),
+    }
+}
+
 /// Format a client creation error with structured remediation guidance for LLMs.
 ///
 /// Inspects the error chain to identify common credential issues and provides
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use redisctl_core::{Config, DeploymentType, ProfileCredentials};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use tower_mcp::extract::{Json, State};
+use tower_mcp::extract::{Context, Json, State};
 use tower_mcp::{
     CallToolResult, Error as McpError, McpRouter, ResultExt, Tool, ToolBuilder, ToolError,
 };
@@ -605,26 +605,24 @@ pub fn set_default_cloud(state: Arc<AppState>) -> Tool {
                     return Err(McpError::tool("Write operations require --read-only=false"));
                 }
 
-                let mut config = Config::load()
-                    .tool_context("Failed to load config")?;
+                Config::update(|config| -> Result<(), McpError> {
+                    // Verify profile exists and is a cloud profile
+                    let profile = config.profiles.get(&input.name).ok_or_else(|| {
+                        ToolError::new(format!("Profile '{}' not found", input.name))
+                    })?;
 
-                // Verify profile exists and is a cloud profile
-                let profile = config
-                    .profiles
-                    .get(&input.name)
-                    .ok_or_else(|| ToolError::new(format!("Profile '{}' not found", input.name)))?;
-
-                if !matches!(profile.deployment_type, DeploymentType::Cloud) {
-                    return Err(McpError::tool(format!(
-                        "Profile '{}' is not a cloud profile (type: {:?})",
-                        input.name, profile.deployment_type
-                    )));
-                }
+                    if !matches!(profile.deployment_type, DeploymentType::Cloud) {
+                        return Err(McpError::tool(format!(
+                            "Profile '{}' is not a cloud profile (type: {:?})",
+                            input.name, profile.deployment_type
+                        )));
+                    }
 
-                config.default_cloud = Some(input.name.clone());
-                config
-                    .save()
-                    .tool_context("Failed to save config")?;
+                    config.default_cloud = Some(input.name.clone());
+                    Ok(())
+                })
+                .tool_context("Failed to save config")??;
+                state.invalidate_profile_clients(&input.name).await;
 
                 Ok(CallToolResult::text(format!(
                     "Default cloud profile set to '{}'",
@@ -656,26 +654,24 @@ pub fn set_default_enterprise(state: Arc<AppState>) -> Tool {
                     return Err(McpError::tool("Write operations require --read-only=false"));
                 }
 
-                let mut config = Config::load()
-                    .tool_context("Failed to load config")?;
+                Config::update(|config| -> Result<(), McpError> {
+                    // Verify profile exists and is an enterprise profile
+                    let profile = config.profiles.get(&input.name).ok_or_else(|| {
+                        ToolError::new(format!("Profile '{}' not found", input.name))
+                    })?;
 
-                // Verify profile exists and is an enterprise profile
-                let profile = config
-                    .profiles
-                    .get(&input.name)
-                    .ok_or_else(|| ToolError::new(format!("Profile '{}' not found", input.name)))?;
-
-                if !matches!(profile.deployment_type, DeploymentType::Enterprise) {
-                    return Err(McpError::tool(format!(
-                        "Profile '{}' is not an enterprise profile (type: {:?})",
-                        input.name, profile.deployment_type
-                    )));
-                }
+                    if !matches!(profile.deployment_type, DeploymentType::Enterprise) {
+                        return Err(McpError::tool(format!(
+                            "Profile '{}' is not an enterprise profile (type: {:?})",
+                            input.name, profile.deployment_type
+                        )));
+                    }
 
-                config.default_enterprise = Some(input.name.clone());
-                config
-                    .save()
-                    .tool_context("Failed to save config")?;
+                    config.default_enterprise = Some(input.name.clone());
+                    Ok(())
+                })
+                .tool_context("Failed to save config")??;
+                state.invalidate_profile_clients(&input.name).await;
 
                 Ok(CallToolResult::text(format!(
                     "Default enterprise profile set to '{}'",
@@ -700,28 +696,32 @@ pub fn delete_profile(state: Arc<AppState>) -> Tool {
         .destructive()
         .extractor_handler(
             state,
-            |State(state): State<Arc<AppState>>, Json(input): Json<DeleteProfileInput>| async move {
+            |State(state): State<Arc<AppState>>,
+             ctx: Context,
+             Json(input): Json<DeleteProfileInput>| async move {
                 // Check destructive permission
                 if !state.is_destructive_allowed() {
                     return Err(McpError::tool(
                         "Destructive operations require policy tier 'full'",
                     ));
                 }
+                crate::tools::confirm_destructive(&state, &ctx, "profile_delete", &input).await?;
 
-                let mut config = Config::load().tool_context("Failed to load config")?;
-
-                // Check if profile exists
-                if !config.profiles.contains_key(&input.name) {
-                    return Err(McpError::tool(format!(
-                        "Profile '{}' not found",
-                        input.name
-                    )));
-                }
-
-                // Remove the profile (also clears defaults if this was a default)
-                config.remove_profile(&input.name);
+                Config::update(|config| -> Result<(), McpError> {
+                    // Check if profile exists
+                    if !config.profiles.contains_key(&input.name) {
+                        return Err(McpError::tool(format!(
+                            "Profile '{}' not found",
+                            input.name
+                        )));
+                    }
 
-                config.save().tool_context("Failed to save config")?;
+                    // Remove the profile (also clears defaults if this was a default)
+                    config.remove_profile(&input.name);
+                    Ok(())
+                })
+                .tool_context("Failed to save config")??;
+                state.invalidate_profile_clients(&input.name).await;
 
                 Ok(CallToolResult::text(format!(
                     "Profile '{}' deleted",
@@ -811,16 +811,6 @@ pub fn create_profile(state: Arc<AppState>) -> Tool {
                     return Err(McpError::tool("Write operations require --read-only=false"));
                 }
 
-                let mut config = Config::load().unwrap_or_default();
-
-                // Check if profile already exists
-                if config.profiles.contains_key(&input.name) {
-                    return Err(McpError::tool(format!(
-                        "Profile '{}' already exists. Use profile_delete first to replace it.",
-                        input.name
-                    )));
-                }
-
                 // Parse deployment type
                 let deployment_type = match input.profile_type.to_lowercase().as_str() {
                     "cloud" => DeploymentType::Cloud,
@@ -889,34 +879,46 @@ pub fn create_profile(state: Arc<AppState>) -> Tool {
                     files_api_key: None,
                     resilience: None,
                     tags: vec![],
+                    kubernetes: None,
+                    output: None,
+                    base: None,
                 };
 
-                // Check if this is the first profile of its type
-                let is_first_of_type =
-                    config.get_profiles_of_type(deployment_type).is_empty();
+                let should_set_default = Config::update(|config| -> Result<bool, McpError> {
+                    // Check if profile already exists
+                    if config.profiles.contains_key(&input.name) {
+                        return Err(McpError::tool(format!(
+                            "Profile '{}' already exists. Use profile_delete first to replace it.",
+                            input.name
+                        )));
+                    }
 
-                // Determine whether to set as default
-                let should_set_default = input.set_default.unwrap_or(is_first_of_type);
+                    // Check if this is the first profile of its type
+                    let is_first_of_type =
+                        config.get_profiles_of_type(deployment_type).is_empty();
 
-                config.set_profile(input.name.clone(), profile);
+                    // Determine whether to set as default
+                    let should_set_default = input.set_default.unwrap_or(is_first_of_type);
 
-                if should_set_default {
-                    match deployment_type {
-                        DeploymentType::Cloud => {
-                            config.default_cloud = Some(input.name.clone());
-                        }
-                        DeploymentType::Enterprise => {
-                            config.default_enterprise = Some(input.name.clone());
-                        }
-                        DeploymentType::Database => {
-                            config.default_database = Some(input.name.clone());
+                    config.set_profile(input.name.clone(), profile);
+
+                    if should_set_default {
+                        match deployment_type {
+                            DeploymentType::Cloud => {
+                                config.default_cloud = Some(input.name.clone());
+                            }
+                            DeploymentType::Enterprise => {
+                                config.default_enterprise = Some(input.name.clone());
+                            }
+                            DeploymentType::Database => {
+                                config.default_database = Some(input.name.clone());
+                            }
                         }
                     }
-                }
-
-                config
-                    .save()
-                    .tool_context("Failed to save config")?;
+                    Ok(should_set_default)
+                })
+                .tool_context("Failed to save config")??;
+                state.invalidate_profile_clients(&input.name).await;
 
                 let mut output = format!(
                     "Profile '{}' created (type: {})",
@@ -938,17 +940,18 @@ pub fn create_profile(state: Arc<AppState>) -> Tool {
 
 /// Build an MCP sub-router containing all App-level tools, resources, and prompts
 pub fn router(state: Arc<AppState>) -> McpRouter {
+    let overrides = state.tool_overrides();
     McpRouter::new()
         // Profile Tools - Read
-        .tool(list_profiles(state.clone()))
-        .tool(show_profile(state.clone()))
-        .tool(config_path(state.clone()))
-        .tool(validate_config(state.clone()))
+        .tool(overrides.apply(list_profiles(state.clone())))
+        .tool(overrides.apply(show_profile(state.clone())))
+        .tool(overrides.apply(config_path(state.clone())))
+        .tool(overrides.apply(validate_config(state.clone())))
         // Profile Tools - Write
-        .tool(create_profile(state.clone()))
-        .tool(set_default_cloud(state.clone()))
-        .tool(set_default_enterprise(state.clone()))
-        .tool(delete_profile(state.clone()))
+        .tool(overrides.apply(create_profile(state.clone())))
+        .tool(overrides.apply(set_default_cloud(state.clone())))
+        .tool(overrides.apply(set_default_enterprise(state.clone())))
+        .tool(overrides.apply(delete_profile(state.clone())))
         // Resources
         .resource(crate::resources::config_path_resource())
         .resource(crate::resources::profiles_resource())
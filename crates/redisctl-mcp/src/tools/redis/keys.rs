@@ -1,17 +1,22 @@
-//! Key-level Redis tools (keys, scan, get, key_type, ttl, exists, memory_usage, object_encoding,
-//! object_freq, object_idletime, object_help, set, del, expire, rename, mget, mset, persist,
-//! unlink, copy, dump, restore, randomkey, touch, incr, decr, append, strlen, getrange, setrange,
-//! setnx)
+//! Key-level Redis tools (keys, scan, scan_count, export, get, key_type, ttl, exists, memory_usage,
+//! object_encoding, object_freq, object_idletime, object_help, set, del, expire, rename, mget,
+//! mset, persist, unlink, copy, dump, restore, randomkey, touch, incr, decr, append, strlen,
+//! getrange, setrange, setnx)
 
+use std::collections::BTreeMap;
+
+use serde_json::{Value, json};
 use tower_mcp::{CallToolResult, ResultExt};
 
-use super::format_value;
+use super::{RedisConnection, bytes_to_json, format_bytes, format_value};
 use crate::serde_helpers;
 use crate::tools::macros::{database_tool, mcp_module};
 
 mcp_module! {
     keys => "redis_keys",
     scan => "redis_scan",
+    scan_count => "redis_scan_count",
+    export => "redis_export",
     get => "redis_get",
     key_type => "redis_type",
     ttl => "redis_ttl",
@@ -171,20 +176,264 @@ database_tool!(read_only, scan, "redis_scan",
     }
 );
 
+/// SCAN calls redis_scan_count makes when `max_iterations` is not given
+const DEFAULT_SCAN_COUNT_ITERATIONS: usize = 1_000;
+
+/// Upper bound on `max_iterations` for redis_scan_count, whatever the caller asks for
+const MAX_SCAN_COUNT_ITERATIONS: usize = 100_000;
+
+/// Keys per SCAN call for redis_scan_count
+const SCAN_COUNT_BATCH: usize = 1_000;
+
+/// Scale the memory of the sampled keys up to all matched keys
+fn estimate_memory(sampled_bytes: u64, sampled_keys: u64, matched: u64) -> Option<u64> {
+    (sampled_keys > 0).then(|| {
+        let average = sampled_bytes as f64 / sampled_keys as f64;
+        (average * matched as f64).round() as u64
+    })
+}
+
+database_tool!(read_only, scan_count, "redis_scan_count",
+    "Count keys matching a pattern (and optional type) with SCAN, without returning them. \
+     Optionally estimates their total memory by running MEMORY USAGE on every Nth matched key. \
+     Stops after max_iterations SCAN calls (default 1000, at most 100000); `iterations` is the \
+     number of SCAN calls made, and `complete` is false when the keyspace was not fully \
+     scanned, so `matched` is a lower bound.",
+    {
+        /// Key pattern to match (default: "*")
+        #[serde(default = "default_pattern")]
+        pub pattern: String,
+        /// Filter by key type (e.g., "string", "list", "set", "zset", "hash", "stream")
+        #[serde(default)]
+        pub key_type: Option<String>,
+        /// Run MEMORY USAGE on every Nth matched key to estimate total memory (default: no sampling)
+        #[serde(default, deserialize_with = "serde_helpers::string_or_opt_usize::deserialize")]
+        pub sample_every: Option<usize>,
+        /// Maximum number of SCAN calls, each covering about 1000 keys (default: 1000)
+        #[serde(default, deserialize_with = "serde_helpers::string_or_opt_usize::deserialize")]
+        pub max_iterations: Option<usize>,
+    } => |conn, input| {
+        let max_iterations = input
+            .max_iterations
+            .unwrap_or(DEFAULT_SCAN_COUNT_ITERATIONS)
+            .clamp(1, MAX_SCAN_COUNT_ITERATIONS);
+        let sample_every = input.sample_every.filter(|n| *n > 0);
+
+        let mut cursor: u64 = 0;
+        let mut iterations = 0usize;
+        let mut matched = 0u64;
+        let mut sampled_keys = 0u64;
+        let mut sampled_bytes = 0u64;
+
+        loop {
+            let mut cmd = redis::cmd("SCAN");
+            cmd.arg(cursor)
+                .arg("MATCH")
+                .arg(&input.pattern)
+                .arg("COUNT")
+                .arg(SCAN_COUNT_BATCH);
+            if let Some(ref key_type) = input.key_type {
+                cmd.arg("TYPE").arg(key_type);
+            }
+
+            // Key names are bytes; one that is not valid UTF-8 still counts
+            let (new_cursor, keys): (u64, Vec<Vec<u8>>) = cmd
+                .query_async(&mut conn)
+                .await
+                .tool_context("SCAN failed")?;
+            iterations += 1;
+
+            for key in keys {
+                matched += 1;
+                if let Some(n) = sample_every
+                    && matched % n as u64 == 0
+                {
+                    // Keys can expire between SCAN and MEMORY USAGE
+                    let bytes: Option<u64> = redis::cmd("MEMORY")
+                        .arg("USAGE")
+                        .arg(&key)
+                        .query_async(&mut conn)
+                        .await
+                        .tool_context("MEMORY USAGE failed")?;
+                    if let Some(bytes) = bytes {
+                        sampled_keys += 1;
+                        sampled_bytes += bytes;
+                    }
+                }
+            }
+
+            cursor = new_cursor;
+            if cursor == 0 || iterations >= max_iterations {
+                break;
+            }
+        }
+
+        CallToolResult::from_serialize(&json!({
+            "pattern": input.pattern,
+            "key_type": input.key_type,
+            "matched": matched,
+            "iterations": iterations,
+            "complete": cursor == 0,
+            "sampled_keys": sampled_keys,
+            "sampled_memory_bytes": estimate_memory(sampled_bytes, sampled_keys, matched),
+        }))
+    }
+);
+
+/// Upper bound on `max_keys` for redis_export, whatever the caller asks for
+const MAX_EXPORT_KEYS: usize = 10_000;
+
+/// Fetch a key's value with the command for its type.
+///
+/// Returns `None` when the key expired, was deleted, or changed type between
+/// SCAN and the fetch, or holds a type that cannot be exported.
+async fn export_key(
+    conn: &mut RedisConnection,
+    key: &[u8],
+) -> redis::RedisResult<Option<(String, Value)>> {
+    let key_type: String = redis::cmd("TYPE").arg(key).query_async(conn).await?;
+
+    let value = match key_type.as_str() {
+        "string" => {
+            let value: Option<Vec<u8>> = redis::cmd("GET").arg(key).query_async(conn).await?;
+            value.map(|v| bytes_to_json(&v))
+        }
+        "hash" => {
+            let fields: BTreeMap<Vec<u8>, Vec<u8>> =
+                redis::cmd("HGETALL").arg(key).query_async(conn).await?;
+            (!fields.is_empty()).then(|| {
+                Value::Object(
+                    fields
+                        .iter()
+                        .map(|(f, v)| (format_bytes(f), bytes_to_json(v)))
+                        .collect(),
+                )
+            })
+        }
+        "list" => {
+            let items: Vec<Vec<u8>> = redis::cmd("LRANGE")
+                .arg(key)
+                .arg(0)
+                .arg(-1)
+                .query_async(conn)
+                .await?;
+            (!items.is_empty()).then(|| items.iter().map(|i| bytes_to_json(i)).collect())
+        }
+        "set" => {
+            let members: Vec<Vec<u8>> = redis::cmd("SMEMBERS").arg(key).query_async(conn).await?;
+            (!members.is_empty()).then(|| members.iter().map(|m| bytes_to_json(m)).collect())
+        }
+        "zset" => {
+            let members: Vec<(Vec<u8>, f64)> = redis::cmd("ZRANGE")
+                .arg(key)
+                .arg(0)
+                .arg(-1)
+                .arg("WITHSCORES")
+                .query_async(conn)
+                .await?;
+            (!members.is_empty()).then(|| {
+                members
+                    .iter()
+                    .map(|(m, score)| json!({"member": bytes_to_json(m), "score": score}))
+                    .collect()
+            })
+        }
+        _ => None,
+    };
+
+    Ok(value.map(|v| (key_type, v)))
+}
+
+database_tool!(read_only, export, "redis_export",
+    "Export key/value pairs for keys matching a pattern. SCANs the pattern, then fetches each \
+     key with the command for its type (GET, HGETALL, LRANGE, SMEMBERS, ZRANGE) and returns \
+     a JSON array of {key, type, value}, base64-encoding keys and values that are not valid \
+     UTF-8. Stops after max_keys keys. Keys that expire or change \
+     type during the export, and types other than string/hash/list/set/zset, are skipped and \
+     counted rather than failing the call.",
+    {
+        /// Key pattern to match (default: "*")
+        #[serde(default = "default_pattern")]
+        pub pattern: String,
+        /// Maximum number of keys to export (default: 100, at most 10000)
+        #[serde(default = "default_limit", deserialize_with = "serde_helpers::string_or_usize::deserialize")]
+        pub max_keys: usize,
+    } => |conn, input| {
+        let max_keys = input.max_keys.clamp(1, MAX_EXPORT_KEYS);
+        let mut cursor: u64 = 0;
+        // Key names are bytes; one that is not valid UTF-8 must not fail the export
+        let mut keys: Vec<Vec<u8>> = Vec::new();
+
+        loop {
+            let (new_cursor, batch): (u64, Vec<Vec<u8>>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&input.pattern)
+                .arg("COUNT")
+                .arg(100)
+                .query_async(&mut conn)
+                .await
+                .tool_context("SCAN failed")?;
+
+            keys.extend(batch);
+            cursor = new_cursor;
+
+            if cursor == 0 || keys.len() >= max_keys {
+                break;
+            }
+        }
+
+        let truncated = cursor != 0 || keys.len() > max_keys;
+        keys.truncate(max_keys);
+
+        let mut exported = Vec::with_capacity(keys.len());
+        let mut skipped = 0usize;
+        for key in keys {
+            match export_key(&mut conn, &key).await {
+                Ok(Some((key_type, value))) => {
+                    exported.push(json!({
+                        "key": bytes_to_json(&key),
+                        "type": key_type,
+                        "value": value,
+                    }));
+                }
+                Ok(None) => skipped += 1,
+                // The key changed type between TYPE and the fetch
+                Err(e) if e.code() == Some("WRONGTYPE") => skipped += 1,
+                Err(e) => {
+                    return Err(tower_mcp::Error::tool(format!(
+                        "Failed to export key '{}': {}",
+                        format_bytes(&key),
+                        e
+                    )));
+                }
+            }
+        }
+
+        CallToolResult::from_serialize(&json!({
+            "pattern": input.pattern,
+            "count": exported.len(),
+            "skipped": skipped,
+            "truncated": truncated,
+            "keys": exported,
+        }))
+    }
+);
+
 database_tool!(read_only, get, "redis_get",
     "Get the value of a key.",
     {
         /// Key to get
         pub key: String,
     } => |conn, input| {
-        let value: Option<String> = redis::cmd("GET")
+        let value: Option<Vec<u8>> = redis::cmd("GET")
             .arg(&input.key)
             .query_async(&mut conn)
             .await
             .tool_context("GET failed")?;
 
         match value {
-            Some(v) => Ok(CallToolResult::text(v)),
+            Some(v) => Ok(CallToolResult::text(format_bytes(&v))),
             None => Ok(CallToolResult::text(format!(
                 "(nil) - key '{}' not found",
                 input.key
@@ -443,15 +692,39 @@ database_tool!(destructive, del, "redis_del",
     }
 );
 
+/// Largest TTL Redis accepts. Expiry is stored as an absolute millisecond
+/// timestamp, so leave room for the current time before `i64::MAX`.
+const MAX_TTL_SECONDS: i64 = i64::MAX / 1000 - 10_000_000_000;
+
+/// Reject TTLs that would delete the key immediately or overflow Redis' expiry
+fn validate_ttl(seconds: i64) -> Result<(), tower_mcp::Error> {
+    if seconds <= 0 {
+        return Err(tower_mcp::Error::tool(format!(
+            "seconds must be positive (got {}); a TTL of 0 or less deletes the key immediately, use redis_del for that",
+            seconds
+        )));
+    }
+    if seconds > MAX_TTL_SECONDS {
+        return Err(tower_mcp::Error::tool(format!(
+            "seconds must be at most {} (got {})",
+            MAX_TTL_SECONDS, seconds
+        )));
+    }
+    Ok(())
+}
+
 database_tool!(write, expire, "redis_expire",
-    "Set a timeout on a key in seconds. Key auto-deletes after expiry.",
+    "Set a timeout on a key in seconds (must be positive). Key auto-deletes after expiry. \
+     Use redis_persist to remove the timeout.",
     {
         /// Key to set expiry on
         pub key: String,
-        /// TTL in seconds
+        /// TTL in seconds (positive)
         #[serde(deserialize_with = "serde_helpers::string_or_i64::deserialize")]
         pub seconds: i64,
     } => |conn, input| {
+        validate_ttl(input.seconds)?;
+
         let result: bool = redis::cmd("EXPIRE")
             .arg(&input.key)
             .arg(input.seconds)
@@ -556,7 +829,7 @@ database_tool!(write, mset, "redis_mset",
 );
 
 database_tool!(write, persist, "redis_persist",
-    "Remove the expiry from a key, making it persistent.",
+    "Remove the expiry from a key, making it persistent. Use redis_expire to set one.",
     {
         /// Key to remove expiry from
         pub key: String,
@@ -846,7 +1119,7 @@ database_tool!(read_only, getrange, "redis_getrange",
         #[serde(deserialize_with = "serde_helpers::string_or_i64::deserialize")]
         pub end: i64,
     } => |conn, input| {
-        let value: String = redis::cmd("GETRANGE")
+        let value: Vec<u8> = redis::cmd("GETRANGE")
             .arg(&input.key)
             .arg(input.start)
             .arg(input.end)
@@ -854,7 +1127,7 @@ database_tool!(read_only, getrange, "redis_getrange",
             .await
             .tool_context("GETRANGE failed")?;
 
-        Ok(CallToolResult::text(value))
+        Ok(CallToolResult::text(format_bytes(&value)))
     }
 );
 
@@ -912,3 +1185,26 @@ database_tool!(write, setnx, "redis_setnx",
         }
     }
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_ttl() {
+        assert!(validate_ttl(1).is_ok());
+        assert!(validate_ttl(86_400).is_ok());
+        assert!(validate_ttl(MAX_TTL_SECONDS).is_ok());
+        assert!(validate_ttl(0).is_err());
+        assert!(validate_ttl(-1).is_err());
+        assert!(validate_ttl(MAX_TTL_SECONDS + 1).is_err());
+        assert!(validate_ttl(i64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_estimate_memory_scales_sample_to_matched_keys() {
+        assert_eq!(estimate_memory(0, 0, 500), None);
+        assert_eq!(estimate_memory(300, 3, 30), Some(1_000));
+        assert_eq!(estimate_memory(150, 2, 3), Some(225));
+    }
+}
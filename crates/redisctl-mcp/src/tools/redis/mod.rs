@@ -34,6 +34,7 @@ pub use structures::*;
 
 use std::sync::Arc;
 
+use base64::Engine;
 use tower_mcp::{McpRouter, ToolError};
 
 use super::SubModule;
@@ -95,6 +96,25 @@ pub fn sub_tool_names(name: &str) -> Option<&'static [&'static str]> {
         .map(|sm| sm.tool_names)
 }
 
+/// Format a bulk string, base64-encoding it when it is not valid UTF-8
+pub(crate) fn format_bytes(bytes: &[u8]) -> String {
+    match bytes_to_json(bytes) {
+        serde_json::Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+/// A bulk string as a JSON string, or a base64 object when it is not valid UTF-8
+pub(crate) fn bytes_to_json(bytes: &[u8]) -> serde_json::Value {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => serde_json::Value::String(s.to_string()),
+        Err(_) => serde_json::json!({
+            "encoding": "base64",
+            "value": base64::engine::general_purpose::STANDARD.encode(bytes),
+        }),
+    }
+}
+
 /// Build an MCP sub-router for a specific sub-module by name.
 pub fn sub_router(name: &str, state: Arc<AppState>) -> Option<McpRouter> {
     match name {
@@ -165,11 +185,14 @@ pub(crate) async fn get_connection(
 }
 
 /// Helper to format Redis values for display
+///
+/// Bulk strings that are not valid UTF-8 are emitted as
+/// `{"encoding":"base64","value":"..."}` so binary payloads survive intact.
 pub(crate) fn format_value(v: &redis::Value) -> String {
     match v {
         redis::Value::Nil => "(nil)".to_string(),
         redis::Value::Int(i) => i.to_string(),
-        redis::Value::BulkString(b) => String::from_utf8_lossy(b).to_string(),
+        redis::Value::BulkString(b) => format_bytes(b),
         redis::Value::SimpleString(s) => s.clone(),
         redis::Value::Array(arr) => format!(
             "[{}]",
@@ -192,3 +215,34 @@ pub fn router(state: Arc<AppState>) -> McpRouter {
         .merge(raw::router(state.clone()))
         .merge(aliases::router(state))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_value_keeps_utf8_bulk_strings() {
+        let v = redis::Value::BulkString("héllo".as_bytes().to_vec());
+        assert_eq!(format_value(&v), "héllo");
+    }
+
+    #[test]
+    fn format_value_base64_encodes_binary() {
+        let v = redis::Value::BulkString(vec![0xff, 0x00, 0xfe]);
+        let parsed: serde_json::Value = serde_json::from_str(&format_value(&v)).unwrap();
+        assert_eq!(parsed["encoding"], "base64");
+        assert_eq!(parsed["value"], "/wD+");
+    }
+
+    #[test]
+    fn format_value_base64_encodes_binary_inside_arrays() {
+        let v = redis::Value::Array(vec![
+            redis::Value::BulkString(b"ok".to_vec()),
+            redis::Value::BulkString(vec![0x80]),
+        ]);
+        assert_eq!(
+            format_value(&v),
+            r#"[ok, {"encoding":"base64","value":"gA=="}]"#
+        );
+    }
+}
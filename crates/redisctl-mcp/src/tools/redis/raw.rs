@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use schemars::JsonSchema;
 use serde::Deserialize;
-use tower_mcp::extract::{Json, State};
+use tower_mcp::extract::{Context, Json, State};
 use tower_mcp::{CallToolResult, Error as McpError, McpRouter, Tool, ToolBuilder};
 
 use crate::state::AppState;
@@ -98,7 +98,9 @@ pub fn redis_command(state: Arc<AppState>) -> Tool {
         .destructive()
         .extractor_handler(
             state,
-            |State(state): State<Arc<AppState>>, Json(input): Json<RedisCommandInput>| async move {
+            |State(state): State<Arc<AppState>>,
+             ctx: Context,
+             Json(input): Json<RedisCommandInput>| async move {
                 if !state.is_destructive_allowed() {
                     return Err(McpError::tool("redis_command requires full tier"));
                 }
@@ -131,6 +133,8 @@ pub fn redis_command(state: Arc<AppState>) -> Tool {
                     return CallToolResult::from_serialize(&preview);
                 }
 
+                crate::tools::confirm_destructive(&state, &ctx, "redis_command", &input).await?;
+
                 let mut conn =
                     super::get_connection(input.url, input.profile.as_deref(), &state).await?;
 
@@ -155,7 +159,7 @@ pub(super) const TOOL_NAMES: &[&str] = &["redis_command"];
 
 /// Build a sub-router containing the raw Redis command tool.
 pub fn router(state: Arc<AppState>) -> McpRouter {
-    McpRouter::new().tool(redis_command(state))
+    McpRouter::new().tool(state.tool_overrides().apply(redis_command(state.clone())))
 }
 
 #[cfg(test)]
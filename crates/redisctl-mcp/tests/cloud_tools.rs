@@ -145,6 +145,91 @@ async fn test_list_databases() {
     assert_eq!(databases[1]["name"], "cache-replica");
 }
 
+#[tokio::test]
+async fn test_list_all_cloud_databases() {
+    let server = MockCloudServer::start().await;
+
+    let sub1 = SubscriptionFixture::new(123, "Production").build();
+    let sub2 = SubscriptionFixture::new(456, "Development").build();
+    server.mock_subscriptions_list(vec![sub1, sub2]).await;
+    server
+        .mock_databases_list(
+            123,
+            vec![
+                DatabaseFixture::new(1001, "cache-primary").build(),
+                DatabaseFixture::new(1002, "sessions").build(),
+            ],
+        )
+        .await;
+    server
+        .mock_databases_list(456, vec![DatabaseFixture::new(2001, "cache-dev").build()])
+        .await;
+
+    let client = server.client();
+    let state = Arc::new(AppState::with_cloud_client(client));
+    let tool = cloud::list_all_cloud_databases(state);
+
+    let result = call_tool_json(&tool, json!({})).await;
+    assert_eq!(result["total"], 3);
+    assert_eq!(result["subscriptions_scanned"], 2);
+    assert_eq!(result["databases"][0]["subscriptionId"], 123);
+    assert_eq!(result["databases"][0]["subscriptionName"], "Production");
+    assert_eq!(result["databases"][2]["subscriptionName"], "Development");
+
+    let result = call_tool_json(&tool, json!({"name": "CACHE"})).await;
+    assert_eq!(result["total"], 2);
+    let names: Vec<_> = result["databases"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|db| db["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["cache-primary", "cache-dev"]);
+}
+
+#[tokio::test]
+async fn test_list_all_cloud_databases_follows_pagination() {
+    use wiremock::Mock;
+    use wiremock::matchers::{method, path, query_param};
+
+    let server = MockCloudServer::start().await;
+    server
+        .mock_subscriptions_list(vec![SubscriptionFixture::new(123, "Production").build()])
+        .await;
+
+    let first_page: Vec<_> = (0..100)
+        .map(|i| DatabaseFixture::new(1000 + i, &format!("db-{}", i)).build())
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscription": [{"subscriptionId": 123, "databases": first_page}]
+        })))
+        .expect(1)
+        .mount(server.inner())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases"))
+        .and(query_param("offset", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscription": [{
+                "subscriptionId": 123,
+                "databases": [DatabaseFixture::new(1100, "db-100").build()]
+            }]
+        })))
+        .expect(1)
+        .mount(server.inner())
+        .await;
+
+    let state = Arc::new(AppState::with_cloud_client(server.client()));
+    let tool = cloud::list_all_cloud_databases(state);
+
+    let result = call_tool_json(&tool, json!({})).await;
+    assert_eq!(result["total"], 101);
+    assert_eq!(result["databases"][100]["name"], "db-100");
+}
+
 #[tokio::test]
 async fn test_get_database() {
     let server = MockCloudServer::start().await;
@@ -180,6 +265,71 @@ async fn test_get_database() {
     assert_eq!(result["replication"], true);
 }
 
+#[tokio::test]
+async fn test_update_cloud_database_sends_only_given_fields() {
+    use redisctl_mcp::policy::{Policy, PolicyConfig, SafetyTier};
+    use wiremock::Mock;
+    use wiremock::matchers::{body_json, method, path};
+
+    let server = MockCloudServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/databases/1001"))
+        .and(body_json(json!({
+            "memoryLimitInGb": 4.0,
+            "throughputMeasurement": {"by": "operations-per-second", "value": 50000}
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-update",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(server.inner())
+        .await;
+
+    let task = TaskFixture::completed("task-update", 1001)
+        .command_type("databaseUpdateRequest")
+        .build();
+    server.mock_task_get("task-update", task).await;
+
+    let database = DatabaseFixture::new(1001, "cache-primary")
+        .memory_limit_in_gb(4.0)
+        .throughput("operations-per-second", 50000)
+        .build();
+    server.mock_database_get(123, 1001, database).await;
+
+    let mut state = AppState::with_cloud_client(server.client());
+    state.policy = Arc::new(Policy::new(
+        PolicyConfig {
+            tier: SafetyTier::ReadWrite,
+            ..Default::default()
+        },
+        std::collections::HashMap::new(),
+        "test".to_string(),
+    ));
+    let tool = cloud::update_cloud_database(Arc::new(state));
+
+    let result = call_tool_json(
+        &tool,
+        json!({
+            "subscription_id": 123,
+            "database_id": 1001,
+            "memory_limit_gb": 4.0,
+            "throughput_by": "operations-per-second",
+            "throughput_value": 50000
+        }),
+    )
+    .await;
+
+    assert_eq!(result["task"]["task_id"], "task-update");
+    assert_eq!(result["task"]["succeeded"], true);
+    assert_eq!(
+        result["updated_fields"],
+        json!(["memoryLimitInGb", "throughputMeasurement"])
+    );
+    assert_eq!(result["database"]["memoryLimitInGb"], 4.0);
+}
+
 // ============================================================================
 // Account Tests
 // ============================================================================
@@ -286,6 +436,54 @@ async fn test_get_task_failed() {
     assert_eq!(result["response"]["error"], "Insufficient credits");
 }
 
+#[tokio::test]
+async fn test_wait_for_cloud_task_completed() {
+    let server = MockCloudServer::start().await;
+
+    let task = TaskFixture::completed("task-001", 123)
+        .command_type("databaseCreateRequest")
+        .build();
+
+    server.mock_task_get("task-001", task).await;
+
+    let client = server.client();
+    let state = Arc::new(AppState::with_cloud_client(client));
+    let tool = cloud::wait_for_cloud_task(state);
+
+    let result = call_tool_json(&tool, json!({"task_id": "task-001", "interval_seconds": 1})).await;
+
+    assert_eq!(result["succeeded"], true);
+    assert_eq!(result["status"], "processing-completed");
+    assert_eq!(result["resource_id"], 123);
+    assert_eq!(result["progress"]["polls"], 1);
+    assert_eq!(
+        result["progress"]["status_history"][0],
+        "processing-completed"
+    );
+    assert_eq!(result["task"]["taskId"], "task-001");
+}
+
+#[tokio::test]
+async fn test_wait_for_cloud_task_failed() {
+    let server = MockCloudServer::start().await;
+
+    let task = TaskFixture::failed("task-002", "Insufficient credits")
+        .command_type("subscriptionCreateRequest")
+        .build();
+
+    server.mock_task_get("task-002", task).await;
+
+    let client = server.client();
+    let state = Arc::new(AppState::with_cloud_client(client));
+    let tool = cloud::wait_for_cloud_task(state);
+
+    let result = call_tool_json(&tool, json!({"task_id": "task-002", "interval_seconds": 1})).await;
+
+    assert_eq!(result["succeeded"], false);
+    assert_eq!(result["error"], "Insufficient credits");
+    assert_eq!(result["last_status"]["status"], "processing-error");
+}
+
 // ============================================================================
 // User Tests
 // ============================================================================
@@ -56,6 +56,50 @@ async fn test_get_cluster() {
     assert_eq!(result["name"], "production-cluster");
 }
 
+#[tokio::test]
+async fn test_get_cluster_summary() {
+    let server = MockEnterpriseServer::start().await;
+
+    server
+        .mock_cluster_info(
+            ClusterFixture::new("production-cluster")
+                .nodes(vec![1, 2])
+                .build(),
+        )
+        .await;
+    server
+        .mock_nodes_list(vec![
+            NodeFixture::new(1, "10.0.0.1").build(),
+            NodeFixture::new(2, "10.0.0.2").build(),
+        ])
+        .await;
+    server
+        .mock_databases_list(vec![DatabaseFixture::new(1, "cache").build()])
+        .await;
+    server.mock_license(LicenseFixture::new().build()).await;
+    // Cluster alerts are a map of alert name to settings; only raised ones count
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster/alerts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "node_failed": {"state": true},
+            "high_memory_usage": {"state": false, "threshold": "80"}
+        })))
+        .mount(server.inner())
+        .await;
+
+    let client = server.client();
+    let state = Arc::new(AppState::with_enterprise_client(client));
+    let tool = enterprise::get_cluster_summary(state);
+
+    let result = call_tool_json(&tool, json!({})).await;
+
+    assert_eq!(result["name"], "production-cluster");
+    assert_eq!(result["nodes"], 2);
+    assert_eq!(result["databases"], 1);
+    assert_eq!(result["license_expired"], false);
+    assert_eq!(result["active_alerts"], 1);
+}
+
 #[tokio::test]
 async fn test_get_cluster_stats() {
     let server = MockEnterpriseServer::start().await;
@@ -746,6 +790,53 @@ async fn test_get_debug_info_status() {
     assert!(result.get("download_url").is_some());
 }
 
+#[tokio::test]
+async fn test_create_debug_info_wait_returns_download_url() {
+    use redisctl_mcp::policy::{Policy, PolicyConfig, SafetyTier};
+
+    let server = MockEnterpriseServer::start().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "task_id": "debug-789",
+            "status": "queued"
+        })))
+        .expect(1)
+        .mount(server.inner())
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/debuginfo/debug-789"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "task_id": "debug-789",
+            "status": "completed",
+            "progress": 100.0,
+            "download_url": "https://example.com/download/debug-789.tar.gz"
+        })))
+        .mount(server.inner())
+        .await;
+
+    let mut state = AppState::with_enterprise_client(server.client());
+    state.policy = Arc::new(Policy::new(
+        PolicyConfig {
+            tier: SafetyTier::ReadWrite,
+            ..Default::default()
+        },
+        std::collections::HashMap::new(),
+        "test".to_string(),
+    ));
+    let tool = enterprise::create_debug_info(Arc::new(state));
+
+    let result = call_tool_json(&tool, json!({"wait": true})).await;
+
+    assert_eq!(result["task_id"], "debug-789");
+    assert_eq!(result["status"], "completed");
+    assert_eq!(
+        result["download_url"],
+        "https://example.com/download/debug-789.tar.gz"
+    );
+}
+
 // ============================================================================
 // Module Tests
 // ============================================================================
@@ -460,6 +460,94 @@ async fn test_key_read_tools() {
     cleanup(&mut conn, p).await;
 }
 
+#[tokio::test]
+#[ignore = "Requires Docker"]
+async fn test_export_tool() {
+    let ctx = get_redis().await.expect("Failed to get Redis container");
+    let state = make_state(ctx.port);
+    let mut conn = get_conn(ctx.port).await;
+    let p = "ex_"; // key prefix
+
+    cleanup(&mut conn, p).await;
+
+    let _: () = ::redis::cmd("SET")
+        .arg(format!("{p}str"))
+        .arg("hello")
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    let _: () = ::redis::cmd("HSET")
+        .arg(format!("{p}hash"))
+        .arg("field")
+        .arg("value")
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    let _: () = ::redis::cmd("RPUSH")
+        .arg(format!("{p}list"))
+        .arg("a")
+        .arg("b")
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    let _: () = ::redis::cmd("ZADD")
+        .arg(format!("{p}zset"))
+        .arg(1.5)
+        .arg("m")
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+    let _: () = ::redis::cmd("XADD")
+        .arg(format!("{p}stream"))
+        .arg("*")
+        .arg("f")
+        .arg("v")
+        .query_async(&mut conn)
+        .await
+        .unwrap();
+
+    let text = call_tool_text(
+        &redis::export(state.clone()),
+        json!({"pattern": format!("{p}*")}),
+    )
+    .await;
+    let result: serde_json::Value = serde_json::from_str(&text).expect("export returns JSON");
+    assert_eq!(result["count"], 4, "export: {}", text);
+    // Streams are not exported
+    assert_eq!(result["skipped"], 1, "export: {}", text);
+    assert_eq!(result["truncated"], false, "export: {}", text);
+
+    let by_key: HashMap<String, serde_json::Value> = result["keys"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|k| (k["key"].as_str().unwrap().to_string(), k.clone()))
+        .collect();
+    assert_eq!(by_key[&format!("{p}str")]["value"], "hello");
+    assert_eq!(by_key[&format!("{p}hash")]["value"]["field"], "value");
+    assert_eq!(by_key[&format!("{p}list")]["value"], json!(["a", "b"]));
+    assert_eq!(
+        by_key[&format!("{p}zset")]["value"],
+        json!([{"member": "m", "score": 1.5}])
+    );
+
+    // max_keys bounds the export
+    let text = call_tool_text(
+        &redis::export(state.clone()),
+        json!({"pattern": format!("{p}*"), "max_keys": 2}),
+    )
+    .await;
+    let result: serde_json::Value = serde_json::from_str(&text).unwrap();
+    assert!(
+        result["keys"].as_array().unwrap().len() <= 2,
+        "export: {}",
+        text
+    );
+    assert_eq!(result["truncated"], true, "export: {}", text);
+
+    cleanup(&mut conn, p).await;
+}
+
 // ============================================================================
 // Key write tools
 // ============================================================================
@@ -523,6 +611,12 @@ async fn test_key_write_tools() {
     .await;
     assert!(text.contains("OK"), "expire: {}", text);
 
+    // redis_expire rejects a TTL that would delete the key
+    let result = redis::expire(state.clone())
+        .call(json!({"key": format!("{p}k1"), "seconds": 0}))
+        .await;
+    assert!(result.is_error, "expire 0 should be rejected");
+
     // redis_persist
     let text = call_tool_text(
         &redis::persist(state.clone()),
@@ -926,7 +926,27 @@ pub enum PrivateLinkCommands {
 pub enum CloudTaskCommands {
     /// List all tasks for this account
     #[command(alias = "ls")]
-    List,
+    #[command(after_help = "EXAMPLES:
+    # List all tasks
+    redisctl cloud task list
+
+    # Show only pending/processing tasks
+    redisctl cloud task list --active
+
+    # Refresh until every in-flight task has finished
+    redisctl cloud task list --active --watch --interval 10
+")]
+    List {
+        /// Only show tasks that are still pending or processing
+        #[arg(long)]
+        active: bool,
+        /// Keep refreshing until no active tasks remain
+        #[arg(long)]
+        watch: bool,
+        /// Refresh interval in seconds when watching
+        #[arg(long, default_value = "5", requires = "watch")]
+        interval: u64,
+    },
     /// Get task status and details
     Get {
         /// Task ID (UUID format)
@@ -1694,6 +1714,9 @@ NOTE: The maximum date range is 40 days. Cost reports are generated asynchronous
     # Download cost report to a file
     redisctl cloud cost-report download cost-report-12345-abcdef --file report.csv
 
+    # Download as a flat spreadsheet (one row per subscription, database, month, line item)
+    redisctl cloud cost-report download cost-report-12345-abcdef --flatten --file costs.csv
+
 NOTE: The costReportId is returned in the task response after the generation completes.
       Check task status with 'redisctl cloud task get <task-id>' to get the costReportId.
 ")]
@@ -1704,6 +1727,12 @@ NOTE: The costReportId is returned in the task response after the generation com
         /// Output file path (defaults to stdout if not specified)
         #[arg(long = "file", short = 'f')]
         file: Option<String>,
+
+        /// Flatten the report into one row per subscription, database, month and
+        /// line item (subscription_id, database_id, period, line_item, amount,
+        /// currency). Written as CSV, or as a JSON array with -o json on stdout
+        #[arg(long)]
+        flatten: bool,
     },
 
     /// Generate and download a cost report in one step
@@ -1720,6 +1749,10 @@ NOTE: The costReportId is returned in the task response after the generation com
     redisctl cloud cost-report export --start-date 2025-01-01 --end-date 2025-01-31 \\
       --subscription 12345 --tag team:platform --file team-costs.csv
 
+    # Export a flat spreadsheet keyed by subscription and month for finance
+    redisctl cloud cost-report export --start-date 2025-01-15 --end-date 2025-02-15 \\
+      --flatten --file costs.csv
+
 NOTE: This command combines 'generate --wait' and 'download' into a single operation.
       The maximum date range is 40 days.
 ")]
@@ -1763,6 +1796,12 @@ NOTE: This command combines 'generate --wait' and 'download' into a single opera
         /// Maximum time to wait for report generation in seconds
         #[arg(long, default_value = "300")]
         timeout: u64,
+
+        /// Flatten the report into one row per subscription, database, month and
+        /// line item (subscription_id, database_id, period, line_item, amount,
+        /// currency). Written as CSV, or as a JSON array with -o json on stdout
+        #[arg(long)]
+        flatten: bool,
     },
 }
 
@@ -1937,12 +1976,35 @@ NOTE: Subscription creation requires complex nested structures for cloud provide
     },
 
     /// Delete a subscription
+    #[command(after_help = "EXAMPLES:
+    # Delete an empty subscription
+    redisctl cloud subscription delete 123456 --wait
+
+    # Delete every database in the subscription, then the subscription
+    redisctl cloud subscription delete 123456 --delete-databases --wait
+
+    # Preview what --delete-databases would delete
+    redisctl cloud subscription delete 123456 --delete-databases --dry-run
+
+    # Non-interactive teardown (e.g. CI)
+    redisctl cloud subscription delete 123456 --force --delete-databases --yes --wait
+
+NOTE: Without a terminal to prompt on, confirmations fail instead of being
+      skipped; pass --force (and --yes with --delete-databases).")]
     Delete {
         /// Subscription ID
         id: u32,
         /// Skip confirmation prompt
         #[arg(long)]
         force: bool,
+        /// Delete all databases in the subscription first (each is waited on),
+        /// then delete the subscription. The databases to delete are listed
+        /// and confirmed separately
+        #[arg(long, visible_alias = "cascade")]
+        delete_databases: bool,
+        /// Skip the database confirmation for --delete-databases
+        #[arg(short, long, requires = "delete_databases")]
+        yes: bool,
         /// Dry run - show what would be deleted without deleting
         #[arg(long)]
         dry_run: bool,
@@ -1951,6 +2013,29 @@ NOTE: Subscription creation requires complex nested structures for cloud provide
         async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
     },
 
+    /// Wait for a subscription to finish provisioning
+    #[command(after_help = "EXAMPLES:
+    # Block until a subscription created through the raw API is active
+    redisctl cloud subscription wait --id 123456
+
+    # Give large deployments more time
+    redisctl cloud subscription wait --id 123456 --timeout 1800 -o json
+
+The pending task for the subscription is polled when there is one; otherwise
+the subscription status is polled until it is active. Prints the subscription
+once it is ready, or fails with the task error.")]
+    Wait {
+        /// Subscription ID
+        #[arg(long)]
+        id: u32,
+        /// Maximum time to wait in seconds
+        #[arg(long, default_value = "600")]
+        timeout: u64,
+        /// Polling interval in seconds
+        #[arg(long, default_value = "10")]
+        interval: u64,
+    },
+
     /// Get available Redis versions
     RedisVersions {
         /// Filter by subscription ID (optional)
@@ -2044,6 +2129,10 @@ NOTE: Subscription creation requires complex nested structures for cloud provide
         data: Option<String>,
     },
 
+    /// Show or set the subscription's maintenance window
+    #[command(subcommand, name = "maintenance-window")]
+    MaintenanceWindow(CloudMaintenanceWindowCommands),
+
     /// List Active-Active regions
     ListAaRegions {
         /// Subscription ID
@@ -2143,6 +2232,75 @@ NOTE: Subscription creation requires complex nested structures for cloud provide
     },
 }
 
+/// Subscription maintenance window subcommands
+#[derive(Subcommand, Debug)]
+pub enum CloudMaintenanceWindowCommands {
+    /// Show when maintenance may run
+    #[command(after_help = "EXAMPLES:
+    redisctl cloud subscription maintenance-window show 123
+
+    # Machine-readable, e.g. to diff against a checked-in schedule
+    redisctl cloud subscription maintenance-window show 123 -o json")]
+    Show {
+        /// Subscription ID
+        id: u32,
+    },
+
+    /// Set a manual maintenance window, or go back to automatic scheduling
+    #[command(after_help = "EXAMPLES:
+    # Sundays from 02:00 UTC for 4 hours
+    redisctl cloud subscription maintenance-window set 123 \\
+      --days sun --start 02:00 --duration 4h
+
+    # Weeknights, waiting for the change to apply
+    redisctl cloud subscription maintenance-window set 123 \\
+      --days mon,tue,wed,thu,fri --start 23:00 --duration 6h --wait
+
+    # Let Redis Cloud choose when to run maintenance
+    redisctl cloud subscription maintenance-window set 123 --automatic
+
+NOTE: Times are in UTC and windows start on the hour. --days accepts day
+      names or three-letter abbreviations. The window replaces any manual
+      windows already configured.")]
+    Set {
+        /// Subscription ID
+        id: u32,
+
+        /// Days the window opens, comma-separated (e.g. sun or mon,wed,fri)
+        #[arg(
+            long,
+            value_delimiter = ',',
+            required_unless_present = "automatic",
+            conflicts_with = "automatic"
+        )]
+        days: Vec<String>,
+
+        /// Start time in UTC, on the hour (HH:MM)
+        #[arg(
+            long,
+            required_unless_present = "automatic",
+            conflicts_with = "automatic"
+        )]
+        start: Option<String>,
+
+        /// Window length in whole hours (e.g. 4h)
+        #[arg(
+            long,
+            required_unless_present = "automatic",
+            conflicts_with = "automatic"
+        )]
+        duration: Option<String>,
+
+        /// Switch to automatic maintenance scheduling
+        #[arg(long)]
+        automatic: bool,
+
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum CloudDatabaseCommands {
     /// List all databases across subscriptions
@@ -2171,6 +2329,10 @@ pub enum CloudDatabaseCommands {
       --replication \\
       --data-persistence aof-every-1-second
 
+    # Wait until the database is active, then print it
+    redisctl cloud database create --subscription 123 --name mydb --memory 1 \\
+      --wait --wait-timeout 900
+
     # Advanced: Mix flags with JSON for rare options
     redisctl cloud database create \\
       --subscription 123 \\
@@ -2436,9 +2598,18 @@ pub enum CloudDatabaseCommands {
     },
 
     /// Get database certificate
+    #[command(after_help = "EXAMPLES:
+    # Save the server CA certificate for TLS connections
+    redisctl cloud database get-certificate 123:456 --file ca.pem
+    redis-cli --tls --cacert ca.pem -h <host> -p <port>
+")]
     GetCertificate {
         /// Database ID (format: subscription_id:database_id)
         id: String,
+
+        /// Write the PEM certificate to this file instead of printing it
+        #[arg(long = "file", short = 'f')]
+        file: Option<String>,
     },
 
     /// Get slow query log
@@ -2778,6 +2949,40 @@ pub enum CloudAclCommands {
         async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
     },
 
+    /// Bind an ACL role to a database, optionally assigning it to ACL users
+    #[command(
+        name = "bind-role",
+        after_help = "EXAMPLES:
+    # Give role 12 access to database 51 in subscription 1234 through all of its rules
+    redisctl cloud acl bind-role 12 --subscription 1234 --database 51 --wait
+
+    # Bind through one Redis rule only, and assign the role to two ACL users
+    redisctl cloud acl bind-role 12 --subscription 1234 --database 51 \\
+        --rule Read-Only --user 7 --user 8 --wait
+
+NOTE: The role, database, rule, and users are all checked before anything
+      is changed. The database is added to the role's existing bindings."
+    )]
+    BindRole {
+        /// Role ID
+        id: i32,
+        /// Subscription ID of the database
+        #[arg(long)]
+        subscription: i32,
+        /// Database ID to bind the role to
+        #[arg(long)]
+        database: i32,
+        /// Redis rule name to bind through (default: every rule already in the role)
+        #[arg(long)]
+        rule: Option<String>,
+        /// ACL user ID to assign the role to (repeatable)
+        #[arg(long = "user", value_name = "USER_ID")]
+        users: Vec<i32>,
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
+    },
+
     // ACL Users
     /// List all ACL users
     #[command(name = "list-acl-users")]
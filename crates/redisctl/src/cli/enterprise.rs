@@ -89,6 +89,10 @@ pub enum EnterpriseCommands {
         /// Show compact pass/fail health summary
         #[arg(long)]
         brief: bool,
+
+        /// Refresh every SECS seconds until Ctrl+C (one document per refresh in JSON/YAML)
+        #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+        watch: Option<u64>,
     },
 
     /// Alert management operations
@@ -235,7 +239,21 @@ pub enum EnterpriseWorkflowCommands {
 #[derive(Subcommand, Debug)]
 pub enum EnterpriseClusterCommands {
     /// Get cluster configuration
-    Get,
+    #[command(
+        visible_alias = "info",
+        after_help = "EXAMPLES:
+    # Full cluster object
+    redisctl enterprise cluster get
+
+    # At-a-glance summary (name, version, counts, license, alerts)
+    redisctl enterprise cluster info --brief"
+    )]
+    Get {
+        /// Show a condensed summary composed from cluster, node, database,
+        /// license, and alert data
+        #[arg(long)]
+        brief: bool,
+    },
 
     /// Update cluster configuration
     #[command(after_help = "EXAMPLES:
@@ -358,7 +376,15 @@ pub enum EnterpriseClusterCommands {
         --username admin@example.com --password mypassword
 
     # Using JSON for additional options
-    redisctl enterprise cluster join --data @join.json")]
+    redisctl enterprise cluster join --data @join.json
+
+    # Join a freshly installed node and wait until it is active
+    redisctl enterprise cluster join --url https://new-node:9443 --insecure \\
+        --nodes 192.168.1.100 --username admin@example.com --password mypassword --wait
+
+--url points at the node being joined; without it the profile's URL is used.
+--nodes is the address of a node already in the target cluster. The command
+fails before sending anything if the node already belongs to a cluster.")]
     Join {
         /// Node address(es) to connect to (can be specified multiple times)
         #[arg(long)]
@@ -372,6 +398,46 @@ pub enum EnterpriseClusterCommands {
         /// Join configuration (JSON file or inline, overridden by other flags)
         #[arg(long, value_name = "FILE|JSON")]
         data: Option<String>,
+        /// REST API URL of the node to join (defaults to the profile's URL)
+        #[arg(long)]
+        url: Option<String>,
+        /// Skip TLS certificate verification for --url (new nodes use self-signed certificates)
+        #[arg(long, requires = "url")]
+        insecure: bool,
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
+    },
+
+    /// Add a new node to the profile's cluster and wait until it takes shards
+    #[command(after_help = "EXAMPLES:
+    # Join a freshly installed node and wait until it accepts shards
+    redisctl enterprise cluster expand --node-address 10.0.0.4 --insecure --wait
+
+    # Longer timeout for a slow network
+    redisctl enterprise cluster expand --node-address node4.example.com \\
+        --insecure --wait --wait-timeout 1200
+
+The cluster admin credentials are taken from the profile and sent with the
+join request. --node-user/--node-pass are only needed when the new node's
+REST API requires different credentials. With --wait, the command follows the
+join, then waits until the new node is active and accepts shards.")]
+    Expand {
+        /// New node's address: host, host:port, or the URL of its REST API
+        #[arg(long)]
+        node_address: String,
+        /// Username for the new node's REST API (defaults to the profile's)
+        #[arg(long)]
+        node_user: Option<String>,
+        /// Password for the new node's REST API (defaults to the profile's)
+        #[arg(long)]
+        node_pass: Option<String>,
+        /// Skip TLS certificate verification for the new node (new nodes use self-signed certificates)
+        #[arg(long)]
+        insecure: bool,
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
     },
 
     /// Recover cluster
@@ -450,6 +516,31 @@ pub enum EnterpriseClusterCommands {
     #[command(name = "verify-rack-awareness")]
     VerifyRackAwareness,
 
+    /// Check clock skew between cluster nodes
+    #[command(
+        name = "time-sync",
+        after_help = "EXAMPLES:
+    # Report each node's clock offset and flag skew over 2 seconds
+    redisctl enterprise cluster time-sync
+
+    # Use a tighter threshold
+    redisctl enterprise cluster time-sync --threshold 1
+
+    # List only skewed or unreachable nodes
+    redisctl enterprise cluster time-sync -o json -q 'nodes[?status != `OK`]'
+
+NOTES:
+    Each node's API is queried directly and its HTTP Date header is compared
+    with the local clock, corrected for round-trip time. Offsets have
+    one-second resolution; skew is reported relative to the cluster median.
+"
+    )]
+    TimeSync {
+        /// Maximum allowed skew from the cluster median, in seconds
+        #[arg(long, default_value = "2")]
+        threshold: u64,
+    },
+
     /// Get cluster certificates
     #[command(name = "get-certificates")]
     GetCertificates,
@@ -728,6 +819,193 @@ NOTE: First-class parameters override values in --data when both are provided.")
         poll_interval: u64,
     },
 
+    /// Wait until a database is active
+    #[command(
+        name = "wait-ready",
+        after_help = "EXAMPLES:
+    # Wait for a database created by another tool
+    redisctl enterprise database wait-ready 3
+
+    # Give up after 10 minutes, checking every 10 seconds
+    redisctl enterprise database wait-ready 3 --timeout 600 --interval 10
+
+NOTE: Exits non-zero if the database fails to create, is deleted, enters
+      recovery, or is not active before the timeout."
+    )]
+    WaitReady {
+        /// Database ID
+        id: u32,
+        /// Maximum time to wait in seconds
+        #[arg(long, default_value = "300")]
+        timeout: u64,
+        /// Seconds between status checks
+        #[arg(long, default_value = "5")]
+        interval: u64,
+    },
+
+    /// Wait until a database is active and its replicas have caught up
+    #[command(
+        name = "wait-healthy",
+        after_help = "EXAMPLES:
+    # Wait until every replica shard is within 1 MiB of its master
+    redisctl enterprise database wait-healthy 3
+
+    # Require replicas to be fully caught up before a planned failover
+    redisctl enterprise database wait-healthy 3 --max-lag 0 --timeout 900
+
+NOTE: Replication lag is the difference between a master shard's replication
+      offset and its replica's, in bytes. A replica whose link to the master
+      is down never counts as caught up. Databases without replication only
+      need to be active."
+    )]
+    WaitHealthy {
+        /// Database ID
+        id: u32,
+        /// Largest acceptable replication lag per replica shard, in bytes
+        #[arg(long, default_value = "1048576")]
+        max_lag: u64,
+        /// Maximum time to wait in seconds
+        #[arg(long, default_value = "300")]
+        timeout: u64,
+        /// Seconds between checks
+        #[arg(long, default_value = "5")]
+        interval: u64,
+    },
+
+    /// Make a database a replica of another database (Replica Of)
+    #[command(after_help = "EXAMPLES:
+    # Replicate database 2 from database 1 in the same cluster
+    redisctl enterprise database replica-of 2 --source 1 --wait
+
+    # Replicate from an external Redis endpoint
+    redisctl enterprise database replica-of 2 --source redis://:secret@10.0.0.5:12000
+
+    # Show the update without applying it
+    redisctl enterprise database replica-of 2 --source 1 --dry-run
+
+NOTE: A numeric --source is looked up in this cluster and must be active; its
+      endpoint and admin password are used to build the source URI. A URL
+      source must accept TCP connections from this machine. With --wait, the
+      command returns once the replica reports in-sync.")]
+    ReplicaOf {
+        /// Database ID of the replica
+        id: u32,
+        /// Source database ID in this cluster, or a redis:// / rediss:// URL
+        #[arg(long)]
+        source: String,
+        /// Show the update without applying it
+        #[arg(long)]
+        dry_run: bool,
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
+    },
+
+    /// List client connections to a database
+    #[command(after_help = "EXAMPLES:
+    # Who is connected to database 1?
+    redisctl enterprise database conns 1
+
+    # Longest-idle connections first, e.g. to spot leaked pools
+    redisctl enterprise database conns 1 -o json -q 'sort_by(@, &idle_secs) | reverse(@)'
+
+    # Endpoint DNS is not resolvable from here; connect by address instead
+    redisctl enterprise database conns 1 --host 10.0.0.12
+
+NOTE: Runs CLIENT LIST against the database endpoint, so the endpoint must be
+      reachable from this machine. The database password is read from the
+      database configuration unless --password is given. TLS endpoints are
+      verified against the system trust store; pass the cluster's proxy
+      certificate with --ca-cert, or --insecure to skip verification.")]
+    Conns {
+        /// Database ID
+        id: u32,
+        /// Connect to this host instead of the endpoint's DNS name
+        #[arg(long)]
+        host: Option<String>,
+        /// Database password (defaults to the password in the database config)
+        #[arg(long)]
+        password: Option<String>,
+        /// Skip verification of the endpoint's TLS certificate
+        #[arg(long, conflicts_with = "ca_cert")]
+        insecure: bool,
+        /// CA certificate (PEM) to verify the endpoint's TLS certificate with
+        #[arg(long, value_name = "FILE")]
+        ca_cert: Option<String>,
+    },
+
+    /// Break down a database's memory usage by overhead, dataset, and data type
+    #[command(
+        name = "memory-breakdown",
+        after_help = "EXAMPLES:
+    # Where is database 1's memory going?
+    redisctl enterprise database memory-breakdown 1
+
+    # Sample more keys for a finer per-type estimate
+    redisctl enterprise database memory-breakdown 1 --samples 10000
+
+    # Overhead only, skipping the key scan
+    redisctl enterprise database memory-breakdown 1 --samples 0 -o json -q overhead
+
+NOTE: Runs MEMORY STATS, INFO, and a sampled SCAN with MEMORY USAGE against
+      the database endpoint, so the endpoint must be reachable from this
+      machine. Per-type figures are extrapolated from the sampled keys."
+    )]
+    MemoryBreakdown {
+        /// Database ID
+        id: u32,
+        /// Number of keys to sample for the per-type breakdown (0 to skip)
+        #[arg(long, default_value = "1000")]
+        samples: u64,
+        /// Connect to this host instead of the endpoint's DNS name
+        #[arg(long)]
+        host: Option<String>,
+        /// Database password (defaults to the password in the database config)
+        #[arg(long)]
+        password: Option<String>,
+        /// Skip verification of the endpoint's TLS certificate
+        #[arg(long, conflicts_with = "ca_cert")]
+        insecure: bool,
+        /// CA certificate (PEM) to verify the endpoint's TLS certificate with
+        #[arg(long, value_name = "FILE")]
+        ca_cert: Option<String>,
+    },
+
+    /// Checksum a database's keys and values
+    #[command(after_help = "EXAMPLES:
+    # Checksum the source before exporting it
+    redisctl enterprise database checksum 1
+
+    # Use it to verify an import into another cluster
+    SUM=$(redisctl enterprise database checksum 1 -o json -q checksum | tr -d '\"')
+    redisctl --profile dr enterprise database import 7 --location s3://bucket/backup.rdb \\
+        --flush --wait --verify-checksum --expected-checksum \"$SUM\"
+
+NOTE: SCANs every key and hashes it with its DUMP payload (SHA-256, in key
+      order) through the database endpoint, so the endpoint must be reachable
+      from this machine. DUMP payloads depend on the Redis version, so only
+      compare checksums of databases running the same version.")]
+    Checksum {
+        /// Database ID
+        id: u32,
+        /// Connect to this host instead of the endpoint's DNS name
+        #[arg(long)]
+        host: Option<String>,
+        /// Database password (defaults to the password in the database config)
+        #[arg(long)]
+        password: Option<String>,
+        /// Skip verification of the endpoint's TLS certificate
+        #[arg(long, conflicts_with = "ca_cert")]
+        insecure: bool,
+        /// CA certificate (PEM) to verify the endpoint's TLS certificate with
+        #[arg(long, value_name = "FILE")]
+        ca_cert: Option<String>,
+    },
+
+    /// Configure TLS and client certificate authentication
+    #[command(subcommand)]
+    Tls(EnterpriseDatabaseTlsCommands),
+
     /// Export database to external storage
     #[command(after_help = "EXAMPLES:
     # Export to S3
@@ -740,6 +1018,16 @@ NOTE: First-class parameters override values in --data when both are provided.")
     # Export to SFTP
     redisctl enterprise database export 1 --location sftp://user@sftp.example.com/backup.rdb
 
+    # Export and wait for completion
+    redisctl enterprise database export 1 --location sftp://user@sftp.example.com/backup.rdb --wait
+
+    # Export to a directory shared with the cluster and copy the RDB here
+    redisctl enterprise database export 1 --location /mnt/exports --local backup.rdb
+
+    # Same, when the share is mounted at a different path on this machine
+    redisctl enterprise database export 1 --location /mnt/exports \\
+        --local backup.rdb --local-mount /Volumes/exports
+
     # Using JSON for advanced configuration
     redisctl enterprise database export 1 --data @export.json")]
     Export {
@@ -750,6 +1038,20 @@ NOTE: First-class parameters override values in --data when both are provided.")
         #[arg(long)]
         location: Option<String>,
 
+        /// Wait for the export, then copy the RDB file to this local path
+        /// (--location must be a directory this machine can also read)
+        #[arg(
+            long,
+            value_name = "FILE",
+            requires = "location",
+            conflicts_with_all = ["data", "aws_access_key", "aws_secret_key"]
+        )]
+        local: Option<std::path::PathBuf>,
+
+        /// Where the --location directory is mounted on this machine, when not at the same path
+        #[arg(long, value_name = "DIR", requires = "local")]
+        local_mount: Option<std::path::PathBuf>,
+
         /// AWS access key for S3 exports
         #[arg(long)]
         aws_access_key: Option<String>,
@@ -761,6 +1063,10 @@ NOTE: First-class parameters override values in --data when both are provided.")
         /// Export configuration as JSON string or @file.json (overridden by other flags)
         #[arg(long)]
         data: Option<String>,
+
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
     },
 
     /// Import data to database from external storage
@@ -778,6 +1084,14 @@ NOTE: First-class parameters override values in --data when both are provided.")
     # Import and wait for completion
     redisctl enterprise database import 1 --location https://example.com/backup.rdb --wait
 
+    # Fail unless the database holds the expected number of keys afterwards
+    redisctl enterprise database import 1 --location https://example.com/backup.rdb \\
+        --flush --wait --verify-checksum --expected-keys 120000
+
+    # Fail unless the imported keys and values match the source database
+    redisctl enterprise database import 1 --location https://example.com/backup.rdb \\
+        --flush --wait --verify-checksum --expected-checksum 9f86d081884c7d65...
+
     # Using JSON for advanced configuration
     redisctl enterprise database import 1 --data @import.json")]
     Import {
@@ -804,15 +1118,105 @@ NOTE: First-class parameters override values in --data when both are provided.")
         #[arg(long)]
         data: Option<String>,
 
+        /// After the import completes, verify the database key count against the
+        /// expected count (from --expected-keys or `expected_keys` in --data), and
+        /// its contents against the expected checksum (from --expected-checksum or
+        /// `expected_checksum` in --data). Without an expected count, fails if the
+        /// import left the database empty
+        #[arg(long, requires = "wait")]
+        verify_checksum: bool,
+
+        /// Number of keys the source contains, checked by --verify-checksum
+        #[arg(long, requires = "verify_checksum")]
+        expected_keys: Option<u64>,
+
+        /// Checksum of the source as printed by `database checksum`, checked by
+        /// --verify-checksum through the database endpoint
+        #[arg(long, requires = "verify_checksum")]
+        expected_checksum: Option<String>,
+
         /// Async operation options
         #[command(flatten)]
         async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
     },
 
-    /// Trigger database backup
-    Backup {
+    /// Export a database's configuration (no data) as a reusable template
+    #[command(
+        name = "export-config",
+        after_help = "EXAMPLES:
+    # Save the configuration of database 1
+    redisctl enterprise database export-config 1 --file db.json
+
+    # Print it instead, as YAML
+    redisctl enterprise database export-config 1 -o yaml
+
+    # Recreate it on another cluster
+    redisctl --profile staging enterprise database import-config --file db.json
+
+NOTE: Cluster-specific fields (uid, port, endpoints, shard placement, status,
+      timestamps) and secrets (passwords, backup locations) are not exported."
+    )]
+    ExportConfig {
         /// Database ID
         id: u32,
+
+        /// Write the configuration to this file instead of stdout
+        #[arg(long)]
+        file: Option<String>,
+    },
+
+    /// Create a database from a configuration exported with export-config
+    #[command(
+        name = "import-config",
+        after_help = "EXAMPLES:
+    # Create a database from a saved configuration
+    redisctl enterprise database import-config --file db.json
+
+    # Same configuration under a different name and port
+    redisctl enterprise database import-config --file db.json --name cache-eu --port 12001
+
+    # Validate against the target cluster without creating
+    redisctl enterprise database import-config --file db.json --dry-run"
+    )]
+    ImportConfig {
+        /// Configuration file (JSON) produced by export-config
+        #[arg(long)]
+        file: String,
+
+        /// Override the database name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// TCP port for the new database (auto-assigned if not specified)
+        #[arg(long)]
+        port: Option<u16>,
+
+        /// Redis password for the new database
+        #[arg(long)]
+        redis_password: Option<String>,
+
+        /// Perform a dry run without creating the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Trigger database backup, or list available backups
+    #[command(
+        args_conflicts_with_subcommands = true,
+        subcommand_negates_reqs = true,
+        after_help = "EXAMPLES:
+    # Back up database 1 now
+    redisctl enterprise database backup 1 --wait
+
+    # List the backups available for restore
+    redisctl enterprise database backup list 1"
+    )]
+    Backup {
+        #[command(subcommand)]
+        command: Option<EnterpriseDatabaseBackupCommands>,
+        /// Database ID
+        #[arg(required = true)]
+        id: Option<u32>,
         /// Async operation options
         #[command(flatten)]
         async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
@@ -823,8 +1227,12 @@ NOTE: First-class parameters override values in --data when both are provided.")
     # Restore from latest backup
     redisctl enterprise database restore 1
 
-    # Restore from specific backup
-    redisctl enterprise database restore 1 --backup-uid backup-12345
+    # Find a backup, then restore it and wait for completion
+    redisctl enterprise database backup list 1
+    redisctl enterprise database restore 1 --backup backup-12345 --wait
+
+    # Skip the confirmation prompt (for scripts)
+    redisctl enterprise database restore 1 --backup backup-12345 --force
 
     # Using JSON for advanced configuration
     redisctl enterprise database restore 1 --data @restore.json")]
@@ -833,21 +1241,70 @@ NOTE: First-class parameters override values in --data when both are provided.")
         id: u32,
 
         /// Specific backup UID to restore from (uses latest if not specified)
-        #[arg(long)]
+        #[arg(long, visible_alias = "backup")]
         backup_uid: Option<String>,
 
         /// Restore configuration as JSON string or @file.json (overridden by other flags)
         #[arg(long)]
         data: Option<String>,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
+    },
+
+    /// Recover a database from its persistence files (AOF/RDB)
+    #[command(after_help = "EXAMPLES:
+    # Start recovery and return immediately
+    redisctl enterprise database recover 1
+
+    # Recover and wait for completion, reporting the recovered key count
+    redisctl enterprise database recover 1 --wait
+
+    # Skip the confirmation prompt
+    redisctl enterprise database recover 1 --wait --force
+
+NOTE: Only databases in the 'recovery' state can be recovered. The recovery
+      plan is checked before the request is submitted.")]
+    Recover {
+        /// Database ID
+        id: u32,
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+        /// Async operation options
+        #[command(flatten)]
+        async_ops: crate::commands::cloud::async_utils::AsyncOperationArgs,
     },
 
     /// Flush database data
+    #[command(after_help = "EXAMPLES:
+    # Flush and wait for it to finish
+    redisctl enterprise database flush 1 --force
+
+    # Start the flush and return its action id right away
+    redisctl enterprise database flush 1 --force --async
+    redisctl enterprise action status <action-uid>")]
     Flush {
         /// Database ID
         id: u32,
         /// Skip confirmation prompt
         #[arg(long)]
         force: bool,
+        /// Return the action id as soon as the flush starts instead of waiting
+        /// for it to finish
+        #[arg(long = "async")]
+        no_wait: bool,
+        /// Maximum time to wait in seconds
+        #[arg(long, default_value = "300", conflicts_with = "no_wait")]
+        wait_timeout: u64,
+        /// Polling interval in seconds
+        #[arg(long, default_value = "5", conflicts_with = "no_wait")]
+        wait_interval: u64,
     },
 
     /// Get database shards info
@@ -998,12 +1455,37 @@ NOTE: First-class parameters override values in --data when both are provided.")
     },
 
     /// Get slow query log
+    #[command(after_help = "EXAMPLES:
+    # Show the slow log
+    redisctl enterprise database slowlog 1
+
+    # Show the 10 most recent entries, then clear the log
+    redisctl enterprise database slowlog 1 --limit 10 --reset
+")]
     Slowlog {
         /// Database ID
         id: u32,
         /// Limit number of entries
         #[arg(long)]
         limit: Option<u32>,
+        /// Clear the slow log after printing it (runs SLOWLOG RESET through the
+        /// database endpoint)
+        #[arg(long)]
+        reset: bool,
+        /// With --reset, connect to this host instead of the endpoint's DNS name
+        #[arg(long, requires = "reset")]
+        host: Option<String>,
+        /// With --reset, the database password (defaults to the password in the
+        /// database config)
+        #[arg(long, requires = "reset")]
+        password: Option<String>,
+        /// With --reset, skip verification of the endpoint's TLS certificate
+        #[arg(long, requires = "reset", conflicts_with = "ca_cert")]
+        insecure: bool,
+        /// With --reset, CA certificate (PEM) to verify the endpoint's TLS
+        /// certificate with
+        #[arg(long, value_name = "FILE", requires = "reset")]
+        ca_cert: Option<String>,
     },
 
     /// Get connected clients
@@ -1013,6 +1495,54 @@ NOTE: First-class parameters override values in --data when both are provided.")
     },
 }
 
+/// Database TLS subcommands
+#[derive(Subcommand, Debug)]
+pub enum EnterpriseDatabaseTlsCommands {
+    /// Require TLS for client connections to a database
+    #[command(after_help = "EXAMPLES:
+    # Require TLS, without client certificates
+    redisctl enterprise database tls enable 1
+
+    # Require TLS and client certificates signed by this CA
+    redisctl enterprise database tls enable 1 --require-client-cert --ca @ca.pem
+
+    # Trust several certificates (replaces the database's current list)
+    redisctl enterprise database tls enable 1 --require-client-cert \\
+        --ca @team-a.pem --ca @team-b.pem
+
+NOTE: --ca replaces the certificates the database trusts for client
+      authentication. Without --ca, the current certificates are kept, and
+      --require-client-cert fails if the database has none.")]
+    Enable {
+        /// Database ID
+        id: u32,
+        /// Reject clients that don't present a trusted certificate
+        #[arg(long)]
+        require_client_cert: bool,
+        /// PEM certificate to trust for client authentication (@file or inline, repeatable)
+        #[arg(long = "ca", value_name = "PEM")]
+        ca: Vec<String>,
+    },
+
+    /// Stop requiring TLS for client connections to a database
+    #[command(after_help = "EXAMPLES:
+    redisctl enterprise database tls disable 1")]
+    Disable {
+        /// Database ID
+        id: u32,
+    },
+}
+
+/// Database backup subcommands
+#[derive(Subcommand, Debug)]
+pub enum EnterpriseDatabaseBackupCommands {
+    /// List backups available for restore, newest first
+    List {
+        /// Database ID
+        id: u32,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum EnterpriseNodeCommands {
     /// List all nodes in cluster
@@ -1051,12 +1581,34 @@ pub enum EnterpriseNodeCommands {
     },
 
     /// Remove node from cluster
+    #[command(after_help = "EXAMPLES:
+    # Remove a node that holds no master shards
+    redisctl enterprise node remove 3
+
+    # Drain master shards off the node first, then remove it
+    redisctl enterprise node remove 3 --drain-first
+
+    # Skip confirmation and safety checks
+    redisctl enterprise node remove 3 --force
+
+NOTE: Removal is refused if the node still holds master shards (unless
+--drain-first is given) or if the cluster would be left with fewer than
+3 nodes. --force skips these checks.")]
     Remove {
         /// Node ID
         id: u32,
-        /// Skip confirmation prompt
+        /// Skip confirmation prompt and shard-safety checks
         #[arg(long)]
         force: bool,
+        /// Drain master shards off the node before removing it
+        #[arg(long)]
+        drain_first: bool,
+        /// Maximum time to wait for each node action in seconds
+        #[arg(long, default_value = "600")]
+        wait_timeout: u64,
+        /// Polling interval in seconds
+        #[arg(long, default_value = "5")]
+        wait_interval: u64,
     },
 
     /// Update node configuration
@@ -2191,12 +2743,18 @@ pub enum EnterpriseStatsCommands {
         /// Poll interval in seconds (for --follow)
         #[arg(long, default_value = "5")]
         poll_interval: u64,
+        /// Refresh every SECS seconds until Ctrl+C (one document per refresh in JSON/YAML)
+        #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "follow")]
+        watch: Option<u64>,
     },
 
     /// Get database shard statistics
     DatabaseShards {
         /// Database ID
         id: u32,
+        /// Refresh every SECS seconds until Ctrl+C (one document per refresh in JSON/YAML)
+        #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+        watch: Option<u64>,
     },
 
     /// Get database metrics over time
@@ -2218,6 +2776,22 @@ pub enum EnterpriseStatsCommands {
         /// Poll interval in seconds (for --follow)
         #[arg(long, default_value = "5")]
         poll_interval: u64,
+        /// Refresh every SECS seconds until Ctrl+C (one document per refresh in JSON/YAML)
+        #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "follow")]
+        watch: Option<u64>,
+    },
+
+    /// Get current statistics for all nodes
+    #[command(after_help = "EXAMPLES:
+    # All nodes as JSON
+    redisctl enterprise stats nodes -o json
+
+    # Prometheus text format, one gauge per node
+    redisctl enterprise stats nodes --format prometheus > nodes.prom")]
+    Nodes {
+        /// Output as the regular -o format (json) or Prometheus text (prometheus)
+        #[arg(long, value_parser = ["json", "prometheus"], default_value = "json")]
+        format: String,
     },
 
     /// Get node metrics over time
@@ -2237,6 +2811,9 @@ pub enum EnterpriseStatsCommands {
         /// Poll interval in seconds (for --follow)
         #[arg(long, default_value = "5")]
         poll_interval: u64,
+        /// Refresh every SECS seconds until Ctrl+C (one document per refresh in JSON/YAML)
+        #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "follow")]
+        watch: Option<u64>,
     },
 
     /// Get cluster metrics over time
@@ -2247,7 +2824,11 @@ pub enum EnterpriseStatsCommands {
     },
 
     /// Get listener statistics
-    Listener,
+    Listener {
+        /// Refresh every SECS seconds until Ctrl+C (one document per refresh in JSON/YAML)
+        #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+        watch: Option<u64>,
+    },
 
     /// Export statistics in various formats
     Export {
@@ -8,6 +8,7 @@
 use clap::{Parser, Subcommand, ValueHint};
 use clap_complete::engine::{ArgValueCandidates, CompletionCandidate};
 use redisctl_core::DeploymentType;
+use std::path::PathBuf;
 
 pub mod cloud;
 pub mod enterprise;
@@ -86,9 +87,9 @@ pub struct Cli {
     #[arg(long, global = true, env = "REDISCTL_CONFIG_FILE", value_hint = ValueHint::FilePath)]
     pub config_file: Option<String>,
 
-    /// Output format
-    #[arg(long, short = 'o', global = true, value_enum, default_value = "auto")]
-    pub output: OutputFormat,
+    /// Output format [default: the profile's `output` setting, else auto]
+    #[arg(long, short = 'o', global = true, value_enum)]
+    pub output: Option<OutputFormat>,
 
     /// JMESPath query to filter output (use @file to read from file)
     #[arg(long, short = 'q', global = true)]
@@ -118,10 +119,137 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub rate_limit: Option<u32>,
 
+    /// Per-request HTTP timeout in seconds (defaults to the API client's own
+    /// timeout). Each retry attempt gets a fresh timeout window. Separate from
+    /// the --timeout of commands that wait for an operation (task wait, wait-ready, ...)
+    #[arg(long, global = true, value_name = "SECS")]
+    pub http_timeout: Option<u64>,
+
+    /// Emit fields in a canonical order (id, name, status first) for diffable output
+    #[arg(long, global = true)]
+    pub field_order: bool,
+
+    /// Drop null-valued fields from output. By default fields the API returns as
+    /// null are printed as null, and fields it omits stay absent
+    #[arg(long, global = true)]
+    pub omit_null: bool,
+
+    /// Sort list output by a field (dotted paths like `region.name` allowed).
+    /// Numeric values sort numerically; items missing the field go last
+    #[arg(long, global = true, value_name = "FIELD")]
+    pub sort_by: Option<String>,
+
+    /// Sort in descending order (with --sort-by)
+    #[arg(long, global = true, requires = "sort_by")]
+    pub reverse: bool,
+
+    /// Show at most N items of list output (after --query and --sort-by) and note
+    /// how many were omitted. Unlike the --limit of some commands (logs, slow
+    /// logs, events), nothing is sent to the API
+    #[arg(long, global = true, value_name = "N")]
+    pub max_items: Option<u32>,
+
+    /// Field delimiter for CSV output (a single character such as ';' or '|')
+    #[arg(long, global = true, value_name = "CHAR", value_parser = parse_csv_delimiter)]
+    pub csv_delimiter: Option<u8>,
+
+    /// Quote every CSV/TSV field, not just those that need it
+    #[arg(long, global = true)]
+    pub csv_quote_all: bool,
+
+    /// Show only these comma-separated columns, in this order, in table output
+    /// (e.g. `uid,status,shards`). Missing fields show as blank cells. Also trims
+    /// JSON Lines and the elements of `api --stream`; ignored for other JSON,
+    /// YAML and CSV
+    #[arg(long, global = true, value_name = "FIELDS")]
+    pub fields: Option<String>,
+
+    /// Wrap list output in JSON/YAML as `{"<key>": [...], "count": N}`, the
+    /// shape MCP list tools return, using the same key as the matching MCP tool
+    /// (e.g. `databases` for `database list`) or `items` when there is none.
+    /// Rejected for other output formats
+    #[arg(long, global = true)]
+    pub wrap_lists: bool,
+
+    /// Remove these comma-separated fields from every object in the output, in
+    /// any format (e.g. `links,endpoint.password`). Dotted paths reach into
+    /// nested objects; an excluded field is dropped even if --fields names it
+    #[arg(long, global = true, value_name = "FIELDS")]
+    pub exclude: Option<String>,
+
+    /// Disable colored output. Color is also off when NO_COLOR is set or the
+    /// output is not a terminal
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Fail (exit code 1) unless the output matches this assertion.
+    /// `<jmespath>==<value>` compares with a literal (`prod`, `3`, 'text');
+    /// a bare JMESPath expression must be truthy. Repeatable
+    #[arg(long, global = true, value_name = "EXPR")]
+    pub expect: Vec<String>,
+
+    /// Rewrite timestamp fields (created_time, timestamp, ...) as ISO 8601 UTC,
+    /// whether the API returned epoch seconds, epoch milliseconds or a local
+    /// offset. The field list can be changed in the config `[timestamps]` section
+    #[arg(long, global = true, env = "REDISCTL_NORMALIZE_TIMESTAMPS")]
+    pub normalize_timestamps: bool,
+
+    /// Rewrite boolean fields (replication, tls, enabled, ...) as JSON booleans
+    /// when the API returned "true"/"false" strings or 0/1. The field list can be
+    /// changed in the config `[booleans]` section
+    #[arg(long, global = true, env = "REDISCTL_COERCE_BOOLS")]
+    pub coerce_bools: bool,
+
+    /// When no profile is configured, use credentials detected from the environment
+    /// (mounted Redis Enterprise operator secrets, REDISCLOUD_* variables)
+    #[arg(long, global = true, env = "REDISCTL_PROFILE_FROM_CONTEXT")]
+    pub profile_from_context: bool,
+
+    /// Check connectivity and credentials before running the command, failing
+    /// fast with a categorized error if the API is unreachable or rejects them
+    #[arg(long, global = true, env = "REDISCTL_PREFLIGHT")]
+    pub preflight: bool,
+
+    /// Ask the API for uncompressed responses (Accept-Encoding: identity).
+    /// Responses are gzip/deflate-decoded transparently otherwise; this is for
+    /// inspecting raw bytes on the wire or ruling out decoding issues
+    #[arg(long, global = true, env = "REDISCTL_NO_COMPRESSION")]
+    pub no_compression: bool,
+
+    /// Pipe formatted output through a shell command, e.g. --pipe-through "jq -r '.[].name'"
+    ///
+    /// Runs with `sh -c` (`cmd /C` on Windows). The command fails if the
+    /// pipe command cannot be started or exits non-zero.
+    #[arg(long, global = true, value_name = "CMD")]
+    pub pipe_through: Option<String>,
+
+    /// POST a JSON summary (command, status, duration, error) to this URL when
+    /// the command finishes, whether it succeeds or fails
+    #[arg(
+        long,
+        global = true,
+        env = "REDISCTL_NOTIFY_WEBHOOK",
+        value_name = "URL"
+    )]
+    pub notify_webhook: Option<String>,
+
+    /// Print the HTTP request a write command would send (method, URL and
+    /// body as JSON) and exit without sending it. Goes before the command, e.g.
+    /// `redisctl --print-request cloud database create ...`
+    #[arg(long)]
+    pub print_request: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+impl Cli {
+    /// Output format for the command; `Auto` when none was chosen
+    pub fn output_format(&self) -> OutputFormat {
+        self.output.unwrap_or(OutputFormat::Auto)
+    }
+}
+
 /// Output format options
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 pub enum OutputFormat {
@@ -133,6 +261,12 @@ pub enum OutputFormat {
     Yaml,
     /// Human-readable table format
     Table,
+    /// Comma-separated values (arrays of objects become rows)
+    Csv,
+    /// Tab-separated values (CSV with a tab delimiter)
+    Tsv,
+    /// JSON Lines: one compact JSON value per line (arrays are written one element per line)
+    Jsonl,
 }
 
 impl OutputFormat {
@@ -143,6 +277,12 @@ impl OutputFormat {
     pub fn is_yaml(&self) -> bool {
         matches!(self, Self::Yaml)
     }
+
+    /// Whether this is a machine-readable format, printed as data rather than
+    /// as a table or human-readable text
+    pub fn is_structured(&self) -> bool {
+        !matches!(self, Self::Auto | Self::Table)
+    }
 }
 
 /// Top-level commands
@@ -163,28 +303,52 @@ pub enum Commands {
     # POST request from file
     redisctl api cloud post /subscriptions --data @subscription.json
 
+    # POST request with body piped from stdin
+    jq -n '{name: \"my-sub\"}' | redisctl api cloud post /subscriptions --data @-
+
     # Output as JSON for scripting
     redisctl api enterprise get /v1/bdbs -o json
+
+    # Stream a large array element by element instead of buffering it
+    redisctl api enterprise get /v1/bdbs --stream | jq -c '.[]'
+
+    # Send extra headers (repeatable)
+    redisctl api enterprise get /v1/bdbs/1 -H 'Accept: application/problem+json' -H 'X-Correlation-Id: abc'
+
+    # List known endpoints, optionally filtered by path or description
+    redisctl api enterprise paths
+    redisctl api cloud paths peering
 ")]
     Api {
         /// Platform type (cloud or enterprise)
         #[arg(value_enum)]
         deployment: DeploymentType,
 
-        /// HTTP method
-        #[arg(value_parser = parse_http_method)]
-        method: HttpMethod,
+        /// HTTP method, or `paths` to list known endpoints
+        #[arg(value_parser = parse_api_action, value_name = "METHOD")]
+        method: ApiAction,
 
-        /// API endpoint path (e.g., /subscriptions)
-        path: String,
+        /// API endpoint path (e.g., /subscriptions); with `paths`, an optional
+        /// filter matched against endpoint paths and descriptions
+        path: Option<String>,
 
-        /// Request body (JSON string or @file)
+        /// Request body (JSON string, @file, or @- to read from stdin)
         #[arg(long)]
         data: Option<String>,
 
         /// Print equivalent curl command instead of executing
         #[arg(long)]
         curl: bool,
+
+        /// Write a top-level JSON array element by element as the response
+        /// arrives instead of buffering it (GET with JSON output, no --query)
+        #[arg(long, conflicts_with = "curl")]
+        stream: bool,
+
+        /// Extra request header as "Key: Value" (repeatable). Replaces a default
+        /// header of the same name; authentication headers cannot be set
+        #[arg(long = "header", short = 'H', value_name = "KEY: VALUE", value_parser = parse_header)]
+        headers: Vec<(String, String)>,
     },
 
     /// Profile management
@@ -215,6 +379,28 @@ pub enum Commands {
 ")]
     Profile(ProfileCommands),
 
+    /// Configuration file management
+    #[command(subcommand)]
+    #[command(after_help = "EXAMPLES:
+    # Upgrade the config file to the current schema version
+    redisctl config migrate
+
+    # Preview which migrations would be applied
+    redisctl config migrate --dry-run
+")]
+    Config(ConfigCommands),
+
+    /// Inspect retry and circuit breaker behavior
+    #[command(subcommand)]
+    #[command(after_help = "EXAMPLES:
+    # Retry delays for the current profile's settings
+    redisctl resilience simulate
+
+    # Try out other settings, with jitter
+    redisctl resilience simulate --attempts 5 --backoff-ms 200 --jitter
+")]
+    Resilience(ResilienceCommands),
+
     /// Cloud-specific operations
     #[command(subcommand, visible_alias = "cl")]
     #[command(before_long_help = "\
@@ -264,6 +450,57 @@ PROFILE REQUIREMENT:
   List existing profiles: redisctl profile list")]
     Db(DbCommands),
 
+    /// Compare a Cloud database with an Enterprise database for migration planning
+    #[command(name = "compare-platforms")]
+    #[command(after_help = "EXAMPLES:
+    # Compare live databases using the default cloud and enterprise profiles
+    redisctl compare-platforms --cloud 123456:789 --enterprise 1
+
+    # Use explicit profiles for each side
+    redisctl compare-platforms --cloud 123456:789 --cloud-profile prod-cloud \\
+        --enterprise 1 --enterprise-profile onprem
+
+    # Compare against a planned Cloud database spec instead of a live one
+    redisctl compare-platforms --cloud-spec @cloud-db.json --enterprise 1
+
+    # Only show aspects that differ
+    redisctl compare-platforms --cloud 123456:789 --enterprise 1 -o json \\
+        -q 'comparison[?match == `false`]'
+")]
+    ComparePlatforms {
+        /// Cloud database ID (format: subscription_id:database_id)
+        #[arg(
+            long,
+            conflicts_with = "cloud_spec",
+            required_unless_present = "cloud_spec"
+        )]
+        cloud: Option<String>,
+
+        /// Cloud database spec in Cloud API shape (JSON string, @file, or - for stdin)
+        #[arg(long)]
+        cloud_spec: Option<String>,
+
+        /// Profile used to fetch the Cloud database (defaults to the default cloud profile)
+        #[arg(long, add = ArgValueCandidates::new(profile_candidates))]
+        cloud_profile: Option<String>,
+
+        /// Enterprise database UID
+        #[arg(
+            long,
+            conflicts_with = "enterprise_spec",
+            required_unless_present = "enterprise_spec"
+        )]
+        enterprise: Option<u32>,
+
+        /// Enterprise database spec in Enterprise API shape (JSON string, @file, or - for stdin)
+        #[arg(long)]
+        enterprise_spec: Option<String>,
+
+        /// Profile used to fetch the Enterprise database (defaults to the default enterprise profile)
+        #[arg(long, add = ArgValueCandidates::new(profile_candidates))]
+        enterprise_profile: Option<String>,
+    },
+
     /// Version information
     #[command(visible_alias = "ver", visible_alias = "v")]
     Version,
@@ -271,7 +508,7 @@ PROFILE REQUIREMENT:
     /// Generate shell completions
     #[command(visible_alias = "comp")]
     Completions {
-        /// Shell to generate completions for
+        /// Shell to generate completions for (`auto` detects the current shell)
         #[arg(value_enum)]
         shell: Shell,
 
@@ -296,6 +533,8 @@ pub enum Shell {
     PowerShell,
     /// Elvish
     Elvish,
+    /// Detect from the parent process or $SHELL (bash if undetectable)
+    Auto,
 }
 
 /// HTTP methods for raw API access
@@ -308,6 +547,56 @@ pub enum HttpMethod {
     Delete,
 }
 
+/// What `redisctl api` should do: send a request or list known endpoints
+#[derive(Debug, Clone)]
+pub enum ApiAction {
+    Request(HttpMethod),
+    Paths,
+}
+
+fn parse_csv_delimiter(s: &str) -> Result<u8, String> {
+    match s {
+        "\\t" | "tab" => Ok(b'\t'),
+        _ => match s.as_bytes() {
+            [b] if b.is_ascii() && !b.is_ascii_alphanumeric() && *b != b'"' && *b != b'\n' => {
+                Ok(*b)
+            }
+            _ => Err(format!(
+                "invalid CSV delimiter: {:?} (expected one punctuation or whitespace character, e.g. ';')",
+                s
+            )),
+        },
+    }
+}
+
+/// Parse a `Key: Value` request header
+fn parse_header(s: &str) -> Result<(String, String), String> {
+    let Some((name, value)) = s.split_once(':') else {
+        return Err(format!(
+            "invalid header {:?}: expected \"Key: Value\" (missing ':')",
+            s
+        ));
+    };
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!("invalid header {:?}: header name is empty", s));
+    }
+    Ok((name.to_string(), value.trim().to_string()))
+}
+
+/// Parse `paths` or an HTTP method case-insensitively
+fn parse_api_action(s: &str) -> Result<ApiAction, String> {
+    if s.eq_ignore_ascii_case("paths") {
+        return Ok(ApiAction::Paths);
+    }
+    parse_http_method(s).map(ApiAction::Request).map_err(|_| {
+        format!(
+            "invalid HTTP method: {} (valid: get, post, put, patch, delete, or paths)",
+            s
+        )
+    })
+}
+
 /// Parse HTTP method case-insensitively
 fn parse_http_method(s: &str) -> Result<HttpMethod, String> {
     match s.to_lowercase().as_str() {
@@ -323,6 +612,15 @@ fn parse_http_method(s: &str) -> Result<HttpMethod, String> {
     }
 }
 
+impl std::fmt::Display for ApiAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiAction::Request(method) => method.fmt(f),
+            ApiAction::Paths => write!(f, "PATHS"),
+        }
+    }
+}
+
 impl std::fmt::Display for HttpMethod {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -335,6 +633,51 @@ impl std::fmt::Display for HttpMethod {
     }
 }
 
+/// Configuration file commands
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Upgrade the config file to the current schema version
+    ///
+    /// Detects the config version, applies any pending migrations and writes the
+    /// upgraded file. The original is backed up next to it as <file>.v<N>.bak.
+    Migrate {
+        /// Show which migrations would be applied without writing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Do not keep a backup of the original file
+        #[arg(long)]
+        no_backup: bool,
+    },
+}
+
+/// Resilience commands
+#[derive(Subcommand, Debug)]
+pub enum ResilienceCommands {
+    /// Print the delays the retry layer would wait between attempts
+    ///
+    /// Starts from the effective settings of the selected profile; the flags
+    /// below replace individual values. No requests are made.
+    Simulate {
+        /// Total attempts, including the first call
+        #[arg(long)]
+        attempts: Option<u32>,
+
+        /// Delay before the first retry in milliseconds
+        #[arg(long)]
+        backoff_ms: Option<u64>,
+
+        /// Upper bound for any single delay in milliseconds
+        #[arg(long)]
+        max_backoff_ms: Option<u64>,
+
+        /// Show the range each delay falls in with full jitter (a random
+        /// delay between zero and the backoff)
+        #[arg(long)]
+        jitter: bool,
+    },
+}
+
 /// Profile management commands
 #[derive(Subcommand, Debug)]
 #[allow(clippy::large_enum_variant)]
@@ -412,6 +755,9 @@ EXAMPLES:
         --username admin@redis.local \\
         --insecure
 
+    # Inside a pod: read credentials from the mounted operator secret
+    redisctl profile set in-cluster --type enterprise --kubernetes --cluster rec
+
     # Create a Database profile (direct Redis connection)
     redisctl profile set my-cache --type database \\
         --host redis-12345.cloud.redislabs.com \\
@@ -450,11 +796,11 @@ EXAMPLES:
         api_url: String,
 
         /// Enterprise URL (for Enterprise profiles)
-        #[arg(long, required_if_eq("type", "enterprise"), value_hint = ValueHint::Url)]
+        #[arg(long, value_hint = ValueHint::Url)]
         url: Option<String>,
 
         /// Username (for Enterprise profiles)
-        #[arg(long, required_if_eq("type", "enterprise"))]
+        #[arg(long)]
         username: Option<String>,
 
         /// Password (for Enterprise profiles)
@@ -474,7 +820,7 @@ EXAMPLES:
         host: Option<String>,
 
         /// Redis port (for Database profiles)
-        #[arg(long, required_if_eq("type", "database"))]
+        #[arg(long, required_if_eq("type", "database"), value_parser = clap::value_parser!(u16).range(1..))]
         port: Option<u16>,
 
         /// Disable TLS (for Database profiles, TLS is enabled by default)
@@ -493,6 +839,26 @@ EXAMPLES:
         /// Tags for organizing profiles (repeatable)
         #[arg(long = "tag", action = clap::ArgAction::Append)]
         tags: Vec<String>,
+
+        /// Read Enterprise credentials from a mounted Redis Enterprise operator
+        /// secret whenever they are needed, instead of storing them (in-cluster use)
+        #[arg(long, conflicts_with_all = ["url", "username", "password"])]
+        kubernetes: bool,
+
+        /// Directory the operator secret is mounted at (with --kubernetes;
+        /// default: /var/run/secrets/redis-enterprise)
+        #[arg(long, requires = "kubernetes", value_hint = ValueHint::DirPath)]
+        secret_path: Option<String>,
+
+        /// Namespace of the Redis Enterprise cluster (with --kubernetes;
+        /// default: the pod's namespace)
+        #[arg(long, requires = "kubernetes")]
+        namespace: Option<String>,
+
+        /// RedisEnterpriseCluster name, used to derive the API URL when the
+        /// secret has no `url` file (with --kubernetes)
+        #[arg(long, requires = "kubernetes")]
+        cluster: Option<String>,
     },
 
     /// Remove a profile
@@ -502,6 +868,81 @@ EXAMPLES:
         name: String,
     },
 
+    /// Copy a profile to a new name
+    #[command(visible_alias = "cp")]
+    #[command(after_help = "EXAMPLES:
+    # Start a prod profile from staging, then point it at the prod cluster
+    redisctl profile copy ent-staging ent-prod
+    redisctl profile set ent-prod --type enterprise --url https://prod:9443 --username admin@redis.local
+
+    # Replace an existing profile
+    redisctl profile copy ent-staging ent-dr --force
+
+Keyring-stored secrets are copied to new keyring entries for the destination
+profile. Default profile settings are never changed.")]
+    Copy {
+        /// Profile to copy
+        source: String,
+        /// Name of the new profile
+        dest: String,
+        /// Overwrite the destination profile if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Export profiles to a portable JSON bundle
+    #[command(after_help = "EXAMPLES:
+    # Export every profile to a file
+    redisctl profile export --file profiles.json
+
+    # Export selected profiles to stdout
+    redisctl profile export ent-prod ent-staging
+
+    # Include resolved secrets (keyring values are read back)
+    redisctl profile export ent-prod --include-secrets --file ent-prod.json
+
+Without --include-secrets, secrets are written as ${VAR} placeholders named
+after the profile (e.g. ${REDISCTL_ENT_PROD_PASSWORD}). Import keeps them for
+expansion from the environment, or points them at an existing keyring entry.
+With --include-secrets, the file is created readable by its owner only.")]
+    Export {
+        /// Profiles to export (defaults to all profiles)
+        names: Vec<String>,
+
+        /// Output file path (defaults to stdout if not specified)
+        #[arg(id = "file", long = "file", short = 'f', value_hint = ValueHint::FilePath)]
+        output: Option<PathBuf>,
+
+        /// Write secrets into the bundle instead of placeholders
+        #[arg(long)]
+        include_secrets: bool,
+    },
+
+    /// Import profiles from a bundle created by 'profile export'
+    #[command(after_help = "EXAMPLES:
+    # Import profiles, failing if any name already exists
+    redisctl profile import profiles.json
+
+    # Replace existing profiles with the same names
+    redisctl profile import profiles.json --overwrite
+
+    # Keep a secret placeholder that is set in the environment
+    REDISCTL_ENT_PROD_PASSWORD=secret redisctl profile import profiles.json
+
+Every profile is validated before anything is written. If any entry is
+malformed, nothing is imported and each problem is listed per profile.
+Placeholders that cannot be resolved are kept as ${VAR} references, which
+are expanded when the configuration is loaded.")]
+    Import {
+        /// Bundle file to import
+        #[arg(value_hint = ValueHint::FilePath)]
+        input: PathBuf,
+
+        /// Replace profiles that already exist
+        #[arg(long)]
+        overwrite: bool,
+    },
+
     /// Set the default profile for enterprise commands
     #[command(name = "default-enterprise", visible_alias = "def-ent")]
     DefaultEnterprise {
@@ -523,6 +964,21 @@ EXAMPLES:
         name: String,
     },
 
+    /// Clear the default profile for a deployment type
+    #[command(name = "clear-default", visible_alias = "unset-default")]
+    #[command(after_help = "EXAMPLES:
+    # Stop using a default cloud profile
+    redisctl profile clear-default --type cloud
+
+    # Clear the default database profile
+    redisctl profile clear-default --type database
+")]
+    ClearDefault {
+        /// Deployment type whose default profile should be cleared
+        #[arg(long, value_enum)]
+        r#type: DeploymentType,
+    },
+
     /// Validate configuration file and profiles
     #[command(visible_alias = "check")]
     #[command(after_help = "EXAMPLES:
@@ -620,9 +1076,18 @@ pub enum DbCommands {
     # Open redis-cli using a database profile
     redisctl db open --profile my-cache
 
+    # Open a Redis Cloud database, resolving its endpoint and password
+    redisctl db open --profile mycloud --subscription 123456 --database 789
+
+    # Open a Redis Enterprise database by its uid
+    redisctl db open --profile prod-cluster --bdb 3
+
     # Print the command without executing (for debugging)
     redisctl db open --profile my-cache --dry-run
 
+    # Emit the resolved connection and argv as JSON (password redacted)
+    redisctl db open --profile my-cache --dry-run -o json
+
     # Pass additional arguments to redis-cli
     redisctl db open --profile my-cache -- -n 1
 
@@ -630,11 +1095,24 @@ pub enum DbCommands {
     redisctl db open --profile my-cache --redis-cli /usr/local/bin/redis-cli
 ")]
     Open {
-        /// Database profile to use (must be a 'database' type profile)
+        /// Profile to use: a 'database' profile, or a cloud/enterprise
+        /// profile together with the database to open
         #[arg(long, short)]
         profile: String,
 
-        /// Print the redis-cli command without executing
+        /// Cloud subscription ID (cloud profiles, with --database)
+        #[arg(long, requires = "database", conflicts_with = "bdb")]
+        subscription: Option<u32>,
+
+        /// Cloud database ID (cloud profiles, with --subscription)
+        #[arg(long, requires = "subscription")]
+        database: Option<u32>,
+
+        /// Enterprise database uid (enterprise profiles)
+        #[arg(long, conflicts_with = "database")]
+        bdb: Option<u32>,
+
+        /// Print the redis-cli command without executing (structured with -o json/yaml)
         #[arg(long)]
         dry_run: bool,
 
@@ -2,17 +2,21 @@
 
 use crate::cli::{HttpMethod, OutputFormat};
 use crate::connection::ConnectionManager;
-use crate::error::Result as CliResult;
-use crate::output::print_output;
+use crate::error::{RedisCtlError, Result as CliResult};
+use crate::output::{
+    max_items, needs_whole_result, note_omitted, prepare_streamed_item, print_output,
+    render_jsonl_item,
+};
 use anyhow::Context;
-use redisctl_core::{Config, DeploymentType};
+use redisctl_core::DeploymentType;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde_json::Value;
+use std::io::Write;
 
 /// Parameters for API command execution
 #[allow(dead_code)] // Used by binary target
 pub struct ApiCommandParams {
-    pub config: Config,
-    pub config_path: Option<std::path::PathBuf>,
+    pub connection_manager: ConnectionManager,
     pub profile_name: Option<String>,
     pub deployment: DeploymentType,
     pub method: HttpMethod,
@@ -21,12 +25,31 @@ pub struct ApiCommandParams {
     pub query: Option<String>,
     pub output_format: OutputFormat,
     pub curl: bool,
+    pub stream: bool,
+    /// Extra request headers from `--header`
+    pub headers: Vec<(String, String)>,
 }
 
 /// Handle raw API commands
 #[allow(dead_code)] // Used by binary target
 pub async fn handle_api_command(params: ApiCommandParams) -> CliResult<()> {
-    let connection_manager = ConnectionManager::with_config_path(params.config, params.config_path);
+    let connection_manager = params.connection_manager;
+
+    let extra_headers = header_map(&params.headers)?;
+
+    if params.stream {
+        return handle_streaming_get(
+            &connection_manager,
+            params.profile_name.as_deref(),
+            params.deployment,
+            &params.method,
+            params.path,
+            extra_headers,
+            params.query.as_deref(),
+            params.output_format,
+        )
+        .await;
+    }
 
     match params.deployment {
         DeploymentType::Cloud => {
@@ -39,6 +62,8 @@ pub async fn handle_api_command(params: ApiCommandParams) -> CliResult<()> {
                 params.query,
                 params.output_format,
                 params.curl,
+                &params.headers,
+                extra_headers,
             )
             .await
         }
@@ -52,6 +77,8 @@ pub async fn handle_api_command(params: ApiCommandParams) -> CliResult<()> {
                 params.query,
                 params.output_format,
                 params.curl,
+                &params.headers,
+                extra_headers,
             )
             .await
         }
@@ -72,6 +99,8 @@ async fn handle_cloud_api(
     query: Option<String>,
     output_format: OutputFormat,
     curl: bool,
+    headers: &[(String, String)],
+    extra_headers: HeaderMap,
 ) -> CliResult<()> {
     // Ensure path starts with /
     let normalized_path = if path.starts_with('/') {
@@ -85,11 +114,33 @@ async fn handle_cloud_api(
 
     if curl {
         let info = connection_manager.resolve_cloud_connection(profile_name)?;
-        let cmd = super::curl::format_cloud_curl(&info, &method, &normalized_path, body.as_ref());
+        let cmd = super::curl::format_cloud_curl(
+            &info,
+            &method,
+            &normalized_path,
+            body.as_ref(),
+            headers,
+        );
         println!("{}", cmd);
         return Ok(());
     }
 
+    // The typed client only sends its own headers
+    if !extra_headers.is_empty() {
+        return send_with_headers(
+            &connection_manager,
+            profile_name,
+            DeploymentType::Cloud,
+            &method,
+            normalized_path,
+            body,
+            extra_headers,
+            query.as_deref(),
+            output_format,
+        )
+        .await;
+    }
+
     let client = connection_manager.create_cloud_client(profile_name).await?;
 
     // Execute the API call based on HTTP method
@@ -125,11 +176,9 @@ async fn handle_cloud_api(
             })?;
             Ok(())
         }
-        Err(e) => {
-            // Format error nicely
-            eprintln!("API Error: {}", e);
-            std::process::exit(1);
-        }
+        // Returned rather than exiting here so main can report it (and run
+        // --notify-webhook) with the usual exit code
+        Err(e) => Err(e.into()),
     }
 }
 
@@ -144,6 +193,8 @@ async fn handle_enterprise_api(
     query: Option<String>,
     output_format: OutputFormat,
     curl: bool,
+    headers: &[(String, String)],
+    extra_headers: HeaderMap,
 ) -> CliResult<()> {
     // Normalize path with smart v1 prefixing for Enterprise
     let normalized_path = normalize_enterprise_path(path);
@@ -153,12 +204,33 @@ async fn handle_enterprise_api(
 
     if curl {
         let info = connection_manager.resolve_enterprise_connection(profile_name)?;
-        let cmd =
-            super::curl::format_enterprise_curl(&info, &method, &normalized_path, body.as_ref());
+        let cmd = super::curl::format_enterprise_curl(
+            &info,
+            &method,
+            &normalized_path,
+            body.as_ref(),
+            headers,
+        );
         println!("{}", cmd);
         return Ok(());
     }
 
+    // The typed client only sends its own headers
+    if !extra_headers.is_empty() {
+        return send_with_headers(
+            &connection_manager,
+            profile_name,
+            DeploymentType::Enterprise,
+            &method,
+            normalized_path,
+            body,
+            extra_headers,
+            query.as_deref(),
+            output_format,
+        )
+        .await;
+    }
+
     let client = connection_manager
         .create_enterprise_client(profile_name)
         .await?;
@@ -196,20 +268,427 @@ async fn handle_enterprise_api(
             })?;
             Ok(())
         }
-        Err(e) => {
-            // Format error nicely
-            eprintln!("API Error: {}", e);
-            std::process::exit(1);
+        // Returned rather than exiting here so main can report it (and run
+        // --notify-webhook) with the usual exit code
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Send a GET and write a top-level JSON array element by element as the body
+/// arrives, so large collections are never held in memory all at once
+///
+/// Responses that aren't a top-level array are buffered and printed as usual.
+#[allow(dead_code, clippy::too_many_arguments)] // Used by binary target
+async fn handle_streaming_get(
+    connection_manager: &ConnectionManager,
+    profile_name: Option<&str>,
+    deployment: DeploymentType,
+    method: &HttpMethod,
+    path: String,
+    extra_headers: HeaderMap,
+    query: Option<&str>,
+    output_format: OutputFormat,
+) -> CliResult<()> {
+    if !matches!(method, HttpMethod::Get) {
+        return Err(RedisCtlError::InvalidInput {
+            message: "--stream only applies to GET requests".to_string(),
+        });
+    }
+    if !matches!(output_format, OutputFormat::Auto | OutputFormat::Json) {
+        return Err(RedisCtlError::InvalidInput {
+            message: "--stream always writes JSON; drop -o or use -o json".to_string(),
+        });
+    }
+    if query.is_some() {
+        return Err(RedisCtlError::InvalidInput {
+            message: "--stream cannot be combined with --query, which needs the whole response"
+                .to_string(),
+        });
+    }
+
+    let path = match deployment {
+        DeploymentType::Enterprise => normalize_enterprise_path(path),
+        _ => path,
+    };
+    stream_get(
+        connection_manager,
+        profile_name,
+        deployment,
+        &path,
+        extra_headers,
+        StreamLayout::Array,
+        None,
+    )
+    .await
+}
+
+/// Send a GET and write the top-level JSON array in its body element by
+/// element as it arrives, shaping each element with `--fields`, `--exclude`
+/// and the other per-item output options
+///
+/// Elements for which `keep` returns false are skipped. With options that need
+/// the whole result (`--sort-by` and the like), or when the body is not an
+/// array, the response is read in full and printed through `print_output`.
+#[allow(dead_code)] // Used by binary target
+pub(crate) async fn stream_get(
+    connection_manager: &ConnectionManager,
+    profile_name: Option<&str>,
+    deployment: DeploymentType,
+    path: &str,
+    extra_headers: HeaderMap,
+    layout: StreamLayout,
+    keep: Option<fn(&Value) -> bool>,
+) -> CliResult<()> {
+    let request = connection_manager
+        .raw_request(profile_name, deployment, reqwest::Method::GET, path)
+        .await?
+        .headers(extra_headers);
+
+    let mut response = send(request).await?;
+
+    let format = match layout {
+        StreamLayout::Array => OutputFormat::Json,
+        StreamLayout::Lines => OutputFormat::Jsonl,
+    };
+    let print_whole = |mut value: Value| {
+        if let (Some(keep), Value::Array(items)) = (keep, &mut value) {
+            items.retain(keep);
         }
+        print_output(value, format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })
+    };
+
+    // --sort-by, --expect and --pipe-through need the complete response
+    if needs_whole_result(None) {
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| RedisCtlError::ConnectionError {
+                message: e.to_string(),
+            })?;
+        return print_whole(serde_json::from_slice(&body)?);
     }
+
+    let mut out = std::io::BufWriter::new(std::io::stdout());
+    let mut stream = JsonArrayStream {
+        layout,
+        keep,
+        limit: max_items(),
+        ..Default::default()
+    };
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| RedisCtlError::ConnectionError {
+            message: e.to_string(),
+        })?
+    {
+        stream.feed(&chunk, &mut out)?;
+    }
+
+    let omitted = stream.omitted;
+    match stream.finish()? {
+        Some(buffered) => print_whole(buffered),
+        None => {
+            out.flush()?;
+            note_omitted(omitted);
+            Ok(())
+        }
+    }
+}
+
+/// Headers that carry credentials; these always come from the profile
+const PROTECTED_HEADERS: &[&str] = &["authorization", "x-api-key", "x-api-secret-key"];
+
+/// Validate `--header` values and collect them into a header map
+fn header_map(headers: &[(String, String)]) -> CliResult<HeaderMap> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let header_name =
+            HeaderName::from_bytes(name.as_bytes()).map_err(|_| RedisCtlError::InvalidInput {
+                message: format!("Invalid header name: {:?}", name),
+            })?;
+        if PROTECTED_HEADERS.contains(&header_name.as_str()) {
+            return Err(RedisCtlError::InvalidInput {
+                message: format!(
+                    "--header cannot set {}; credentials come from the profile",
+                    name
+                ),
+            });
+        }
+        let header_value =
+            HeaderValue::from_str(value).map_err(|_| RedisCtlError::InvalidInput {
+                message: format!("Invalid value for header {}: {:?}", name, value),
+            })?;
+        map.append(header_name, header_value);
+    }
+    Ok(map)
+}
+
+/// The reqwest method for an `api` command method
+fn http_method(method: &HttpMethod) -> reqwest::Method {
+    match method {
+        HttpMethod::Get => reqwest::Method::GET,
+        HttpMethod::Post => reqwest::Method::POST,
+        HttpMethod::Put => reqwest::Method::PUT,
+        HttpMethod::Patch => reqwest::Method::PATCH,
+        HttpMethod::Delete => reqwest::Method::DELETE,
+    }
+}
+
+/// Send a raw request, turning non-2xx responses into API errors
+async fn send(request: reqwest::RequestBuilder) -> CliResult<reqwest::Response> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| RedisCtlError::ConnectionError {
+            message: e.to_string(),
+        })?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(RedisCtlError::ApiError {
+            message: format!("HTTP {}: {}", status.as_u16(), body.trim()),
+        });
+    }
+    Ok(response)
+}
+
+/// Execute a request with user-supplied headers and print the response
+#[allow(clippy::too_many_arguments)]
+async fn send_with_headers(
+    connection_manager: &ConnectionManager,
+    profile_name: Option<&str>,
+    deployment: DeploymentType,
+    method: &HttpMethod,
+    path: String,
+    body: Option<Value>,
+    extra_headers: HeaderMap,
+    query: Option<&str>,
+    output_format: OutputFormat,
+) -> CliResult<()> {
+    let mut request = connection_manager
+        .raw_request(profile_name, deployment, http_method(method), &path)
+        .await?;
+    if !matches!(method, HttpMethod::Get | HttpMethod::Delete) {
+        request = request.json(&body.unwrap_or(serde_json::json!({})));
+    }
+    // Applied last so they replace defaults such as Accept
+    let request = request.headers(extra_headers);
+
+    let text = send(request)
+        .await?
+        .text()
+        .await
+        .map_err(|e| RedisCtlError::ConnectionError {
+            message: e.to_string(),
+        })?;
+    // Bodies that aren't JSON (plain-text errors and the like) print as a string
+    let response = if text.trim().is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_str(&text).unwrap_or(Value::String(text))
+    };
+
+    let format = match output_format {
+        OutputFormat::Auto => OutputFormat::Json,
+        other => other,
+    };
+    print_output(response, format, query).map_err(|e| RedisCtlError::OutputError {
+        message: e.to_string(),
+    })
 }
 
-/// Parse request body from a JSON string or @file reference.
+/// Where [`JsonArrayStream`] is in the response body
+#[derive(Debug, Default, PartialEq)]
+enum StreamState {
+    /// Before the first non-whitespace byte
+    #[default]
+    Start,
+    /// Inside the top-level array
+    InArray,
+    /// After the closing `]`
+    Done,
+    /// The body is not an array; everything is buffered
+    Buffered,
+}
+
+/// How [`stream_get`] writes the elements of a streamed array
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) enum StreamLayout {
+    /// The array itself, as `serde_json::to_string_pretty` would print it
+    #[default]
+    Array,
+    /// One compact JSON value per line
+    Lines,
+}
+
+/// Incrementally splits a top-level JSON array into its elements
+///
+/// Only one element is held in memory at a time.
+#[derive(Debug, Default)]
+struct JsonArrayStream {
+    layout: StreamLayout,
+    /// Skip elements for which this returns false
+    keep: Option<fn(&Value) -> bool>,
+    state: StreamState,
+    /// Nesting depth inside the current element
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// Raw bytes of the element being read (or the whole body once `Buffered`)
+    element: Vec<u8>,
+    written: usize,
+    /// Write at most this many elements (`--max-items`)
+    limit: Option<usize>,
+    /// Elements skipped because of `limit`
+    omitted: usize,
+}
+
+impl JsonArrayStream {
+    /// Consume the next chunk of the body, writing any elements it completes
+    fn feed(&mut self, chunk: &[u8], out: &mut impl std::io::Write) -> CliResult<()> {
+        for &b in chunk {
+            match self.state {
+                StreamState::Start if b.is_ascii_whitespace() => {}
+                StreamState::Start if b == b'[' => {
+                    self.state = StreamState::InArray;
+                    if self.layout == StreamLayout::Array {
+                        out.write_all(b"[")?;
+                    }
+                }
+                StreamState::Start | StreamState::Buffered => {
+                    self.state = StreamState::Buffered;
+                    self.element.push(b);
+                }
+                StreamState::Done if b.is_ascii_whitespace() => {}
+                StreamState::Done => {
+                    return Err(RedisCtlError::OutputError {
+                        message: "Unexpected data after the end of the JSON array".to_string(),
+                    });
+                }
+                StreamState::InArray => self.feed_array_byte(b, out)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn feed_array_byte(&mut self, b: u8, out: &mut impl std::io::Write) -> CliResult<()> {
+        if self.in_string {
+            self.element.push(b);
+            if self.escaped {
+                self.escaped = false;
+            } else if b == b'\\' {
+                self.escaped = true;
+            } else if b == b'"' {
+                self.in_string = false;
+            }
+            return Ok(());
+        }
+
+        match b {
+            b',' if self.depth == 0 => self.write_element(out)?,
+            b']' if self.depth == 0 => {
+                if !self.element.is_empty() {
+                    self.write_element(out)?;
+                }
+                if self.layout == StreamLayout::Array {
+                    out.write_all(if self.written == 0 { b"]" } else { b"\n]" })?;
+                    out.write_all(b"\n")?;
+                }
+                self.state = StreamState::Done;
+            }
+            b if b.is_ascii_whitespace() && self.element.is_empty() => {}
+            b => {
+                match b {
+                    b'"' => self.in_string = true,
+                    b'[' | b'{' => self.depth += 1,
+                    b']' | b'}' => self.depth = self.depth.saturating_sub(1),
+                    _ => {}
+                }
+                self.element.push(b);
+            }
+        }
+        Ok(())
+    }
+
+    fn write_element(&mut self, out: &mut impl std::io::Write) -> CliResult<()> {
+        let mut value: Value =
+            serde_json::from_slice(&self.element).map_err(|e| RedisCtlError::OutputError {
+                message: format!("Invalid JSON in streamed response: {}", e),
+            })?;
+        self.element.clear();
+        if self.keep.is_some_and(|keep| !keep(&value)) {
+            return Ok(());
+        }
+        if self.limit.is_some_and(|limit| self.written >= limit) {
+            self.omitted += 1;
+            return Ok(());
+        }
+        prepare_streamed_item(&mut value);
+        match self.layout {
+            StreamLayout::Array => {
+                out.write_all(if self.written == 0 { b"\n" } else { b",\n" })?;
+                let pretty = serde_json::to_string_pretty(&value)?;
+                for (i, line) in pretty.lines().enumerate() {
+                    if i > 0 {
+                        out.write_all(b"\n")?;
+                    }
+                    write!(out, "  {}", line)?;
+                }
+            }
+            StreamLayout::Lines => writeln!(out, "{}", render_jsonl_item(&value)?)?,
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Finish the body. Returns the parsed value if the body was not an
+    /// array and had to be buffered instead.
+    fn finish(self) -> CliResult<Option<Value>> {
+        match self.state {
+            StreamState::Done => Ok(None),
+            StreamState::Buffered => Ok(Some(serde_json::from_slice(&self.element)?)),
+            StreamState::Start => Err(RedisCtlError::ApiError {
+                message: "Empty response body".to_string(),
+            }),
+            StreamState::InArray => Err(RedisCtlError::ApiError {
+                message: "Response ended before the JSON array was closed".to_string(),
+            }),
+        }
+    }
+}
+
+/// Parse request body from a JSON string, @file reference, or @- for stdin.
+///
+/// Called before any client is created so bad input fails without touching the network.
 fn parse_body(data: Option<String>) -> Result<Option<Value>, crate::error::RedisCtlError> {
+    parse_body_from(data, std::io::stdin().lock())
+}
+
+fn parse_body_from(
+    data: Option<String>,
+    mut stdin: impl std::io::Read,
+) -> Result<Option<Value>, crate::error::RedisCtlError> {
     let Some(data_str) = data else {
         return Ok(None);
     };
-    if let Some(file_path) = data_str.strip_prefix('@') {
+    if data_str == "@-" {
+        let mut content = String::new();
+        stdin
+            .read_to_string(&mut content)
+            .context("Failed to read request body from stdin")?;
+        if content.trim().is_empty() {
+            return Err(crate::error::RedisCtlError::InvalidInput {
+                message: "--data @- expects a JSON request body on stdin, but stdin was empty"
+                    .to_string(),
+            });
+        }
+        Ok(Some(
+            serde_json::from_str(&content).context("Failed to parse JSON from stdin")?,
+        ))
+    } else if let Some(file_path) = data_str.strip_prefix('@') {
         let content = std::fs::read_to_string(file_path)
             .with_context(|| format!("Failed to read file: {}", file_path))?;
         Ok(Some(serde_json::from_str(&content).with_context(|| {
@@ -250,3 +729,131 @@ fn normalize_enterprise_path(path: String) -> String {
         format!("/v1/{}", path)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_body_reads_stdin() {
+        let body = parse_body_from(Some("@-".to_string()), &b"{\"name\": \"sub\"}\n"[..])
+            .unwrap()
+            .unwrap();
+        assert_eq!(body["name"], "sub");
+    }
+
+    #[test]
+    fn test_parse_body_rejects_empty_stdin() {
+        let err = parse_body_from(Some("@-".to_string()), &b"  \n"[..]).unwrap_err();
+        assert!(err.to_string().contains("stdin was empty"));
+    }
+
+    /// Feed `body` to a stream in `chunk_size` pieces and return what it wrote
+    fn stream_in_chunks(body: &str, chunk_size: usize) -> (String, Option<Value>) {
+        let mut out = Vec::new();
+        let mut stream = JsonArrayStream::default();
+        for chunk in body.as_bytes().chunks(chunk_size) {
+            stream.feed(chunk, &mut out).unwrap();
+        }
+        let buffered = stream.finish().unwrap();
+        (String::from_utf8(out).unwrap(), buffered)
+    }
+
+    #[test]
+    fn test_json_array_stream_matches_pretty_output() {
+        let body = r#" [ {"uid": 1, "name": "a,b]", "tags": ["x", {"y": "\"}"}]}, 2 , "three", null, [] ] "#;
+        let expected = serde_json::to_string_pretty(&serde_json::from_str::<Value>(body).unwrap())
+            .unwrap()
+            + "\n";
+
+        for chunk_size in [1, 3, 7, body.len()] {
+            let (written, buffered) = stream_in_chunks(body, chunk_size);
+            assert_eq!(written, expected, "chunk size {}", chunk_size);
+            assert!(buffered.is_none());
+        }
+    }
+
+    #[test]
+    fn test_json_array_stream_stops_at_limit() {
+        let mut stream = JsonArrayStream {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        stream
+            .feed(br#"[{"uid": 1}, {"uid": 2}, {"uid": 3}]"#, &mut out)
+            .unwrap();
+        assert_eq!(stream.omitted, 1);
+        assert!(stream.finish().unwrap().is_none());
+
+        let written: Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(written, serde_json::json!([{"uid": 1}, {"uid": 2}]));
+    }
+
+    #[test]
+    fn test_json_array_stream_writes_filtered_lines() {
+        let mut stream = JsonArrayStream {
+            layout: StreamLayout::Lines,
+            keep: Some(|v: &Value| v["state"] != "done"),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        let body = br#"[{"id": 1, "state": "done"}, {"id": 2, "state": "running"}]"#;
+        stream.feed(body, &mut out).unwrap();
+        assert!(stream.finish().unwrap().is_none());
+        let line = String::from_utf8(out).unwrap();
+        assert_eq!(line.lines().count(), 1);
+        assert_eq!(
+            serde_json::from_str::<Value>(&line).unwrap(),
+            serde_json::json!({"id": 2, "state": "running"})
+        );
+    }
+
+    #[test]
+    fn test_json_array_stream_empty_array() {
+        assert_eq!(stream_in_chunks("[ ]", 1).0, "[]\n");
+    }
+
+    #[test]
+    fn test_json_array_stream_buffers_objects() {
+        let (written, buffered) = stream_in_chunks(r#"{"subscriptions": [1, 2]}"#, 4);
+        assert!(written.is_empty());
+        assert_eq!(buffered.unwrap()["subscriptions"][1], 2);
+    }
+
+    #[test]
+    fn test_json_array_stream_rejects_truncated_body() {
+        let mut stream = JsonArrayStream::default();
+        stream
+            .feed(br#"[{"uid": 1}, {"uid""#, &mut Vec::new())
+            .unwrap();
+        assert!(stream.finish().is_err());
+    }
+
+    #[test]
+    fn test_header_map_rejects_credentials() {
+        let headers = vec![("X-API-Key".to_string(), "other".to_string())];
+        let err = header_map(&headers).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("credentials come from the profile")
+        );
+
+        let headers = vec![
+            ("Accept".to_string(), "application/problem+json".to_string()),
+            ("X-Tag".to_string(), "a".to_string()),
+            ("X-Tag".to_string(), "b".to_string()),
+        ];
+        let map = header_map(&headers).unwrap();
+        assert_eq!(map["accept"], "application/problem+json");
+        assert_eq!(map.get_all("x-tag").iter().count(), 2);
+    }
+
+    #[test]
+    fn test_parse_body_inline_json_ignores_stdin() {
+        let body = parse_body_from(Some("{\"a\": 1}".to_string()), &b"ignored"[..])
+            .unwrap()
+            .unwrap();
+        assert_eq!(body["a"], 1);
+    }
+}
@@ -0,0 +1,414 @@
+//! Catalog of known REST endpoints for `redisctl api <platform> paths`
+//!
+//! Paths use `{name}` for path parameters. The catalog covers the endpoints
+//! most commonly used with the raw `api` command; it is not a complete copy of
+//! either API reference.
+
+use serde::Serialize;
+
+use crate::cli::OutputFormat;
+use crate::error::{RedisCtlError, Result as CliResult};
+use crate::output::print_output;
+use redisctl_core::DeploymentType;
+
+/// One documented endpoint
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ApiPath {
+    pub method: &'static str,
+    pub path: &'static str,
+    pub description: &'static str,
+}
+
+const fn endpoint(method: &'static str, path: &'static str, description: &'static str) -> ApiPath {
+    ApiPath {
+        method,
+        path,
+        description,
+    }
+}
+
+/// Redis Cloud API (relative to the API base URL, e.g. `/v1`)
+pub const CLOUD_PATHS: &[ApiPath] = &[
+    endpoint("GET", "/", "Current account and API key"),
+    endpoint("GET", "/account", "Account information"),
+    endpoint("GET", "/payment-methods", "Payment methods"),
+    endpoint("GET", "/regions", "Supported cloud regions"),
+    endpoint("GET", "/database-modules", "Available database modules"),
+    endpoint("GET", "/logs", "System log"),
+    endpoint("GET", "/session-logs", "Session log"),
+    endpoint("GET", "/tasks", "Recent asynchronous tasks"),
+    endpoint("GET", "/tasks/{taskId}", "Task status"),
+    endpoint("GET", "/subscriptions", "Pro subscriptions"),
+    endpoint("POST", "/subscriptions", "Create a Pro subscription"),
+    endpoint("GET", "/subscriptions/{subscriptionId}", "Pro subscription"),
+    endpoint(
+        "PUT",
+        "/subscriptions/{subscriptionId}",
+        "Update a Pro subscription",
+    ),
+    endpoint(
+        "DELETE",
+        "/subscriptions/{subscriptionId}",
+        "Delete a Pro subscription",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/cidr",
+        "CIDR allowlist",
+    ),
+    endpoint(
+        "PUT",
+        "/subscriptions/{subscriptionId}/cidr",
+        "Update the CIDR allowlist",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/maintenance-windows",
+        "Maintenance windows",
+    ),
+    endpoint(
+        "PUT",
+        "/subscriptions/{subscriptionId}/maintenance-windows",
+        "Update maintenance windows",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/pricing",
+        "Subscription pricing",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/databases",
+        "Databases in a Pro subscription",
+    ),
+    endpoint(
+        "POST",
+        "/subscriptions/{subscriptionId}/databases",
+        "Create a Pro database",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}",
+        "Pro database",
+    ),
+    endpoint(
+        "PUT",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}",
+        "Update a Pro database",
+    ),
+    endpoint(
+        "DELETE",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}",
+        "Delete a Pro database",
+    ),
+    endpoint(
+        "POST",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}/backup",
+        "Back up a Pro database",
+    ),
+    endpoint(
+        "POST",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}/import",
+        "Import data into a Pro database",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}/certificate",
+        "Database TLS certificate",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}/slow-log",
+        "Database slow log",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}/tags",
+        "Database tags",
+    ),
+    endpoint(
+        "POST",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}/tags",
+        "Add a database tag",
+    ),
+    endpoint(
+        "POST",
+        "/subscriptions/{subscriptionId}/databases/{databaseId}/flush",
+        "Flush an Active-Active database",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/peerings",
+        "VPC peerings",
+    ),
+    endpoint(
+        "POST",
+        "/subscriptions/{subscriptionId}/peerings",
+        "Create a VPC peering",
+    ),
+    endpoint(
+        "DELETE",
+        "/subscriptions/{subscriptionId}/peerings/{peeringId}",
+        "Delete a VPC peering",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/private-service-connect",
+        "Private Service Connect service",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/transitGateways",
+        "Transit gateways",
+    ),
+    endpoint(
+        "GET",
+        "/subscriptions/{subscriptionId}/regions",
+        "Active-Active regions",
+    ),
+    endpoint(
+        "POST",
+        "/subscriptions/{subscriptionId}/regions",
+        "Add an Active-Active region",
+    ),
+    endpoint("GET", "/fixed/plans", "Essentials plans"),
+    endpoint("GET", "/fixed/subscriptions", "Essentials subscriptions"),
+    endpoint(
+        "POST",
+        "/fixed/subscriptions",
+        "Create an Essentials subscription",
+    ),
+    endpoint(
+        "GET",
+        "/fixed/subscriptions/{subscriptionId}",
+        "Essentials subscription",
+    ),
+    endpoint(
+        "DELETE",
+        "/fixed/subscriptions/{subscriptionId}",
+        "Delete an Essentials subscription",
+    ),
+    endpoint(
+        "GET",
+        "/fixed/subscriptions/{subscriptionId}/databases",
+        "Essentials databases",
+    ),
+    endpoint(
+        "POST",
+        "/fixed/subscriptions/{subscriptionId}/databases",
+        "Create an Essentials database",
+    ),
+    endpoint(
+        "GET",
+        "/fixed/subscriptions/{subscriptionId}/databases/{databaseId}",
+        "Essentials database",
+    ),
+    endpoint(
+        "PUT",
+        "/fixed/subscriptions/{subscriptionId}/databases/{databaseId}",
+        "Update an Essentials database",
+    ),
+    endpoint(
+        "DELETE",
+        "/fixed/subscriptions/{subscriptionId}/databases/{databaseId}",
+        "Delete an Essentials database",
+    ),
+    endpoint("GET", "/users", "Account users"),
+    endpoint("GET", "/users/{userId}", "Account user"),
+    endpoint("PUT", "/users/{userId}", "Update an account user"),
+    endpoint("DELETE", "/users/{userId}", "Delete an account user"),
+    endpoint("GET", "/acl/users", "ACL users"),
+    endpoint("POST", "/acl/users", "Create an ACL user"),
+    endpoint("GET", "/acl/roles", "ACL roles"),
+    endpoint("POST", "/acl/roles", "Create an ACL role"),
+    endpoint("GET", "/acl/redisRules", "ACL Redis rules"),
+    endpoint("POST", "/acl/redisRules", "Create an ACL Redis rule"),
+    endpoint("GET", "/cloud-accounts", "Cloud provider accounts"),
+    endpoint("POST", "/cloud-accounts", "Add a cloud provider account"),
+    endpoint(
+        "GET",
+        "/cloud-accounts/{cloudAccountId}",
+        "Cloud provider account",
+    ),
+];
+
+/// Redis Enterprise REST API (relative to the cluster URL, e.g. `https://host:9443`)
+pub const ENTERPRISE_PATHS: &[ApiPath] = &[
+    endpoint("GET", "/v1/cluster", "Cluster configuration"),
+    endpoint("PUT", "/v1/cluster", "Update the cluster configuration"),
+    endpoint("GET", "/v1/cluster/policy", "Cluster policy"),
+    endpoint("PUT", "/v1/cluster/policy", "Update the cluster policy"),
+    endpoint("GET", "/v1/cluster/stats/last", "Latest cluster statistics"),
+    endpoint("GET", "/v1/cluster/alerts", "Cluster alerts"),
+    endpoint("GET", "/v1/cluster/certificates", "Cluster certificates"),
+    endpoint(
+        "POST",
+        "/v1/cluster/certificates/rotate",
+        "Rotate cluster certificates",
+    ),
+    endpoint("GET", "/v1/cluster/debuginfo", "Cluster support package"),
+    endpoint("GET", "/v1/license", "Cluster license"),
+    endpoint("PUT", "/v1/license", "Install a license"),
+    endpoint("GET", "/v1/bootstrap", "Bootstrap status"),
+    endpoint("POST", "/v1/bootstrap/{action}", "Create or join a cluster"),
+    endpoint("GET", "/v1/nodes", "Nodes"),
+    endpoint("GET", "/v1/nodes/{uid}", "Node"),
+    endpoint("PUT", "/v1/nodes/{uid}", "Update a node"),
+    endpoint("GET", "/v1/nodes/{uid}/status", "Node status"),
+    endpoint("GET", "/v1/nodes/stats/last", "Latest node statistics"),
+    endpoint(
+        "POST",
+        "/v1/nodes/{uid}/actions/{action}",
+        "Run a node action",
+    ),
+    endpoint("GET", "/v1/nodes/{uid}/debuginfo", "Node support package"),
+    endpoint("GET", "/v1/bdbs", "Databases"),
+    endpoint("POST", "/v1/bdbs", "Create a database"),
+    endpoint("POST", "/v1/bdbs/dry-run", "Validate a database request"),
+    endpoint("GET", "/v1/bdbs/{uid}", "Database"),
+    endpoint("PUT", "/v1/bdbs/{uid}", "Update a database"),
+    endpoint("DELETE", "/v1/bdbs/{uid}", "Delete a database"),
+    endpoint("GET", "/v1/bdbs/stats/last", "Latest database statistics"),
+    endpoint("GET", "/v1/bdbs/{uid}/shards", "Database shards"),
+    endpoint(
+        "GET",
+        "/v1/bdbs/{uid}/debuginfo",
+        "Database support package",
+    ),
+    endpoint("PUT", "/v1/bdbs/{uid}/flush", "Flush a database"),
+    endpoint("POST", "/v1/bdbs/{uid}/actions/export", "Export a database"),
+    endpoint(
+        "POST",
+        "/v1/bdbs/{uid}/actions/import",
+        "Import into a database",
+    ),
+    endpoint(
+        "POST",
+        "/v1/bdbs/{uid}/actions/backup",
+        "Back up a database",
+    ),
+    endpoint(
+        "POST",
+        "/v1/bdbs/{uid}/actions/recover",
+        "Recover a database",
+    ),
+    endpoint(
+        "GET",
+        "/v1/bdbs/{uid}/upgrade",
+        "Upgrade database Redis version",
+    ),
+    endpoint("GET", "/v1/bdbs/alerts", "Database alerts"),
+    endpoint("GET", "/v1/crdbs", "Active-Active databases"),
+    endpoint("POST", "/v1/crdbs", "Create an Active-Active database"),
+    endpoint("GET", "/v1/crdbs/{guid}", "Active-Active database"),
+    endpoint(
+        "PATCH",
+        "/v1/crdbs/{guid}",
+        "Update an Active-Active database",
+    ),
+    endpoint(
+        "DELETE",
+        "/v1/crdbs/{guid}",
+        "Delete an Active-Active database",
+    ),
+    endpoint("GET", "/v1/crdb_tasks/{task_id}", "Active-Active task"),
+    endpoint("GET", "/v1/shards", "Shards"),
+    endpoint("GET", "/v1/shards/{uid}", "Shard"),
+    endpoint("GET", "/v1/shards/stats/last", "Latest shard statistics"),
+    endpoint("POST", "/v1/shards/actions/failover", "Fail over shards"),
+    endpoint("POST", "/v1/shards/actions/migrate", "Migrate shards"),
+    endpoint("GET", "/v1/endpoints/stats", "Endpoint statistics"),
+    endpoint("GET", "/v1/proxies", "Proxies"),
+    endpoint("PUT", "/v1/proxies/{uid}", "Update a proxy"),
+    endpoint("GET", "/v1/actions", "Running and recent actions"),
+    endpoint("GET", "/v1/actions/{uid}", "Action status"),
+    endpoint("GET", "/v1/logs", "Cluster event log"),
+    endpoint("GET", "/v1/modules", "Installed modules"),
+    endpoint("POST", "/v2/modules", "Upload a module"),
+    endpoint("GET", "/v1/users", "Users"),
+    endpoint("POST", "/v1/users", "Create a user"),
+    endpoint("GET", "/v1/users/{uid}", "User"),
+    endpoint("PUT", "/v1/users/{uid}", "Update a user"),
+    endpoint("DELETE", "/v1/users/{uid}", "Delete a user"),
+    endpoint("POST", "/v1/users/authorize", "Get a JWT for a user"),
+    endpoint("GET", "/v1/roles", "Roles"),
+    endpoint("POST", "/v1/roles", "Create a role"),
+    endpoint("GET", "/v1/redis_acls", "Redis ACLs"),
+    endpoint("POST", "/v1/redis_acls", "Create a Redis ACL"),
+    endpoint("GET", "/v1/ldap_mappings", "LDAP mappings"),
+    endpoint("GET", "/v1/cluster/ldap", "LDAP configuration"),
+    endpoint("GET", "/v1/services", "Cluster services"),
+    endpoint("GET", "/v1/suffixes", "DNS suffixes"),
+    endpoint("GET", "/v1/jsonschema", "JSON schema of API objects"),
+    endpoint("GET", "/v1/ocsp", "OCSP configuration"),
+    endpoint("GET", "/v1/usage_report", "Usage report"),
+];
+
+/// Print the catalog for `deployment`, keeping entries whose path or
+/// description contains `filter` (case-insensitive)
+pub fn print_api_paths(
+    deployment: DeploymentType,
+    filter: Option<&str>,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let catalog = match deployment {
+        DeploymentType::Cloud => CLOUD_PATHS,
+        DeploymentType::Enterprise => ENTERPRISE_PATHS,
+        DeploymentType::Database => {
+            return Err(RedisCtlError::InvalidInput {
+                message: "Database profiles have no REST API; use `redisctl db` instead"
+                    .to_string(),
+            });
+        }
+    };
+
+    let paths = filter_paths(catalog, filter);
+    print_output(paths, output_format, query).map_err(|e| RedisCtlError::OutputError {
+        message: e.to_string(),
+    })
+}
+
+fn filter_paths(catalog: &[ApiPath], filter: Option<&str>) -> Vec<ApiPath> {
+    let filter = filter.map(str::to_lowercase);
+    catalog
+        .iter()
+        .filter(|p| {
+            filter.as_deref().is_none_or(|f| {
+                p.path.to_lowercase().contains(f) || p.description.to_lowercase().contains(f)
+            })
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_entries_are_well_formed() {
+        for p in CLOUD_PATHS.iter().chain(ENTERPRISE_PATHS) {
+            assert!(
+                ["GET", "POST", "PUT", "PATCH", "DELETE"].contains(&p.method),
+                "{} {}",
+                p.method,
+                p.path
+            );
+            assert!(p.path.starts_with('/'), "{}", p.path);
+        }
+        assert!(ENTERPRISE_PATHS.iter().all(|p| p.path.starts_with("/v")));
+    }
+
+    #[test]
+    fn test_filter_paths_matches_path_or_description() {
+        let by_path = filter_paths(ENTERPRISE_PATHS, Some("/V1/CRDBS"));
+        assert!(!by_path.is_empty());
+        assert!(by_path.iter().all(|p| p.path.starts_with("/v1/crdbs")));
+
+        let by_description = filter_paths(CLOUD_PATHS, Some("peering"));
+        assert!(by_description.iter().any(|p| p.method == "POST"));
+
+        assert_eq!(filter_paths(CLOUD_PATHS, None).len(), CLOUD_PATHS.len());
+    }
+}
@@ -0,0 +1,172 @@
+//! Result collection for batch and fan-out commands
+//!
+//! Commands that act on several targets (profiles, databases, nodes) keep going
+//! when one target fails and report every outcome at the end. Each failed item
+//! carries a structured `error` object so scripts can branch on the error type
+//! without parsing messages:
+//!
+//! ```json
+//! {
+//!   "results": [
+//!     {"profile": "prod", "status": "SUCCESS"},
+//!     {"profile": "dr", "status": "FAILED",
+//!      "error": {"type": "connection_error", "message": "Connection error: ..."}}
+//!   ],
+//!   "summary": {"total": 2, "succeeded": 1, "failed": 1}
+//! }
+//! ```
+
+#![allow(dead_code)] // Used by binary target
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::error::RedisCtlError;
+
+/// Structured error attached to a failed batch item
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchItemError {
+    /// Error category, e.g. `connection_error` or `api_error`
+    #[serde(rename = "type")]
+    pub error_type: String,
+    /// Human-readable error message
+    pub message: String,
+}
+
+impl BatchItemError {
+    pub fn new(error_type: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            error_type: error_type.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl From<&RedisCtlError> for BatchItemError {
+    fn from(err: &RedisCtlError) -> Self {
+        Self::new(err.kind(), err.to_string())
+    }
+}
+
+impl From<RedisCtlError> for BatchItemError {
+    fn from(err: RedisCtlError) -> Self {
+        Self::from(&err)
+    }
+}
+
+impl From<redis_enterprise::RestError> for BatchItemError {
+    fn from(err: redis_enterprise::RestError) -> Self {
+        RedisCtlError::from(err).into()
+    }
+}
+
+impl From<redis_cloud::CloudError> for BatchItemError {
+    fn from(err: redis_cloud::CloudError) -> Self {
+        RedisCtlError::from(err).into()
+    }
+}
+
+impl From<&anyhow::Error> for BatchItemError {
+    fn from(err: &anyhow::Error) -> Self {
+        match err.downcast_ref::<RedisCtlError>() {
+            Some(inner) => Self::new(inner.kind(), format!("{:#}", err)),
+            None => Self::new("error", format!("{:#}", err)),
+        }
+    }
+}
+
+/// Collects per-item outcomes of a batch command
+#[derive(Debug, Default)]
+pub struct BatchResults {
+    results: Vec<Value>,
+    succeeded: usize,
+    failed: usize,
+}
+
+impl BatchResults {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a successful item
+    pub fn success(&mut self, item: Value) {
+        self.succeeded += 1;
+        self.results.push(item);
+    }
+
+    /// Record a failed item, attaching the structured error under `error`
+    pub fn failure(&mut self, mut item: Value, error: impl Into<BatchItemError>) {
+        let error = error.into();
+        if let Value::Object(map) = &mut item {
+            map.insert("error".to_string(), json!(error));
+        } else {
+            item = json!({ "item": item, "error": error });
+        }
+        self.failed += 1;
+        self.results.push(item);
+    }
+
+    /// Record an item that was neither attempted nor failed (dry runs, skips)
+    pub fn skipped(&mut self, item: Value) {
+        self.results.push(item);
+    }
+
+    pub fn succeeded(&self) -> usize {
+        self.succeeded
+    }
+
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Render as `{results, summary: {total, succeeded, failed}}`
+    pub fn into_value(self) -> Value {
+        json!({
+            "results": self.results,
+            "summary": {
+                "total": self.results.len(),
+                "succeeded": self.succeeded,
+                "failed": self.failed,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_attaches_structured_error() {
+        let mut batch = BatchResults::new();
+        batch.success(json!({"profile": "prod", "status": "SUCCESS"}));
+        batch.failure(
+            json!({"profile": "dr", "status": "FAILED"}),
+            RedisCtlError::ConnectionError {
+                message: "Connection refused".to_string(),
+            },
+        );
+        batch.skipped(json!({"profile": "qa", "status": "SKIPPED"}));
+
+        let output = batch.into_value();
+        assert_eq!(output["summary"]["total"], 3);
+        assert_eq!(output["summary"]["succeeded"], 1);
+        assert_eq!(output["summary"]["failed"], 1);
+
+        let error = &output["results"][1]["error"];
+        assert_eq!(error["type"], "connection_error");
+        assert_eq!(error["message"], "Connection error: Connection refused");
+        assert!(output["results"][0].get("error").is_none());
+    }
+
+    #[test]
+    fn test_anyhow_error_keeps_inner_kind() {
+        let err = anyhow::Error::from(RedisCtlError::Timeout {
+            message: "after 30s".to_string(),
+        });
+        assert_eq!(BatchItemError::from(&err).error_type, "timeout");
+
+        let err = anyhow::anyhow!("something else");
+        assert_eq!(BatchItemError::from(&err).error_type, "error");
+    }
+}
@@ -109,6 +109,31 @@ pub async fn handle_acl_command(
             };
             acl_impl::delete_role(&params, *id, *force).await
         }
+        CloudAclCommands::BindRole {
+            id,
+            subscription,
+            database,
+            rule,
+            users,
+            async_ops,
+        } => {
+            let params = AclOperationParams {
+                conn_mgr,
+                profile_name,
+                async_ops,
+                output_format,
+                query,
+            };
+            acl_impl::bind_role(
+                &params,
+                *id,
+                *subscription,
+                *database,
+                rule.as_deref(),
+                users,
+            )
+            .await
+        }
 
         // ACL Users
         CloudAclCommands::ListAclUsers => {
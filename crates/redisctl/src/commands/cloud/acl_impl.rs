@@ -3,7 +3,7 @@
 use crate::cli::OutputFormat;
 use crate::commands::cloud::async_utils::{AsyncOperationArgs, handle_async_response};
 use crate::connection::ConnectionManager;
-use crate::error::Result as CliResult;
+use crate::error::{RedisCtlError, Result as CliResult};
 use anyhow::Context;
 use redis_cloud::acl::AclHandler;
 use serde_json::Value;
@@ -315,7 +315,7 @@ pub async fn list_redis_rules(
 
     let data = handle_output(rules_json, output_format, query)?;
 
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_redis_rules_table(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -451,7 +451,7 @@ pub async fn list_roles(
 
     let data = handle_output(roles_json, output_format, query)?;
 
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_acl_roles_table(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -592,7 +592,7 @@ pub async fn list_acl_users(
 
     let data = handle_output(users_json, output_format, query)?;
 
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_acl_users_table(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -615,7 +615,7 @@ pub async fn get_acl_user(
 
     let data = handle_output(user_json, output_format, query)?;
 
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_acl_user_detail(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -743,3 +743,250 @@ pub async fn delete_acl_user(
     )
     .await
 }
+
+/// Build the `redisRules` payload that adds a database to a role's bindings
+///
+/// Existing bindings are kept. With `rule`, only that rule gains the database
+/// (it is added to the role if missing); otherwise every rule in the role does.
+fn bind_database_rules(
+    role: &Value,
+    subscription_id: i32,
+    database_id: i32,
+    rule: Option<&str>,
+) -> CliResult<Value> {
+    let binding = serde_json::json!({
+        "subscriptionId": subscription_id,
+        "databaseId": database_id,
+    });
+
+    let mut rules: Vec<Value> = role
+        .get("redisRules")
+        .and_then(|v| v.as_array())
+        .map(|rules| {
+            rules
+                .iter()
+                .map(|r| {
+                    let databases: Vec<Value> = r
+                        .get("databases")
+                        .and_then(|v| v.as_array())
+                        .map(|dbs| {
+                            dbs.iter()
+                                .map(|db| {
+                                    let mut entry = serde_json::json!({
+                                        "subscriptionId": db.get("subscriptionId"),
+                                        "databaseId": db.get("databaseId"),
+                                    });
+                                    if let Some(regions) = db.get("regions") {
+                                        entry["regions"] = regions.clone();
+                                    }
+                                    entry
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    serde_json::json!({
+                        "ruleName": r.get("ruleName"),
+                        "databases": databases,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    match rule {
+        Some(name) if !rules.iter().any(|r| r["ruleName"] == name) => {
+            rules.push(serde_json::json!({"ruleName": name, "databases": []}));
+        }
+        None if rules.is_empty() => {
+            return Err(RedisCtlError::InvalidInput {
+                message: "Role has no Redis rules to bind through; pass --rule".to_string(),
+            });
+        }
+        _ => {}
+    }
+
+    let is_bound = |db: &Value| {
+        db["subscriptionId"].as_i64() == Some(i64::from(subscription_id))
+            && db["databaseId"].as_i64() == Some(i64::from(database_id))
+    };
+    for r in rules
+        .iter_mut()
+        .filter(|r| rule.is_none_or(|name| r["ruleName"] == name))
+    {
+        if let Some(databases) = r["databases"].as_array_mut()
+            && !databases.iter().any(is_bound)
+        {
+            databases.push(binding.clone());
+        }
+    }
+
+    Ok(Value::Array(rules))
+}
+
+pub async fn bind_role(
+    params: &AclOperationParams<'_>,
+    id: i32,
+    subscription_id: i32,
+    database_id: i32,
+    rule: Option<&str>,
+    users: &[i32],
+) -> CliResult<()> {
+    let client = params
+        .conn_mgr
+        .create_cloud_client(params.profile_name)
+        .await?;
+
+    // Validate everything up front so a typo doesn't leave a half-applied binding
+    let roles = client
+        .get_raw("/acl/roles")
+        .await
+        .context("Failed to list roles")?;
+    let role = extract_items(&roles, "roles")
+        .and_then(|items| {
+            items
+                .iter()
+                .find(|r| r.get("id").and_then(|v| v.as_i64()) == Some(i64::from(id)))
+        })
+        .cloned()
+        .ok_or_else(|| RedisCtlError::InvalidInput {
+            message: format!("ACL role {} not found", id),
+        })?;
+    let role_name = role
+        .get("name")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    client
+        .get_raw(&format!(
+            "/subscriptions/{}/databases/{}",
+            subscription_id, database_id
+        ))
+        .await
+        .map_err(|e| RedisCtlError::InvalidInput {
+            message: format!(
+                "Database {} not found in subscription {}: {}",
+                database_id, subscription_id, e
+            ),
+        })?;
+
+    if let Some(name) = rule {
+        let redis_rules = client
+            .get_raw("/acl/redisRules")
+            .await
+            .context("Failed to list Redis rules")?;
+        let exists = extract_items(&redis_rules, "redisRules")
+            .is_some_and(|items| items.iter().any(|r| r["name"] == name));
+        if !exists {
+            return Err(RedisCtlError::InvalidInput {
+                message: format!("Redis rule '{}' not found", name),
+            });
+        }
+    }
+
+    for user_id in users {
+        client
+            .get_raw(&format!("/acl/users/{}", user_id))
+            .await
+            .map_err(|e| RedisCtlError::InvalidInput {
+                message: format!("ACL user {} not found: {}", user_id, e),
+            })?;
+    }
+
+    let request_data = serde_json::json!({
+        "redisRules": bind_database_rules(&role, subscription_id, database_id, rule)?,
+    });
+    let response = client
+        .put_raw(&format!("/acl/roles/{}", id), request_data)
+        .await
+        .context("Failed to update role")?;
+
+    handle_async_response(
+        params.conn_mgr,
+        params.profile_name,
+        response,
+        params.async_ops,
+        params.output_format,
+        params.query,
+        &format!(
+            "ACL role {} bound to database {}:{}",
+            id, subscription_id, database_id
+        ),
+    )
+    .await?;
+
+    for user_id in users {
+        let response = client
+            .put_raw(
+                &format!("/acl/users/{}", user_id),
+                serde_json::json!({ "role": role_name }),
+            )
+            .await
+            .context("Failed to assign role to ACL user")?;
+
+        handle_async_response(
+            params.conn_mgr,
+            params.profile_name,
+            response,
+            params.async_ops,
+            params.output_format,
+            params.query,
+            &format!("ACL role {} assigned to ACL user {}", id, user_id),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role() -> Value {
+        serde_json::json!({
+            "id": 12,
+            "name": "app-readers",
+            "redisRules": [
+                {
+                    "ruleId": 1,
+                    "ruleName": "Read-Only",
+                    "databases": [
+                        {"subscriptionId": 1234, "databaseId": 50, "databaseName": "cache", "regions": []}
+                    ]
+                },
+                {"ruleId": 2, "ruleName": "Full-Access", "databases": []}
+            ]
+        })
+    }
+
+    #[test]
+    fn test_bind_database_rules_adds_to_every_rule() {
+        let rules = bind_database_rules(&role(), 1234, 51, None).unwrap();
+        assert_eq!(rules[0]["databases"].as_array().unwrap().len(), 2);
+        assert_eq!(rules[0]["databases"][1]["databaseId"], 51);
+        assert_eq!(rules[1]["databases"][0]["databaseId"], 51);
+        // Response-only fields are not sent back
+        assert!(rules[0]["databases"][0].get("databaseName").is_none());
+    }
+
+    #[test]
+    fn test_bind_database_rules_single_rule_and_idempotent() {
+        let rules = bind_database_rules(&role(), 1234, 50, Some("Full-Access")).unwrap();
+        assert_eq!(rules[0]["databases"].as_array().unwrap().len(), 1);
+        assert_eq!(rules[1]["databases"][0]["databaseId"], 50);
+
+        let rules = bind_database_rules(&role(), 1234, 50, Some("Read-Only")).unwrap();
+        assert_eq!(rules[0]["databases"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_bind_database_rules_new_rule_or_error() {
+        let empty = serde_json::json!({"id": 3, "name": "new", "redisRules": []});
+        assert!(bind_database_rules(&empty, 1, 2, None).is_err());
+
+        let rules = bind_database_rules(&empty, 1, 2, Some("Read-Only")).unwrap();
+        assert_eq!(rules[0]["ruleName"], "Read-Only");
+        assert_eq!(rules[0]["databases"][0]["subscriptionId"], 1);
+    }
+}
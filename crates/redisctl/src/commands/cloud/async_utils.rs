@@ -16,12 +16,10 @@ use crate::output::print_output;
 
 /// Helper to print non-table output
 fn print_json_or_yaml(data: Value, output_format: OutputFormat) -> CliResult<()> {
-    match output_format {
-        OutputFormat::Json => print_output(data, crate::output::OutputFormat::Json, None)?,
-        OutputFormat::Yaml => print_output(data, crate::output::OutputFormat::Yaml, None)?,
-        OutputFormat::Auto | OutputFormat::Table => {
-            print_output(data, crate::output::OutputFormat::Json, None)?
-        }
+    if output_format.is_structured() {
+        print_output(data, output_format, None)?
+    } else {
+        print_output(data, crate::output::OutputFormat::Json, None)?
     }
     Ok(())
 }
@@ -89,18 +87,17 @@ pub async fn handle_async_response(
     }
 
     // Normal output without waiting
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!("{}", success_message);
-            if let Some(task_id) = task_id {
-                println!("Task ID: {}", task_id);
-                println!(
-                    "To wait for completion, run: redisctl cloud task wait {}",
-                    task_id
-                );
-            }
+    if output_format.is_structured() {
+        print_json_or_yaml(result, output_format)?
+    } else {
+        println!("{}", success_message);
+        if let Some(task_id) = task_id {
+            println!("Task ID: {}", task_id);
+            println!(
+                "To wait for completion, run: redisctl cloud task wait {}",
+                task_id
+            );
         }
-        OutputFormat::Json | OutputFormat::Yaml => print_json_or_yaml(result, output_format)?,
     }
 
     Ok(())
@@ -165,16 +162,10 @@ pub async fn wait_for_task(
             // Convert typed task to JSON for output formatting
             let task_json = serde_json::to_value(&task).unwrap_or_else(|_| serde_json::json!({}));
 
-            match output_format {
-                OutputFormat::Auto | OutputFormat::Table => {
-                    print_task_details(&task_json)?;
-                }
-                OutputFormat::Json => {
-                    print_output(task_json, crate::output::OutputFormat::Json, None)?;
-                }
-                OutputFormat::Yaml => {
-                    print_output(task_json, crate::output::OutputFormat::Yaml, None)?;
-                }
+            if output_format.is_structured() {
+                print_output(task_json, output_format, None)?;
+            } else {
+                print_task_details(&task_json)?;
             }
             Ok(())
         }
@@ -1,6 +1,7 @@
 //! Cost report command implementations
 //!
-//! Handles generating and downloading cost reports in FOCUS format.
+//! Handles generating and downloading cost reports in FOCUS format, and
+//! flattening them into spreadsheet rows with `--flatten`.
 
 #![allow(dead_code)] // Functions used from main.rs binary
 
@@ -11,7 +12,9 @@ use crate::error::{RedisCtlError, Result as CliResult};
 use anyhow::Context;
 use indicatif::{ProgressBar, ProgressStyle};
 use redis_cloud::cost_report::{CostReportCreateRequest, CostReportFormat, SubscriptionType, Tag};
-use serde_json::{Value, json};
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
@@ -54,8 +57,17 @@ pub async fn handle_cost_report_command(
         CloudCostReportCommands::Download {
             cost_report_id,
             file,
+            flatten,
         } => {
-            download_cost_report(conn_mgr, profile_name, cost_report_id, file, output_format).await
+            download_cost_report(
+                conn_mgr,
+                profile_name,
+                cost_report_id,
+                file,
+                flatten,
+                output_format,
+            )
+            .await
         }
         CloudCostReportCommands::Export {
             start_date,
@@ -68,6 +80,7 @@ pub async fn handle_cost_report_command(
             regions,
             tags,
             timeout,
+            flatten,
         } => {
             export_cost_report(
                 conn_mgr,
@@ -82,6 +95,7 @@ pub async fn handle_cost_report_command(
                 regions,
                 tags,
                 timeout,
+                flatten,
                 output_format,
             )
             .await
@@ -186,13 +200,18 @@ async fn download_cost_report(
     profile_name: Option<&str>,
     cost_report_id: String,
     output: Option<String>,
+    flatten: bool,
     output_format: OutputFormat,
 ) -> CliResult<()> {
     let client = conn_mgr.create_cloud_client(profile_name).await?;
 
-    let bytes = client
+    let mut bytes = client
         .get_bytes(&format!("/cost-report/{}", cost_report_id))
         .await?;
+    if flatten {
+        let as_json = output.is_none() && matches!(output_format, OutputFormat::Json);
+        bytes = flatten_report(&bytes, as_json)?;
+    }
 
     match output {
         Some(path) => {
@@ -266,6 +285,7 @@ async fn export_cost_report(
     regions: Vec<String>,
     tags: Vec<String>,
     timeout: u64,
+    flatten: bool,
     output_format: OutputFormat,
 ) -> CliResult<()> {
     let client = conn_mgr.create_cloud_client(profile_name).await?;
@@ -365,12 +385,17 @@ async fn export_cost_report(
     pb.set_message(format!("Downloading report {}...", cost_report_id));
 
     // Download the report
-    let bytes = client
+    let mut bytes = client
         .get_bytes(&format!("/cost-report/{}", cost_report_id))
         .await?;
 
     pb.finish_and_clear();
 
+    if flatten {
+        let as_json = file.is_none() && matches!(output_format, OutputFormat::Json);
+        bytes = flatten_report(&bytes, as_json)?;
+    }
+
     // Write output
     match file {
         Some(path) => {
@@ -468,3 +493,272 @@ async fn wait_for_task_result(
         sleep(interval).await;
     }
 }
+
+/// Columns of a flattened cost report, in output order
+const FLAT_COLUMNS: &[&str] = &[
+    "subscription_id",
+    "database_id",
+    "period",
+    "line_item",
+    "amount",
+    "currency",
+];
+
+// Source fields for each column, FOCUS names first; matched case-insensitively
+const SUBSCRIPTION_FIELDS: &[&str] = &["SubAccountId", "subscriptionId", "subscription_id"];
+const DATABASE_FIELDS: &[&str] = &["ResourceId", "databaseId", "database_id"];
+const PERIOD_FIELDS: &[&str] = &[
+    "ChargePeriodStart",
+    "BillingPeriodStart",
+    "period",
+    "startDate",
+    "date",
+];
+const LINE_ITEM_FIELDS: &[&str] = &[
+    "ChargeDescription",
+    "SkuId",
+    "ServiceName",
+    "ChargeCategory",
+    "ChargeType",
+    "lineItem",
+    "line_item",
+    "description",
+];
+const AMOUNT_FIELDS: &[&str] = &["BilledCost", "EffectiveCost", "amount", "cost"];
+const CURRENCY_FIELDS: &[&str] = &["BillingCurrency", "currency"];
+
+/// One row of a flattened cost report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct CostRow {
+    subscription_id: String,
+    database_id: String,
+    /// Month the charges fall in (`YYYY-MM`)
+    period: String,
+    line_item: String,
+    amount: f64,
+    currency: String,
+}
+
+/// Flatten a downloaded report (FOCUS CSV or JSON) into CSV, or into a JSON
+/// array of rows when `as_json` is set
+fn flatten_report(bytes: &[u8], as_json: bool) -> CliResult<Vec<u8>> {
+    let records = parse_report(bytes)?;
+    let rows = flatten_records(&records);
+    if as_json {
+        return Ok(serde_json::to_vec_pretty(&rows)?);
+    }
+    let records: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            vec![
+                row.subscription_id.clone(),
+                row.database_id.clone(),
+                row.period.clone(),
+                row.line_item.clone(),
+                row.amount.to_string(),
+                row.currency.clone(),
+            ]
+        })
+        .collect();
+    let mut csv = crate::output::render_csv_records(FLAT_COLUMNS, &records)?;
+    csv.push('\n');
+    Ok(csv.into_bytes())
+}
+
+/// Cost line items of a report, as objects keyed by column name
+fn parse_report(bytes: &[u8]) -> CliResult<Vec<Map<String, Value>>> {
+    if let Ok(value) = serde_json::from_slice::<Value>(bytes) {
+        let mut records = Vec::new();
+        collect_line_items(&value, &Map::new(), &mut records);
+        return Ok(records);
+    }
+
+    let mut reader = csv::Reader::from_reader(bytes);
+    let headers = reader
+        .headers()
+        .context("Cost report is neither JSON nor CSV")?
+        .clone();
+    let mut records = Vec::new();
+    for record in reader.records() {
+        let record = record.context("Failed to parse cost report CSV")?;
+        records.push(
+            headers
+                .iter()
+                .zip(record.iter())
+                .map(|(h, v)| (h.to_string(), Value::String(v.to_string())))
+                .collect(),
+        );
+    }
+    Ok(records)
+}
+
+/// Collect every object carrying an amount, with the scalar fields of the
+/// objects it is nested in, so nested breakdowns (subscription > database >
+/// line item) flatten the same way as a flat FOCUS record list
+fn collect_line_items(
+    value: &Value,
+    context: &Map<String, Value>,
+    out: &mut Vec<Map<String, Value>>,
+) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                collect_line_items(item, context, out);
+            }
+        }
+        Value::Object(obj) => {
+            let mut merged = context.clone();
+            for (key, value) in obj {
+                if !value.is_array() && !value.is_object() {
+                    merged.insert(key.clone(), value.clone());
+                }
+            }
+            if field(obj, AMOUNT_FIELDS).is_some() {
+                out.push(merged);
+            } else {
+                for child in obj.values().filter(|v| v.is_array() || v.is_object()) {
+                    collect_line_items(child, &merged, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Sum line items per subscription, database, month, line item and currency
+fn flatten_records(records: &[Map<String, Value>]) -> Vec<CostRow> {
+    let mut totals: BTreeMap<(String, String, String, String, String), f64> = BTreeMap::new();
+    for record in records {
+        let text = |names: &[&str]| field(record, names).map(cell_text).unwrap_or_default();
+        let amount = field(record, AMOUNT_FIELDS).and_then(|v| match v {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        });
+        let Some(amount) = amount else {
+            continue;
+        };
+        let period = text(PERIOD_FIELDS).chars().take(7).collect();
+        let key = (
+            text(SUBSCRIPTION_FIELDS),
+            text(DATABASE_FIELDS),
+            period,
+            text(LINE_ITEM_FIELDS),
+            text(CURRENCY_FIELDS),
+        );
+        *totals.entry(key).or_default() += amount;
+    }
+
+    totals
+        .into_iter()
+        .map(
+            |((subscription_id, database_id, period, line_item, currency), amount)| CostRow {
+                subscription_id,
+                database_id,
+                period,
+                line_item,
+                // Drop float noise from summing, e.g. 0.1 + 0.2
+                amount: (amount * 1e6).round() / 1e6,
+                currency,
+            },
+        )
+        .collect()
+}
+
+/// First non-empty field among `names`, ignoring case
+fn field<'a>(record: &'a Map<String, Value>, names: &[&str]) -> Option<&'a Value> {
+    names.iter().find_map(|name| {
+        record
+            .iter()
+            .find(|(key, value)| key.eq_ignore_ascii_case(name) && !is_blank(value))
+            .map(|(_, value)| value)
+    })
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        _ => false,
+    }
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_focus_csv_splits_months_and_subscriptions() {
+        let report = "\
+SubAccountId,ResourceId,ChargePeriodStart,ChargeDescription,BilledCost,BillingCurrency
+123,db-1,2025-01-30T00:00:00Z,Shard hours,1.5,USD
+123,db-1,2025-01-31T00:00:00Z,Shard hours,1.25,USD
+123,db-1,2025-02-01T00:00:00Z,Shard hours,1.5,USD
+456,db-9,2025-01-31T00:00:00Z,Data transfer,0.1,USD
+456,db-9,2025-01-31T00:00:00Z,Data transfer,0.2,USD
+";
+        let rows = flatten_records(&parse_report(report.as_bytes()).unwrap());
+        let summary: Vec<(&str, &str, &str, f64)> = rows
+            .iter()
+            .map(|r| {
+                (
+                    r.subscription_id.as_str(),
+                    r.period.as_str(),
+                    r.line_item.as_str(),
+                    r.amount,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("123", "2025-01", "Shard hours", 2.75),
+                ("123", "2025-02", "Shard hours", 1.5),
+                ("456", "2025-01", "Data transfer", 0.3),
+            ]
+        );
+        assert!(rows.iter().all(|r| r.currency == "USD"));
+    }
+
+    #[test]
+    fn test_flatten_nested_json_carries_parent_ids() {
+        let report = json!({
+            "subscriptions": [{
+                "subscriptionId": 123,
+                "databases": [{
+                    "databaseId": 7,
+                    "lineItems": [
+                        {"period": "2025-03", "description": "Memory", "amount": "4.00", "currency": "EUR"},
+                        {"period": "2025-03", "description": "Backup", "amount": 0.5, "currency": "EUR"}
+                    ]
+                }]
+            }]
+        });
+        let rows = flatten_records(&parse_report(report.to_string().as_bytes()).unwrap());
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].subscription_id, "123");
+        assert_eq!(rows[0].database_id, "7");
+        assert_eq!(rows[0].line_item, "Backup");
+        assert_eq!(rows[1].amount, 4.0);
+    }
+
+    #[test]
+    fn test_flatten_report_csv_columns() {
+        let csv = flatten_report(
+            b"SubAccountId,BilledCost,BillingCurrency,ChargePeriodStart\n1,2,USD,2025-01-01\n",
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "subscription_id,database_id,period,line_item,amount,currency\n1,,2025-01,,2,USD\n"
+        );
+    }
+}
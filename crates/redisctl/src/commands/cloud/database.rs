@@ -199,9 +199,16 @@ pub async fn handle_database_command(
             )
             .await
         }
-        CloudDatabaseCommands::GetCertificate { id } => {
-            super::database_impl::get_certificate(conn_mgr, profile_name, id, output_format, query)
-                .await
+        CloudDatabaseCommands::GetCertificate { id, file } => {
+            super::database_impl::get_certificate(
+                conn_mgr,
+                profile_name,
+                id,
+                file.as_deref(),
+                output_format,
+                query,
+            )
+            .await
         }
         CloudDatabaseCommands::SlowLog { id, limit, offset } => {
             super::database_impl::get_slow_log(
@@ -458,24 +465,12 @@ async fn list_databases(
         Value::Array(all_databases)
     };
 
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            print_databases_table(&data)?;
-        }
-        OutputFormat::Json => {
-            print_output(data, crate::output::OutputFormat::Json, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
-        OutputFormat::Yaml => {
-            print_output(data, crate::output::OutputFormat::Yaml, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
+    if output_format.is_structured() {
+        print_output(data, output_format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+    } else {
+        print_databases_table(&data)?;
     }
 
     Ok(())
@@ -673,24 +668,12 @@ async fn get_database(
         response
     };
 
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            print_database_detail(&data)?;
-        }
-        OutputFormat::Json => {
-            print_output(data, crate::output::OutputFormat::Json, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
-        OutputFormat::Yaml => {
-            print_output(data, crate::output::OutputFormat::Yaml, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
+    if output_format.is_structured() {
+        print_output(data, output_format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+    } else {
+        print_database_detail(&data)?;
     }
 
     Ok(())
@@ -3,6 +3,7 @@
 use super::async_utils::{AsyncOperationArgs, handle_async_response};
 use super::utils::*;
 use crate::cli::OutputFormat;
+use crate::commands::request_preview::preview_cloud_request;
 use crate::connection::ConnectionManager;
 use crate::error::{RedisCtlError, Result as CliResult};
 use crate::output::print_output;
@@ -21,10 +22,10 @@ use tabled::{Table, Tabled, settings::Style};
 
 /// Helper to print non-table output
 fn print_json_or_yaml(data: Value, output_format: OutputFormat) -> CliResult<()> {
-    match output_format {
-        OutputFormat::Json => print_output(data, crate::output::OutputFormat::Json, None)?,
-        OutputFormat::Yaml => print_output(data, crate::output::OutputFormat::Yaml, None)?,
-        _ => print_output(data, crate::output::OutputFormat::Json, None)?,
+    if output_format.is_structured() {
+        print_output(data, output_format, None)?
+    } else {
+        print_output(data, crate::output::OutputFormat::Json, None)?
     }
     Ok(())
 }
@@ -98,10 +99,17 @@ pub async fn create_database(
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
+    // A zero --wait-timeout leaves nothing to wait for: submit and return the task
+    let async_ops = &AsyncOperationArgs {
+        wait: async_ops.wait && async_ops.wait_timeout > 0,
+        ..async_ops.clone()
+    };
+
     // Use Layer 2 workflow for simple cases with --wait
-    // Fall back to legacy for: --data, --dataset-size, advanced options, --dry-run
+    // Fall back to legacy for: --data, --dataset-size, advanced options, --dry-run, --print-request
     let use_layer2 = async_ops.wait
         && !dry_run
+        && !conn_mgr.print_request
         && data.is_none()
         && dataset_size.is_none()
         && eviction_policy == "volatile-lru"
@@ -197,8 +205,13 @@ async fn create_database_with_workflow(
             .build(),
     };
 
-    // Set up progress spinner
-    let pb = Arc::new(ProgressBar::new_spinner());
+    // Progress goes to stderr for humans; machine-readable output gets only the result
+    let output_format = crate::output::resolve_auto(output_format);
+    let pb = if matches!(output_format, OutputFormat::Table) {
+        Arc::new(ProgressBar::new_spinner())
+    } else {
+        Arc::new(ProgressBar::hidden())
+    };
     pb.set_style(
         ProgressStyle::default_spinner()
             .template("{spinner:.green} {msg} [{elapsed_precise}]")
@@ -238,31 +251,26 @@ async fn create_database_with_workflow(
         Some(progress_callback),
     )
     .await
-    .map_err(|e| RedisCtlError::ApiError {
-        message: e.to_string(),
-    })?;
+    .map_err(RedisCtlError::from)?;
 
     pb.finish_and_clear();
 
     // Output result
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!("Database created successfully");
-            println!("  ID: {}:{}", subscription_id, database.database_id);
-            println!("  Name: {}", database.name.as_deref().unwrap_or(""));
-            println!("  Status: {}", database.status.as_deref().unwrap_or(""));
-            if let Some(endpoint) = &database.public_endpoint {
-                println!("  Endpoint: {}", endpoint);
-            }
-        }
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let json_value = serde_json::to_value(&database)?;
-            let data = if let Some(q) = query {
-                apply_jmespath(&json_value, q)?
-            } else {
-                json_value
-            };
-            print_json_or_yaml(data, output_format)?;
+    if output_format.is_structured() {
+        let json_value = serde_json::to_value(&database)?;
+        let data = if let Some(q) = query {
+            apply_jmespath(&json_value, q)?
+        } else {
+            json_value
+        };
+        print_json_or_yaml(data, output_format)?;
+    } else {
+        println!("Database created successfully");
+        println!("  ID: {}:{}", subscription_id, database.database_id);
+        println!("  Name: {}", database.name.as_deref().unwrap_or(""));
+        println!("  Status: {}", database.status.as_deref().unwrap_or(""));
+        if let Some(endpoint) = &database.public_endpoint {
+            println!("  Endpoint: {}", endpoint);
         }
     }
 
@@ -367,11 +375,20 @@ async fn create_database_legacy(
         request_obj.insert("dryRun".to_string(), json!(true));
     }
 
+    let path = format!("/subscriptions/{}/databases", subscription_id);
+    if preview_cloud_request(
+        conn_mgr,
+        profile_name,
+        "POST",
+        &path,
+        &request,
+        output_format,
+    )? {
+        return Ok(());
+    }
+
     let response = client
-        .post_raw(
-            &format!("/subscriptions/{}/databases", subscription_id),
-            request,
-        )
+        .post_raw(&path, request)
         .await
         .context("Failed to create database")?;
 
@@ -546,29 +563,24 @@ async fn update_database_with_workflow(
         Some(progress_callback),
     )
     .await
-    .map_err(|e| RedisCtlError::ApiError {
-        message: e.to_string(),
-    })?;
+    .map_err(RedisCtlError::from)?;
 
     pb.finish_and_clear();
 
     // Output result
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!("Database updated successfully");
-            println!("  ID: {}:{}", subscription_id, database.database_id);
-            println!("  Name: {}", database.name.as_deref().unwrap_or(""));
-            println!("  Status: {}", database.status.as_deref().unwrap_or(""));
-        }
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let json_value = serde_json::to_value(&database)?;
-            let data = if let Some(q) = query {
-                apply_jmespath(&json_value, q)?
-            } else {
-                json_value
-            };
-            print_json_or_yaml(data, output_format)?;
-        }
+    if output_format.is_structured() {
+        let json_value = serde_json::to_value(&database)?;
+        let data = if let Some(q) = query {
+            apply_jmespath(&json_value, q)?
+        } else {
+            json_value
+        };
+        print_json_or_yaml(data, output_format)?;
+    } else {
+        println!("Database updated successfully");
+        println!("  ID: {}:{}", subscription_id, database.database_id);
+        println!("  Name: {}", database.name.as_deref().unwrap_or(""));
+        println!("  Status: {}", database.status.as_deref().unwrap_or(""));
     }
 
     Ok(())
@@ -645,14 +657,23 @@ async fn update_database_legacy(
         request_obj.insert("dryRun".to_string(), json!(true));
     }
 
+    let path = format!(
+        "/subscriptions/{}/databases/{}",
+        subscription_id, database_id
+    );
+    if preview_cloud_request(
+        conn_mgr,
+        profile_name,
+        "PUT",
+        &path,
+        &request,
+        output_format,
+    )? {
+        return Ok(());
+    }
+
     let response = client
-        .put_raw(
-            &format!(
-                "/subscriptions/{}/databases/{}",
-                subscription_id, database_id
-            ),
-            request,
-        )
+        .put_raw(&path, request)
         .await
         .context("Failed to update database")?;
 
@@ -783,28 +804,23 @@ async fn delete_database_with_workflow(
         Some(progress_callback),
     )
     .await
-    .map_err(|e| RedisCtlError::ApiError {
-        message: e.to_string(),
-    })?;
+    .map_err(RedisCtlError::from)?;
 
     pb.finish_and_clear();
 
     // Output result
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!(
-                "Database {}:{} deleted successfully",
-                subscription_id, database_id
-            );
-        }
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let result = json!({
-                "message": "Database deleted successfully",
-                "subscription_id": subscription_id,
-                "database_id": database_id
-            });
-            print_json_or_yaml(result, output_format)?;
-        }
+    if output_format.is_structured() {
+        let result = json!({
+            "message": "Database deleted successfully",
+            "subscription_id": subscription_id,
+            "database_id": database_id
+        });
+        print_json_or_yaml(result, output_format)?;
+    } else {
+        println!(
+            "Database {}:{} deleted successfully",
+            subscription_id, database_id
+        );
     }
 
     Ok(())
@@ -974,28 +990,23 @@ async fn backup_database_with_workflow(
         Some(progress_callback),
     )
     .await
-    .map_err(|e| RedisCtlError::ApiError {
-        message: e.to_string(),
-    })?;
+    .map_err(RedisCtlError::from)?;
 
     pb.finish_and_clear();
 
     // Output result
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!(
-                "Database {}:{} backup completed successfully",
-                subscription_id, database_id
-            );
-        }
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let result = json!({
-                "message": "Backup completed successfully",
-                "subscription_id": subscription_id,
-                "database_id": database_id
-            });
-            print_json_or_yaml(result, output_format)?;
-        }
+    if output_format.is_structured() {
+        let result = json!({
+            "message": "Backup completed successfully",
+            "subscription_id": subscription_id,
+            "database_id": database_id
+        });
+        print_json_or_yaml(result, output_format)?;
+    } else {
+        println!(
+            "Database {}:{} backup completed successfully",
+            subscription_id, database_id
+        );
     }
 
     Ok(())
@@ -1210,28 +1221,23 @@ async fn import_database_with_workflow(
         Some(progress_callback),
     )
     .await
-    .map_err(|e| RedisCtlError::ApiError {
-        message: e.to_string(),
-    })?;
+    .map_err(RedisCtlError::from)?;
 
     pb.finish_and_clear();
 
     // Output result
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!(
-                "Import into database {}:{} completed successfully",
-                subscription_id, database_id
-            );
-        }
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let result = json!({
-                "message": "Import completed successfully",
-                "subscription_id": subscription_id,
-                "database_id": database_id
-            });
-            print_json_or_yaml(result, output_format)?;
-        }
+    if output_format.is_structured() {
+        let result = json!({
+            "message": "Import completed successfully",
+            "subscription_id": subscription_id,
+            "database_id": database_id
+        });
+        print_json_or_yaml(result, output_format)?;
+    } else {
+        println!(
+            "Import into database {}:{} completed successfully",
+            subscription_id, database_id
+        );
     }
 
     Ok(())
@@ -1349,11 +1355,22 @@ async fn import_database_legacy(
     .await
 }
 
-/// Get database certificate
+/// PEM text of a certificate response; the API names the field
+/// `publicCertificatePEMString`, older responses `certificate`
+fn certificate_pem(response: &Value) -> Option<&str> {
+    response
+        .get("publicCertificatePEMString")
+        .or_else(|| response.get("certificate"))
+        .and_then(Value::as_str)
+        .filter(|pem| !pem.trim().is_empty())
+}
+
+/// Get database certificate, optionally saving the PEM to `file`
 pub async fn get_certificate(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
     id: &str,
+    file: Option<&str>,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
@@ -1368,6 +1385,34 @@ pub async fn get_certificate(
         .await
         .context("Failed to get certificate")?;
 
+    if let Some(path) = file {
+        let pem = certificate_pem(&response).ok_or_else(|| RedisCtlError::ApiError {
+            message: format!("No TLS certificate available for database {}", id),
+        })?;
+        let mut contents = pem.to_string();
+        if !contents.ends_with('\n') {
+            contents.push('\n');
+        }
+        std::fs::write(path, &contents).map_err(|e| RedisCtlError::FileError {
+            path: path.to_string(),
+            message: e.to_string(),
+        })?;
+
+        match output_format {
+            OutputFormat::Json | OutputFormat::Yaml => {
+                let result = json!({
+                    "success": true,
+                    "database_id": id,
+                    "output_file": path,
+                    "bytes_written": contents.len(),
+                });
+                print_json_or_yaml(result, output_format)?;
+            }
+            _ => println!("Certificate for database {} written to '{}'", id, path),
+        }
+        return Ok(());
+    }
+
     let result = if let Some(q) = query {
         apply_jmespath(&response, q)?
     } else {
@@ -1376,8 +1421,8 @@ pub async fn get_certificate(
 
     match output_format {
         OutputFormat::Table => {
-            if let Some(cert) = result.get("certificate") {
-                println!("{}", cert.as_str().unwrap_or(""));
+            if let Some(cert) = certificate_pem(&result) {
+                println!("{}", cert);
             } else {
                 println!("No certificate available");
             }
@@ -6,7 +6,7 @@ use anyhow::Context;
 use serde_json::Value;
 use tabled::{Table, Tabled, settings::Style};
 
-use crate::cli::{CloudSubscriptionCommands, OutputFormat};
+use crate::cli::{CloudMaintenanceWindowCommands, CloudSubscriptionCommands, OutputFormat};
 use crate::connection::ConnectionManager;
 use crate::error::Result as CliResult;
 
@@ -101,9 +101,26 @@ pub async fn handle_subscription_command(
             )
             .await
         }
+        CloudSubscriptionCommands::Wait {
+            id,
+            timeout,
+            interval,
+        } => {
+            subscription_impl::wait_for_subscription(
+                conn_mgr,
+                profile_name,
+                *id,
+                *timeout,
+                *interval,
+            )
+            .await?;
+            get_subscription(conn_mgr, profile_name, *id, output_format, query).await
+        }
         CloudSubscriptionCommands::Delete {
             id,
             force,
+            delete_databases,
+            yes,
             dry_run,
             async_ops,
         } => {
@@ -112,6 +129,8 @@ pub async fn handle_subscription_command(
                 profile_name,
                 *id,
                 *force,
+                *delete_databases,
+                *yes,
                 *dry_run,
                 async_ops,
                 output_format,
@@ -182,6 +201,46 @@ pub async fn handle_subscription_command(
             )
             .await
         }
+        CloudSubscriptionCommands::MaintenanceWindow(window_cmd) => match window_cmd {
+            CloudMaintenanceWindowCommands::Show { id } => {
+                subscription_impl::show_maintenance_window(
+                    conn_mgr,
+                    profile_name,
+                    *id,
+                    output_format,
+                    query,
+                )
+                .await
+            }
+            CloudMaintenanceWindowCommands::Set {
+                id,
+                days,
+                start,
+                duration,
+                automatic,
+                async_ops,
+            } => {
+                let window = if *automatic {
+                    None
+                } else {
+                    Some(subscription_impl::MaintenanceWindowArgs {
+                        days,
+                        start: start.as_deref().unwrap_or_default(),
+                        duration: duration.as_deref().unwrap_or_default(),
+                    })
+                };
+                subscription_impl::set_maintenance_window(
+                    conn_mgr,
+                    profile_name,
+                    *id,
+                    window,
+                    async_ops,
+                    output_format,
+                    query,
+                )
+                .await
+            }
+        },
         CloudSubscriptionCommands::ListAaRegions { id } => {
             subscription_impl::list_aa_regions(conn_mgr, profile_name, *id, output_format, query)
                 .await
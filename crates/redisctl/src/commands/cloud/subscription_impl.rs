@@ -3,11 +3,12 @@
 use super::async_utils::{AsyncOperationArgs, handle_async_response};
 use super::utils::*;
 use crate::cli::OutputFormat;
+use crate::commands::request_preview::preview_cloud_request;
 use crate::connection::ConnectionManager;
 use crate::error::{RedisCtlError, Result as CliResult};
 use crate::output::print_output;
 use anyhow::Context;
-use redisctl_core::cloud::delete_subscription_and_wait;
+use redisctl_core::cloud::{delete_database_and_wait, delete_subscription_and_wait};
 use redisctl_core::progress::ProgressEvent;
 use serde_json::{Value, json};
 use std::time::Duration;
@@ -15,10 +16,10 @@ use tabled::{Table, Tabled, settings::Style};
 
 /// Helper to print non-table output
 fn print_json_or_yaml(data: Value, output_format: OutputFormat) -> CliResult<()> {
-    match output_format {
-        OutputFormat::Json => print_output(data, crate::output::OutputFormat::Json, None)?,
-        OutputFormat::Yaml => print_output(data, crate::output::OutputFormat::Yaml, None)?,
-        _ => print_output(data, crate::output::OutputFormat::Json, None)?,
+    if output_format.is_structured() {
+        print_output(data, output_format, None)?
+    } else {
+        print_output(data, crate::output::OutputFormat::Json, None)?
     }
     Ok(())
 }
@@ -131,6 +132,17 @@ pub async fn create_subscription(
         });
     }
 
+    if preview_cloud_request(
+        conn_mgr,
+        profile_name,
+        "POST",
+        "/subscriptions",
+        &request,
+        output_format,
+    )? {
+        return Ok(());
+    }
+
     let response = client
         .post_raw("/subscriptions", request)
         .await
@@ -198,8 +210,20 @@ pub async fn update_subscription(
         request_obj.insert("dryRun".to_string(), serde_json::json!(true));
     }
 
+    let path = format!("/subscriptions/{}", id);
+    if preview_cloud_request(
+        conn_mgr,
+        profile_name,
+        "PUT",
+        &path,
+        &request,
+        output_format,
+    )? {
+        return Ok(());
+    }
+
     let response = client
-        .put_raw(&format!("/subscriptions/{}", id), request)
+        .put_raw(&path, request)
         .await
         .context("Failed to update subscription")?;
 
@@ -216,18 +240,39 @@ pub async fn update_subscription(
 }
 
 /// Delete a subscription
+///
+/// With `delete_databases`, every database in the subscription is deleted
+/// first (waiting for each deletion task) so the subscription itself can then
+/// be removed; `yes` skips the confirmation for those databases. `force` only
+/// skips the confirmation for the subscription.
 #[allow(clippy::too_many_arguments)]
 pub async fn delete_subscription(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
     id: u32,
     force: bool,
+    delete_databases: bool,
+    yes: bool,
     dry_run: bool,
     async_ops: &AsyncOperationArgs,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
+    let databases = if delete_databases {
+        let client = conn_mgr.create_cloud_client(profile_name).await?;
+        let response = client
+            .get_raw(&format!("/subscriptions/{}/databases", id))
+            .await
+            .context("Failed to list subscription databases")?;
+        subscription_databases(&response)
+    } else {
+        Vec::new()
+    };
+
     if dry_run {
+        for (db_id, name) in &databases {
+            eprintln!("Would delete database {} ({}).", db_id, name);
+        }
         eprintln!("Would delete subscription {}.", id);
         eprintln!();
         eprintln!("No changes were made.");
@@ -235,20 +280,34 @@ pub async fn delete_subscription(
     }
 
     // Confirmation prompt unless --force is used
-    if !force {
-        use dialoguer::Confirm;
-        let confirm = Confirm::new()
-            .with_prompt(format!("Are you sure you want to delete subscription {}? This will delete all databases in the subscription!", id))
-            .default(false)
-            .interact()
-            .map_err(|e| RedisCtlError::InvalidInput {
-                message: format!("Failed to read confirmation: {}", e),
-            })?;
+    if !force && !confirm_deletion(&format!("delete subscription {}", id), "--force")? {
+        println!("Subscription deletion cancelled");
+        return Ok(());
+    }
 
-        if !confirm {
+    if !databases.is_empty() {
+        eprintln!(
+            "Subscription {} contains {} database(s):",
+            id,
+            databases.len()
+        );
+        for (db_id, name) in &databases {
+            eprintln!("  {} ({})", db_id, name);
+        }
+        if !yes
+            && !confirm_deletion(
+                &format!(
+                    "delete these {} database(s) and subscription {}",
+                    databases.len(),
+                    id
+                ),
+                "--yes",
+            )?
+        {
             println!("Subscription deletion cancelled");
             return Ok(());
         }
+        delete_subscription_databases(conn_mgr, profile_name, id, &databases, async_ops).await?;
     }
 
     // Use Layer 2 workflow when --wait is specified
@@ -261,6 +320,95 @@ pub async fn delete_subscription(
     }
 }
 
+/// Ask the user to confirm a deletion
+///
+/// Without a terminal to ask on, this fails and names the flag that confirms
+/// up front, instead of quietly cancelling.
+fn confirm_deletion(action: &str, confirm_flag: &str) -> CliResult<bool> {
+    use std::io::IsTerminal;
+    if !std::io::stdin().is_terminal() {
+        return Err(RedisCtlError::InvalidInput {
+            message: format!(
+                "Refusing to {} without confirmation: stdin is not a terminal. Pass {} to confirm.",
+                action, confirm_flag
+            ),
+        });
+    }
+    dialoguer::Confirm::new()
+        .with_prompt(format!("Are you sure you want to {}?", action))
+        .default(false)
+        .interact()
+        .map_err(|e| RedisCtlError::InvalidInput {
+            message: format!("Failed to read confirmation: {}", e),
+        })
+}
+
+/// Extract `(databaseId, name)` pairs from a subscription databases response
+///
+/// Accepts both a bare array and the `{"subscription": [{"databases": [...]}]}`
+/// envelope returned by the API.
+fn subscription_databases(response: &Value) -> Vec<(u64, String)> {
+    let databases = match response {
+        Value::Array(dbs) => Some(dbs),
+        _ => response
+            .get("subscription")
+            .and_then(|s| s.get(0))
+            .and_then(|s| s.get("databases"))
+            .and_then(|d| d.as_array()),
+    };
+
+    databases
+        .into_iter()
+        .flatten()
+        .filter_map(|db| {
+            let db_id = db.get("databaseId").and_then(|v| v.as_u64())?;
+            Some((db_id, extract_field(db, "name", "-")))
+        })
+        .collect()
+}
+
+/// Delete each database in a subscription, waiting for every deletion task
+async fn delete_subscription_databases(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    subscription_id: u32,
+    databases: &[(u64, String)],
+    async_ops: &AsyncOperationArgs,
+) -> CliResult<()> {
+    let client = conn_mgr.create_cloud_client(profile_name).await?;
+
+    for (db_id, name) in databases {
+        eprintln!("Deleting database {} ({})...", db_id, name);
+
+        let progress_callback: Option<Box<dyn Fn(ProgressEvent) + Send + Sync>> =
+            Some(Box::new(|event| {
+                if let ProgressEvent::Polling {
+                    status, elapsed, ..
+                } = event
+                {
+                    eprintln!("Status: {} ({:.0}s elapsed)", status, elapsed.as_secs());
+                }
+            }));
+
+        delete_database_and_wait(
+            &client,
+            subscription_id as i32,
+            *db_id as i32,
+            Duration::from_secs(async_ops.wait_timeout),
+            progress_callback,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to delete database {} in subscription {}",
+                db_id, subscription_id
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
 /// Delete subscription using Layer 2 workflow (with --wait)
 async fn delete_subscription_with_workflow(
     conn_mgr: &ConnectionManager,
@@ -293,17 +441,14 @@ async fn delete_subscription_with_workflow(
     .context("Failed to delete subscription")?;
 
     // Output result
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!("Subscription {} deleted successfully", id);
-        }
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let result = json!({
-                "subscription_id": id,
-                "status": "deleted"
-            });
-            print_json_or_yaml(result, output_format)?;
-        }
+    if output_format.is_structured() {
+        let result = json!({
+            "subscription_id": id,
+            "status": "deleted"
+        });
+        print_json_or_yaml(result, output_format)?;
+    } else {
+        println!("Subscription {} deleted successfully", id);
     }
 
     Ok(())
@@ -337,6 +482,103 @@ async fn delete_subscription_legacy(
     .await
 }
 
+/// Task states after which a subscription task will not change again
+const TERMINAL_TASK_STATES: &[&str] = &[
+    "processing-completed",
+    "processing-error",
+    "completed",
+    "failed",
+    "error",
+    "cancelled",
+];
+
+/// Block until a subscription finishes provisioning
+///
+/// Polls the subscription's pending task when the tasks API still lists one,
+/// so a failed provisioning surfaces the task's error. Without a pending task
+/// (already finished, or created long enough ago to drop off the list) the
+/// subscription status is polled until it reads `active`.
+pub async fn wait_for_subscription(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    id: u32,
+    timeout_secs: u64,
+    interval_secs: u64,
+) -> CliResult<()> {
+    let client = conn_mgr.create_cloud_client(profile_name).await?;
+    let timeout = Duration::from_secs(timeout_secs);
+    let interval = Duration::from_secs(interval_secs);
+
+    let tasks = client
+        .get_raw("/tasks")
+        .await
+        .context("Failed to fetch tasks")?;
+
+    if let Some(task_id) = pending_subscription_task(&tasks, id) {
+        let progress_callback: Option<Box<dyn Fn(ProgressEvent) + Send + Sync>> =
+            Some(Box::new(|event| match event {
+                ProgressEvent::Started { task_id } => {
+                    eprintln!("Waiting for task {}", task_id);
+                }
+                ProgressEvent::Polling {
+                    status, elapsed, ..
+                } => {
+                    eprintln!("Status: {} ({:.0}s elapsed)", status, elapsed.as_secs());
+                }
+                _ => {}
+            }));
+
+        redisctl_core::poll_task(&client, &task_id, timeout, interval, progress_callback).await?;
+        return Ok(());
+    }
+
+    let on_pending: redisctl_core::StatusCallback = Box::new(|status, elapsed| {
+        eprintln!("Status: {} ({:.0}s elapsed)", status, elapsed.as_secs());
+    });
+    redisctl_core::poll_until(
+        timeout,
+        interval,
+        || async {
+            let subscription = client.get_raw(&format!("/subscriptions/{}", id)).await?;
+            let status = subscription["status"].as_str().unwrap_or("unknown");
+            Ok(match status {
+                "active" => redisctl_core::PollStatus::Ready(()),
+                "error" => redisctl_core::PollStatus::Failed(format!(
+                    "Subscription {} is in error state",
+                    id
+                )),
+                other => redisctl_core::PollStatus::Pending(other.to_string()),
+            })
+        },
+        Some(on_pending),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Find the unfinished task working on subscription `id` in a `/tasks` listing
+fn pending_subscription_task(tasks: &Value, id: u32) -> Option<String> {
+    let tasks = tasks
+        .as_array()
+        .or_else(|| tasks.get("tasks").and_then(Value::as_array))?;
+
+    tasks
+        .iter()
+        .filter(|task| task["response"]["resourceId"].as_u64() == Some(u64::from(id)))
+        // Database tasks carry database ids, which can collide with subscription ids
+        .filter(|task| {
+            task["commandType"]
+                .as_str()
+                .is_none_or(|c| c.to_lowercase().contains("subscription"))
+        })
+        .filter(|task| {
+            let status = task["status"].as_str().unwrap_or_default().to_lowercase();
+            !TERMINAL_TASK_STATES.contains(&status.as_str())
+        })
+        .find_map(|task| task["taskId"].as_str().map(str::to_string))
+}
+
 /// Redis version info for table display
 #[derive(Tabled)]
 struct RedisVersionRow {
@@ -755,6 +997,221 @@ fn parse_hour(time: &str) -> CliResult<i32> {
         })
 }
 
+/// Manual maintenance window as given on the command line
+pub struct MaintenanceWindowArgs<'a> {
+    pub days: &'a [String],
+    pub start: &'a str,
+    pub duration: &'a str,
+}
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Maintenance window for table display
+#[derive(Tabled)]
+struct ScheduledWindowRow {
+    #[tabled(rename = "DAYS")]
+    days: String,
+    #[tabled(rename = "START (UTC)")]
+    start: String,
+    #[tabled(rename = "DURATION")]
+    duration: String,
+}
+
+/// Show the maintenance window configuration of a subscription
+pub async fn show_maintenance_window(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    id: u32,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let client = conn_mgr.create_cloud_client(profile_name).await?;
+
+    let response = client
+        .get_raw(&format!("/subscriptions/{}/maintenance-windows", id))
+        .await
+        .context("Failed to get maintenance windows")?;
+
+    if !matches!(output_format, OutputFormat::Auto | OutputFormat::Table) {
+        let result = if let Some(q) = query {
+            apply_jmespath(&response, q)?
+        } else {
+            response
+        };
+        return print_json_or_yaml(result, output_format);
+    }
+
+    let mode = extract_field(&response, "mode", "automatic");
+    let rows: Vec<ScheduledWindowRow> = response["windows"]
+        .as_array()
+        .map(|windows| {
+            windows
+                .iter()
+                .map(|w| ScheduledWindowRow {
+                    days: w["days"]
+                        .as_array()
+                        .map(|days| {
+                            days.iter()
+                                .filter_map(Value::as_str)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        })
+                        .unwrap_or_default(),
+                    start: w["startHour"]
+                        .as_u64()
+                        .map(|h| format!("{:02}:00", h))
+                        .unwrap_or_default(),
+                    duration: w["durationInHours"]
+                        .as_u64()
+                        .map(|h| format!("{}h", h))
+                        .unwrap_or_default(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    println!("Subscription {} maintenance mode: {}", id, mode);
+    if rows.is_empty() {
+        if mode == "manual" {
+            println!("No maintenance windows configured");
+        } else {
+            println!("Redis Cloud schedules maintenance automatically");
+        }
+    } else {
+        let mut table = Table::new(rows);
+        table.with(Style::blank());
+        println!("{}", table);
+    }
+
+    Ok(())
+}
+
+/// Set a manual maintenance window, or switch to automatic scheduling when
+/// `window` is `None`
+pub async fn set_maintenance_window(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    id: u32,
+    window: Option<MaintenanceWindowArgs<'_>>,
+    async_ops: &AsyncOperationArgs,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    // Validate everything before creating a client
+    let request = match window {
+        Some(window) => json!({
+            "mode": "manual",
+            "windows": [{
+                "days": parse_weekdays(window.days)?,
+                "startHour": parse_window_start(window.start)?,
+                "durationInHours": parse_window_duration(window.duration)?,
+            }],
+        }),
+        None => json!({ "mode": "automatic" }),
+    };
+
+    let client = conn_mgr.create_cloud_client(profile_name).await?;
+    let response = client
+        .put_raw(
+            &format!("/subscriptions/{}/maintenance-windows", id),
+            request,
+        )
+        .await
+        .context("Failed to update maintenance windows")?;
+
+    handle_async_response(
+        conn_mgr,
+        profile_name,
+        response,
+        async_ops,
+        output_format,
+        query,
+        "Maintenance window updated successfully",
+    )
+    .await
+}
+
+/// Full day names for `--days`, accepting names or three-letter abbreviations
+fn parse_weekdays(days: &[String]) -> CliResult<Vec<&'static str>> {
+    let mut parsed = Vec::new();
+    for day in days.iter().map(|d| d.trim().to_lowercase()) {
+        let name = WEEKDAYS
+            .iter()
+            .find(|name| {
+                let name = name.to_lowercase();
+                day == name || (day.len() == 3 && name.starts_with(&day))
+            })
+            .ok_or_else(|| RedisCtlError::InvalidInput {
+                message: format!(
+                    "Invalid day '{}'. Use a day name or abbreviation (sun, mon, tue, wed, thu, fri, sat)",
+                    day
+                ),
+            })?;
+        if !parsed.contains(name) {
+            parsed.push(*name);
+        }
+    }
+    if parsed.is_empty() {
+        return Err(RedisCtlError::InvalidInput {
+            message: "--days needs at least one day".to_string(),
+        });
+    }
+    Ok(parsed)
+}
+
+/// Start hour from `HH:MM` (or `HH`); windows must start on the hour
+fn parse_window_start(start: &str) -> CliResult<u32> {
+    let invalid = || RedisCtlError::InvalidInput {
+        message: format!(
+            "Invalid start time '{}'. Expected HH:MM in UTC on the hour (e.g. 02:00)",
+            start
+        ),
+    };
+    let (hour, minute) = start.trim().split_once(':').unwrap_or((start.trim(), "00"));
+    let hour: u32 = hour.parse().map_err(|_| invalid())?;
+    let minute: u32 = minute.parse().map_err(|_| invalid())?;
+    if hour > 23 || minute != 0 {
+        return Err(invalid());
+    }
+    Ok(hour)
+}
+
+/// Window length in whole hours from `4h`, `4` or `240m`
+fn parse_window_duration(duration: &str) -> CliResult<u32> {
+    let invalid = || RedisCtlError::InvalidInput {
+        message: format!(
+            "Invalid duration '{}'. Expected whole hours between 1h and 24h (e.g. 4h)",
+            duration
+        ),
+    };
+    let duration = duration.trim().to_lowercase();
+    let hours = if let Some(minutes) = duration.strip_suffix('m') {
+        let minutes: u32 = minutes.parse().map_err(|_| invalid())?;
+        if minutes % 60 != 0 {
+            return Err(invalid());
+        }
+        minutes / 60
+    } else {
+        duration
+            .strip_suffix('h')
+            .unwrap_or(&duration)
+            .parse()
+            .map_err(|_| invalid())?
+    };
+    if !(1..=24).contains(&hours) {
+        return Err(invalid());
+    }
+    Ok(hours)
+}
+
 /// Active-Active region for table display
 #[derive(Tabled)]
 struct AaRegionRow {
@@ -971,3 +1428,36 @@ pub async fn delete_aa_regions(
     )
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_weekdays() {
+        let days = ["sun".to_string(), "Monday".to_string(), "SUN".to_string()];
+        assert_eq!(parse_weekdays(&days).unwrap(), vec!["Sunday", "Monday"]);
+        assert!(parse_weekdays(&["sunday-ish".to_string()]).is_err());
+        assert!(parse_weekdays(&["su".to_string()]).is_err());
+        assert!(parse_weekdays(&[]).is_err());
+    }
+
+    #[test]
+    fn test_parse_window_start() {
+        assert_eq!(parse_window_start("02:00").unwrap(), 2);
+        assert_eq!(parse_window_start("23").unwrap(), 23);
+        assert!(parse_window_start("02:30").is_err());
+        assert!(parse_window_start("24:00").is_err());
+        assert!(parse_window_start("two").is_err());
+    }
+
+    #[test]
+    fn test_parse_window_duration() {
+        assert_eq!(parse_window_duration("4h").unwrap(), 4);
+        assert_eq!(parse_window_duration("6").unwrap(), 6);
+        assert_eq!(parse_window_duration("240m").unwrap(), 4);
+        assert!(parse_window_duration("90m").is_err());
+        assert!(parse_window_duration("0h").is_err());
+        assert!(parse_window_duration("25h").is_err());
+    }
+}
@@ -3,6 +3,7 @@
 #![allow(dead_code)]
 
 use crate::cli::{CloudTaskCommands, OutputFormat};
+use crate::commands::api::StreamLayout;
 use crate::connection::ConnectionManager;
 use crate::error::{RedisCtlError, Result as CliResult};
 use crate::output::print_output;
@@ -10,6 +11,8 @@ use anyhow::Context;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
 use redis_cloud::CloudClient;
+use redisctl_core::DeploymentType;
+use reqwest::header::HeaderMap;
 use serde_json::Value;
 use std::time::Duration;
 use tokio::time::{Instant, sleep};
@@ -23,7 +26,17 @@ pub async fn handle_task_command(
     query: Option<&str>,
 ) -> CliResult<()> {
     match command {
-        CloudTaskCommands::List => list_tasks(conn_mgr, profile_name, output_format, query).await,
+        CloudTaskCommands::List {
+            active,
+            watch,
+            interval,
+        } => {
+            if *watch {
+                watch_tasks(conn_mgr, profile_name, *interval, output_format, query).await
+            } else {
+                list_tasks(conn_mgr, profile_name, *active, output_format, query).await
+            }
+        }
         CloudTaskCommands::Get { id } => {
             get_task(conn_mgr, profile_name, id, output_format, query).await
         }
@@ -64,17 +77,30 @@ pub async fn handle_task_command(
 async fn list_tasks(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
+    active_only: bool,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
+    // JSON Lines are written as the task list arrives instead of collecting it
+    if crate::output::streams_jsonl(output_format, query) {
+        let keep: Option<fn(&Value) -> bool> = active_only.then_some(is_active_task);
+        return crate::commands::api::stream_get(
+            conn_mgr,
+            profile_name,
+            DeploymentType::Cloud,
+            "/tasks",
+            HeaderMap::new(),
+            StreamLayout::Lines,
+            keep,
+        )
+        .await;
+    }
+
     let client = conn_mgr.create_cloud_client(profile_name).await?;
-    let tasks = client
-        .get_raw("/tasks")
-        .await
-        .with_context(|| "Failed to fetch tasks")
-        .map_err(|e| RedisCtlError::ApiError {
-            message: e.to_string(),
-        })?;
+    let mut tasks = fetch_tasks(&client).await?;
+    if active_only {
+        tasks = filter_active_tasks(tasks);
+    }
 
     // Apply JMESPath query if provided
     let data = if let Some(q) = query {
@@ -83,32 +109,104 @@ async fn list_tasks(
         tasks
     };
 
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            print_tasks_table(&data)?;
-        }
-        OutputFormat::Json => {
-            print_output(data, crate::output::OutputFormat::Json, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
+    if output_format.is_structured() {
+        print_output(data, output_format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+    } else {
+        print_tasks_table(&data)?;
+    }
+
+    Ok(())
+}
+
+/// Refresh the list of active tasks until none remain
+async fn watch_tasks(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    interval_secs: u64,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let client = conn_mgr.create_cloud_client(profile_name).await?;
+    let interval = Duration::from_secs(interval_secs);
+
+    loop {
+        let active = filter_active_tasks(fetch_tasks(&client).await?);
+        let remaining = active.as_array().map_or(0, |a| a.len());
+
+        let data = if let Some(q) = query {
+            super::utils::apply_jmespath(&active, q)?
+        } else {
+            active
+        };
+
+        if output_format.is_structured() {
+            print_output(data, output_format, None)?;
+        } else {
+            // Clear screen and redraw from the top
+            print!("\x1B[2J\x1B[H");
+            println!(
+                "[{}] {} active task(s), refreshing every {}s (Ctrl+C to stop)\n",
+                chrono::Local::now().format("%H:%M:%S"),
+                remaining,
+                interval_secs
+            );
+            if let Some(table) = format_tasks_table(&data) {
+                println!("{}", table);
+            }
         }
-        OutputFormat::Yaml => {
-            print_output(data, crate::output::OutputFormat::Yaml, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
+
+        if remaining == 0 {
+            // Keep structured output on stdout parseable
+            if output_format.is_structured() {
+                eprintln!("All active tasks have completed");
+            } else {
+                println!("All active tasks have completed");
+            }
+            return Ok(());
         }
+
+        sleep(interval).await;
     }
+}
 
-    Ok(())
+/// Fetch all tasks for the account
+async fn fetch_tasks(client: &CloudClient) -> CliResult<Value> {
+    client
+        .get_raw("/tasks")
+        .await
+        .with_context(|| "Failed to fetch tasks")
+        .map_err(RedisCtlError::from)
+}
+
+/// Keep only tasks that are still pending or processing
+fn filter_active_tasks(tasks: Value) -> Value {
+    match tasks {
+        Value::Array(arr) => Value::Array(arr.into_iter().filter(is_active_task).collect()),
+        other => other,
+    }
+}
+
+/// Whether a task is still pending or processing
+fn is_active_task(task: &Value) -> bool {
+    is_active_state(&get_task_state(task))
 }
 
 /// Print tasks in table format
 fn print_tasks_table(tasks: &Value) -> CliResult<()> {
     use super::utils::output_with_pager;
+
+    match format_tasks_table(tasks) {
+        Some(table) => output_with_pager(&table),
+        None => println!("No tasks found"),
+    }
+
+    Ok(())
+}
+
+/// Render tasks as a table, or `None` when there are no tasks
+fn format_tasks_table(tasks: &Value) -> Option<String> {
     use tabled::{Table, Tabled, settings::Style};
 
     #[derive(Tabled)]
@@ -125,18 +223,7 @@ fn print_tasks_table(tasks: &Value) -> CliResult<()> {
         description: String,
     }
 
-    let tasks_array = match tasks.as_array() {
-        Some(arr) => arr,
-        None => {
-            println!("No tasks found");
-            return Ok(());
-        }
-    };
-
-    if tasks_array.is_empty() {
-        println!("No tasks found");
-        return Ok(());
-    }
+    let tasks_array = tasks.as_array().filter(|arr| !arr.is_empty())?;
 
     let rows: Vec<TaskRow> = tasks_array
         .iter()
@@ -178,9 +265,7 @@ fn print_tasks_table(tasks: &Value) -> CliResult<()> {
 
     let mut table = Table::new(&rows);
     table.with(Style::blank());
-    output_with_pager(&table.to_string());
-
-    Ok(())
+    Some(table.to_string())
 }
 
 /// Get task status and details
@@ -201,24 +286,12 @@ async fn get_task(
         task
     };
 
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            print_task_details(&data)?;
-        }
-        OutputFormat::Json => {
-            print_output(data, crate::output::OutputFormat::Json, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
-        OutputFormat::Yaml => {
-            print_output(data, crate::output::OutputFormat::Yaml, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
+    if output_format.is_structured() {
+        print_output(data, output_format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+    } else {
+        print_task_details(&data)?;
     }
 
     Ok(())
@@ -256,16 +329,10 @@ async fn wait_for_task(
         if is_terminal_state(&state) {
             pb.finish_with_message(format!("Task {}: {}", task_id, format_task_state(&state)));
 
-            match output_format {
-                OutputFormat::Auto | OutputFormat::Table => {
-                    print_task_details(&task)?;
-                }
-                OutputFormat::Json => {
-                    print_output(task, crate::output::OutputFormat::Json, None)?;
-                }
-                OutputFormat::Yaml => {
-                    print_output(task, crate::output::OutputFormat::Yaml, None)?;
-                }
+            if output_format.is_structured() {
+                print_output(task, output_format, None)?;
+            } else {
+                print_task_details(&task)?;
             }
 
             return Ok(());
@@ -310,7 +377,7 @@ async fn poll_task(
         let timestamp = chrono::Local::now().format("%H:%M:%S");
 
         // Clear screen for table output in auto mode
-        if matches!(output_format, OutputFormat::Auto | OutputFormat::Table) {
+        if !output_format.is_structured() {
             // Move cursor up to overwrite previous output
             if poll_count > 0 {
                 print!("\x1B[2K"); // Clear line
@@ -331,15 +398,7 @@ async fn poll_task(
             }
         } else {
             // For JSON/YAML, print the full output each time
-            match output_format {
-                OutputFormat::Json => {
-                    print_output(task.clone(), crate::output::OutputFormat::Json, None)?;
-                }
-                OutputFormat::Yaml => {
-                    print_output(task.clone(), crate::output::OutputFormat::Yaml, None)?;
-                }
-                _ => {} // Auto/Table already handled above
-            }
+            print_output(task.clone(), output_format, None)?;
         }
 
         if is_terminal_state(&state) {
@@ -365,9 +424,7 @@ async fn fetch_task(client: &CloudClient, task_id: &str) -> CliResult<Value> {
         .get_raw(&format!("/tasks/{}", task_id))
         .await
         .with_context(|| format!("Failed to fetch task {}", task_id))
-        .map_err(|e| RedisCtlError::ApiError {
-            message: e.to_string(),
-        })
+        .map_err(RedisCtlError::from)
 }
 
 /// Extract task state from response
@@ -387,6 +444,20 @@ fn is_terminal_state(state: &str) -> bool {
     )
 }
 
+/// Check if task is still pending or in progress
+fn is_active_state(state: &str) -> bool {
+    matches!(
+        state.to_lowercase().as_str(),
+        "received"
+            | "pending"
+            | "initialized"
+            | "processing"
+            | "processing-in-progress"
+            | "running"
+            | "in_progress"
+    )
+}
+
 /// Format task state with color
 fn format_task_state(state: &str) -> String {
     match state.to_lowercase().as_str() {
@@ -89,24 +89,12 @@ async fn list_users(
     };
 
     // Format output based on requested format
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            print_users_table(&data)?;
-        }
-        OutputFormat::Json => {
-            print_output(data, crate::output::OutputFormat::Json, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
-        OutputFormat::Yaml => {
-            print_output(data, crate::output::OutputFormat::Yaml, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
+    if output_format.is_structured() {
+        print_output(data, output_format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+    } else {
+        print_users_table(&data)?;
     }
 
     Ok(())
@@ -285,24 +273,12 @@ async fn get_user(
         response
     };
 
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            print_user_detail(&data)?;
-        }
-        OutputFormat::Json => {
-            print_output(data, crate::output::OutputFormat::Json, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
-        OutputFormat::Yaml => {
-            print_output(data, crate::output::OutputFormat::Yaml, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
+    if output_format.is_structured() {
+        print_output(data, output_format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+    } else {
+        print_user_detail(&data)?;
     }
 
     Ok(())
@@ -521,25 +497,13 @@ async fn update_user(
         response
     };
 
-    match output_format {
-        OutputFormat::Auto | OutputFormat::Table => {
-            println!("User {} updated successfully", user_id);
-            print_user_detail(&data)?;
-        }
-        OutputFormat::Json => {
-            print_output(data, crate::output::OutputFormat::Json, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
-        OutputFormat::Yaml => {
-            print_output(data, crate::output::OutputFormat::Yaml, None).map_err(|e| {
-                RedisCtlError::OutputError {
-                    message: e.to_string(),
-                }
-            })?;
-        }
+    if output_format.is_structured() {
+        print_output(data, output_format, None).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+    } else {
+        println!("User {} updated successfully", user_id);
+        print_user_detail(&data)?;
     }
 
     Ok(())
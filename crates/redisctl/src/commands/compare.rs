@@ -0,0 +1,521 @@
+//! Cloud vs Enterprise database capability comparison
+//!
+//! Normalizes a Redis Cloud database and a Redis Enterprise database (either
+//! fetched live or supplied as JSON specs) into a common shape and reports the
+//! differences that matter when migrating between the two platforms.
+
+#![allow(dead_code)] // Functions called from bin target
+
+use crate::cli::OutputFormat;
+use crate::commands::cloud::utils::output_with_pager;
+use crate::commands::enterprise::utils::read_json_data;
+use crate::connection::ConnectionManager;
+use crate::error::{RedisCtlError, Result as CliResult};
+use crate::output::{handle_output, print_formatted_output, resolve_auto};
+use anyhow::Context;
+use serde::Serialize;
+use serde_json::{Value, json};
+use tabled::{Table, Tabled, settings::Style};
+
+const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Where to source one side of the comparison from
+pub enum Source<'a> {
+    /// Fetch a live database through the API
+    Live {
+        id: &'a str,
+        profile: Option<&'a str>,
+    },
+    /// Use a JSON spec (string, @file, or - for stdin) in the platform's API shape
+    Spec(&'a str),
+}
+
+/// Platform-neutral view of the database settings relevant to migration
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct DatabaseCapabilities {
+    pub name: Option<String>,
+    pub memory_gb: Option<f64>,
+    pub persistence: Option<String>,
+    pub eviction: Option<String>,
+    pub replication: Option<bool>,
+    pub shards: Option<u64>,
+    pub oss_cluster_api: Option<bool>,
+    pub tls: Option<bool>,
+    pub modules: Vec<String>,
+    pub redis_version: Option<String>,
+}
+
+/// One compared aspect
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparisonRow {
+    pub aspect: &'static str,
+    pub cloud: String,
+    pub enterprise: String,
+    #[serde(rename = "match")]
+    pub matches: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Tabled)]
+struct ComparisonTableRow {
+    #[tabled(rename = "ASPECT")]
+    aspect: String,
+    #[tabled(rename = "CLOUD")]
+    cloud: String,
+    #[tabled(rename = "ENTERPRISE")]
+    enterprise: String,
+    #[tabled(rename = "MATCH")]
+    matches: String,
+    #[tabled(rename = "NOTE")]
+    note: String,
+}
+
+/// Handle the `compare-platforms` command
+pub async fn handle_compare_platforms(
+    conn_mgr: &ConnectionManager,
+    cloud: Source<'_>,
+    enterprise: Source<'_>,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let cloud_db = match cloud {
+        Source::Live { id, profile } => fetch_cloud_database(conn_mgr, id, profile).await?,
+        Source::Spec(data) => read_json_data(data)?,
+    };
+    let enterprise_db = match enterprise {
+        Source::Live { id, profile } => fetch_enterprise_database(conn_mgr, id, profile).await?,
+        Source::Spec(data) => read_json_data(data)?,
+    };
+
+    let cloud_caps = normalize_cloud(&cloud_db);
+    let enterprise_caps = normalize_enterprise(&enterprise_db);
+    let rows = compare(&cloud_caps, &enterprise_caps);
+    let differences = rows.iter().filter(|r| r.matches == Some(false)).count();
+
+    let report = json!({
+        "cloud": cloud_caps,
+        "enterprise": enterprise_caps,
+        "differences": differences,
+        "comparison": rows,
+    });
+
+    if resolve_auto(output_format).is_structured() {
+        let data = handle_output(report, output_format, query)?;
+        print_formatted_output(data, output_format)?;
+    } else {
+        let table_rows: Vec<ComparisonTableRow> = rows
+            .iter()
+            .map(|r| ComparisonTableRow {
+                aspect: r.aspect.to_string(),
+                cloud: r.cloud.clone(),
+                enterprise: r.enterprise.clone(),
+                matches: match r.matches {
+                    Some(true) => "yes".to_string(),
+                    Some(false) => "NO".to_string(),
+                    None => "-".to_string(),
+                },
+                note: r.note.clone().unwrap_or_default(),
+            })
+            .collect();
+        let mut table = Table::new(&table_rows);
+        table.with(Style::blank());
+        output_with_pager(&format!("{}\n\n{} difference(s) found", table, differences));
+    }
+
+    Ok(())
+}
+
+async fn fetch_cloud_database(
+    conn_mgr: &ConnectionManager,
+    id: &str,
+    profile: Option<&str>,
+) -> CliResult<Value> {
+    let (subscription_id, database_id) =
+        id.split_once(':')
+            .ok_or_else(|| RedisCtlError::InvalidInput {
+                message: format!(
+                    "Invalid cloud database ID format: {}. Expected format: subscription_id:database_id",
+                    id
+                ),
+            })?;
+    let client = conn_mgr.create_cloud_client(profile).await?;
+    let response = client
+        .get_raw(&format!(
+            "/subscriptions/{}/databases/{}",
+            subscription_id, database_id
+        ))
+        .await
+        .context("Failed to get cloud database")?;
+    Ok(response)
+}
+
+async fn fetch_enterprise_database(
+    conn_mgr: &ConnectionManager,
+    id: &str,
+    profile: Option<&str>,
+) -> CliResult<Value> {
+    let client = conn_mgr.create_enterprise_client(profile).await?;
+    client
+        .get_raw(&format!("/v1/bdbs/{}", id))
+        .await
+        .map_err(RedisCtlError::from)
+}
+
+/// Map a Cloud database (API shape) to the common capability view
+pub fn normalize_cloud(db: &Value) -> DatabaseCapabilities {
+    DatabaseCapabilities {
+        name: db.get("name").and_then(Value::as_str).map(str::to_string),
+        memory_gb: db
+            .get("memoryLimitInGb")
+            .or_else(|| db.get("datasetSizeInGb"))
+            .and_then(Value::as_f64),
+        persistence: db
+            .get("dataPersistence")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        eviction: db
+            .get("dataEvictionPolicy")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        replication: db.get("replication").and_then(Value::as_bool),
+        shards: db
+            .pointer("/clustering/numberOfShards")
+            .and_then(Value::as_u64),
+        oss_cluster_api: db.get("supportOSSClusterApi").and_then(Value::as_bool),
+        tls: db.pointer("/security/enableTls").and_then(Value::as_bool),
+        modules: module_names(db.get("modules"), "name"),
+        redis_version: db
+            .get("redisVersion")
+            .or_else(|| db.get("redisVersionCompliance"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    }
+}
+
+/// Map an Enterprise database (API shape) to the common capability view
+pub fn normalize_enterprise(db: &Value) -> DatabaseCapabilities {
+    DatabaseCapabilities {
+        name: db.get("name").and_then(Value::as_str).map(str::to_string),
+        memory_gb: db
+            .get("memory_size")
+            .and_then(Value::as_f64)
+            .map(|bytes| bytes / BYTES_PER_GB),
+        persistence: enterprise_persistence(db),
+        eviction: db
+            .get("eviction_policy")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        replication: db.get("replication").and_then(Value::as_bool),
+        shards: db.get("shards_count").and_then(Value::as_u64),
+        oss_cluster_api: db.get("oss_cluster").and_then(Value::as_bool),
+        tls: db
+            .get("tls_mode")
+            .and_then(Value::as_str)
+            .map(|mode| mode != "disabled")
+            .or_else(|| db.get("ssl").and_then(Value::as_bool)),
+        modules: module_names(db.get("module_list"), "module_name"),
+        redis_version: db
+            .get("redis_version")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+    }
+}
+
+/// Translate Enterprise persistence settings into the Cloud vocabulary
+/// (`none`, `aof-every-1-second`, `snapshot-every-1-hour`, ...)
+fn enterprise_persistence(db: &Value) -> Option<String> {
+    let mode = db.get("data_persistence").and_then(Value::as_str)?;
+    let persistence = match mode {
+        "disabled" => "none".to_string(),
+        "aof" => match db.get("aof_policy").and_then(Value::as_str) {
+            Some("appendfsync-always") => "aof-every-write".to_string(),
+            _ => "aof-every-1-second".to_string(),
+        },
+        "snapshot" => {
+            let secs = db
+                .pointer("/snapshot_policy/0/secs")
+                .and_then(Value::as_u64);
+            match secs {
+                Some(3600) => "snapshot-every-1-hour".to_string(),
+                Some(21600) => "snapshot-every-6-hours".to_string(),
+                Some(43200) => "snapshot-every-12-hours".to_string(),
+                Some(other) => format!("snapshot-every-{}-seconds", other),
+                None => "snapshot".to_string(),
+            }
+        }
+        other => other.to_string(),
+    };
+    Some(persistence)
+}
+
+/// Collect module names under a canonical spelling so that e.g. Cloud's
+/// `RediSearch` and Enterprise's `search` compare equal
+fn module_names(modules: Option<&Value>, key: &str) -> Vec<String> {
+    let mut names: Vec<String> = modules
+        .and_then(Value::as_array)
+        .map(|list| {
+            list.iter()
+                .filter_map(|m| m.get(key).and_then(Value::as_str))
+                .map(canonical_module_name)
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn canonical_module_name(name: &str) -> String {
+    let lower = name.to_ascii_lowercase();
+    match lower.as_str() {
+        "search" | "redisearch" | "ft" => "search".to_string(),
+        "rejson" | "redisjson" | "json" => "json".to_string(),
+        "timeseries" | "redistimeseries" => "timeseries".to_string(),
+        "bf" | "bloom" | "redisbloom" => "bloom".to_string(),
+        "graph" | "redisgraph" => "graph".to_string(),
+        "gears" | "redisgears" | "rg" => "gears".to_string(),
+        _ => lower,
+    }
+}
+
+/// Compare two normalized databases aspect by aspect
+pub fn compare(
+    cloud: &DatabaseCapabilities,
+    enterprise: &DatabaseCapabilities,
+) -> Vec<ComparisonRow> {
+    let mut rows = vec![
+        row(
+            "memory",
+            cloud.memory_gb.map(format_gb),
+            enterprise.memory_gb.map(format_gb),
+            |a, b| a == b,
+            "Size the target so the dataset fits with headroom",
+        ),
+        row(
+            "persistence",
+            cloud.persistence.clone(),
+            enterprise.persistence.clone(),
+            |a, b| a == b,
+            "Configure the same persistence policy on the target",
+        ),
+        row(
+            "eviction",
+            cloud.eviction.clone(),
+            enterprise.eviction.clone(),
+            |a, b| a == b,
+            "Different eviction policy changes behavior when memory is full",
+        ),
+        row(
+            "replication",
+            cloud.replication.map(|v| v.to_string()),
+            enterprise.replication.map(|v| v.to_string()),
+            |a, b| a == b,
+            "High availability differs between platforms",
+        ),
+        row(
+            "shards",
+            cloud.shards.map(|v| v.to_string()),
+            enterprise.shards.map(|v| v.to_string()),
+            |a, b| a == b,
+            "Multi-key operations across slots behave differently when sharding changes",
+        ),
+        row(
+            "oss_cluster_api",
+            cloud.oss_cluster_api.map(|v| v.to_string()),
+            enterprise.oss_cluster_api.map(|v| v.to_string()),
+            |a, b| a == b,
+            "Clients must switch between cluster-aware and standalone mode",
+        ),
+        row(
+            "tls",
+            cloud.tls.map(|v| v.to_string()),
+            enterprise.tls.map(|v| v.to_string()),
+            |a, b| a == b,
+            "Client connection settings must be updated",
+        ),
+        row(
+            "redis_version",
+            cloud.redis_version.clone(),
+            enterprise.redis_version.clone(),
+            |a, b| major_minor(a) == major_minor(b),
+            "RDB imports require a target version at least as new as the source",
+        ),
+    ];
+
+    let only_cloud: Vec<&str> = cloud
+        .modules
+        .iter()
+        .filter(|m| !enterprise.modules.contains(m))
+        .map(String::as_str)
+        .collect();
+    let only_enterprise: Vec<&str> = enterprise
+        .modules
+        .iter()
+        .filter(|m| !cloud.modules.contains(m))
+        .map(String::as_str)
+        .collect();
+    let mut missing = Vec::new();
+    if !only_cloud.is_empty() {
+        missing.push(format!("only on cloud: {}", only_cloud.join(", ")));
+    }
+    if !only_enterprise.is_empty() {
+        missing.push(format!(
+            "only on enterprise: {}",
+            only_enterprise.join(", ")
+        ));
+    }
+    rows.push(ComparisonRow {
+        aspect: "modules",
+        cloud: display_modules(&cloud.modules),
+        enterprise: display_modules(&enterprise.modules),
+        matches: Some(missing.is_empty()),
+        note: (!missing.is_empty()).then(|| missing.join("; ")),
+    });
+
+    rows
+}
+
+fn row(
+    aspect: &'static str,
+    cloud: Option<String>,
+    enterprise: Option<String>,
+    same: impl Fn(&str, &str) -> bool,
+    note: &str,
+) -> ComparisonRow {
+    let matches = match (&cloud, &enterprise) {
+        (Some(a), Some(b)) => Some(same(a, b)),
+        _ => None,
+    };
+    ComparisonRow {
+        aspect,
+        cloud: cloud.unwrap_or_else(|| "-".to_string()),
+        enterprise: enterprise.unwrap_or_else(|| "-".to_string()),
+        matches,
+        note: (matches == Some(false)).then(|| note.to_string()),
+    }
+}
+
+fn format_gb(gb: f64) -> String {
+    format!("{:.2} GB", gb)
+}
+
+fn major_minor(version: &str) -> String {
+    version.split('.').take(2).collect::<Vec<_>>().join(".")
+}
+
+fn display_modules(modules: &[String]) -> String {
+    if modules.is_empty() {
+        "none".to_string()
+    } else {
+        modules.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cloud_db() -> Value {
+        json!({
+            "databaseId": 1,
+            "name": "cache",
+            "memoryLimitInGb": 2.0,
+            "dataPersistence": "aof-every-1-second",
+            "dataEvictionPolicy": "volatile-lru",
+            "replication": true,
+            "clustering": {"numberOfShards": 2},
+            "supportOSSClusterApi": false,
+            "security": {"enableTls": true},
+            "modules": [{"name": "RediSearch"}, {"name": "RedisJSON"}],
+            "redisVersion": "7.2.4"
+        })
+    }
+
+    fn enterprise_db() -> Value {
+        json!({
+            "uid": 1,
+            "name": "cache",
+            "memory_size": 2147483648u64,
+            "data_persistence": "aof",
+            "aof_policy": "appendfsync-every-sec",
+            "eviction_policy": "volatile-lru",
+            "replication": true,
+            "shards_count": 2,
+            "oss_cluster": false,
+            "tls_mode": "enabled",
+            "module_list": [{"module_name": "search"}, {"module_name": "ReJSON"}],
+            "redis_version": "7.2"
+        })
+    }
+
+    #[test]
+    fn equivalent_databases_have_no_differences() {
+        let rows = compare(
+            &normalize_cloud(&cloud_db()),
+            &normalize_enterprise(&enterprise_db()),
+        );
+        let differing: Vec<_> = rows
+            .iter()
+            .filter(|r| r.matches != Some(true))
+            .map(|r| r.aspect)
+            .collect();
+        assert!(
+            differing.is_empty(),
+            "unexpected differences: {differing:?}"
+        );
+    }
+
+    #[test]
+    fn reports_persistence_eviction_and_module_differences() {
+        let mut enterprise = enterprise_db();
+        enterprise["data_persistence"] = json!("snapshot");
+        enterprise["snapshot_policy"] = json!([{"secs": 21600, "writes": 1}]);
+        enterprise["eviction_policy"] = json!("noeviction");
+        enterprise["module_list"] = json!([{"module_name": "search"}, {"module_name": "bf"}]);
+
+        let rows = compare(
+            &normalize_cloud(&cloud_db()),
+            &normalize_enterprise(&enterprise),
+        );
+        let get = |aspect| rows.iter().find(|r| r.aspect == aspect).unwrap();
+
+        assert_eq!(get("persistence").enterprise, "snapshot-every-6-hours");
+        assert_eq!(get("persistence").matches, Some(false));
+        assert_eq!(get("eviction").matches, Some(false));
+        let modules = get("modules");
+        assert_eq!(modules.matches, Some(false));
+        assert_eq!(
+            modules.note.as_deref(),
+            Some("only on cloud: json; only on enterprise: bloom")
+        );
+    }
+
+    #[test]
+    fn missing_fields_are_unknown_not_different() {
+        let rows = compare(
+            &normalize_cloud(&json!({})),
+            &normalize_enterprise(&enterprise_db()),
+        );
+        let shards = rows.iter().find(|r| r.aspect == "shards").unwrap();
+        assert_eq!(shards.matches, None);
+        assert_eq!(shards.cloud, "-");
+        assert!(shards.note.is_none());
+    }
+
+    #[test]
+    fn enterprise_persistence_maps_to_cloud_vocabulary() {
+        assert_eq!(
+            enterprise_persistence(&json!({"data_persistence": "disabled"})).as_deref(),
+            Some("none")
+        );
+        assert_eq!(
+            enterprise_persistence(
+                &json!({"data_persistence": "aof", "aof_policy": "appendfsync-always"})
+            )
+            .as_deref(),
+            Some("aof-every-write")
+        );
+        assert_eq!(enterprise_persistence(&json!({})), None);
+    }
+}
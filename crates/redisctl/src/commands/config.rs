@@ -0,0 +1,85 @@
+//! Configuration file management command implementations
+
+#![allow(dead_code)] // Functions called from bin target
+
+use crate::cli::{ConfigCommands, OutputFormat};
+use crate::connection::ConnectionManager;
+use crate::error::RedisCtlError;
+use crate::output;
+use redisctl_core::Config;
+use redisctl_core::config::CURRENT_CONFIG_VERSION;
+
+/// Handle config file commands
+pub async fn handle_config_command(
+    config_cmd: &ConfigCommands,
+    conn_mgr: &ConnectionManager,
+    output_format: OutputFormat,
+) -> Result<(), RedisCtlError> {
+    match config_cmd {
+        ConfigCommands::Migrate { dry_run, no_backup } => {
+            handle_migrate(conn_mgr, *dry_run, !*no_backup, output_format).await
+        }
+    }
+}
+
+async fn handle_migrate(
+    conn_mgr: &ConnectionManager,
+    dry_run: bool,
+    backup: bool,
+    output_format: OutputFormat,
+) -> Result<(), RedisCtlError> {
+    let config_path = match &conn_mgr.config_path {
+        Some(path) => path.clone(),
+        None => Config::config_path()?,
+    };
+
+    if !config_path.exists() {
+        return Err(RedisCtlError::FileError {
+            path: config_path.display().to_string(),
+            message: "No config file to migrate".to_string(),
+        });
+    }
+
+    let report = Config::migrate_file(&config_path, backup, dry_run)?;
+
+    if output::resolve_auto(output_format).is_structured() {
+        let output_data = serde_json::json!({
+            "config_path": config_path.to_str(),
+            "dry_run": dry_run,
+            "from_version": report.from_version,
+            "to_version": report.to_version,
+            "applied": report.applied,
+            "backup_path": report.backup_path,
+        });
+        output::print_output(&output_data, output_format, None)?;
+    } else {
+        if !report.changed() {
+            println!(
+                "Config is already at version {} (current: {}); nothing to migrate",
+                report.from_version, CURRENT_CONFIG_VERSION
+            );
+            return Ok(());
+        }
+
+        let verb = if dry_run { "Would migrate" } else { "Migrated" };
+        println!(
+            "{} {} from version {} to {}:",
+            verb,
+            config_path.display(),
+            report.from_version,
+            report.to_version
+        );
+        for step in &report.applied {
+            println!("  - {}", step);
+        }
+        if let Some(backup_path) = &report.backup_path {
+            println!("Original saved to {}", backup_path.display());
+        }
+        if dry_run {
+            println!();
+            println!("No changes were made.");
+        }
+    }
+
+    Ok(())
+}
@@ -13,6 +13,7 @@ pub fn format_cloud_curl(
     method: &HttpMethod,
     path: &str,
     body: Option<&Value>,
+    headers: &[(String, String)],
 ) -> String {
     let mut parts = vec![
         "curl".to_string(),
@@ -21,7 +22,7 @@ pub fn format_cloud_curl(
     ];
 
     parts.push(format!("'{}{}'", info.base_url, path));
-    parts.push("-H 'Accept: application/json'".to_string());
+    push_headers(&mut parts, headers);
     parts.push("-H 'x-api-key: <REDACTED>'".to_string());
     parts.push("-H 'x-api-secret-key: <REDACTED>'".to_string());
 
@@ -42,6 +43,7 @@ pub fn format_enterprise_curl(
     method: &HttpMethod,
     path: &str,
     body: Option<&Value>,
+    headers: &[(String, String)],
 ) -> String {
     let mut parts = vec!["curl".to_string(), "-s".to_string()];
 
@@ -52,7 +54,7 @@ pub fn format_enterprise_curl(
     parts.push(format!("-X {}", method));
 
     parts.push(format!("'{}{}'", info.base_url, path));
-    parts.push("-H 'Accept: application/json'".to_string());
+    push_headers(&mut parts, headers);
     parts.push("-u '<REDACTED>:<REDACTED>'".to_string());
 
     if let Some(ref ca_cert_path) = info.ca_cert {
@@ -67,6 +69,20 @@ pub fn format_enterprise_curl(
     parts.join(" \\\n  ")
 }
 
+/// Add the default Accept header and any user headers, letting a user header
+/// replace the default of the same name
+fn push_headers(parts: &mut Vec<String>, headers: &[(String, String)]) {
+    if !headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("accept"))
+    {
+        parts.push("-H 'Accept: application/json'".to_string());
+    }
+    for (name, value) in headers {
+        parts.push(format!("-H '{}: {}'", name, value));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,7 +95,7 @@ mod tests {
             api_secret: "test-secret".to_string(),
             user_agent: "redisctl/test".to_string(),
         };
-        let result = format_cloud_curl(&info, &HttpMethod::Get, "/subscriptions", None);
+        let result = format_cloud_curl(&info, &HttpMethod::Get, "/subscriptions", None, &[]);
         assert!(result.starts_with("curl"));
         assert!(result.contains("-X GET"));
         assert!(result.contains("'https://api.redislabs.com/v1/subscriptions'"));
@@ -98,7 +114,8 @@ mod tests {
             user_agent: "redisctl/test".to_string(),
         };
         let body = serde_json::json!({"name": "test"});
-        let result = format_cloud_curl(&info, &HttpMethod::Post, "/subscriptions", Some(&body));
+        let result =
+            format_cloud_curl(&info, &HttpMethod::Post, "/subscriptions", Some(&body), &[]);
         assert!(result.contains("-X POST"));
         assert!(result.contains("-d '{\"name\":\"test\"}'"));
         assert!(result.contains("Content-Type: application/json"));
@@ -114,7 +131,7 @@ mod tests {
             ca_cert: None,
             user_agent: "redisctl/test".to_string(),
         };
-        let result = format_enterprise_curl(&info, &HttpMethod::Get, "/v1/cluster", None);
+        let result = format_enterprise_curl(&info, &HttpMethod::Get, "/v1/cluster", None, &[]);
         assert!(result.contains("-k"));
         assert!(result.contains("-X GET"));
         assert!(result.contains("'https://cluster:9443/v1/cluster'"));
@@ -132,7 +149,7 @@ mod tests {
             ca_cert: Some("/path/to/ca.crt".to_string()),
             user_agent: "redisctl/test".to_string(),
         };
-        let result = format_enterprise_curl(&info, &HttpMethod::Get, "/v1/bdbs", None);
+        let result = format_enterprise_curl(&info, &HttpMethod::Get, "/v1/bdbs", None, &[]);
         assert!(!result.contains("-k"));
         assert!(result.contains("--cacert '/path/to/ca.crt'"));
     }
@@ -148,9 +165,29 @@ mod tests {
             user_agent: "redisctl/test".to_string(),
         };
         let body = serde_json::json!({"name": "db1"});
-        let result = format_enterprise_curl(&info, &HttpMethod::Post, "/v1/bdbs", Some(&body));
+        let result = format_enterprise_curl(&info, &HttpMethod::Post, "/v1/bdbs", Some(&body), &[]);
         assert!(result.contains("-X POST"));
         assert!(result.contains("-d '{\"name\":\"db1\"}'"));
         assert!(result.contains("Content-Type: application/json"));
     }
+
+    #[test]
+    fn user_headers_replace_default_accept() {
+        let info = EnterpriseConnectionInfo {
+            base_url: "https://cluster:9443".to_string(),
+            username: "admin".to_string(),
+            password: None,
+            insecure: false,
+            ca_cert: None,
+            user_agent: "redisctl/test".to_string(),
+        };
+        let headers = vec![
+            ("Accept".to_string(), "application/problem+json".to_string()),
+            ("X-Correlation-Id".to_string(), "abc".to_string()),
+        ];
+        let result = format_enterprise_curl(&info, &HttpMethod::Get, "/v1/bdbs", None, &headers);
+        assert!(!result.contains("Accept: application/json"));
+        assert!(result.contains("-H 'Accept: application/problem+json'"));
+        assert!(result.contains("-H 'X-Correlation-Id: abc'"));
+    }
 }
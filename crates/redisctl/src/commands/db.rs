@@ -3,9 +3,12 @@
 #![allow(dead_code)] // Functions called from bin target
 
 use crate::cli::{DbCommands, OutputFormat};
+use crate::commands::enterprise::database_impl::endpoint_host_port;
 use crate::connection::ConnectionManager;
 use crate::error::RedisCtlError;
+use crate::output;
 use redisctl_core::DeploymentType;
+use serde_json::Value;
 use std::process::Command;
 use tracing::debug;
 
@@ -13,49 +16,197 @@ use tracing::debug;
 pub async fn handle_db_command(
     db_cmd: &DbCommands,
     conn_mgr: &ConnectionManager,
-    _output: OutputFormat,
+    output: OutputFormat,
 ) -> Result<(), RedisCtlError> {
     match db_cmd {
         DbCommands::Open {
             profile,
+            subscription,
+            database,
+            bdb,
             dry_run,
             redis_cli,
             args,
-        } => handle_open(conn_mgr, profile, *dry_run, redis_cli, args).await,
+        } => {
+            let target = match (subscription, database, bdb) {
+                (Some(subscription), Some(database), _) => Some(DatabaseTarget::Cloud {
+                    subscription: *subscription,
+                    database: *database,
+                }),
+                (_, _, Some(bdb)) => Some(DatabaseTarget::Enterprise { bdb: *bdb }),
+                _ => None,
+            };
+            handle_open(conn_mgr, profile, target, *dry_run, redis_cli, args, output).await
+        }
     }
 }
 
-/// Handle the 'db open' command - spawn redis-cli with profile credentials
-async fn handle_open(
+/// Database to open through a cloud or enterprise profile
+#[derive(Debug, Clone, Copy)]
+enum DatabaseTarget {
+    Cloud { subscription: u32, database: u32 },
+    Enterprise { bdb: u32 },
+}
+
+/// Resolved connection settings for redis-cli
+struct Connection {
+    host: String,
+    port: u16,
+    password: Option<String>,
+    tls: bool,
+    /// Skip certificate verification (cluster-issued certificates)
+    insecure: bool,
+    username: String,
+    database: u8,
+}
+
+/// Resolve the connection from the profile, looking the database up through
+/// the Cloud or Enterprise API for those profile types
+async fn resolve_connection(
     conn_mgr: &ConnectionManager,
     profile_name: &str,
-    dry_run: bool,
-    redis_cli_path: &str,
-    extra_args: &[String],
-) -> Result<(), RedisCtlError> {
-    // Get the profile
+    target: Option<DatabaseTarget>,
+) -> Result<Connection, RedisCtlError> {
     let profile = conn_mgr.config.profiles.get(profile_name).ok_or_else(|| {
         RedisCtlError::Configuration(format!("Profile '{}' not found", profile_name))
     })?;
 
-    // Verify it's a database profile
-    if profile.deployment_type != DeploymentType::Database {
-        return Err(RedisCtlError::Configuration(format!(
-            "Profile '{}' is not a database profile (type: {})",
-            profile_name, profile.deployment_type
-        )));
+    match (profile.deployment_type, target) {
+        (DeploymentType::Database, None) => {
+            let (host, port, password, tls, username, database) = profile
+                .resolve_database_credentials()
+                .map_err(|e| {
+                    RedisCtlError::Configuration(format!("Failed to resolve credentials: {}", e))
+                })?
+                .ok_or_else(|| {
+                    RedisCtlError::Configuration(format!(
+                        "Profile '{}' has no database credentials",
+                        profile_name
+                    ))
+                })?;
+            Ok(Connection {
+                host,
+                port,
+                password,
+                tls,
+                insecure: false,
+                username,
+                database,
+            })
+        }
+        (
+            DeploymentType::Cloud,
+            Some(DatabaseTarget::Cloud {
+                subscription,
+                database,
+            }),
+        ) => {
+            let client = conn_mgr.create_cloud_client(Some(profile_name)).await?;
+            let db = client
+                .get_raw(&format!(
+                    "/subscriptions/{}/databases/{}",
+                    subscription, database
+                ))
+                .await?;
+            cloud_connection(&db).ok_or_else(|| RedisCtlError::InvalidInput {
+                message: format!(
+                    "Database {} in subscription {} has no endpoint yet",
+                    database, subscription
+                ),
+            })
+        }
+        (DeploymentType::Enterprise, Some(DatabaseTarget::Enterprise { bdb })) => {
+            let client = conn_mgr
+                .create_enterprise_client(Some(profile_name))
+                .await?;
+            let db = client.get_raw(&format!("/v1/bdbs/{}", bdb)).await?;
+            enterprise_connection(&db).ok_or_else(|| RedisCtlError::InvalidInput {
+                message: format!("Database {} has no endpoint to connect to", bdb),
+            })
+        }
+        (DeploymentType::Database, Some(_)) => Err(RedisCtlError::InvalidInput {
+            message: format!(
+                "Profile '{}' is a database profile; --subscription, --database and --bdb only apply to cloud and enterprise profiles",
+                profile_name
+            ),
+        }),
+        (deployment_type, _) => {
+            let hint = match deployment_type {
+                DeploymentType::Cloud => "pass --subscription <id> --database <id>",
+                _ => "pass --bdb <uid>",
+            };
+            Err(RedisCtlError::Configuration(format!(
+                "Profile '{}' is not a database profile (type: {}); {} to open one of its databases",
+                profile_name, deployment_type, hint
+            )))
+        }
     }
+}
 
-    // Get resolved credentials
-    let (host, port, password, tls, username, _database) = profile
-        .resolve_database_credentials()
-        .map_err(|e| RedisCtlError::Configuration(format!("Failed to resolve credentials: {}", e)))?
-        .ok_or_else(|| {
-            RedisCtlError::Configuration(format!(
-                "Profile '{}' has no database credentials",
-                profile_name
-            ))
-        })?;
+/// Connection settings from a Cloud database, preferring the public endpoint
+fn cloud_connection(db: &Value) -> Option<Connection> {
+    let endpoint = db["publicEndpoint"]
+        .as_str()
+        .or_else(|| db["privateEndpoint"].as_str())
+        .filter(|e| !e.is_empty())?;
+    let (host, port) = endpoint.rsplit_once(':')?;
+    let password = db["security"]["password"]
+        .as_str()
+        .or_else(|| db["password"].as_str())
+        .filter(|p| !p.is_empty())
+        .map(str::to_string);
+    Some(Connection {
+        host: host.to_string(),
+        port: port.parse().ok()?,
+        password,
+        tls: db["security"]["enableTls"].as_bool().unwrap_or(false),
+        insecure: false,
+        username: "default".to_string(),
+        database: 0,
+    })
+}
+
+/// Connection settings from an Enterprise database (bdb)
+fn enterprise_connection(db: &Value) -> Option<Connection> {
+    let (host, port) = endpoint_host_port(db)?;
+    let tls = db["tls_mode"]
+        .as_str()
+        .is_some_and(|mode| mode != "disabled")
+        || db["ssl"].as_bool().unwrap_or(false);
+    Some(Connection {
+        host,
+        port,
+        password: db["authentication_redis_pass"]
+            .as_str()
+            .filter(|p| !p.is_empty())
+            .map(str::to_string),
+        tls,
+        // Cluster-issued certificates rarely match the name used to reach the endpoint
+        insecure: tls,
+        username: "default".to_string(),
+        database: 0,
+    })
+}
+
+/// Handle the 'db open' command - spawn redis-cli with profile credentials
+async fn handle_open(
+    conn_mgr: &ConnectionManager,
+    profile_name: &str,
+    target: Option<DatabaseTarget>,
+    dry_run: bool,
+    redis_cli_path: &str,
+    extra_args: &[String],
+    output: OutputFormat,
+) -> Result<(), RedisCtlError> {
+    let Connection {
+        host,
+        port,
+        password,
+        tls,
+        insecure,
+        username,
+        database,
+    } = resolve_connection(conn_mgr, profile_name, target).await?;
 
     // Build redis-cli arguments
     let mut cli_args = vec![
@@ -80,6 +231,15 @@ async fn handle_open(
     // Add TLS if enabled
     if tls {
         cli_args.push("--tls".to_string());
+        if insecure {
+            cli_args.push("--insecure".to_string());
+        }
+    }
+
+    // Select the configured logical database if not the default
+    if database != 0 {
+        cli_args.push("-n".to_string());
+        cli_args.push(database.to_string());
     }
 
     // Add any extra arguments
@@ -87,40 +247,45 @@ async fn handle_open(
 
     debug!(
         "redis-cli args (password redacted): {:?}",
-        cli_args
-            .iter()
-            .enumerate()
-            .map(|(i, arg)| {
-                // Redact password value (comes after -a)
-                if i > 0 && cli_args[i - 1] == "-a" {
-                    "***".to_string()
-                } else {
-                    arg.clone()
-                }
-            })
-            .collect::<Vec<_>>()
+        redact_password(&cli_args)
     );
 
     if dry_run {
         // Print the command (with password redacted for safety)
-        let display_args: Vec<String> = cli_args
-            .iter()
-            .enumerate()
-            .map(|(i, arg)| {
-                if i > 0 && cli_args[i - 1] == "-a" {
-                    "***".to_string()
+        let display_args = redact_password(&cli_args);
+
+        // Structured output only when explicitly requested, so piping the
+        // text command keeps working
+        if output.is_structured() {
+            let mut argv = vec![redis_cli_path.to_string()];
+            argv.extend(display_args);
+            let result = serde_json::json!({
+                "profile": profile_name,
+                "host": host,
+                "port": port,
+                "tls": tls,
+                "username": username,
+                "db": database,
+                "password_redacted": password.is_some(),
+                "argv": argv,
+            });
+            output::print_output(&result, output, None)?;
+            return Ok(());
+        }
+
+        let quoted: Vec<String> = display_args
+            .into_iter()
+            .map(|arg| {
+                // Quote args with spaces
+                if arg.contains(' ') {
+                    format!("\"{}\"", arg)
                 } else {
-                    // Quote args with spaces
-                    if arg.contains(' ') {
-                        format!("\"{}\"", arg)
-                    } else {
-                        arg.clone()
-                    }
+                    arg
                 }
             })
             .collect();
 
-        println!("{} {}", redis_cli_path, display_args.join(" "));
+        println!("{} {}", redis_cli_path, quoted.join(" "));
         return Ok(());
     }
 
@@ -169,3 +334,17 @@ async fn handle_open(
         Ok(())
     }
 }
+
+/// Replace the value following `-a` with `***`
+fn redact_password(args: &[String]) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .map(|(i, arg)| {
+            if i > 0 && args[i - 1] == "-a" {
+                "***".to_string()
+            } else {
+                arg.clone()
+            }
+        })
+        .collect()
+}
@@ -17,9 +17,12 @@ pub async fn handle_cluster_command(
 ) -> CliResult<()> {
     match command {
         // Cluster Configuration
-        EnterpriseClusterCommands::Get => {
+        EnterpriseClusterCommands::Get { brief: false } => {
             cluster_impl::get_cluster(conn_mgr, profile_name, output_format, query).await
         }
+        EnterpriseClusterCommands::Get { brief: true } => {
+            cluster_impl::get_cluster_summary(conn_mgr, profile_name, output_format, query).await
+        }
         EnterpriseClusterCommands::Update {
             name,
             email_alerts,
@@ -101,6 +104,9 @@ pub async fn handle_cluster_command(
             username,
             password,
             data,
+            url,
+            insecure,
+            async_ops,
         } => {
             cluster_impl::join_cluster(
                 conn_mgr,
@@ -109,6 +115,29 @@ pub async fn handle_cluster_command(
                 username.as_deref(),
                 password.as_deref(),
                 data.as_deref(),
+                url.as_deref(),
+                *insecure,
+                async_ops,
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseClusterCommands::Expand {
+            node_address,
+            node_user,
+            node_pass,
+            insecure,
+            async_ops,
+        } => {
+            cluster_impl::expand_cluster(
+                conn_mgr,
+                profile_name,
+                node_address,
+                node_user.as_deref(),
+                node_pass.as_deref(),
+                *insecure,
+                async_ops,
                 output_format,
                 query,
             )
@@ -184,6 +213,10 @@ pub async fn handle_cluster_command(
         EnterpriseClusterCommands::VerifyRackAwareness => {
             cluster_impl::verify_rack_awareness(conn_mgr, profile_name, output_format, query).await
         }
+        EnterpriseClusterCommands::TimeSync { threshold } => {
+            cluster_impl::check_time_sync(conn_mgr, profile_name, *threshold, output_format, query)
+                .await
+        }
 
         // Certificates & Security
         EnterpriseClusterCommands::GetCertificates => {
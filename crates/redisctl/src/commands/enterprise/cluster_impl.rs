@@ -3,6 +3,7 @@
 #![allow(dead_code)]
 
 use crate::cli::OutputFormat;
+use crate::commands::cloud::async_utils::AsyncOperationArgs;
 use crate::connection::ConnectionManager;
 use crate::error::RedisCtlError;
 use crate::error::Result as CliResult;
@@ -15,6 +16,7 @@ use redis_enterprise::license::LicenseHandler;
 use redis_enterprise::nodes::NodeHandler;
 use redis_enterprise::ocsp::OcspHandler;
 use redis_enterprise::shards::ShardHandler;
+use std::time::Duration;
 use tabled::{Table, settings::Style};
 
 use super::utils::*;
@@ -34,7 +36,7 @@ pub async fn get_cluster(
     let info = handler.info().await?;
     let info_json = serde_json::to_value(info).context("Failed to serialize cluster info")?;
     let data = handle_output(info_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_cluster_detail(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -42,6 +44,64 @@ pub async fn get_cluster(
     Ok(())
 }
 
+/// Condensed cluster summary: identity, version, counts, license, and alerts
+pub async fn get_cluster_summary(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let client = conn_mgr.create_enterprise_client(profile_name).await?;
+
+    let summary = redisctl_core::enterprise::cluster_summary(&client).await?;
+    let data = handle_output(summary, output_format, query)?;
+    if use_custom_table(output_format) {
+        print_cluster_summary(&data)?;
+    } else {
+        print_formatted_output(data, output_format)?;
+    }
+    Ok(())
+}
+
+/// Print the cluster summary in key-value format
+fn print_cluster_summary(data: &serde_json::Value) -> CliResult<()> {
+    let fields = [
+        ("Name", "name"),
+        ("Version", "version"),
+        ("Nodes", "nodes"),
+        ("Active Nodes", "nodes_active"),
+        ("Databases", "databases"),
+        ("License Expired", "license_expired"),
+        ("License Expiration", "license_expiration"),
+        ("Active Alerts", "active_alerts"),
+    ];
+
+    let rows: Vec<DetailRow> = fields
+        .iter()
+        .filter_map(|(label, key)| {
+            let value = match data.get(*key)? {
+                serde_json::Value::Null => return None,
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            Some(DetailRow {
+                field: label.to_string(),
+                value,
+            })
+        })
+        .collect();
+
+    if rows.is_empty() {
+        println!("No cluster information available");
+        return Ok(());
+    }
+
+    let mut table = Table::new(&rows);
+    table.with(Style::blank());
+    output_with_pager(&table.to_string());
+    Ok(())
+}
+
 /// Print cluster detail in key-value format
 fn print_cluster_detail(data: &serde_json::Value) -> CliResult<()> {
     let mut rows = Vec::new();
@@ -394,11 +454,12 @@ pub async fn join_cluster(
     username_arg: Option<&str>,
     password_arg: Option<&str>,
     data: Option<&str>,
+    url: Option<&str>,
+    insecure: bool,
+    async_ops: &AsyncOperationArgs,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
-    let client = conn_mgr.create_enterprise_client(profile_name).await?;
-
     // Start with JSON from --data if provided, otherwise empty object
     let mut join_data = if let Some(data_str) = data {
         read_json_data(data_str).context("Failed to parse join data")?
@@ -446,6 +507,38 @@ pub async fn join_cluster(
         })?
         .to_string();
 
+    // The join request goes to the node being joined, not the existing cluster
+    let client = match url {
+        Some(url) => conn_mgr.create_enterprise_node_client(url, &username, &password, insecure)?,
+        None => conn_mgr.create_enterprise_client(profile_name).await?,
+    };
+
+    if async_ops.wait {
+        let pb = operation_spinner(format!("Joining cluster at {}", nodes));
+
+        let progress_callback = Some(action_progress(&pb, "Join"));
+
+        let result = redisctl_core::enterprise::join_cluster_and_wait(
+            &client,
+            &nodes,
+            &username,
+            &password,
+            Duration::from_secs(async_ops.wait_timeout),
+            progress_callback,
+        )
+        .await;
+        pb.finish_and_clear();
+        let node_list = result.map_err(RedisCtlError::from)?;
+
+        let data = handle_output(node_list, output_format, query)?;
+        print_formatted_output(data, output_format)?;
+        return Ok(());
+    }
+
+    redisctl_core::enterprise::ensure_node_not_clustered(&client)
+        .await
+        .map_err(RedisCtlError::from)?;
+
     // Use ClusterHandler for join operation
     let cluster_handler = ClusterHandler::new(client);
     let result = cluster_handler
@@ -456,6 +549,192 @@ pub async fn join_cluster(
     Ok(())
 }
 
+/// REST API URL and bare host of a node given as host, host:port or URL
+fn node_api_url(address: &str) -> (String, String) {
+    let address = address.trim().trim_end_matches('/');
+    let authority = address
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(address);
+    let host = authority
+        .rsplit_once(':')
+        .map(|(host, _)| host)
+        .unwrap_or(authority)
+        .to_string();
+    let url = if address.contains("://") {
+        address.to_string()
+    } else if authority.contains(':') {
+        format!("https://{}", authority)
+    } else {
+        format!("https://{}:9443", authority)
+    };
+    (url, host)
+}
+
+/// Classify the cluster's `GET /v1/nodes` while waiting for a joined node:
+/// ready once it is active and accepts shards
+///
+/// The node is recognized by its address, or as the node whose uid was not in
+/// the cluster before the join.
+fn joined_node_readiness(
+    nodes: &serde_json::Value,
+    address: &str,
+    known_uids: &[u64],
+) -> redisctl_core::PollStatus<serde_json::Value> {
+    use redisctl_core::PollStatus;
+
+    let node = nodes.as_array().and_then(|nodes| {
+        nodes
+            .iter()
+            .find(|n| n["addr"].as_str() == Some(address))
+            .or_else(|| {
+                nodes.iter().find(|n| {
+                    n["uid"]
+                        .as_u64()
+                        .is_some_and(|uid| !known_uids.contains(&uid))
+                })
+            })
+    });
+    let Some(node) = node else {
+        return PollStatus::Pending("waiting for the node to appear in the cluster".to_string());
+    };
+
+    let uid = node["uid"].as_u64().unwrap_or_default();
+    match node["status"].as_str().unwrap_or("unknown") {
+        "active" if node["accept_servers"].as_bool() != Some(false) => {
+            PollStatus::Ready(node.clone())
+        }
+        "active" => PollStatus::Pending(format!("node {} active, not accepting shards yet", uid)),
+        status => PollStatus::Pending(format!("node {} {}", uid, status)),
+    }
+}
+
+/// Join a new node to the profile's cluster, optionally waiting until it is
+/// active and accepts shards
+#[allow(clippy::too_many_arguments)]
+pub async fn expand_cluster(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    node_address: &str,
+    node_user: Option<&str>,
+    node_pass: Option<&str>,
+    insecure: bool,
+    async_ops: &AsyncOperationArgs,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let info = conn_mgr.resolve_enterprise_connection(profile_name)?;
+    let password = info
+        .password
+        .clone()
+        .ok_or_else(|| RedisCtlError::InvalidInput {
+            message: "The profile has no password; it is sent as the cluster credentials in the join request"
+                .to_string(),
+        })?;
+    let cluster_client = conn_mgr.create_enterprise_client(profile_name).await?;
+
+    // Join through a node that is already active in the cluster
+    let nodes = cluster_client.get_raw("/v1/nodes").await?;
+    let known_uids: Vec<u64> = nodes
+        .as_array()
+        .map(|nodes| nodes.iter().filter_map(|n| n["uid"].as_u64()).collect())
+        .unwrap_or_default();
+    let cluster_address = nodes
+        .as_array()
+        .and_then(|nodes| {
+            nodes
+                .iter()
+                .find(|n| n["status"].as_str() == Some("active"))
+                .and_then(|n| n["addr"].as_str())
+        })
+        .map(str::to_string)
+        .or_else(|| node_api_url(&info.base_url).1.into())
+        .filter(|addr| !addr.is_empty())
+        .ok_or_else(|| RedisCtlError::InvalidInput {
+            message: "Could not find an active cluster node to join through".to_string(),
+        })?;
+
+    let (node_url, node_host) = node_api_url(node_address);
+    let node_client = conn_mgr.create_enterprise_node_client(
+        &node_url,
+        node_user.unwrap_or(&info.username),
+        node_pass.unwrap_or(&password),
+        insecure,
+    )?;
+
+    if !async_ops.wait {
+        redisctl_core::enterprise::ensure_node_not_clustered(&node_client)
+            .await
+            .map_err(RedisCtlError::from)?;
+        let result = ClusterHandler::new(node_client)
+            .join_node(&cluster_address, &info.username, &password)
+            .await?;
+        let data = handle_output(result, output_format, query)?;
+        print_formatted_output(data, output_format)?;
+        return Ok(());
+    }
+
+    let timeout = Duration::from_secs(async_ops.wait_timeout);
+    let start = std::time::Instant::now();
+    let pb = operation_spinner(format!(
+        "Joining {} to the cluster at {}",
+        node_host, cluster_address
+    ));
+
+    let progress_callback = Some(action_progress(&pb, "Join"));
+    let joined = redisctl_core::enterprise::join_cluster_and_wait(
+        &node_client,
+        &cluster_address,
+        &info.username,
+        &password,
+        timeout,
+        progress_callback,
+    )
+    .await;
+    if let Err(e) = joined {
+        pb.finish_and_clear();
+        return Err(e.into());
+    }
+
+    let on_pending = {
+        let pb = pb.clone();
+        Some(Box::new(move |status: &str, _elapsed: Duration| {
+            pb.set_message(format!("Waiting for the new node: {}", status));
+        }) as redisctl_core::StatusCallback)
+    };
+    let cluster_client = &cluster_client;
+    let node_host = node_host.as_str();
+    let known_uids = known_uids.as_slice();
+    let node = redisctl_core::poll_until(
+        timeout.saturating_sub(start.elapsed()),
+        Duration::from_secs(async_ops.wait_interval),
+        || async move {
+            Ok(match cluster_client.get_raw("/v1/nodes").await {
+                Ok(nodes) => joined_node_readiness(&nodes, node_host, known_uids),
+                Err(_) => redisctl_core::PollStatus::Pending("waiting for cluster API".to_string()),
+            })
+        },
+        on_pending,
+    )
+    .await;
+    pb.finish_and_clear();
+    let node = node.map_err(RedisCtlError::from)?;
+
+    match resolve_auto(output_format) {
+        OutputFormat::Table => println!(
+            "Node {} ({}) joined the cluster and accepts shards after {}s",
+            node["uid"],
+            node["addr"].as_str().unwrap_or(node_host),
+            start.elapsed().as_secs()
+        ),
+        _ => {
+            let data = handle_output(node, output_format, query)?;
+            print_formatted_output(data, output_format)?;
+        }
+    }
+    Ok(())
+}
+
 pub async fn recover_cluster(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
@@ -990,6 +1269,249 @@ fn build_rack_awareness_report(
     })
 }
 
+/// Default Enterprise REST API port, used when the profile URL has none.
+const DEFAULT_API_PORT: u16 = 9443;
+
+/// Per-request timeout when querying individual nodes for their clock.
+const NODE_TIME_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// One node's clock reading, relative to the local clock.
+struct ClockReading {
+    offset_ms: i64,
+    round_trip_ms: u64,
+    /// Whether the node time came from its `system_time` field rather than the
+    /// one-second resolution HTTP `Date` header
+    from_system_time: bool,
+}
+
+/// One node's clock sample and the NTP state it reports.
+struct NodeTimeSample {
+    node_uid: u64,
+    addr: String,
+    /// `ntp_*` fields of the node object
+    ntp: serde_json::Map<String, serde_json::Value>,
+    result: Result<ClockReading, String>,
+}
+
+/// Check clock skew between cluster nodes.
+///
+/// Queries each node's REST API directly and concurrently, and compares the
+/// node's `system_time` (or, when the node doesn't report it, the HTTP `Date`
+/// header) with the local clock at the midpoint of the round trip. Nodes whose
+/// offset differs from the cluster median by more than `threshold_secs`, or
+/// whose NTP fields report it as unsynchronized, are flagged.
+pub async fn check_time_sync(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    threshold_secs: u64,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let client = conn_mgr.create_enterprise_client(profile_name).await?;
+    let info = conn_mgr.resolve_enterprise_connection(profile_name)?;
+
+    let nodes = client
+        .get_raw("/v1/nodes")
+        .await
+        .map_err(RedisCtlError::from)?;
+
+    let (scheme, port) = api_scheme_and_port(&info.base_url);
+
+    let http = info
+        .http_client_builder()?
+        .timeout(NODE_TIME_TIMEOUT)
+        .build()
+        .context("Failed to create HTTP client")?;
+
+    let samples = nodes.as_array().into_iter().flatten().filter_map(|node| {
+        let node_uid = parse_node_uid(&node["uid"])?;
+        let addr = node["addr"].as_str().unwrap_or_default().to_string();
+        let ntp = node
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(key, _)| key.starts_with("ntp"))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        let (http, info) = (&http, &info);
+        Some(async move {
+            let result = if addr.is_empty() {
+                Err("node has no address".to_string())
+            } else {
+                let url = format!(
+                    "{}://{}:{}/v1/nodes/{}",
+                    scheme,
+                    url_host(&addr),
+                    port,
+                    node_uid
+                );
+                sample_node_clock(http, &url, &info.username, info.password.as_deref()).await
+            };
+            NodeTimeSample {
+                node_uid,
+                addr,
+                ntp,
+                result,
+            }
+        })
+    });
+    let samples = futures::future::join_all(samples).await;
+
+    let result = build_time_sync_report(&samples, threshold_secs);
+    let data = handle_output(result, output_format, query)?;
+    print_formatted_output(data, output_format)?;
+    Ok(())
+}
+
+/// Extract the scheme and port the cluster API is served on from a base URL.
+fn api_scheme_and_port(base_url: &str) -> (&str, u16) {
+    let (scheme, rest) = base_url.split_once("://").unwrap_or(("https", base_url));
+    let authority = rest.split('/').next().unwrap_or(rest);
+    let port = authority
+        .rsplit_once(':')
+        .and_then(|(_, p)| p.parse().ok())
+        .unwrap_or(DEFAULT_API_PORT);
+    (scheme, port)
+}
+
+/// Host part of a URL for a node address, bracketing IPv6 addresses.
+fn url_host(addr: &str) -> std::borrow::Cow<'_, str> {
+    if addr.contains(':') && !addr.starts_with('[') {
+        format!("[{}]", addr).into()
+    } else {
+        addr.into()
+    }
+}
+
+/// Query a node and read its clock relative to ours. The node's own
+/// `system_time` is used when the response carries it; otherwise the `Date`
+/// header, which any HTTP status has, so auth failures still produce a sample.
+async fn sample_node_clock(
+    http: &reqwest::Client,
+    url: &str,
+    username: &str,
+    password: Option<&str>,
+) -> Result<ClockReading, String> {
+    let sent = chrono::Utc::now();
+    let response = http
+        .get(url)
+        .basic_auth(username, password)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    let received = chrono::Utc::now();
+
+    let date = response
+        .headers()
+        .get(reqwest::header::DATE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let system_time = if response.status().is_success() {
+        response
+            .json::<serde_json::Value>()
+            .await
+            .ok()
+            .and_then(|node| {
+                node["system_time"]
+                    .as_str()
+                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            })
+    } else {
+        None
+    };
+
+    let (node_time, from_system_time) = match system_time {
+        Some(time) => (time, true),
+        None => {
+            let date = date.ok_or_else(|| "response has no Date header".to_string())?;
+            let time = chrono::DateTime::parse_from_rfc2822(&date)
+                .map_err(|e| format!("invalid Date header '{}': {}", date, e))?;
+            (time, false)
+        }
+    };
+
+    let round_trip = received - sent;
+    let midpoint = sent + round_trip / 2;
+    let offset = node_time.with_timezone(&chrono::Utc) - midpoint;
+    Ok(ClockReading {
+        offset_ms: offset.num_milliseconds(),
+        round_trip_ms: round_trip.num_milliseconds().max(0) as u64,
+        from_system_time,
+    })
+}
+
+fn build_time_sync_report(samples: &[NodeTimeSample], threshold_secs: u64) -> serde_json::Value {
+    let threshold_ms = threshold_secs as i64 * 1000;
+
+    let mut offsets: Vec<i64> = samples
+        .iter()
+        .filter_map(|s| s.result.as_ref().ok().map(|reading| reading.offset_ms))
+        .collect();
+    offsets.sort();
+    let median = offsets.get(offsets.len() / 2).copied();
+    let spread = match (offsets.first(), offsets.last()) {
+        (Some(min), Some(max)) => Some(max - min),
+        _ => None,
+    };
+
+    let mut in_sync = true;
+    let mut node_reports = Vec::new();
+    for sample in samples {
+        let ntp = if sample.ntp.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::Value::Object(sample.ntp.clone())
+        };
+        let ntp_unsynced = sample.ntp.values().any(|v| v.as_bool() == Some(false));
+        let report = match &sample.result {
+            Ok(reading) => {
+                let skew = reading.offset_ms - median.unwrap_or(reading.offset_ms);
+                let status = if skew.abs() > threshold_ms {
+                    in_sync = false;
+                    "SKEWED"
+                } else if ntp_unsynced {
+                    in_sync = false;
+                    "NTP_UNSYNCED"
+                } else {
+                    "OK"
+                };
+                // The Date header truncates to whole seconds
+                let resolution_ms = if reading.from_system_time { 0 } else { 1000 };
+                serde_json::json!({
+                    "node_uid": sample.node_uid,
+                    "addr": sample.addr,
+                    "offset_ms": reading.offset_ms,
+                    "skew_ms": skew,
+                    "round_trip_ms": reading.round_trip_ms,
+                    "uncertainty_ms": reading.round_trip_ms / 2 + resolution_ms,
+                    "time_source": if reading.from_system_time { "system_time" } else { "date_header" },
+                    "ntp": ntp,
+                    "status": status
+                })
+            }
+            Err(error) => {
+                in_sync = false;
+                serde_json::json!({
+                    "node_uid": sample.node_uid,
+                    "addr": sample.addr,
+                    "ntp": ntp,
+                    "status": "UNREACHABLE",
+                    "error": error
+                })
+            }
+        };
+        node_reports.push(report);
+    }
+
+    serde_json::json!({
+        "in_sync": in_sync,
+        "threshold_ms": threshold_ms,
+        "max_skew_ms": spread,
+        "total_nodes": samples.len(),
+        "nodes": node_reports
+    })
+}
+
 /// Combined cluster health check.
 ///
 /// Runs cluster status, balance verification, and rack-awareness verification,
@@ -1265,3 +1787,145 @@ pub async fn update_ocsp_config(
     print_formatted_output(data, output_format)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_api_url() {
+        assert_eq!(
+            node_api_url("10.0.0.4"),
+            ("https://10.0.0.4:9443".to_string(), "10.0.0.4".to_string())
+        );
+        assert_eq!(
+            node_api_url("node4:8443"),
+            ("https://node4:8443".to_string(), "node4".to_string())
+        );
+        assert_eq!(
+            node_api_url("https://node4:9443/"),
+            ("https://node4:9443".to_string(), "node4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_joined_node_readiness() {
+        use redisctl_core::PollStatus;
+        let known = [1, 2];
+
+        let nodes = serde_json::json!([{"uid": 1, "addr": "10.0.0.1", "status": "active"}]);
+        assert!(matches!(
+            joined_node_readiness(&nodes, "10.0.0.3", &known),
+            PollStatus::Pending(_)
+        ));
+
+        // Recognized by its new uid even when the address differs
+        let nodes = serde_json::json!([
+            {"uid": 1, "addr": "10.0.0.1", "status": "active"},
+            {"uid": 3, "addr": "172.16.0.3", "status": "provisioning"}
+        ]);
+        match joined_node_readiness(&nodes, "10.0.0.3", &known) {
+            PollStatus::Pending(msg) => assert_eq!(msg, "node 3 provisioning"),
+            _ => panic!("expected pending"),
+        }
+
+        let nodes = serde_json::json!([
+            {"uid": 3, "addr": "10.0.0.3", "status": "active", "accept_servers": false}
+        ]);
+        assert!(matches!(
+            joined_node_readiness(&nodes, "10.0.0.3", &known),
+            PollStatus::Pending(_)
+        ));
+
+        let nodes = serde_json::json!([
+            {"uid": 3, "addr": "10.0.0.3", "status": "active", "accept_servers": true}
+        ]);
+        match joined_node_readiness(&nodes, "10.0.0.3", &known) {
+            PollStatus::Ready(node) => assert_eq!(node["uid"], 3),
+            _ => panic!("expected ready"),
+        }
+    }
+
+    fn sample(node_uid: u64, result: Result<(i64, u64), String>) -> NodeTimeSample {
+        NodeTimeSample {
+            node_uid,
+            addr: format!("10.0.0.{}", node_uid),
+            ntp: serde_json::Map::new(),
+            result: result.map(|(offset_ms, round_trip_ms)| ClockReading {
+                offset_ms,
+                round_trip_ms,
+                from_system_time: true,
+            }),
+        }
+    }
+
+    #[test]
+    fn time_sync_flags_nodes_skewed_from_median() {
+        let samples = vec![
+            sample(1, Ok((120, 8))),
+            sample(2, Ok((-300, 6))),
+            sample(3, Ok((4100, 7))),
+        ];
+        let report = build_time_sync_report(&samples, 2);
+
+        assert_eq!(report["in_sync"], false);
+        assert_eq!(report["max_skew_ms"], 4400);
+        assert_eq!(report["nodes"][0]["status"], "OK");
+        assert_eq!(report["nodes"][0]["skew_ms"], 0);
+        assert_eq!(report["nodes"][1]["status"], "OK");
+        assert_eq!(report["nodes"][2]["status"], "SKEWED");
+        assert_eq!(report["nodes"][2]["skew_ms"], 3980);
+    }
+
+    #[test]
+    fn time_sync_reports_unreachable_nodes() {
+        let samples = vec![
+            sample(1, Ok((0, 5))),
+            sample(2, Err("connection refused".to_string())),
+        ];
+        let report = build_time_sync_report(&samples, 2);
+
+        assert_eq!(report["in_sync"], false);
+        assert_eq!(report["nodes"][1]["status"], "UNREACHABLE");
+        assert_eq!(report["nodes"][1]["error"], "connection refused");
+    }
+
+    #[test]
+    fn time_sync_flags_unsynced_ntp_and_date_header_resolution() {
+        let mut unsynced = sample(1, Ok((0, 10)));
+        unsynced
+            .ntp
+            .insert("ntp_synced".to_string(), serde_json::json!(false));
+        let mut from_header = sample(2, Ok((0, 10)));
+        if let Ok(reading) = &mut from_header.result {
+            reading.from_system_time = false;
+        }
+        let report = build_time_sync_report(&[unsynced, from_header], 2);
+
+        assert_eq!(report["in_sync"], false);
+        assert_eq!(report["nodes"][0]["status"], "NTP_UNSYNCED");
+        assert_eq!(report["nodes"][0]["ntp"]["ntp_synced"], false);
+        assert_eq!(report["nodes"][0]["uncertainty_ms"], 5);
+        assert_eq!(report["nodes"][1]["status"], "OK");
+        assert_eq!(report["nodes"][1]["ntp"], serde_json::Value::Null);
+        assert_eq!(report["nodes"][1]["time_source"], "date_header");
+        assert_eq!(report["nodes"][1]["uncertainty_ms"], 1005);
+    }
+
+    #[test]
+    fn url_host_brackets_ipv6() {
+        assert_eq!(url_host("10.0.0.1"), "10.0.0.1");
+        assert_eq!(url_host("fd00::1"), "[fd00::1]");
+        assert_eq!(url_host("[fd00::1]"), "[fd00::1]");
+    }
+
+    #[test]
+    fn api_scheme_and_port_defaults() {
+        assert_eq!(api_scheme_and_port("https://cluster:9443"), ("https", 9443));
+        assert_eq!(
+            api_scheme_and_port("https://cluster.example.com:8443/"),
+            ("https", 8443)
+        );
+        assert_eq!(api_scheme_and_port("https://cluster"), ("https", 9443));
+    }
+}
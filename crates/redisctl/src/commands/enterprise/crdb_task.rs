@@ -1,6 +1,12 @@
 use crate::error::RedisCtlError;
 use clap::Subcommand;
+use redis_enterprise::EnterpriseClient;
+use redis_enterprise::crdb_tasks::CrdbTasksHandler;
+use redisctl_core::{PollStatus, StatusCallback};
+use serde_json::{Value, json};
+use std::time::Duration;
 
+use super::utils::operation_spinner;
 use crate::cli::OutputFormat;
 use crate::connection::ConnectionManager;
 use crate::error::Result as CliResult;
@@ -29,9 +35,27 @@ pub enum CrdbTaskCommands {
     },
 
     /// Get task status
+    #[command(after_help = "EXAMPLES:
+    # Current status only
+    redisctl enterprise crdb-task status abc-123
+
+    # Follow the task to completion and show each participating cluster
+    redisctl enterprise crdb-task status abc-123 --wait")]
     Status {
         /// Task ID
         task_id: String,
+
+        /// Poll until the task finishes, then report per-cluster status
+        #[arg(long)]
+        wait: bool,
+
+        /// Maximum time to wait in seconds
+        #[arg(long, default_value = "600", requires = "wait")]
+        wait_timeout: u64,
+
+        /// Polling interval in seconds
+        #[arg(long, default_value = "5", requires = "wait")]
+        wait_interval: u64,
     },
 
     /// Get task progress information
@@ -163,7 +187,24 @@ impl CrdbTaskCommands {
                 super::utils::print_formatted_output(output_data, output_format)?;
             }
 
-            CrdbTaskCommands::Status { task_id } => {
+            CrdbTaskCommands::Status {
+                task_id,
+                wait: true,
+                wait_timeout,
+                wait_interval,
+            } => {
+                wait_for_task(
+                    &client,
+                    task_id,
+                    Duration::from_secs(*wait_timeout),
+                    Duration::from_secs(*wait_interval),
+                    output_format,
+                    query,
+                )
+                .await?;
+            }
+
+            CrdbTaskCommands::Status { task_id, .. } => {
                 let response: serde_json::Value = client
                     .get(&format!("/crdb_tasks/{}", task_id))
                     .await
@@ -346,6 +387,119 @@ impl CrdbTaskCommands {
     }
 }
 
+/// Task states after which the task will not change again
+const TERMINAL_STATES: &[&str] = &["finished", "failed", "canceled", "cancelled"];
+
+/// Poll a CRDB task until it reaches a terminal state, then print the outcome
+/// for each participating cluster. Fails if the task did not finish cleanly.
+async fn wait_for_task(
+    client: &EnterpriseClient,
+    task_id: &str,
+    timeout: Duration,
+    interval: Duration,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let handler = CrdbTasksHandler::new(client.clone());
+
+    let pb = operation_spinner(format!("Waiting for CRDB task {}", task_id));
+
+    let on_pending: StatusCallback = {
+        let pb = pb.clone();
+        let task_id = task_id.to_string();
+        Box::new(move |state, _| pb.set_message(format!("CRDB task {}: {}", task_id, state)))
+    };
+
+    let result = redisctl_core::poll_until(
+        timeout,
+        interval,
+        || async {
+            let task = handler.get(task_id).await?;
+            let task = serde_json::to_value(task).unwrap_or_default();
+            let status = task["status"].as_str().unwrap_or("unknown");
+            if TERMINAL_STATES.contains(&status) {
+                return Ok(PollStatus::Ready(task));
+            }
+            let state = match task["progress"].as_f64() {
+                Some(pct) => format!("{} ({:.0}%)", status, pct),
+                None => status.to_string(),
+            };
+            Ok(PollStatus::Pending(state))
+        },
+        Some(on_pending),
+    )
+    .await;
+    pb.finish_and_clear();
+    let task = result.map_err(RedisCtlError::from)?;
+
+    // Participating clusters come from the CRDB itself; the task only lists
+    // the clusters that reported errors.
+    let crdb = match task["crdb_guid"].as_str() {
+        Some(guid) => client.get_raw(&format!("/v1/crdbs/{}", guid)).await.ok(),
+        None => None,
+    };
+    let summary = task_summary(&task, crdb.as_ref());
+    let failed = summary["status"] != "finished";
+
+    let output_data = super::utils::handle_output(summary, output_format, query)?;
+    super::utils::print_formatted_output(output_data, output_format)?;
+
+    if failed {
+        return Err(RedisCtlError::ApiError {
+            message: format!(
+                "CRDB task {} ended with status '{}'",
+                task_id,
+                task["status"].as_str().unwrap_or("unknown")
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Summarize a finished CRDB task with one entry per participating cluster
+fn task_summary(task: &Value, crdb: Option<&Value>) -> Value {
+    let status = task["status"].as_str().unwrap_or("unknown");
+    let errors: Vec<&Value> = task["errors"].as_array().into_iter().flatten().collect();
+    let error_for = |cluster: &str| {
+        errors
+            .iter()
+            .find(|e| e["cluster_name"].as_str() == Some(cluster))
+            .and_then(|e| e["description"].as_str())
+    };
+
+    let mut clusters: Vec<String> = crdb
+        .and_then(|c| c["instances"].as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|i| i["cluster"]["name"].as_str().map(String::from))
+        .collect();
+    for e in &errors {
+        if let Some(name) = e["cluster_name"].as_str()
+            && !clusters.iter().any(|c| c == name)
+        {
+            clusters.push(name.to_string());
+        }
+    }
+
+    let clusters: Vec<Value> = clusters
+        .iter()
+        .map(|name| match error_for(name) {
+            Some(error) => json!({"cluster": name, "status": "failed", "error": error}),
+            None if matches!(status, "finished" | "failed") => {
+                json!({"cluster": name, "status": "ok"})
+            }
+            None => json!({"cluster": name, "status": status}),
+        })
+        .collect();
+
+    json!({
+        "task_id": task["task_id"].as_str().or(task["id"].as_str()),
+        "crdb_guid": task["crdb_guid"],
+        "status": status,
+        "clusters": clusters,
+    })
+}
+
 #[allow(dead_code)]
 pub async fn handle_crdb_task_command(
     conn_mgr: &ConnectionManager,
@@ -358,3 +512,43 @@ pub async fn handle_crdb_task_command(
         .execute(conn_mgr, profile_name, output_format, query)
         .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_summary_marks_failed_clusters() {
+        let task = json!({
+            "id": "t-1",
+            "crdb_guid": "guid-1",
+            "status": "failed",
+            "errors": [{"cluster_name": "east", "description": "instance unreachable"}]
+        });
+        let crdb = json!({
+            "instances": [
+                {"id": 1, "cluster": {"name": "west"}},
+                {"id": 2, "cluster": {"name": "east"}}
+            ]
+        });
+
+        let summary = task_summary(&task, Some(&crdb));
+        assert_eq!(summary["task_id"], "t-1");
+        assert_eq!(summary["status"], "failed");
+        assert_eq!(
+            summary["clusters"],
+            json!([
+                {"cluster": "west", "status": "ok"},
+                {"cluster": "east", "status": "failed", "error": "instance unreachable"}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_task_summary_finished_without_crdb() {
+        let task = json!({"task_id": "t-2", "status": "finished"});
+        let summary = task_summary(&task, None);
+        assert_eq!(summary["status"], "finished");
+        assert_eq!(summary["clusters"], json!([]));
+    }
+}
@@ -2,9 +2,12 @@
 
 #![allow(dead_code)]
 
-use crate::cli::{EnterpriseDatabaseCommands, OutputFormat};
+use crate::cli::{
+    EnterpriseDatabaseBackupCommands, EnterpriseDatabaseCommands, EnterpriseDatabaseTlsCommands,
+    OutputFormat,
+};
 use crate::connection::ConnectionManager;
-use crate::error::Result as CliResult;
+use crate::error::{RedisCtlError, Result as CliResult};
 
 use super::database_impl;
 
@@ -108,12 +111,182 @@ pub async fn handle_database_command(
         EnterpriseDatabaseCommands::Watch { id, poll_interval } => {
             database_impl::watch_database(conn_mgr, profile_name, *id, *poll_interval, query).await
         }
+        EnterpriseDatabaseCommands::WaitReady {
+            id,
+            timeout,
+            interval,
+        } => {
+            database_impl::wait_database_ready(
+                conn_mgr,
+                profile_name,
+                *id,
+                std::time::Duration::from_secs(*timeout),
+                std::time::Duration::from_secs(*interval),
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::WaitHealthy {
+            id,
+            max_lag,
+            timeout,
+            interval,
+        } => {
+            database_impl::wait_database_healthy(
+                conn_mgr,
+                profile_name,
+                *id,
+                *max_lag,
+                std::time::Duration::from_secs(*timeout),
+                std::time::Duration::from_secs(*interval),
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::ReplicaOf {
+            id,
+            source,
+            dry_run,
+            async_ops,
+        } => {
+            database_impl::set_database_replica_of(
+                conn_mgr,
+                profile_name,
+                *id,
+                source,
+                *dry_run,
+                async_ops,
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::MemoryBreakdown {
+            id,
+            samples,
+            host,
+            password,
+            insecure,
+            ca_cert,
+        } => {
+            database_impl::database_memory_breakdown(
+                conn_mgr,
+                profile_name,
+                *id,
+                *samples,
+                &database_impl::EndpointOptions {
+                    host: host.as_deref(),
+                    password: password.as_deref(),
+                    insecure: *insecure,
+                    ca_cert: ca_cert.as_deref(),
+                },
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::Checksum {
+            id,
+            host,
+            password,
+            insecure,
+            ca_cert,
+        } => {
+            database_impl::database_checksum(
+                conn_mgr,
+                profile_name,
+                *id,
+                &database_impl::EndpointOptions {
+                    host: host.as_deref(),
+                    password: password.as_deref(),
+                    insecure: *insecure,
+                    ca_cert: ca_cert.as_deref(),
+                },
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::Tls(tls_cmd) => match tls_cmd {
+            EnterpriseDatabaseTlsCommands::Enable {
+                id,
+                require_client_cert,
+                ca,
+            } => {
+                database_impl::enable_database_tls(
+                    conn_mgr,
+                    profile_name,
+                    *id,
+                    *require_client_cert,
+                    ca,
+                    output_format,
+                    query,
+                )
+                .await
+            }
+            EnterpriseDatabaseTlsCommands::Disable { id } => {
+                database_impl::disable_database_tls(
+                    conn_mgr,
+                    profile_name,
+                    *id,
+                    output_format,
+                    query,
+                )
+                .await
+            }
+        },
+        EnterpriseDatabaseCommands::Conns {
+            id,
+            host,
+            password,
+            insecure,
+            ca_cert,
+        } => {
+            database_impl::list_database_connections(
+                conn_mgr,
+                profile_name,
+                *id,
+                &database_impl::EndpointOptions {
+                    host: host.as_deref(),
+                    password: password.as_deref(),
+                    insecure: *insecure,
+                    ca_cert: ca_cert.as_deref(),
+                },
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::Export {
+            id,
+            location: Some(location),
+            local: Some(local),
+            local_mount,
+            async_ops,
+            ..
+        } => {
+            database_impl::export_database_to_local(
+                conn_mgr,
+                profile_name,
+                *id,
+                location,
+                local,
+                local_mount.as_deref(),
+                async_ops,
+                output_format,
+            )
+            .await
+        }
         EnterpriseDatabaseCommands::Export {
             id,
             location,
             aws_access_key,
             aws_secret_key,
             data,
+            async_ops,
+            ..
         } => {
             database_impl::export_database(
                 conn_mgr,
@@ -123,6 +296,7 @@ pub async fn handle_database_command(
                 aws_access_key.as_deref(),
                 aws_secret_key.as_deref(),
                 data.as_deref(),
+                async_ops,
                 output_format,
                 query,
             )
@@ -135,6 +309,9 @@ pub async fn handle_database_command(
             aws_secret_key,
             flush,
             data,
+            verify_checksum,
+            expected_keys,
+            expected_checksum,
             async_ops,
         } => {
             database_impl::import_database(
@@ -146,27 +323,82 @@ pub async fn handle_database_command(
                 aws_secret_key.as_deref(),
                 *flush,
                 data.as_deref(),
+                *verify_checksum,
+                *expected_keys,
+                expected_checksum.as_deref(),
                 async_ops,
                 output_format,
                 query,
             )
             .await
         }
-        EnterpriseDatabaseCommands::Backup { id, async_ops } => {
-            database_impl::backup_database(
+        EnterpriseDatabaseCommands::ExportConfig { id, file } => {
+            database_impl::export_database_config(
                 conn_mgr,
                 profile_name,
                 *id,
-                async_ops,
+                file.as_deref(),
                 output_format,
                 query,
             )
             .await
         }
+        EnterpriseDatabaseCommands::ImportConfig {
+            file,
+            name,
+            port,
+            redis_password,
+            dry_run,
+        } => {
+            database_impl::import_database_config(
+                conn_mgr,
+                profile_name,
+                file,
+                name.as_deref(),
+                *port,
+                redis_password.as_deref(),
+                *dry_run,
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::Backup {
+            command,
+            id,
+            async_ops,
+        } => match (command, id) {
+            (Some(EnterpriseDatabaseBackupCommands::List { id }), _) => {
+                database_impl::list_database_backups(
+                    conn_mgr,
+                    profile_name,
+                    *id,
+                    output_format,
+                    query,
+                )
+                .await
+            }
+            (None, Some(id)) => {
+                database_impl::backup_database(
+                    conn_mgr,
+                    profile_name,
+                    *id,
+                    async_ops,
+                    output_format,
+                    query,
+                )
+                .await
+            }
+            (None, None) => Err(RedisCtlError::InvalidInput {
+                message: "Database ID is required".to_string(),
+            }),
+        },
         EnterpriseDatabaseCommands::Restore {
             id,
             backup_uid,
             data,
+            force,
+            async_ops,
         } => {
             database_impl::restore_database(
                 conn_mgr,
@@ -174,14 +406,51 @@ pub async fn handle_database_command(
                 *id,
                 backup_uid.as_deref(),
                 data.as_deref(),
+                *force,
+                async_ops,
                 output_format,
                 query,
             )
             .await
         }
-        EnterpriseDatabaseCommands::Flush { id, force } => {
-            database_impl::flush_database(conn_mgr, profile_name, *id, *force, output_format, query)
-                .await
+        EnterpriseDatabaseCommands::Recover {
+            id,
+            force,
+            async_ops,
+        } => {
+            database_impl::recover_database(
+                conn_mgr,
+                profile_name,
+                *id,
+                *force,
+                async_ops,
+                output_format,
+                query,
+            )
+            .await
+        }
+        EnterpriseDatabaseCommands::Flush {
+            id,
+            force,
+            no_wait,
+            wait_timeout,
+            wait_interval,
+        } => {
+            let async_ops = crate::commands::cloud::async_utils::AsyncOperationArgs {
+                wait: !*no_wait,
+                wait_timeout: *wait_timeout,
+                wait_interval: *wait_interval,
+            };
+            database_impl::flush_database(
+                conn_mgr,
+                profile_name,
+                *id,
+                *force,
+                &async_ops,
+                output_format,
+                query,
+            )
+            .await
         }
         EnterpriseDatabaseCommands::GetShards { id } => {
             database_impl::get_database_shards(conn_mgr, profile_name, *id, output_format, query)
@@ -291,12 +560,27 @@ pub async fn handle_database_command(
             )
             .await
         }
-        EnterpriseDatabaseCommands::Slowlog { id, limit } => {
+        EnterpriseDatabaseCommands::Slowlog {
+            id,
+            limit,
+            reset,
+            host,
+            password,
+            insecure,
+            ca_cert,
+        } => {
             database_impl::get_database_slowlog(
                 conn_mgr,
                 profile_name,
                 *id,
                 *limit,
+                *reset,
+                &database_impl::EndpointOptions {
+                    host: host.as_deref(),
+                    password: password.as_deref(),
+                    insecure: *insecure,
+                    ca_cert: ca_cert.as_deref(),
+                },
                 output_format,
                 query,
             )
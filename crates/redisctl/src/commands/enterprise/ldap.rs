@@ -54,8 +54,29 @@ pub enum LdapCommands {
     /// Delete LDAP configuration
     Delete,
 
-    /// Test LDAP connection
+    /// Test LDAP connection, or authenticate a user through the configured LDAP
+    #[command(after_help = "EXAMPLES:
+    # Check that a directory user can log in and see which roles they get
+    redisctl enterprise ldap test --username jdoe
+
+    # Non-interactive (password on the command line)
+    redisctl enterprise ldap test --username jdoe --password 'secret' -o json
+
+    # Test the LDAP server connection with a configuration payload
+    redisctl enterprise ldap test --data @ldap-test.json
+
+NOTES:
+    With --username the cluster authenticates the user exactly as it would on
+    login (bind + group lookup) and issues a short-lived token that is
+    discarded. Nothing is created or changed. The command exits non-zero when
+    authentication fails.")]
     Test {
+        /// Directory username to authenticate through the cluster's LDAP
+        #[arg(long, conflicts_with = "data")]
+        username: Option<String>,
+        /// Password for --username (prompted if omitted)
+        #[arg(long, requires = "username")]
+        password: Option<String>,
         /// Optional test configuration JSON
         #[arg(long)]
         data: Option<String>,
@@ -177,7 +198,22 @@ pub async fn handle_ldap_command(
         LdapCommands::Delete => {
             handle_ldap_delete(conn_mgr, profile_name, output_format, query).await
         }
-        LdapCommands::Test { data } => {
+        LdapCommands::Test {
+            username: Some(username),
+            password,
+            ..
+        } => {
+            handle_ldap_auth_test(
+                conn_mgr,
+                profile_name,
+                &username,
+                password,
+                output_format,
+                query,
+            )
+            .await
+        }
+        LdapCommands::Test { data, .. } => {
             handle_ldap_test(
                 conn_mgr,
                 profile_name,
@@ -387,6 +423,101 @@ async fn handle_ldap_test(
     utils::print_formatted_output(result, output_format)
 }
 
+/// Authenticate a user through the cluster's LDAP integration.
+///
+/// Uses the JWT authorize endpoint, which performs the same bind and group
+/// lookup as a real login. The issued token is only inspected for the roles
+/// it grants and is never printed.
+async fn handle_ldap_auth_test(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    username: &str,
+    password: Option<String>,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> Result<(), RedisCtlError> {
+    let password = match password {
+        Some(p) => p,
+        None => rpassword::prompt_password(format!("LDAP password for {}: ", username))
+            .context("Failed to read password")?,
+    };
+
+    let client = conn_mgr.create_enterprise_client(profile_name).await?;
+
+    let payload = serde_json::json!({
+        "username": username,
+        "password": password,
+    });
+
+    let (authenticated, report) = match client.post_raw("/v1/users/authorize", payload).await {
+        Ok(response) => {
+            let claims = response
+                .get("access_token")
+                .and_then(Value::as_str)
+                .and_then(jwt_claims);
+            (
+                true,
+                serde_json::json!({
+                    "username": username,
+                    "authenticated": true,
+                    "roles": claims.as_ref().map(roles_from_claims),
+                }),
+            )
+        }
+        Err(redis_enterprise::RestError::Unauthorized) => (
+            false,
+            serde_json::json!({
+                "username": username,
+                "authenticated": false,
+                "error": "Invalid credentials, or the user is not in a mapped LDAP group",
+            }),
+        ),
+        Err(e) => return Err(RedisCtlError::from(e)),
+    };
+
+    let result = if let Some(q) = query {
+        utils::apply_jmespath(&report, q)?
+    } else {
+        report
+    };
+    utils::print_formatted_output(result, output_format)?;
+
+    if authenticated {
+        Ok(())
+    } else {
+        Err(RedisCtlError::AuthenticationFailed {
+            message: format!("LDAP authentication failed for '{}'", username),
+            profile_name: profile_name.unwrap_or("<default>").to_string(),
+        })
+    }
+}
+
+/// Decode the (unverified) claims section of a JWT.
+fn jwt_claims(token: &str) -> Option<Value> {
+    use base64::Engine;
+
+    let payload = token.split('.').nth(1)?;
+    let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// Collect role information from token claims (any claim whose name mentions
+/// "role", e.g. `roles`, `role`, `role_uids`).
+fn roles_from_claims(claims: &Value) -> Value {
+    let roles: serde_json::Map<String, Value> = claims
+        .as_object()
+        .map(|obj| {
+            obj.iter()
+                .filter(|(k, _)| k.to_ascii_lowercase().contains("role"))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    Value::Object(roles)
+}
+
 // LDAP Mappings handlers
 async fn handle_mappings_list(
     conn_mgr: &ConnectionManager,
@@ -628,3 +759,32 @@ mod tests {
         TestCli::command().debug_assert();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jwt_claims_roles() {
+        use base64::Engine;
+
+        let claims = serde_json::json!({"username": "jdoe", "role": "db_viewer", "exp": 1});
+        let token = format!(
+            "e30.{}.sig",
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+
+        let decoded = jwt_claims(&token).unwrap();
+        assert_eq!(decoded["username"], "jdoe");
+        assert_eq!(
+            roles_from_claims(&decoded),
+            serde_json::json!({"role": "db_viewer"})
+        );
+    }
+
+    #[test]
+    fn test_jwt_claims_rejects_garbage() {
+        assert!(jwt_claims("not-a-token").is_none());
+        assert!(jwt_claims("a.!!!.c").is_none());
+    }
+}
@@ -4,6 +4,7 @@ use redisctl_core::Config;
 use serde_json::Value;
 
 use crate::cli::OutputFormat;
+use crate::commands::batch::BatchResults;
 
 #[derive(Debug, Subcommand)]
 pub enum LicenseWorkflowCommands {
@@ -90,7 +91,7 @@ async fn handle_license_audit(
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> AnyhowResult<()> {
-    let mut audit_results = Vec::new();
+    let mut audit_results = BatchResults::new();
     let conn_manager = crate::connection::ConnectionManager::new(config.clone());
 
     // Get all enterprise profiles
@@ -127,7 +128,7 @@ async fn handle_license_audit(
                             continue;
                         }
 
-                        audit_results.push(serde_json::json!({
+                        audit_results.success(serde_json::json!({
                             "profile": profile_name,
                             "cluster_name": license.get("cluster_name").and_then(|v| v.as_str()).unwrap_or("unknown"),
                             "expiration_date": expiration_date,
@@ -148,25 +149,31 @@ async fn handle_license_audit(
                         }));
                     }
                     Err(e) => {
-                        audit_results.push(serde_json::json!({
-                            "profile": profile_name,
-                            "error": format!("Failed to get license: {}", e),
-                            "status": "ERROR"
-                        }));
+                        audit_results.failure(
+                            serde_json::json!({
+                                "profile": profile_name,
+                                "status": "ERROR",
+                                "message": "Failed to get license"
+                            }),
+                            e,
+                        );
                     }
                 }
             }
             Err(e) => {
-                audit_results.push(serde_json::json!({
-                    "profile": profile_name,
-                    "error": format!("Failed to connect: {}", e),
-                    "status": "ERROR"
-                }));
+                audit_results.failure(
+                    serde_json::json!({
+                        "profile": profile_name,
+                        "status": "ERROR",
+                        "message": "Failed to connect"
+                    }),
+                    e,
+                );
             }
         }
     }
 
-    let response = Value::Array(audit_results);
+    let response = audit_results.into_value();
     let response = if let Some(q) = query {
         super::utils::apply_jmespath(&response, q)?
     } else {
@@ -199,11 +206,11 @@ async fn handle_bulk_update(
         profiles.split(',').map(|s| s.trim().to_string()).collect()
     };
 
-    let mut update_results = Vec::new();
+    let mut update_results = BatchResults::new();
 
     for profile_name in target_profiles {
         if !config.profiles.contains_key(&profile_name) {
-            update_results.push(serde_json::json!({
+            update_results.skipped(serde_json::json!({
                 "profile": profile_name,
                 "status": "SKIPPED",
                 "message": "Profile not found"
@@ -212,7 +219,7 @@ async fn handle_bulk_update(
         }
 
         if dry_run {
-            update_results.push(serde_json::json!({
+            update_results.skipped(serde_json::json!({
                 "profile": profile_name,
                 "status": "DRY_RUN",
                 "message": "Would update license"
@@ -224,32 +231,38 @@ async fn handle_bulk_update(
             {
                 Ok(client) => match client.put::<_, Value>("/v1/license", &license_data).await {
                     Ok(_) => {
-                        update_results.push(serde_json::json!({
+                        update_results.success(serde_json::json!({
                             "profile": profile_name,
                             "status": "SUCCESS",
                             "message": "License updated successfully"
                         }));
                     }
                     Err(e) => {
-                        update_results.push(serde_json::json!({
-                            "profile": profile_name,
-                            "status": "FAILED",
-                            "message": format!("Failed to update license: {}", e)
-                        }));
+                        update_results.failure(
+                            serde_json::json!({
+                                "profile": profile_name,
+                                "status": "FAILED",
+                                "message": "Failed to update license"
+                            }),
+                            e,
+                        );
                     }
                 },
                 Err(e) => {
-                    update_results.push(serde_json::json!({
-                        "profile": profile_name,
-                        "status": "FAILED",
-                        "message": format!("Failed to connect: {}", e)
-                    }));
+                    update_results.failure(
+                        serde_json::json!({
+                            "profile": profile_name,
+                            "status": "FAILED",
+                            "message": "Failed to connect"
+                        }),
+                        e,
+                    );
                 }
             }
         }
     }
 
-    let response = Value::Array(update_results);
+    let response = update_results.into_value();
     let response = if let Some(q) = query {
         super::utils::apply_jmespath(&response, q)?
     } else {
@@ -468,9 +481,13 @@ async fn handle_license_monitor(
     super::utils::print_formatted_output(response.clone(), output_format)
         .map_err(|e| anyhow::anyhow!(e))?;
 
-    // Exit with error code if requested
+    // Fail the command if requested
     if fail_on_warning && (!warnings.is_empty() || !errors.is_empty()) {
-        std::process::exit(1);
+        anyhow::bail!(
+            "License check reported {} warning(s) and {} error(s)",
+            warnings.len(),
+            errors.len()
+        );
     }
 
     Ok(())
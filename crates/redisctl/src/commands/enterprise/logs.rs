@@ -1,4 +1,5 @@
 use clap::Subcommand;
+use std::path::PathBuf;
 
 #[derive(Debug, Subcommand)]
 pub enum LogsCommands {
@@ -49,4 +50,43 @@ pub enum LogsCommands {
         #[arg(long, default_value = "2", requires = "follow")]
         poll_interval: u64,
     },
+
+    /// Export all cluster event logs in a time range to a file
+    #[command(after_help = "EXAMPLES:
+    # Export the last 30 days of logs as gzipped NDJSON
+    redisctl enterprise logs export --since 30d --file logs.jsonl.gz --compress
+
+    # Export a fixed window as plain NDJSON
+    redisctl enterprise logs export --since 2024-01-01T00:00:00Z --until 2024-02-01T00:00:00Z --file logs.jsonl
+
+    # Export as a single JSON array
+    redisctl enterprise logs export --since 7d --file logs.json --format json
+
+Time values accept ISO 8601 timestamps or relative durations (e.g. 30m, 12h, 7d, 2w).
+")]
+    Export {
+        /// Start time (ISO 8601 or relative, e.g. 30d)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// End time (ISO 8601 or relative, e.g. 1h)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Output file path
+        #[arg(long, short = 'f')]
+        file: PathBuf,
+
+        /// File format (json-lines writes one event per line)
+        #[arg(long, default_value = "json-lines", value_parser = ["json-lines", "json"])]
+        format: String,
+
+        /// Compress the output with gzip
+        #[arg(long)]
+        compress: bool,
+
+        /// Number of events to fetch per request
+        #[arg(long, default_value = "1000")]
+        page_size: u32,
+    },
 }
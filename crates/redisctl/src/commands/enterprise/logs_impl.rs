@@ -7,8 +7,13 @@ use crate::cli::OutputFormat;
 use crate::commands::enterprise::logs::LogsCommands;
 use crate::connection::ConnectionManager;
 use crate::error::Result as CliResult;
+use flate2::Compression;
+use flate2::write::GzEncoder;
 use futures::StreamExt;
 use redis_enterprise::logs::LogsQuery;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tokio::signal;
 
@@ -23,6 +28,50 @@ struct LogListParams {
     poll_interval: u64,
 }
 
+/// Parameters for log export operation
+struct LogExportParams {
+    since: Option<String>,
+    until: Option<String>,
+    file: PathBuf,
+    format: String,
+    compress: bool,
+    page_size: u32,
+}
+
+/// Resolve a time argument to an ISO 8601 timestamp.
+///
+/// Accepts either an absolute timestamp (passed through unchanged) or a relative
+/// duration such as `30m`, `12h`, `7d` or `2w`, interpreted as "that long ago".
+fn parse_time_arg(value: &str) -> CliResult<String> {
+    let value = value.trim();
+    let split = value.char_indices().last().map_or(0, |(i, _)| i);
+    let (amount, unit) = value.split_at(split);
+
+    let Ok(amount) = amount.parse::<i64>() else {
+        return Ok(value.to_string());
+    };
+
+    let delta = match unit {
+        "s" => chrono::Duration::seconds(amount),
+        "m" => chrono::Duration::minutes(amount),
+        "h" => chrono::Duration::hours(amount),
+        "d" => chrono::Duration::days(amount),
+        "w" => chrono::Duration::weeks(amount),
+        _ => {
+            return Err(RedisCtlError::InvalidInput {
+                message: format!(
+                    "Invalid time '{}'. Use ISO 8601 or a relative duration like 30m, 12h, 7d, 2w",
+                    value
+                ),
+            });
+        }
+    };
+
+    Ok((chrono::Utc::now() - delta)
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string())
+}
+
 pub async fn handle_logs_commands(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
@@ -51,6 +100,24 @@ pub async fn handle_logs_commands(
             };
             handle_list_logs(conn_mgr, profile_name, params, output_format, query).await
         }
+        LogsCommands::Export {
+            since,
+            until,
+            file,
+            format,
+            compress,
+            page_size,
+        } => {
+            let params = LogExportParams {
+                since: since.clone(),
+                until: until.clone(),
+                file: file.clone(),
+                format: format.clone(),
+                compress: *compress,
+                page_size: *page_size,
+            };
+            handle_export_logs(conn_mgr, profile_name, params).await
+        }
     }
 }
 
@@ -69,6 +136,11 @@ async fn handle_list_logs(
         return handle_stream_logs(handler, params, output_format, query).await;
     }
 
+    // JSON Lines are written page by page instead of collecting every event
+    if crate::output::streams_jsonl(output_format, query) {
+        return list_logs_jsonl(&handler, params).await;
+    }
+
     // Normal (non-streaming) mode
     let logs_query = if params.since.is_some()
         || params.until.is_some()
@@ -77,8 +149,8 @@ async fn handle_list_logs(
         || params.offset.is_some()
     {
         Some(LogsQuery {
-            stime: params.since,
-            etime: params.until,
+            stime: params.since.as_deref().map(parse_time_arg).transpose()?,
+            etime: params.until.as_deref().map(parse_time_arg).transpose()?,
             order: params.order,
             limit: params.limit,
             offset: params.offset,
@@ -108,29 +180,208 @@ async fn handle_list_logs(
     Ok(())
 }
 
+/// Events fetched per request when listing logs as JSON Lines
+const JSONL_PAGE_SIZE: u32 = 1000;
+
+/// Page through the events matching `params`, writing each page as JSON Lines
+/// as soon as it arrives
+async fn list_logs_jsonl(
+    handler: &redis_enterprise::LogsHandler,
+    params: LogListParams,
+) -> CliResult<()> {
+    let stime = params.since.as_deref().map(parse_time_arg).transpose()?;
+    let etime = params.until.as_deref().map(parse_time_arg).transpose()?;
+
+    let mut offset = params.offset.unwrap_or(0);
+    let mut remaining = params.limit;
+    loop {
+        let page_size = remaining.map_or(JSONL_PAGE_SIZE, |r| r.min(JSONL_PAGE_SIZE));
+        if page_size == 0 {
+            break;
+        }
+
+        let page = handler
+            .list(Some(LogsQuery {
+                stime: stime.clone(),
+                etime: etime.clone(),
+                order: params.order.clone(),
+                limit: Some(page_size),
+                offset: Some(offset),
+            }))
+            .await
+            .map_err(RedisCtlError::from)?;
+
+        let count = page.len() as u32;
+        crate::output::write_jsonl_items(&page).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        })?;
+
+        if count < page_size {
+            break;
+        }
+        offset += count;
+        remaining = remaining.map(|r| r - count);
+    }
+
+    Ok(())
+}
+
+async fn handle_export_logs(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    params: LogExportParams,
+) -> CliResult<()> {
+    if params.page_size == 0 {
+        return Err(RedisCtlError::InvalidInput {
+            message: "--page-size must be greater than 0".to_string(),
+        });
+    }
+
+    let stime = params.since.as_deref().map(parse_time_arg).transpose()?;
+    let etime = params.until.as_deref().map(parse_time_arg).transpose()?;
+
+    let client = conn_mgr.create_enterprise_client(profile_name).await?;
+    let handler = redis_enterprise::LogsHandler::new(client);
+
+    let mut writer = open_export_writer(&params.file, params.compress)?;
+    let json_array = params.format == "json";
+    let file_error = |e: std::io::Error| RedisCtlError::FileError {
+        path: params.file.display().to_string(),
+        message: e.to_string(),
+    };
+
+    if json_array {
+        writer.write_all(b"[").map_err(file_error)?;
+    }
+
+    // Page through the range oldest-first, writing each page as it arrives
+    let mut offset: u32 = 0;
+    let mut total: u64 = 0;
+    loop {
+        let page = handler
+            .list(Some(LogsQuery {
+                stime: stime.clone(),
+                etime: etime.clone(),
+                order: Some("asc".to_string()),
+                limit: Some(params.page_size),
+                offset: Some(offset),
+            }))
+            .await
+            .map_err(RedisCtlError::from)?;
+
+        let count = page.len() as u32;
+        for entry in &page {
+            if json_array && total > 0 {
+                writer.write_all(b",").map_err(file_error)?;
+            }
+            serde_json::to_writer(&mut writer, entry)?;
+            if !json_array {
+                writer.write_all(b"\n").map_err(file_error)?;
+            }
+            total += 1;
+        }
+
+        if count < params.page_size {
+            break;
+        }
+        offset += count;
+    }
+
+    if json_array {
+        writer.write_all(b"]\n").map_err(file_error)?;
+    }
+    writer.finish().map_err(file_error)?;
+
+    eprintln!("Exported {} log events to {}", total, params.file.display());
+
+    Ok(())
+}
+
+/// Destination of a log export, optionally gzip-compressed
+enum ExportWriter {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl ExportWriter {
+    /// Write the gzip trailer (when compressing) and flush the file.
+    ///
+    /// Dropping the writer instead would swallow these errors and could leave
+    /// a truncated archive behind.
+    fn finish(self) -> std::io::Result<()> {
+        match self {
+            ExportWriter::Plain(mut file) => file.flush(),
+            ExportWriter::Gzip(encoder) => encoder.finish()?.flush(),
+        }
+    }
+}
+
+impl Write for ExportWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            ExportWriter::Plain(file) => file.write(buf),
+            ExportWriter::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            ExportWriter::Plain(file) => file.flush(),
+            ExportWriter::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+/// Open the export destination, wrapping it in a gzip encoder when requested.
+fn open_export_writer(path: &Path, compress: bool) -> CliResult<ExportWriter> {
+    let file = File::create(path).map_err(|e| RedisCtlError::FileError {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    })?;
+    let buffered = BufWriter::new(file);
+
+    if compress {
+        Ok(ExportWriter::Gzip(GzEncoder::new(
+            buffered,
+            Compression::default(),
+        )))
+    } else {
+        Ok(ExportWriter::Plain(buffered))
+    }
+}
+
 async fn handle_stream_logs(
     handler: redis_enterprise::LogsHandler,
     params: LogListParams,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
-    // Only support table/auto format for streaming (JSON/YAML don't make sense for streams)
-    if !matches!(output_format, OutputFormat::Auto | OutputFormat::Table) {
+    // Only table and JSON Lines suit an open-ended stream (JSON/YAML documents never end)
+    if !matches!(
+        output_format,
+        OutputFormat::Auto | OutputFormat::Table | OutputFormat::Jsonl
+    ) {
         return Err(RedisCtlError::InvalidInput {
-            message: "Streaming logs (--follow) only supports table output format".to_string(),
+            message: "Streaming logs (--follow) only supports table or jsonl output format"
+                .to_string(),
         });
     }
+    let jsonl = matches!(output_format, OutputFormat::Jsonl);
 
     let poll_interval = Duration::from_secs(params.poll_interval);
     let mut stream = handler.stream_logs(poll_interval, params.limit);
 
-    println!("Streaming logs (Ctrl+C to stop)...\n");
+    if !jsonl {
+        println!("Streaming logs (Ctrl+C to stop)...\n");
+    }
 
     loop {
         tokio::select! {
             // Handle Ctrl+C
             _ = signal::ctrl_c() => {
-                println!("\nStopping log stream...");
+                if !jsonl {
+                    println!("\nStopping log stream...");
+                }
                 break;
             }
             // Handle next log entry
@@ -148,6 +399,15 @@ async fn handle_stream_logs(
                         };
 
                         // Print each entry as it arrives
+                        if jsonl {
+                            crate::output::write_jsonl_items([output_data]).map_err(|e| {
+                                RedisCtlError::OutputError {
+                                    message: e.to_string(),
+                                }
+                            })?;
+                            continue;
+                        }
+
                         // For table format, print a simple formatted line
                         if let Some(time) = output_data.get("time").and_then(|t| t.as_str()) {
                             let event_type = output_data.get("type")
@@ -183,3 +443,30 @@ async fn handle_stream_logs(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_arg_passes_through_absolute() {
+        assert_eq!(
+            parse_time_arg("2024-01-01T00:00:00Z").unwrap(),
+            "2024-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_parse_time_arg_relative() {
+        let parsed = parse_time_arg("30d").unwrap();
+        let ts = chrono::DateTime::parse_from_rfc3339(&parsed).unwrap();
+        let age = chrono::Utc::now().signed_duration_since(ts);
+        assert!(age >= chrono::Duration::days(30));
+        assert!(age < chrono::Duration::days(30) + chrono::Duration::minutes(1));
+    }
+
+    #[test]
+    fn test_parse_time_arg_invalid_unit() {
+        assert!(parse_time_arg("30y").is_err());
+    }
+}
@@ -29,6 +29,7 @@ pub mod module_impl;
 pub mod node;
 pub mod node_impl;
 pub mod ocsp;
+pub mod prometheus;
 pub mod proxy;
 pub mod rbac;
 pub mod rbac_impl;
@@ -6,7 +6,7 @@ use crate::error::RedisCtlError;
 use crate::cli::OutputFormat;
 use crate::commands::enterprise::module::ModuleCommands;
 use crate::commands::enterprise::utils::{
-    DetailRow, extract_field, output_with_pager, resolve_auto, truncate_string,
+    DetailRow, extract_field, output_with_pager, truncate_string, use_custom_table,
 };
 use crate::connection::ConnectionManager;
 use crate::error::Result as CliResult;
@@ -107,7 +107,7 @@ async fn handle_list(
         modules_json
     };
 
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_modules_table(&output_data)?;
     } else {
         crate::commands::enterprise::utils::print_formatted_output(output_data, output_format)?;
@@ -210,7 +210,7 @@ async fn handle_get(
         module_json
     };
 
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_module_detail(&output_data)?;
     } else {
         crate::commands::enterprise::utils::print_formatted_output(output_data, output_format)?;
@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use std::time::Duration;
+
 use crate::cli::{EnterpriseNodeCommands, OutputFormat};
 use crate::connection::ConnectionManager;
 use crate::error::Result as CliResult;
@@ -41,8 +43,25 @@ pub async fn handle_node_command(
             )
             .await
         }
-        EnterpriseNodeCommands::Remove { id, force } => {
-            node_impl::remove_node(conn_mgr, profile_name, *id, *force, output_format, query).await
+        EnterpriseNodeCommands::Remove {
+            id,
+            force,
+            drain_first,
+            wait_timeout,
+            wait_interval,
+        } => {
+            node_impl::remove_node(
+                conn_mgr,
+                profile_name,
+                *id,
+                *force,
+                *drain_first,
+                Duration::from_secs(*wait_timeout),
+                Duration::from_secs(*wait_interval),
+                output_format,
+                query,
+            )
+            .await
         }
         EnterpriseNodeCommands::Update {
             id,
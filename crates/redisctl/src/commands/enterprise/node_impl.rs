@@ -2,10 +2,13 @@
 
 #![allow(dead_code)]
 
+use std::time::Duration;
+
 use crate::cli::OutputFormat;
 use crate::connection::ConnectionManager;
-use crate::error::Result as CliResult;
+use crate::error::{RedisCtlError, Result as CliResult};
 use anyhow::Context;
+use redis_enterprise::EnterpriseClient;
 use redis_enterprise::nodes::NodeHandler;
 use serde_json::Value;
 use tabled::{Table, Tabled, settings::Style};
@@ -42,7 +45,7 @@ pub async fn list_nodes(
     let nodes = handler.list().await?;
     let nodes_json = serde_json::to_value(nodes).context("Failed to serialize nodes")?;
     let data = handle_output(nodes_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_nodes_table(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -62,7 +65,7 @@ pub async fn get_node(
     let node = handler.get(id).await?;
     let node_json = serde_json::to_value(node).context("Failed to serialize node")?;
     let data = handle_output(node_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_node_detail(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -198,23 +201,168 @@ pub async fn add_node(
     Ok(())
 }
 
+/// Minimum number of nodes a cluster should keep to maintain quorum
+const MIN_SAFE_NODES: usize = 3;
+
+/// Count the master and replica shards placed on a node
+fn shards_on_node(shards: &Value, node_id: u32) -> (usize, usize) {
+    let mut masters = 0;
+    let mut replicas = 0;
+    for shard in shards.as_array().into_iter().flatten() {
+        // node_uid may be serialized as a string or number depending on API version
+        let node = shard["node_uid"]
+            .as_u64()
+            .or_else(|| shard["node_uid"].as_str().and_then(|s| s.parse().ok()));
+        if node != Some(node_id as u64) {
+            continue;
+        }
+        match shard["role"].as_str() {
+            Some("master") => masters += 1,
+            _ => replicas += 1,
+        }
+    }
+    (masters, replicas)
+}
+
+/// Run a node action and poll it to completion with a spinner
+async fn run_node_action(
+    client: &EnterpriseClient,
+    id: u32,
+    action: &str,
+    label: &str,
+    timeout: Duration,
+    interval: Duration,
+) -> CliResult<()> {
+    let response = client
+        .post_raw(&format!("/v1/nodes/{}/actions/{}", id, action), Value::Null)
+        .await
+        .map_err(RedisCtlError::from)?;
+
+    let Some(action_uid) = response.get("action_uid").and_then(|v| v.as_str()) else {
+        return Ok(());
+    };
+
+    let pb = operation_spinner(format!("{} node {}", label, id));
+
+    let progress_callback = Some(action_progress(&pb, label));
+
+    let result = redisctl_core::enterprise::poll_action(
+        client,
+        action_uid,
+        timeout,
+        interval,
+        progress_callback,
+    )
+    .await;
+
+    pb.finish();
+
+    result.map_err(RedisCtlError::from)?;
+    Ok(())
+}
+
+/// Remove a node from the cluster after verifying it is safe to do so
+#[allow(clippy::too_many_arguments)]
 pub async fn remove_node(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
     id: u32,
     force: bool,
-    _output_format: OutputFormat,
-    _query: Option<&str>,
+    drain_first: bool,
+    timeout: Duration,
+    interval: Duration,
+    output_format: OutputFormat,
+    query: Option<&str>,
 ) -> CliResult<()> {
-    if !force && !confirm_action(&format!("Remove node {} from cluster?", id))? {
+    let client = conn_mgr.create_enterprise_client(profile_name).await?;
+
+    let nodes = client
+        .get_raw("/v1/nodes")
+        .await
+        .map_err(RedisCtlError::from)?;
+    let node_count = nodes.as_array().map(|n| n.len()).unwrap_or(0);
+    let node_exists = nodes
+        .as_array()
+        .into_iter()
+        .flatten()
+        .any(|n| n["uid"].as_u64() == Some(id as u64));
+    if !node_exists {
+        return Err(RedisCtlError::InvalidInput {
+            message: format!("Node {} is not a member of the cluster", id),
+        });
+    }
+
+    let shards = client
+        .get_raw("/v1/shards")
+        .await
+        .map_err(RedisCtlError::from)?;
+    let (masters, replicas) = shards_on_node(&shards, id);
+
+    if !force {
+        if node_count.saturating_sub(1) < MIN_SAFE_NODES {
+            return Err(RedisCtlError::InvalidInput {
+                message: format!(
+                    "Removing node {} would leave {} node(s); at least {} are needed to keep quorum (use --force to override)",
+                    id,
+                    node_count.saturating_sub(1),
+                    MIN_SAFE_NODES
+                ),
+            });
+        }
+        if masters > 0 && !drain_first {
+            return Err(RedisCtlError::InvalidInput {
+                message: format!(
+                    "Node {} holds {} master shard(s); drain it first with --drain-first (use --force to override)",
+                    id, masters
+                ),
+            });
+        }
+    }
+
+    if !force
+        && !confirm_action(&format!(
+            "Remove node {} from cluster ({} master, {} replica shard(s))?",
+            id, masters, replicas
+        ))?
+    {
         println!("Operation cancelled");
         return Ok(());
     }
 
-    let client = conn_mgr.create_enterprise_client(profile_name).await?;
-    let handler = NodeHandler::new(client);
-    handler.remove(id).await?;
-    println!("Node {} removed successfully", id);
+    let drained = drain_first && masters > 0;
+    if drained {
+        run_node_action(&client, id, "drain", "Drain", timeout, interval).await?;
+
+        let shards = client
+            .get_raw("/v1/shards")
+            .await
+            .map_err(RedisCtlError::from)?;
+        let (remaining, _) = shards_on_node(&shards, id);
+        if remaining > 0 && !force {
+            return Err(RedisCtlError::ApiError {
+                message: format!(
+                    "Node {} still holds {} master shard(s) after draining",
+                    id, remaining
+                ),
+            });
+        }
+    }
+
+    run_node_action(&client, id, "remove", "Removal", timeout, interval).await?;
+
+    if resolve_auto(output_format).is_structured() {
+        let result = serde_json::json!({
+            "node_id": id,
+            "status": "removed",
+            "drained": drained,
+            "master_shards": masters,
+            "replica_shards": replicas,
+        });
+        let data = handle_output(result, output_format, query)?;
+        print_formatted_output(data, output_format)?;
+    } else {
+        println!("Node {} removed successfully", id);
+    }
     Ok(())
 }
 
@@ -230,8 +378,6 @@ pub async fn update_node(
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
-    use crate::error::RedisCtlError;
-
     let client = conn_mgr.create_enterprise_client(profile_name).await?;
     let handler = NodeHandler::new(client);
 
@@ -0,0 +1,145 @@
+//! Prometheus text exposition for Enterprise statistics
+
+use serde_json::{Map, Value};
+use std::fmt::Write;
+
+/// Node metrics exported as gauges: stats field, metric name suffix, help text
+const NODE_METRICS: &[(&str, &str, &str)] = &[
+    (
+        "cpu_user",
+        "cpu_user",
+        "Fraction of CPU time spent in user mode",
+    ),
+    (
+        "cpu_system",
+        "cpu_system",
+        "Fraction of CPU time spent in system mode",
+    ),
+    ("cpu_idle", "cpu_idle", "Fraction of CPU time spent idle"),
+    (
+        "free_memory",
+        "free_memory_bytes",
+        "Free memory on the node in bytes",
+    ),
+    (
+        "available_memory",
+        "available_memory_bytes",
+        "Memory available for databases on the node in bytes",
+    ),
+    (
+        "ingress_bytes",
+        "ingress_bytes",
+        "Network bytes received per second",
+    ),
+    (
+        "egress_bytes",
+        "egress_bytes",
+        "Network bytes sent per second",
+    ),
+    ("conns", "connections", "Client connections to the node"),
+];
+
+/// Render all-nodes stats (the `/v1/nodes/stats/last` response) as Prometheus
+/// gauges with a `node` label. Metrics a node does not report are left out.
+pub fn render_node_stats(stats: &Value) -> String {
+    let samples = node_samples(stats);
+    let mut out = String::new();
+
+    for (field, name, help) in NODE_METRICS {
+        let values: Vec<(&str, f64)> = samples
+            .iter()
+            .filter_map(|(node, metrics)| {
+                metric_value(metrics.get(*field)?).map(|v| (node.as_str(), v))
+            })
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+
+        let metric = format!("redis_enterprise_node_{name}");
+        let _ = writeln!(out, "# HELP {metric} {help}");
+        let _ = writeln!(out, "# TYPE {metric} gauge");
+        for (node, value) in values {
+            let _ = writeln!(out, "{metric}{{node=\"{node}\"}} {value}");
+        }
+    }
+
+    out
+}
+
+/// Latest metrics for each node, paired with the node uid
+fn node_samples(stats: &Value) -> Vec<(String, &Map<String, Value>)> {
+    let Some(entries) = stats.get("stats").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let uid = match entry.get("uid")? {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            let interval = entry.get("intervals")?.as_array()?.last()?;
+            let metrics = interval.get("metrics").unwrap_or(interval).as_object()?;
+            Some((uid, metrics))
+        })
+        .collect()
+}
+
+/// Stats values arrive as numbers or numeric strings depending on the field
+fn metric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_node_stats_labels_each_node() {
+        let stats = json!({
+            "stats": [
+                {
+                    "uid": 1,
+                    "intervals": [
+                        {"time": "2024-01-15T10:29:00Z", "metrics": {"cpu_user": 0.9}},
+                        {"time": "2024-01-15T10:30:00Z", "metrics": {
+                            "cpu_user": 0.25,
+                            "free_memory": 1073741824,
+                            "ingress_bytes": "2048"
+                        }}
+                    ]
+                },
+                {
+                    "uid": 2,
+                    "intervals": [
+                        {"time": "2024-01-15T10:30:00Z", "metrics": {"cpu_user": 0.5}}
+                    ]
+                }
+            ]
+        });
+
+        let text = render_node_stats(&stats);
+        assert!(text.contains("# TYPE redis_enterprise_node_cpu_user gauge\n"));
+        // Only the latest interval is exported
+        assert!(text.contains("redis_enterprise_node_cpu_user{node=\"1\"} 0.25\n"));
+        assert!(text.contains("redis_enterprise_node_cpu_user{node=\"2\"} 0.5\n"));
+        assert!(text.contains("redis_enterprise_node_free_memory_bytes{node=\"1\"} 1073741824\n"));
+        assert!(text.contains("redis_enterprise_node_ingress_bytes{node=\"1\"} 2048\n"));
+        // Node 2 reports no memory, and no node reports egress
+        assert!(!text.contains("free_memory_bytes{node=\"2\"}"));
+        assert!(!text.contains("egress_bytes"));
+    }
+
+    #[test]
+    fn test_render_node_stats_empty() {
+        assert_eq!(render_node_stats(&json!({"stats": []})), "");
+        assert_eq!(render_node_stats(&json!({})), "");
+    }
+}
@@ -1,6 +1,6 @@
 use crate::cli::OutputFormat;
 use crate::commands::enterprise::utils::{
-    DetailRow, extract_field, format_status, output_with_pager, resolve_auto,
+    DetailRow, extract_field, format_status, output_with_pager, use_custom_table,
 };
 use crate::connection::ConnectionManager;
 use crate::error::RedisCtlError;
@@ -133,7 +133,7 @@ async fn handle_proxy_command_impl(
                 response
             };
 
-            if matches!(resolve_auto(output_format), OutputFormat::Table) {
+            if use_custom_table(output_format) {
                 print_proxies_table(&output_data)?;
             } else {
                 super::utils::print_formatted_output(output_data, output_format)?;
@@ -151,7 +151,7 @@ async fn handle_proxy_command_impl(
                 response
             };
 
-            if matches!(resolve_auto(output_format), OutputFormat::Table) {
+            if use_custom_table(output_format) {
                 print_proxy_detail(&output_data)?;
             } else {
                 super::utils::print_formatted_output(output_data, output_format)?;
@@ -69,7 +69,7 @@ pub async fn list_users(
     let users = handler.list().await?;
     let users_json = serde_json::to_value(users).context("Failed to serialize users")?;
     let data = handle_output(users_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_users_table(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -98,7 +98,7 @@ pub async fn get_user(
         );
     }
     let data = handle_output(user_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_user_detail(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -412,7 +412,7 @@ pub async fn list_roles(
     let roles = handler.list().await?;
     let roles_json = serde_json::to_value(roles).context("Failed to serialize roles")?;
     let data = handle_output(roles_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_roles_table(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -432,7 +432,7 @@ pub async fn get_role(
     let role = handler.get(id).await?;
     let role_json = serde_json::to_value(role).context("Failed to serialize role")?;
     let data = handle_output(role_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_role_detail(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -619,7 +619,7 @@ pub async fn list_acls(
     let acls = handler.list().await?;
     let acls_json = serde_json::to_value(acls).context("Failed to serialize ACLs")?;
     let data = handle_output(acls_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_acls_table(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -639,7 +639,7 @@ pub async fn get_acl(
     let acl = handler.get(id).await?;
     let acl_json = serde_json::to_value(acl).context("Failed to serialize ACL")?;
     let data = handle_output(acl_json, output_format, query)?;
-    if matches!(resolve_auto(output_format), OutputFormat::Table) {
+    if use_custom_table(output_format) {
         print_acl_detail(&data)?;
     } else {
         print_formatted_output(data, output_format)?;
@@ -1,6 +1,6 @@
 use crate::cli::OutputFormat;
 use crate::commands::enterprise::utils::{
-    DetailRow, extract_field, format_status, output_with_pager, resolve_auto,
+    DetailRow, extract_field, format_status, output_with_pager, use_custom_table,
 };
 use crate::connection::ConnectionManager;
 use crate::error::RedisCtlError;
@@ -217,7 +217,7 @@ impl ShardCommands {
                 } else {
                     response
                 };
-                if matches!(resolve_auto(output_format), OutputFormat::Table) {
+                if use_custom_table(output_format) {
                     print_shards_table(&output_data)?;
                 } else {
                     super::utils::print_formatted_output(output_data, output_format)?;
@@ -235,7 +235,7 @@ impl ShardCommands {
                 } else {
                     response
                 };
-                if matches!(resolve_auto(output_format), OutputFormat::Table) {
+                if use_custom_table(output_format) {
                     print_shard_detail(&output_data)?;
                 } else {
                     super::utils::print_formatted_output(output_data, output_format)?;
@@ -253,7 +253,7 @@ impl ShardCommands {
                 } else {
                     response
                 };
-                if matches!(resolve_auto(output_format), OutputFormat::Table) {
+                if use_custom_table(output_format) {
                     print_shards_table(&output_data)?;
                 } else {
                     super::utils::print_formatted_output(output_data, output_format)?;
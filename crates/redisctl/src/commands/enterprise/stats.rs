@@ -7,6 +7,8 @@ use crate::error::Result as CliResult;
 use anyhow::Context;
 use futures::StreamExt;
 use redis_enterprise::stats::StatsHandler;
+use serde_json::Value;
+use std::future::Future;
 use std::time::Duration;
 use tokio::signal;
 
@@ -25,6 +27,7 @@ pub async fn handle_stats_command(
             id,
             follow,
             poll_interval,
+            watch,
         } => {
             if *follow {
                 handle_database_stats_stream(
@@ -37,11 +40,13 @@ pub async fn handle_stats_command(
                 )
                 .await
             } else {
-                handle_database_stats(conn_mgr, profile_name, *id, output_format, query).await
+                handle_database_stats(conn_mgr, profile_name, *id, *watch, output_format, query)
+                    .await
             }
         }
-        EnterpriseStatsCommands::DatabaseShards { id } => {
-            handle_database_shard_stats(conn_mgr, profile_name, *id, output_format, query).await
+        EnterpriseStatsCommands::DatabaseShards { id, watch } => {
+            handle_database_shard_stats(conn_mgr, profile_name, *id, *watch, output_format, query)
+                .await
         }
         EnterpriseStatsCommands::DatabaseMetrics { id, interval } => {
             handle_database_metrics(conn_mgr, profile_name, *id, interval, output_format, query)
@@ -51,6 +56,7 @@ pub async fn handle_stats_command(
             id,
             follow,
             poll_interval,
+            watch,
         } => {
             if *follow {
                 handle_node_stats_stream(
@@ -63,15 +69,19 @@ pub async fn handle_stats_command(
                 )
                 .await
             } else {
-                handle_node_stats(conn_mgr, profile_name, *id, output_format, query).await
+                handle_node_stats(conn_mgr, profile_name, *id, *watch, output_format, query).await
             }
         }
+        EnterpriseStatsCommands::Nodes { format } => {
+            handle_nodes_stats(conn_mgr, profile_name, format, output_format, query).await
+        }
         EnterpriseStatsCommands::NodeMetrics { id, interval } => {
             handle_node_metrics(conn_mgr, profile_name, *id, interval, output_format, query).await
         }
         EnterpriseStatsCommands::Cluster {
             follow,
             poll_interval,
+            watch,
         } => {
             if *follow {
                 handle_cluster_stats_stream(
@@ -83,14 +93,14 @@ pub async fn handle_stats_command(
                 )
                 .await
             } else {
-                handle_cluster_stats(conn_mgr, profile_name, output_format, query).await
+                handle_cluster_stats(conn_mgr, profile_name, *watch, output_format, query).await
             }
         }
         EnterpriseStatsCommands::ClusterMetrics { interval } => {
             handle_cluster_metrics(conn_mgr, profile_name, interval, output_format, query).await
         }
-        EnterpriseStatsCommands::Listener => {
-            handle_listener_stats(conn_mgr, profile_name, output_format, query).await
+        EnterpriseStatsCommands::Listener { watch } => {
+            handle_listener_stats(conn_mgr, profile_name, *watch, output_format, query).await
         }
         EnterpriseStatsCommands::Export { format, interval } => {
             handle_stats_export(
@@ -111,16 +121,39 @@ async fn handle_database_stats(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
     database_id: u32,
+    watch_secs: Option<u64>,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
     let client = conn_mgr.create_enterprise_client(profile_name).await?;
     let stats_handler = StatsHandler::new(client);
-    let response = stats_handler.database_last(database_id).await?;
-    let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
-    let data = handle_output(stats_json, output_format, query)?;
-    print_formatted_output(data, output_format)?;
-    Ok(())
+    print_stats(watch_secs, output_format, || async {
+        let response = stats_handler.database_last(database_id).await?;
+        let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
+        handle_output(stats_json, output_format, query)
+    })
+    .await
+}
+
+/// Print stats once, or refresh them every `watch_secs` seconds until Ctrl+C
+async fn print_stats<F, Fut>(
+    watch_secs: Option<u64>,
+    output_format: OutputFormat,
+    fetch: F,
+) -> CliResult<()>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = CliResult<Value>>,
+{
+    match watch_secs {
+        Some(secs) => {
+            watch(secs, output_format, || async {
+                print_watch_document(fetch().await?, output_format)
+            })
+            .await
+        }
+        None => print_formatted_output(fetch().await?, output_format),
+    }
 }
 
 /// Handle streaming database statistics
@@ -178,16 +211,18 @@ async fn handle_database_shard_stats(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
     database_id: u32,
+    watch_secs: Option<u64>,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
     let client = conn_mgr.create_enterprise_client(profile_name).await?;
     let stats_handler = StatsHandler::new(client);
-    let response = stats_handler.shard(database_id, None).await?;
-    let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
-    let data = handle_output(stats_json, output_format, query)?;
-    print_formatted_output(data, output_format)?;
-    Ok(())
+    print_stats(watch_secs, output_format, || async {
+        let response = stats_handler.shard(database_id, None).await?;
+        let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
+        handle_output(stats_json, output_format, query)
+    })
+    .await
 }
 
 /// Handle database metrics over time
@@ -221,16 +256,18 @@ async fn handle_node_stats(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
     node_id: u32,
+    watch_secs: Option<u64>,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
     let client = conn_mgr.create_enterprise_client(profile_name).await?;
     let stats_handler = StatsHandler::new(client);
-    let response = stats_handler.node_last(node_id).await?;
-    let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
-    let data = handle_output(stats_json, output_format, query)?;
-    print_formatted_output(data, output_format)?;
-    Ok(())
+    print_stats(watch_secs, output_format, || async {
+        let response = stats_handler.node_last(node_id).await?;
+        let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
+        handle_output(stats_json, output_format, query)
+    })
+    .await
 }
 
 /// Handle streaming node statistics
@@ -277,6 +314,29 @@ async fn handle_node_stats_stream(
     Ok(())
 }
 
+/// Handle statistics for all nodes
+async fn handle_nodes_stats(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    format: &str,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
+    let client = conn_mgr.create_enterprise_client(profile_name).await?;
+    let stats_handler = StatsHandler::new(client);
+    let response = stats_handler.nodes_last().await?;
+    let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
+
+    if format == "prometheus" {
+        print!("{}", super::prometheus::render_node_stats(&stats_json));
+        return Ok(());
+    }
+
+    let data = handle_output(stats_json, output_format, query)?;
+    print_formatted_output(data, output_format)?;
+    Ok(())
+}
+
 /// Handle node metrics over time
 async fn handle_node_metrics(
     conn_mgr: &ConnectionManager,
@@ -305,16 +365,18 @@ async fn handle_node_metrics(
 async fn handle_cluster_stats(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
+    watch_secs: Option<u64>,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
     let client = conn_mgr.create_enterprise_client(profile_name).await?;
     let stats_handler = StatsHandler::new(client);
-    let response = stats_handler.cluster_last().await?;
-    let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
-    let data = handle_output(stats_json, output_format, query)?;
-    print_formatted_output(data, output_format)?;
-    Ok(())
+    print_stats(watch_secs, output_format, || async {
+        let response = stats_handler.cluster_last().await?;
+        let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
+        handle_output(stats_json, output_format, query)
+    })
+    .await
 }
 
 /// Handle streaming cluster statistics
@@ -387,6 +449,7 @@ async fn handle_cluster_metrics(
 async fn handle_listener_stats(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
+    watch_secs: Option<u64>,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
@@ -394,11 +457,12 @@ async fn handle_listener_stats(
     // Note: Listener stats might need to be implemented via raw API call
     // For now, using cluster stats as fallback
     let stats_handler = StatsHandler::new(client);
-    let response = stats_handler.cluster_last().await?;
-    let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
-    let data = handle_output(stats_json, output_format, query)?;
-    print_formatted_output(data, output_format)?;
-    Ok(())
+    print_stats(watch_secs, output_format, || async {
+        let response = stats_handler.cluster_last().await?;
+        let stats_json = serde_json::to_value(response).context("Failed to serialize stats")?;
+        handle_output(stats_json, output_format, query)
+    })
+    .await
 }
 
 /// Handle statistics export
@@ -465,6 +529,7 @@ mod tests {
             id: 1,
             follow: false,
             poll_interval: 5,
+            watch: None,
         };
 
         // Node stats
@@ -472,12 +537,14 @@ mod tests {
             id: 1,
             follow: false,
             poll_interval: 5,
+            watch: None,
         };
 
         // Cluster stats
         let _cmd = EnterpriseStatsCommands::Cluster {
             follow: false,
             poll_interval: 5,
+            watch: None,
         };
 
         // Export stats
@@ -10,6 +10,7 @@ use crate::connection::ConnectionManager;
 use crate::error::Result as CliResult;
 use anyhow::Context;
 use colored::Colorize;
+use redis_enterprise::EnterpriseClient;
 use redis_enterprise::bdb::BdbHandler;
 use redis_enterprise::cluster::ClusterHandler;
 use redis_enterprise::nodes::NodeHandler;
@@ -83,12 +84,13 @@ impl StatusSections {
     }
 }
 
-/// Get comprehensive cluster status
+/// Get comprehensive cluster status, refreshing every `watch_secs` seconds if set
 pub async fn get_status(
     conn_mgr: &ConnectionManager,
     profile_name: Option<&str>,
     sections: StatusSections,
     brief: bool,
+    watch_secs: Option<u64>,
     output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<()> {
@@ -101,6 +103,26 @@ pub async fn get_status(
         StatusSections::all()
     };
 
+    match watch_secs {
+        Some(secs) => {
+            watch(secs, output_format, || {
+                print_status(&client, &sections, brief, true, output_format, query)
+            })
+            .await
+        }
+        None => print_status(&client, &sections, brief, false, output_format, query).await,
+    }
+}
+
+/// Fetch the requested sections and print them once
+async fn print_status(
+    client: &EnterpriseClient,
+    sections: &StatusSections,
+    brief: bool,
+    watching: bool,
+    output_format: OutputFormat,
+    query: Option<&str>,
+) -> CliResult<()> {
     // Collect cluster info
     let cluster_result = if sections.cluster {
         ClusterHandler::new(client.clone())
@@ -158,7 +180,7 @@ pub async fn get_status(
     // Table/Auto format without query: print colored tables
     if matches!(output_format, OutputFormat::Table | OutputFormat::Auto) && query.is_none() {
         print_status_tables(
-            &sections,
+            sections,
             &cluster_result,
             &nodes_result,
             &databases_result,
@@ -183,9 +205,11 @@ pub async fn get_status(
     let data = handle_output(status_json, output_format, query)?;
 
     // Format and display
-    print_formatted_output(data, output_format)?;
-
-    Ok(())
+    if watching {
+        print_watch_document(data, output_format)
+    } else {
+        print_formatted_output(data, output_format)
+    }
 }
 
 // ---------------------------------------------------------------------------
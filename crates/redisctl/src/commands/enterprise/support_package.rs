@@ -1,11 +1,10 @@
 #![allow(dead_code)]
 
+mod download;
 mod optimizer;
 #[cfg(feature = "upload")]
 mod upload;
 
-use crate::error::RedisCtlError;
-
 use anyhow::{Context, Result as AnyhowResult};
 use chrono::Local;
 use clap::Subcommand;
@@ -13,11 +12,13 @@ use indicatif::{ProgressBar, ProgressStyle};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use crate::cli::OutputFormat;
 use crate::commands::cloud::async_utils::AsyncOperationArgs;
 use crate::connection::ConnectionManager;
 use crate::error::Result as CliResult;
+use download::{DownloadOptions, download_package};
 use optimizer::{OptimizationOptions, optimize_support_package};
 
 /// Support package generation commands for troubleshooting
@@ -37,6 +38,10 @@ pub enum SupportPackageCommands {
         #[arg(long)]
         skip_checks: bool,
 
+        /// Continue an interrupted download from its .part file
+        #[arg(long)]
+        resume: bool,
+
         /// Optimize package size by truncating logs and removing redundant data
         #[arg(long)]
         optimize: bool,
@@ -85,6 +90,10 @@ pub enum SupportPackageCommands {
         #[arg(long)]
         skip_checks: bool,
 
+        /// Continue an interrupted download from its .part file
+        #[arg(long)]
+        resume: bool,
+
         /// Optimize package size by truncating logs and removing redundant data
         #[arg(long)]
         optimize: bool,
@@ -133,6 +142,10 @@ pub enum SupportPackageCommands {
         #[arg(long)]
         skip_checks: bool,
 
+        /// Continue an interrupted download from its .part file
+        #[arg(long)]
+        resume: bool,
+
         /// Optimize package size by truncating logs and removing redundant data
         #[arg(long)]
         optimize: bool,
@@ -183,6 +196,8 @@ pub struct SupportPackageResult {
     pub file_size: usize,
     pub file_size_display: String,
     pub elapsed_seconds: u64,
+    /// Bytes reused from an interrupted download continued with --resume
+    pub resumed_bytes: u64,
     pub cluster_name: Option<String>,
     pub cluster_version: Option<String>,
     pub message: String,
@@ -202,6 +217,7 @@ pub async fn handle_support_package_command(
             file,
             use_new_api,
             skip_checks,
+            resume,
             optimize,
             no_optimize: _,
             log_lines,
@@ -237,6 +253,7 @@ pub async fn handle_support_package_command(
                 profile_name,
                 output_path,
                 use_new_api,
+                resume,
                 &async_ops,
                 output_format,
                 optimization_opts,
@@ -253,6 +270,7 @@ pub async fn handle_support_package_command(
             file,
             use_new_api,
             skip_checks,
+            resume,
             optimize,
             no_optimize: _,
             log_lines,
@@ -292,6 +310,7 @@ pub async fn handle_support_package_command(
                 uid,
                 output_path,
                 use_new_api,
+                resume,
                 &async_ops,
                 output_format,
                 optimization_opts,
@@ -308,6 +327,7 @@ pub async fn handle_support_package_command(
             file,
             use_new_api,
             skip_checks,
+            resume,
             optimize,
             no_optimize: _,
             log_lines,
@@ -349,6 +369,7 @@ pub async fn handle_support_package_command(
                 uid,
                 output_path,
                 use_new_api,
+                resume,
                 &async_ops,
                 output_format,
                 optimization_opts,
@@ -416,7 +437,8 @@ async fn generate_cluster_package(
     profile_name: Option<&str>,
     output_path: PathBuf,
     use_new_api: bool,
-    _async_ops: &AsyncOperationArgs,
+    resume: bool,
+    async_ops: &AsyncOperationArgs,
     output_format: OutputFormat,
     optimization_opts: Option<OptimizationOptions>,
     #[cfg(feature = "upload")] upload: bool,
@@ -440,9 +462,7 @@ async fn generate_cluster_package(
     }
 
     // Only show interactive output if not in JSON mode
-    let spinner = if matches!(output_format, OutputFormat::Json) {
-        None
-    } else {
+    if !matches!(output_format, OutputFormat::Json) {
         println!("Redis Enterprise Support Package");
         println!("================================");
 
@@ -455,96 +475,34 @@ async fn generate_cluster_package(
 
         println!("\nOutput: {}", output_path.display());
         println!("\nGenerating support package...");
-
-        // Show progress spinner
-        let spinner = ProgressBar::new_spinner();
-        spinner.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.green} {msg}")
-                .unwrap(),
-        );
-        spinner.set_message("Collecting cluster data...");
-        spinner.enable_steady_tick(std::time::Duration::from_millis(100));
-        Some(spinner)
-    };
-
-    let start_time = std::time::Instant::now();
-
-    // Use the appropriate endpoint based on flag
-    let debuginfo_handler = redis_enterprise::debuginfo::DebugInfoHandler::new(client);
-    let mut data = if use_new_api {
-        debuginfo_handler
-            .cluster_debuginfo_binary()
-            .await
-            .map_err(RedisCtlError::from)?
-    } else {
-        debuginfo_handler
-            .all_binary()
-            .await
-            .map_err(RedisCtlError::from)?
-    };
-
-    let original_size = data.len();
-
-    // Apply optimization if requested
-    if let Some(opts) = optimization_opts {
-        if let Some(ref spinner) = spinner {
-            spinner.set_message("Optimizing package...");
-        }
-
-        data =
-            optimize_support_package(&data, &opts).context("Failed to optimize support package")?;
-
-        if !matches!(output_format, OutputFormat::Json) && opts.verbose {
-            let reduction = ((original_size - data.len()) as f64 / original_size as f64) * 100.0;
-            eprintln!(
-                "Optimization: {} → {} ({:.1}% reduction)",
-                format_file_size(original_size),
-                format_file_size(data.len()),
-                reduction
-            );
-        }
-    }
-
-    if let Some(spinner) = spinner {
-        spinner.finish_and_clear();
     }
 
-    // Handle upload if requested
-    #[cfg(feature = "upload")]
-    let uploaded_path = if upload {
-        let api_key =
-            upload::get_files_api_key(profile_name).context("Failed to get Files.com API key")?;
-
-        let filename = output_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("support-package.tar.gz");
-
-        let upload_path = upload::upload_package(&api_key, &data, filename, None)
-            .await
-            .context("Failed to upload support package")?;
+    let start_time = std::time::Instant::now();
 
-        Some(upload_path)
+    let endpoint = if use_new_api {
+        "/v1/cluster/debuginfo".to_string()
     } else {
-        None
+        "/v1/debuginfo/all".to_string()
     };
-
-    // Save to file unless --no-save
-    #[cfg(feature = "upload")]
-    let should_save = !no_save;
-    #[cfg(not(feature = "upload"))]
-    let should_save = true;
-
-    if should_save {
-        fs::write(&output_path, &data).context(format!(
-            "Failed to save support package to {:?}",
-            output_path
-        ))?;
-    }
+    let package = fetch_package(
+        conn_mgr,
+        profile_name,
+        &endpoint,
+        "Collecting cluster data...".to_string(),
+        &output_path,
+        resume,
+        async_ops,
+        output_format,
+        optimization_opts,
+        #[cfg(feature = "upload")]
+        upload,
+        #[cfg(feature = "upload")]
+        no_save,
+    )
+    .await?;
 
     let elapsed = start_time.elapsed();
-    let file_size = data.len();
+    let file_size = package.file_size;
     let size_display = format_file_size(file_size);
 
     // Output based on format
@@ -557,6 +515,7 @@ async fn generate_cluster_package(
                 file_size,
                 file_size_display: size_display.clone(),
                 elapsed_seconds: elapsed.as_secs(),
+                resumed_bytes: package.resumed_from,
                 cluster_name,
                 cluster_version,
                 message: "Support package created successfully".to_string(),
@@ -564,10 +523,14 @@ async fn generate_cluster_package(
             };
 
             #[cfg(feature = "upload")]
-            if uploaded_path.is_some() {
+            if package.uploaded_path.is_some() {
                 result.message = format!(
                     "Support package {} and uploaded to Files.com",
-                    if should_save { "created" } else { "uploaded" }
+                    if package.should_save {
+                        "created"
+                    } else {
+                        "uploaded"
+                    }
                 );
             }
 
@@ -578,23 +541,29 @@ async fn generate_cluster_package(
             println!("\n✓ Support package created successfully");
 
             #[cfg(feature = "upload")]
-            if let Some(ref upload_path) = uploaded_path {
+            if let Some(ref upload_path) = package.uploaded_path {
                 println!("  Uploaded to: {}", upload_path);
             }
 
-            if should_save {
+            if package.should_save {
                 println!("  File: {}", output_path.display());
             }
             println!("  Size: {}", size_display);
+            if package.resumed_from > 0 {
+                println!(
+                    "  Resumed: {} reused from an earlier download",
+                    format_file_size(package.resumed_from as usize)
+                );
+            }
             println!("  Time: {}s", elapsed.as_secs());
 
             #[cfg(feature = "upload")]
-            if uploaded_path.is_none() {
+            if package.uploaded_path.is_none() {
                 println!("\nNext steps:");
                 println!("1. Upload to Redis Support: https://support.redis.com/upload");
                 println!("2. Reference your case number when uploading");
                 println!("3. Delete local file after upload to free space");
-            } else if should_save {
+            } else if package.should_save {
                 println!("\nPackage uploaded to Files.com and saved locally.");
                 println!("You can delete the local file after confirming upload.");
             }
@@ -620,7 +589,8 @@ async fn generate_database_package(
     uid: u32,
     output_path: PathBuf,
     use_new_api: bool,
-    _async_ops: &AsyncOperationArgs,
+    resume: bool,
+    async_ops: &AsyncOperationArgs,
     output_format: OutputFormat,
     optimization_opts: Option<OptimizationOptions>,
     #[cfg(feature = "upload")] upload: bool,
@@ -641,9 +611,7 @@ async fn generate_database_package(
     }
 
     // Only show interactive output if not in JSON mode
-    let spinner = if matches!(output_format, OutputFormat::Json) {
-        None
-    } else {
+    if !matches!(output_format, OutputFormat::Json) {
         println!("Redis Enterprise Support Package");
         println!("================================");
         println!("Database: {}", uid);
@@ -654,96 +622,34 @@ async fn generate_database_package(
 
         println!("\nOutput: {}", output_path.display());
         println!("\nGenerating support package...");
-
-        // Show progress spinner
-        let spinner = ProgressBar::new_spinner();
-        spinner.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.green} {msg}")
-                .unwrap(),
-        );
-        spinner.set_message(format!("Collecting database {} data...", uid));
-        spinner.enable_steady_tick(std::time::Duration::from_millis(100));
-        Some(spinner)
-    };
-
-    let start_time = std::time::Instant::now();
-
-    // Use the appropriate endpoint based on flag
-    let debuginfo_handler = redis_enterprise::debuginfo::DebugInfoHandler::new(client);
-    let mut data = if use_new_api {
-        debuginfo_handler
-            .database_debuginfo_binary(uid)
-            .await
-            .context(format!("Failed to collect debug info for database {}", uid))?
-    } else {
-        debuginfo_handler
-            .all_bdb_binary(uid)
-            .await
-            .context(format!("Failed to collect debug info for database {}", uid))?
-    };
-
-    let original_size = data.len();
-
-    // Apply optimization if requested
-    if let Some(opts) = optimization_opts {
-        if let Some(ref spinner) = spinner {
-            spinner.set_message("Optimizing package...");
-        }
-
-        data =
-            optimize_support_package(&data, &opts).context("Failed to optimize support package")?;
-
-        if !matches!(output_format, OutputFormat::Json) && opts.verbose {
-            let reduction = ((original_size - data.len()) as f64 / original_size as f64) * 100.0;
-            eprintln!(
-                "Optimization: {} → {} ({:.1}% reduction)",
-                format_file_size(original_size),
-                format_file_size(data.len()),
-                reduction
-            );
-        }
-    }
-
-    if let Some(spinner) = spinner {
-        spinner.finish_and_clear();
     }
 
-    // Handle upload if requested
-    #[cfg(feature = "upload")]
-    let uploaded_path = if upload {
-        let api_key =
-            upload::get_files_api_key(profile_name).context("Failed to get Files.com API key")?;
-
-        let filename = output_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("support-package.tar.gz");
-
-        let upload_path = upload::upload_package(&api_key, &data, filename, None)
-            .await
-            .context("Failed to upload support package")?;
+    let start_time = std::time::Instant::now();
 
-        Some(upload_path)
+    let endpoint = if use_new_api {
+        format!("/v1/bdbs/{}/debuginfo", uid)
     } else {
-        None
+        format!("/v1/debuginfo/all/bdb/{}", uid)
     };
-
-    // Save to file unless --no-save
-    #[cfg(feature = "upload")]
-    let should_save = !no_save;
-    #[cfg(not(feature = "upload"))]
-    let should_save = true;
-
-    if should_save {
-        fs::write(&output_path, &data).context(format!(
-            "Failed to save support package to {:?}",
-            output_path
-        ))?;
-    }
+    let package = fetch_package(
+        conn_mgr,
+        profile_name,
+        &endpoint,
+        format!("Collecting database {} data...", uid),
+        &output_path,
+        resume,
+        async_ops,
+        output_format,
+        optimization_opts,
+        #[cfg(feature = "upload")]
+        upload,
+        #[cfg(feature = "upload")]
+        no_save,
+    )
+    .await?;
 
     let elapsed = start_time.elapsed();
-    let file_size = data.len();
+    let file_size = package.file_size;
     let size_display = format_file_size(file_size);
 
     // Output based on format
@@ -756,6 +662,7 @@ async fn generate_database_package(
                 file_size,
                 file_size_display: size_display.clone(),
                 elapsed_seconds: elapsed.as_secs(),
+                resumed_bytes: package.resumed_from,
                 cluster_name: Some(format!("Database {}", uid)),
                 cluster_version: database_name,
                 message: "Database support package created successfully".to_string(),
@@ -763,10 +670,14 @@ async fn generate_database_package(
             };
 
             #[cfg(feature = "upload")]
-            if uploaded_path.is_some() {
+            if package.uploaded_path.is_some() {
                 result.message = format!(
                     "Database support package {} and uploaded to Files.com",
-                    if should_save { "created" } else { "uploaded" }
+                    if package.should_save {
+                        "created"
+                    } else {
+                        "uploaded"
+                    }
                 );
             }
 
@@ -776,23 +687,29 @@ async fn generate_database_package(
             println!("\n✓ Database support package created successfully");
 
             #[cfg(feature = "upload")]
-            if let Some(ref upload_path) = uploaded_path {
+            if let Some(ref upload_path) = package.uploaded_path {
                 println!("  Uploaded to: {}", upload_path);
             }
 
-            if should_save {
+            if package.should_save {
                 println!("  File: {}", output_path.display());
             }
             println!("  Size: {}", size_display);
+            if package.resumed_from > 0 {
+                println!(
+                    "  Resumed: {} reused from an earlier download",
+                    format_file_size(package.resumed_from as usize)
+                );
+            }
             println!("  Time: {}s", elapsed.as_secs());
 
             #[cfg(feature = "upload")]
-            if uploaded_path.is_none() {
+            if package.uploaded_path.is_none() {
                 println!("\nNext steps:");
                 println!("1. Upload to Redis Support: https://support.redis.com/upload");
                 println!("2. Reference your case number when uploading");
                 println!("3. Delete local file after upload to free space");
-            } else if should_save {
+            } else if package.should_save {
                 println!("\nPackage uploaded to Files.com and saved locally.");
                 println!("You can delete the local file after confirming upload.");
             }
@@ -818,7 +735,8 @@ async fn generate_node_package(
     uid: Option<u32>,
     output_path: PathBuf,
     use_new_api: bool,
-    _async_ops: &AsyncOperationArgs,
+    resume: bool,
+    async_ops: &AsyncOperationArgs,
     output_format: OutputFormat,
     optimization_opts: Option<OptimizationOptions>,
     #[cfg(feature = "upload")] upload: bool,
@@ -840,9 +758,7 @@ async fn generate_node_package(
     }
 
     // Only show interactive output if not in JSON mode
-    let spinner = if matches!(output_format, OutputFormat::Json) {
-        None
-    } else {
+    if !matches!(output_format, OutputFormat::Json) {
         println!("Redis Enterprise Support Package");
         println!("================================");
 
@@ -857,116 +773,37 @@ async fn generate_node_package(
 
         println!("\nOutput: {}", output_path.display());
         println!("\nGenerating support package...");
-
-        // Show progress spinner
-        let spinner = ProgressBar::new_spinner();
-        spinner.set_style(
-            ProgressStyle::default_spinner()
-                .template("{spinner:.green} {msg}")
-                .unwrap(),
-        );
-        let msg = if let Some(node_uid) = uid {
-            format!("Collecting node {} data...", node_uid)
-        } else {
-            "Collecting all nodes data...".to_string()
-        };
-        spinner.set_message(msg);
-        spinner.enable_steady_tick(std::time::Duration::from_millis(100));
-        Some(spinner)
-    };
-
-    let start_time = std::time::Instant::now();
-
-    // Use the appropriate endpoint based on flag
-    let debuginfo_handler = redis_enterprise::debuginfo::DebugInfoHandler::new(client);
-    let mut data = if let Some(node_uid) = uid {
-        if use_new_api {
-            debuginfo_handler
-                .node_debuginfo_binary(node_uid)
-                .await
-                .context(format!(
-                    "Failed to collect debug info for node {}",
-                    node_uid
-                ))?
-        } else {
-            debuginfo_handler
-                .node_binary()
-                .await
-                .map_err(RedisCtlError::from)?
-        }
-    } else if use_new_api {
-        debuginfo_handler
-            .nodes_debuginfo_binary()
-            .await
-            .map_err(RedisCtlError::from)?
-    } else {
-        debuginfo_handler
-            .node_binary()
-            .await
-            .map_err(RedisCtlError::from)?
-    };
-
-    let original_size = data.len();
-
-    // Apply optimization if requested
-    if let Some(opts) = optimization_opts {
-        if let Some(ref spinner) = spinner {
-            spinner.set_message("Optimizing package...");
-        }
-
-        data =
-            optimize_support_package(&data, &opts).context("Failed to optimize support package")?;
-
-        if !matches!(output_format, OutputFormat::Json) && opts.verbose {
-            let reduction = ((original_size - data.len()) as f64 / original_size as f64) * 100.0;
-            eprintln!(
-                "Optimization: {} → {} ({:.1}% reduction)",
-                format_file_size(original_size),
-                format_file_size(data.len()),
-                reduction
-            );
-        }
-    }
-
-    if let Some(spinner) = spinner {
-        spinner.finish_and_clear();
     }
 
-    // Handle upload if requested
-    #[cfg(feature = "upload")]
-    let uploaded_path = if upload {
-        let api_key =
-            upload::get_files_api_key(profile_name).context("Failed to get Files.com API key")?;
-
-        let filename = output_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("support-package.tar.gz");
-
-        let upload_path = upload::upload_package(&api_key, &data, filename, None)
-            .await
-            .context("Failed to upload support package")?;
+    let start_time = std::time::Instant::now();
 
-        Some(upload_path)
-    } else {
-        None
+    let endpoint = match uid {
+        Some(node_uid) if use_new_api => format!("/v1/nodes/{}/debuginfo", node_uid),
+        None if use_new_api => "/v1/nodes/debuginfo".to_string(),
+        _ => "/v1/debuginfo/node".to_string(),
     };
-
-    // Save to file unless --no-save
-    #[cfg(feature = "upload")]
-    let should_save = !no_save;
-    #[cfg(not(feature = "upload"))]
-    let should_save = true;
-
-    if should_save {
-        fs::write(&output_path, &data).context(format!(
-            "Failed to save support package to {:?}",
-            output_path
-        ))?;
-    }
+    let package = fetch_package(
+        conn_mgr,
+        profile_name,
+        &endpoint,
+        match uid {
+            Some(node_uid) => format!("Collecting node {} data...", node_uid),
+            None => "Collecting all nodes data...".to_string(),
+        },
+        &output_path,
+        resume,
+        async_ops,
+        output_format,
+        optimization_opts,
+        #[cfg(feature = "upload")]
+        upload,
+        #[cfg(feature = "upload")]
+        no_save,
+    )
+    .await?;
 
     let elapsed = start_time.elapsed();
-    let file_size = data.len();
+    let file_size = package.file_size;
     let size_display = format_file_size(file_size);
 
     // Output based on format
@@ -985,6 +822,7 @@ async fn generate_node_package(
                 file_size,
                 file_size_display: size_display.clone(),
                 elapsed_seconds: elapsed.as_secs(),
+                resumed_bytes: package.resumed_from,
                 cluster_name: uid.map(|id| format!("Node {}", id)),
                 cluster_version: node_address,
                 message: if uid.is_some() {
@@ -996,11 +834,15 @@ async fn generate_node_package(
             };
 
             #[cfg(feature = "upload")]
-            if uploaded_path.is_some() {
+            if package.uploaded_path.is_some() {
                 result.message = format!(
                     "{} support package {} and uploaded to Files.com",
                     if uid.is_some() { "Node" } else { "Nodes" },
-                    if should_save { "created" } else { "uploaded" }
+                    if package.should_save {
+                        "created"
+                    } else {
+                        "uploaded"
+                    }
                 );
             }
 
@@ -1011,23 +853,29 @@ async fn generate_node_package(
             println!("\n✓ {} support package created successfully", package_type);
 
             #[cfg(feature = "upload")]
-            if let Some(ref upload_path) = uploaded_path {
+            if let Some(ref upload_path) = package.uploaded_path {
                 println!("  Uploaded to: {}", upload_path);
             }
 
-            if should_save {
+            if package.should_save {
                 println!("  File: {}", output_path.display());
             }
             println!("  Size: {}", size_display);
+            if package.resumed_from > 0 {
+                println!(
+                    "  Resumed: {} reused from an earlier download",
+                    format_file_size(package.resumed_from as usize)
+                );
+            }
             println!("  Time: {}s", elapsed.as_secs());
 
             #[cfg(feature = "upload")]
-            if uploaded_path.is_none() {
+            if package.uploaded_path.is_none() {
                 println!("\nNext steps:");
                 println!("1. Upload to Redis Support: https://support.redis.com/upload");
                 println!("2. Reference your case number when uploading");
                 println!("3. Delete local file after upload to free space");
-            } else if should_save {
+            } else if package.should_save {
                 println!("\nPackage uploaded to Files.com and saved locally.");
                 println!("You can delete the local file after confirming upload.");
             }
@@ -1045,6 +893,136 @@ async fn generate_node_package(
     Ok(())
 }
 
+/// A downloaded package after optimization and upload
+struct FetchedPackage {
+    file_size: usize,
+    resumed_from: u64,
+    #[cfg(feature = "upload")]
+    uploaded_path: Option<String>,
+    should_save: bool,
+}
+
+/// Stream a package to `output_path`, then optimize and upload it as requested
+#[allow(clippy::too_many_arguments)]
+async fn fetch_package(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    endpoint: &str,
+    collecting_message: String,
+    output_path: &Path,
+    resume: bool,
+    async_ops: &AsyncOperationArgs,
+    output_format: OutputFormat,
+    optimization_opts: Option<OptimizationOptions>,
+    #[cfg(feature = "upload")] upload: bool,
+    #[cfg(feature = "upload")] no_save: bool,
+) -> CliResult<FetchedPackage> {
+    let interactive = !matches!(output_format, OutputFormat::Json);
+    let downloaded = download_package(
+        conn_mgr,
+        profile_name,
+        endpoint,
+        output_path,
+        &DownloadOptions {
+            resume,
+            show_progress: interactive,
+            collecting_message,
+            collect_timeout: Duration::from_secs(async_ops.wait_timeout),
+            collect_interval: Duration::from_secs(async_ops.wait_interval),
+        },
+    )
+    .await?;
+    let mut file_size = downloaded.bytes as usize;
+
+    #[cfg(feature = "upload")]
+    let needs_data = optimization_opts.is_some() || upload;
+    #[cfg(not(feature = "upload"))]
+    let needs_data = optimization_opts.is_some();
+
+    #[cfg(feature = "upload")]
+    let mut uploaded_path = None;
+
+    // Optimization and upload need the whole package in memory
+    if needs_data {
+        let mut data = fs::read(output_path)
+            .with_context(|| format!("Failed to read {}", output_path.display()))?;
+
+        if let Some(opts) = optimization_opts {
+            let spinner = if interactive {
+                let spinner = ProgressBar::new_spinner();
+                spinner.set_style(
+                    ProgressStyle::default_spinner()
+                        .template("{spinner:.green} {msg}")
+                        .unwrap(),
+                );
+                spinner.enable_steady_tick(Duration::from_millis(100));
+                spinner
+            } else {
+                ProgressBar::hidden()
+            };
+            spinner.set_message("Optimizing package...");
+
+            let original_size = data.len();
+            data = optimize_support_package(&data, &opts)
+                .context("Failed to optimize support package")?;
+            spinner.finish_and_clear();
+
+            if interactive && opts.verbose {
+                let reduction =
+                    ((original_size - data.len()) as f64 / original_size as f64) * 100.0;
+                eprintln!(
+                    "Optimization: {} → {} ({:.1}% reduction)",
+                    format_file_size(original_size),
+                    format_file_size(data.len()),
+                    reduction
+                );
+            }
+
+            fs::write(output_path, &data).context(format!(
+                "Failed to save support package to {:?}",
+                output_path
+            ))?;
+            file_size = data.len();
+        }
+
+        #[cfg(feature = "upload")]
+        if upload {
+            let api_key = upload::get_files_api_key(profile_name)
+                .context("Failed to get Files.com API key")?;
+
+            let filename = output_path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("support-package.tar.gz");
+
+            let upload_path = upload::upload_package(&api_key, &data, filename, None)
+                .await
+                .context("Failed to upload support package")?;
+
+            uploaded_path = Some(upload_path);
+        }
+    }
+
+    // Keep the file unless --no-save
+    #[cfg(feature = "upload")]
+    let should_save = !no_save;
+    #[cfg(not(feature = "upload"))]
+    let should_save = true;
+
+    if !should_save {
+        fs::remove_file(output_path)
+            .with_context(|| format!("Failed to remove {}", output_path.display()))?;
+    }
+
+    Ok(FetchedPackage {
+        file_size,
+        resumed_from: downloaded.resumed_from,
+        #[cfg(feature = "upload")]
+        uploaded_path,
+        should_save,
+    })
+}
+
 /// List available support packages (placeholder - API doesn't support this yet)
 async fn list_support_packages(
     _conn_mgr: &ConnectionManager,
@@ -0,0 +1,411 @@
+//! Streaming support package downloads
+//!
+//! Support packages can be several gigabytes, so they are written to disk as
+//! they arrive instead of being buffered in memory. Data goes to
+//! `<file>.part`, which is renamed to the requested path only once the
+//! download completes. An interrupted run leaves the `.part` file behind, and
+//! `--resume` continues it with an HTTP Range request.
+//!
+//! The first response's `ETag` (or `Last-Modified`) is kept in
+//! `<file>.part.validator` and sent as `If-Range` when resuming, so a package
+//! that changed on the server is downloaded again from the start instead of
+//! being appended to the old bytes.
+
+use std::fs::{self, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+use indicatif::{ProgressBar, ProgressStyle};
+use redisctl_core::enterprise::{EnterpriseProgressCallback, EnterpriseProgressEvent, poll_action};
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_RANGE, CONTENT_TYPE, ETAG, IF_RANGE, LAST_MODIFIED, RANGE};
+
+use crate::connection::{ConnectionManager, EnterpriseConnectionInfo};
+use crate::error::{RedisCtlError, Result as CliResult};
+
+/// How a support package download behaves
+pub(super) struct DownloadOptions {
+    /// Continue from an existing `.part` file instead of starting over
+    pub resume: bool,
+    /// Show spinners and the download progress bar
+    pub show_progress: bool,
+    /// Message shown while the cluster collects the package
+    pub collecting_message: String,
+    /// Maximum time to wait for an asynchronous collection
+    pub collect_timeout: Duration,
+    /// How often to poll an asynchronous collection
+    pub collect_interval: Duration,
+}
+
+/// A completed download
+pub(super) struct Downloaded {
+    /// Size of the complete package in bytes
+    pub bytes: u64,
+    /// Bytes reused from an earlier, interrupted run
+    pub resumed_from: u64,
+}
+
+/// Path of the partial download kept next to `output_path`
+pub(super) fn part_path(output_path: &Path) -> PathBuf {
+    let mut name = output_path.as_os_str().to_os_string();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+/// File holding the validator of the response a `.part` file came from
+fn validator_path(part: &Path) -> PathBuf {
+    let mut name = part.as_os_str().to_os_string();
+    name.push(".validator");
+    PathBuf::from(name)
+}
+
+/// Download the package served at `endpoint` into `output_path`
+///
+/// When the cluster answers with an action (`action_uid`) instead of the
+/// archive, the collection is polled with [`poll_action`] and the download is
+/// requested again once it completes.
+pub(super) async fn download_package(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    endpoint: &str,
+    output_path: &Path,
+    options: &DownloadOptions,
+) -> CliResult<Downloaded> {
+    let info = conn_mgr.resolve_enterprise_connection(profile_name)?;
+    let http = http_client(conn_mgr, &info)?;
+    let url = format!("{}{}", info.base_url.trim_end_matches('/'), endpoint);
+    let part = part_path(output_path);
+    let validator_file = validator_path(&part);
+
+    let existing = fs::metadata(&part).map(|m| m.len()).unwrap_or(0);
+    let validator = fs::read_to_string(&validator_file)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    let mut offset = if options.resume && validator.is_some() {
+        existing
+    } else {
+        0
+    };
+    if existing > 0 && offset == 0 && options.show_progress {
+        if options.resume {
+            eprintln!(
+                "Restarting download: the server did not identify the version of {}",
+                part.display()
+            );
+        } else {
+            eprintln!(
+                "Discarding partial download {} (use --resume to continue it)",
+                part.display()
+            );
+        }
+    }
+
+    let spinner = if options.show_progress {
+        let spinner = ProgressBar::new_spinner();
+        spinner.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.green} {msg} [{elapsed_precise}]")
+                .unwrap(),
+        );
+        spinner.enable_steady_tick(Duration::from_millis(100));
+        spinner
+    } else {
+        ProgressBar::hidden()
+    };
+    spinner.set_message(options.collecting_message.clone());
+
+    let mut polled_action = false;
+    let mut response = loop {
+        let response = send(&http, &url, &info, offset, validator.as_deref()).await?;
+
+        if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            // The partial file is already complete when it matches the size
+            // the server reports; otherwise it is stale and we start over
+            if total_from_content_range(&response) == Some(offset) && offset > 0 {
+                spinner.finish_and_clear();
+                fs::rename(&part, output_path).with_context(|| {
+                    format!(
+                        "Failed to move {} to {}",
+                        part.display(),
+                        output_path.display()
+                    )
+                })?;
+                let _ = fs::remove_file(&validator_file);
+                return Ok(Downloaded {
+                    bytes: offset,
+                    resumed_from: offset,
+                });
+            }
+            offset = 0;
+            continue;
+        }
+
+        if !response.status().is_success() {
+            spinner.finish_and_clear();
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(RedisCtlError::ApiError {
+                message: format!("HTTP {}: {}", status.as_u16(), body.trim()),
+            });
+        }
+
+        if !is_json(&response) {
+            break response;
+        }
+
+        let body: serde_json::Value =
+            response
+                .json()
+                .await
+                .map_err(|e| RedisCtlError::ConnectionError {
+                    message: e.to_string(),
+                })?;
+        let Some(action_uid) = body["action_uid"].as_str().filter(|_| !polled_action) else {
+            spinner.finish_and_clear();
+            return Err(RedisCtlError::ApiError {
+                message: format!("Expected a support package archive, got: {}", body),
+            });
+        };
+
+        let client = conn_mgr.create_enterprise_client(profile_name).await?;
+        let result = poll_action(
+            &client,
+            action_uid,
+            options.collect_timeout,
+            options.collect_interval,
+            Some(collection_progress(&spinner, &options.collecting_message)),
+        )
+        .await;
+        if let Err(e) = result {
+            spinner.finish_and_clear();
+            return Err(RedisCtlError::from(e));
+        }
+        polled_action = true;
+    };
+    spinner.finish_and_clear();
+
+    // A plain 200 means the server ignored the Range header, or the package
+    // changed since the partial download (If-Range did not match)
+    if response.status() != StatusCode::PARTIAL_CONTENT {
+        offset = 0;
+    }
+    if offset == 0 {
+        let _ = match response_validator(&response) {
+            Some(validator) => fs::write(&validator_file, validator),
+            None => fs::remove_file(&validator_file),
+        };
+    }
+    let total = if offset > 0 {
+        total_from_content_range(&response)
+    } else {
+        response.content_length()
+    };
+
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(offset > 0)
+        .truncate(offset == 0)
+        .open(&part)
+        .with_context(|| format!("Failed to open {}", part.display()))?;
+    let mut writer = BufWriter::new(file);
+
+    let bar = if !options.show_progress {
+        ProgressBar::hidden()
+    } else if let Some(total) = total {
+        let bar = ProgressBar::new(total);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template("{bar:40.green/dim} {bytes}/{total_bytes} ({bytes_per_sec}, {eta})")
+                .unwrap(),
+        );
+        bar
+    } else {
+        let bar = ProgressBar::new_spinner();
+        bar.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.green} {bytes} ({bytes_per_sec})")
+                .unwrap(),
+        );
+        bar
+    };
+    bar.set_position(offset);
+
+    let mut written = offset;
+    loop {
+        let chunk = match response.chunk().await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(e) => {
+                bar.abandon();
+                let _ = writer.flush();
+                return Err(interrupted(&part, e.to_string()));
+            }
+        };
+        if let Err(e) = writer.write_all(&chunk) {
+            bar.abandon();
+            return Err(interrupted(&part, e.to_string()));
+        }
+        written += chunk.len() as u64;
+        bar.set_position(written);
+    }
+    writer
+        .flush()
+        .map_err(|e| interrupted(&part, e.to_string()))?;
+    drop(writer);
+    bar.finish_and_clear();
+
+    if let Some(total) = total
+        && written < total
+    {
+        return Err(interrupted(
+            &part,
+            format!("connection closed after {} of {} bytes", written, total),
+        ));
+    }
+
+    fs::rename(&part, output_path).with_context(|| {
+        format!(
+            "Failed to move {} to {}",
+            part.display(),
+            output_path.display()
+        )
+    })?;
+    let _ = fs::remove_file(&validator_file);
+
+    Ok(Downloaded {
+        bytes: written,
+        resumed_from: offset,
+    })
+}
+
+/// HTTP client for the profile's cluster, without an overall request
+/// timeout since large packages can take a long time to transfer
+fn http_client(
+    conn_mgr: &ConnectionManager,
+    info: &EnterpriseConnectionInfo,
+) -> CliResult<reqwest::Client> {
+    let mut builder = info.http_client_builder()?;
+    if let Some(timeout) = conn_mgr.http_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    Ok(builder.build().context("Failed to create HTTP client")?)
+}
+
+/// Request the package, asking for the bytes after `offset` when resuming
+///
+/// The range is conditional on `validator`, so the server sends the whole
+/// package instead when it no longer matches.
+async fn send(
+    http: &reqwest::Client,
+    url: &str,
+    info: &EnterpriseConnectionInfo,
+    offset: u64,
+    validator: Option<&str>,
+) -> CliResult<reqwest::Response> {
+    let mut request = http
+        .get(url)
+        .basic_auth(&info.username, info.password.as_deref())
+        // Compressed transfer would make Range offsets refer to encoded bytes
+        .header("Accept-Encoding", "identity");
+    if offset > 0
+        && let Some(validator) = validator
+    {
+        request = request
+            .header(RANGE, format!("bytes={}-", offset))
+            .header(IF_RANGE, validator);
+    }
+    request
+        .send()
+        .await
+        .map_err(|e| RedisCtlError::ConnectionError {
+            message: e.to_string(),
+        })
+}
+
+/// Validator to resume this response with: a strong `ETag`, or else
+/// `Last-Modified` (weak ETags can't be used with `If-Range`)
+fn response_validator(response: &reqwest::Response) -> Option<String> {
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    header(ETAG)
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| header(LAST_MODIFIED))
+}
+
+fn is_json(response: &reqwest::Response) -> bool {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"))
+}
+
+/// Complete size from a `Content-Range` header (`bytes 100-199/200` or `bytes */200`)
+fn total_from_content_range(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(CONTENT_RANGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_content_range_total)
+}
+
+fn parse_content_range_total(value: &str) -> Option<u64> {
+    value.rsplit_once('/')?.1.trim().parse().ok()
+}
+
+/// Show the collection action's status on the spinner
+fn collection_progress(spinner: &ProgressBar, message: &str) -> EnterpriseProgressCallback {
+    let spinner = spinner.clone();
+    let message = message.trim_end_matches('.').to_string();
+    Box::new(move |event| {
+        if let EnterpriseProgressEvent::Polling {
+            status, progress, ..
+        } = event
+        {
+            match progress {
+                Some(pct) => spinner.set_message(format!("{}: {} {:.0}%", message, status, pct)),
+                None => spinner.set_message(format!("{}: {}", message, status)),
+            }
+        }
+    })
+}
+
+fn interrupted(part: &Path, reason: String) -> RedisCtlError {
+    RedisCtlError::ConnectionError {
+        message: format!(
+            "Download interrupted ({}). The partial package is kept at {}; \
+             run the command again with --resume to continue.",
+            reason,
+            part.display()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_path_appends_suffix() {
+        assert_eq!(
+            part_path(Path::new("out/package.tar.gz")),
+            PathBuf::from("out/package.tar.gz.part")
+        );
+    }
+
+    #[test]
+    fn test_parse_content_range_total() {
+        assert_eq!(parse_content_range_total("bytes 100-199/200"), Some(200));
+        assert_eq!(parse_content_range_total("bytes */4096"), Some(4096));
+        assert_eq!(parse_content_range_total("bytes 0-99/*"), None);
+    }
+}
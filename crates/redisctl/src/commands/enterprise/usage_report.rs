@@ -1,6 +1,9 @@
+use std::path::{Path, PathBuf};
+
 use crate::error::RedisCtlError;
 use anyhow::Context;
-use clap::Subcommand;
+use chrono::NaiveDate;
+use clap::{Subcommand, ValueEnum};
 
 use crate::{cli::OutputFormat, connection::ConnectionManager, error::Result as CliResult};
 
@@ -32,6 +35,64 @@ pub enum UsageReportCommands {
         #[arg(short, long, default_value = "json")]
         format: String,
     },
+
+    /// Save the usage report for the current period, for use from cron
+    ///
+    /// Reports are written to `<output-dir>/usage-report-<period>.<format>`, where
+    /// the period is the date (daily), ISO week (weekly), or month (monthly).
+    /// If the report for the current period already exists it is not regenerated,
+    /// so the command can be scheduled more often than the interval.
+    #[command(after_help = "EXAMPLES:
+    # Monthly CSV report, safe to run daily from cron
+    redisctl enterprise usage-report schedule --interval monthly --output-dir reports/ --format csv
+
+    # crontab entry
+    0 2 * * * redisctl enterprise usage-report schedule --interval monthly --output-dir /var/reports
+
+    # Regenerate the current period's report
+    redisctl enterprise usage-report schedule --interval weekly --output-dir reports/ --force
+")]
+    Schedule {
+        /// How often a new report is produced
+        #[arg(long, value_enum, default_value = "monthly")]
+        interval: ReportInterval,
+
+        /// Directory to save reports in (created if missing)
+        #[arg(long)]
+        output_dir: PathBuf,
+
+        /// Report format (json or csv)
+        #[arg(short, long, default_value = "json")]
+        format: String,
+
+        /// Regenerate even if the current period's report exists
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Report period for scheduled usage reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportInterval {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl ReportInterval {
+    /// Period label used in the report file name
+    fn period_label(self, date: NaiveDate) -> String {
+        match self {
+            ReportInterval::Daily => date.format("%Y-%m-%d").to_string(),
+            ReportInterval::Weekly => date.format("%G-W%V").to_string(),
+            ReportInterval::Monthly => date.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+/// File name of the scheduled report covering `date`
+fn scheduled_report_name(interval: ReportInterval, date: NaiveDate, format: &str) -> String {
+    format!("usage-report-{}.{}", interval.period_label(date), format)
 }
 
 impl UsageReportCommands {
@@ -73,6 +134,7 @@ async fn handle_usage_report_command_impl(
             super::utils::print_formatted_output(output_data, output_format)?;
         }
         UsageReportCommands::Export { output, format } => {
+            check_report_format(format)?;
             let response: serde_json::Value = client
                 .get("/v1/usage_report")
                 .await
@@ -84,28 +146,58 @@ async fn handle_usage_report_command_impl(
                 response
             };
 
-            match format.as_str() {
-                "json" => {
-                    let json_str = serde_json::to_string_pretty(&output_data)
-                        .context("Failed to serialize to JSON")?;
-                    std::fs::write(output, json_str)
-                        .context(format!("Failed to write to {}", output))?;
-                    println!("Usage report exported to {}", output);
-                }
-                "csv" => {
-                    // Convert JSON to CSV format
-                    let csv_data = json_to_csv(&output_data)?;
-                    std::fs::write(output, csv_data)
-                        .context(format!("Failed to write to {}", output))?;
-                    println!("Usage report exported to {} as CSV", output);
-                }
-                _ => {
-                    return Err(anyhow::anyhow!(
-                        "Unsupported format: {}. Use 'json' or 'csv'",
-                        format
-                    )
-                    .into());
-                }
+            write_report(Path::new(output), format, &output_data)?;
+            if format == "csv" {
+                println!("Usage report exported to {} as CSV", output);
+            } else {
+                println!("Usage report exported to {}", output);
+            }
+        }
+        UsageReportCommands::Schedule {
+            interval,
+            output_dir,
+            format,
+            force,
+        } => {
+            check_report_format(format)?;
+            let today = chrono::Local::now().date_naive();
+            let path = output_dir.join(scheduled_report_name(*interval, today, format));
+
+            let skipped = path.exists() && !*force;
+            if !skipped {
+                let response: serde_json::Value = client
+                    .get("/v1/usage_report")
+                    .await
+                    .map_err(RedisCtlError::from)?;
+
+                let output_data = if let Some(q) = query {
+                    super::utils::apply_jmespath(&response, q)?
+                } else {
+                    response
+                };
+
+                std::fs::create_dir_all(output_dir).map_err(|e| RedisCtlError::FileError {
+                    path: output_dir.display().to_string(),
+                    message: e.to_string(),
+                })?;
+                write_report(&path, format, &output_data)?;
+            }
+
+            if crate::output::resolve_auto(output_format).is_structured() {
+                let result = serde_json::json!({
+                    "path": path.display().to_string(),
+                    "period": interval.period_label(today),
+                    "generated": !skipped,
+                });
+                super::utils::print_formatted_output(result, output_format)?;
+            } else if skipped {
+                println!(
+                    "Usage report for {} already exists at {}; skipping",
+                    interval.period_label(today),
+                    path.display()
+                );
+            } else {
+                println!("Usage report saved to {}", path.display());
             }
         }
     }
@@ -113,6 +205,33 @@ async fn handle_usage_report_command_impl(
     Ok(())
 }
 
+fn check_report_format(format: &str) -> CliResult<()> {
+    match format {
+        "json" | "csv" => Ok(()),
+        _ => Err(RedisCtlError::InvalidInput {
+            message: format!("Unsupported format: {}. Use 'json' or 'csv'", format),
+        }),
+    }
+}
+
+/// Write the report to `path`, replacing any existing file atomically
+fn write_report(path: &Path, format: &str, data: &serde_json::Value) -> CliResult<()> {
+    let contents = if format == "csv" {
+        json_to_csv(data)?
+    } else {
+        serde_json::to_string_pretty(data).context("Failed to serialize to JSON")?
+    };
+
+    // Write next to the target and rename so a cron run never leaves a partial report
+    let tmp_path = path.with_extension(format!("{}.tmp", format));
+    std::fs::write(&tmp_path, contents)
+        .and_then(|_| std::fs::rename(&tmp_path, path))
+        .map_err(|e| RedisCtlError::FileError {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })
+}
+
 fn json_to_csv(data: &serde_json::Value) -> CliResult<String> {
     // Simple CSV conversion for usage report data
     let mut csv = String::new();
@@ -206,6 +325,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_schedule_command_parsing() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct TestCli {
+            #[command(subcommand)]
+            cmd: UsageReportCommands,
+        }
+
+        let cli = TestCli::parse_from([
+            "test",
+            "schedule",
+            "--interval",
+            "weekly",
+            "--output-dir",
+            "reports",
+            "--format",
+            "csv",
+        ]);
+        if let UsageReportCommands::Schedule {
+            interval,
+            output_dir,
+            format,
+            force,
+        } = cli.cmd
+        {
+            assert_eq!(interval, ReportInterval::Weekly);
+            assert_eq!(output_dir, PathBuf::from("reports"));
+            assert_eq!(format, "csv");
+            assert!(!force);
+        } else {
+            panic!("Expected Schedule command");
+        }
+    }
+
+    #[test]
+    fn test_scheduled_report_name() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        assert_eq!(
+            scheduled_report_name(ReportInterval::Daily, date, "json"),
+            "usage-report-2025-01-01.json"
+        );
+        // ISO week: 2025-01-01 falls in week 1 of 2025
+        assert_eq!(
+            scheduled_report_name(ReportInterval::Weekly, date, "csv"),
+            "usage-report-2025-W01.csv"
+        );
+        assert_eq!(
+            scheduled_report_name(ReportInterval::Monthly, date, "csv"),
+            "usage-report-2025-01.csv"
+        );
+    }
+
     #[test]
     fn test_json_to_csv() {
         // Test single object
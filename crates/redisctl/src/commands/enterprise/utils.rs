@@ -1,13 +1,23 @@
 //! Utility functions for Enterprise commands
-use crate::error::Result as CliResult;
+use crate::cli::OutputFormat;
+use crate::error::{RedisCtlError, Result as CliResult};
+use crate::output::emit;
 use anyhow::Context;
 use dialoguer::Confirm;
+use indicatif::{ProgressBar, ProgressStyle};
+use redisctl_core::enterprise::{EnterpriseProgressCallback, EnterpriseProgressEvent};
 use serde_json::Value;
+use std::borrow::Cow;
+use std::future::Future;
+use std::time::Duration;
+use tokio::signal;
 
 pub use crate::commands::cloud::utils::{
     DetailRow, extract_field, format_memory_size, format_status, output_with_pager, truncate_string,
 };
-pub use crate::output::{apply_jmespath, handle_output, print_formatted_output, resolve_auto};
+pub use crate::output::{
+    apply_jmespath, handle_output, print_formatted_output, resolve_auto, use_custom_table,
+};
 
 /// Confirm an action with the user
 pub fn confirm_action(message: &str) -> CliResult<bool> {
@@ -37,6 +47,21 @@ pub fn confirm_action(message: &str) -> CliResult<bool> {
     }
 }
 
+/// Confirm an action with the user, failing instead of cancelling when there
+/// is no terminal to ask on, so scripts notice that nothing was done
+pub fn confirm_or_fail(message: &str, confirm_flag: &str) -> CliResult<bool> {
+    use std::io::IsTerminal;
+    if !std::io::stdin().is_terminal() {
+        return Err(RedisCtlError::InvalidInput {
+            message: format!(
+                "{} Refusing to continue without confirmation: stdin is not a terminal. Pass {} to confirm.",
+                message, confirm_flag
+            ),
+        });
+    }
+    confirm_action(message)
+}
+
 /// Read JSON data from string, file, or stdin
 pub fn read_json_data(data: &str) -> CliResult<Value> {
     let json_str = if data == "-" {
@@ -66,3 +91,167 @@ pub fn read_json_data(data: &str) -> CliResult<Value> {
 pub fn format_bytes(bytes: u64) -> String {
     format_memory_size(bytes as f64 / (1024.0 * 1024.0 * 1024.0))
 }
+
+/// Spinner shown while a long-running Enterprise operation is in progress
+pub fn operation_spinner(message: impl Into<Cow<'static, str>>) -> ProgressBar {
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg} [{elapsed_precise}]")
+            .unwrap(),
+    );
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb.set_message(message);
+    pb
+}
+
+/// Progress callback that shows an action's events on `pb`, each message
+/// starting with `label` (e.g. "Export started: ...", "Export running: 40%").
+///
+/// The spinner is left running; callers finish or clear it once the workflow
+/// returns, since some keep waiting on other conditions afterwards.
+pub fn action_progress(pb: &ProgressBar, label: &str) -> EnterpriseProgressCallback {
+    let pb = pb.clone();
+    let label = label.to_string();
+    Box::new(move |event| match event {
+        EnterpriseProgressEvent::Started { action_uid } => {
+            pb.set_message(format!("{} started: {}", label, action_uid));
+        }
+        EnterpriseProgressEvent::Polling {
+            status,
+            progress,
+            eta,
+            ..
+        } => pb.set_message(match (progress, eta) {
+            (Some(pct), Some(eta)) => format!(
+                "{} {}: {}% (about {} left)",
+                label,
+                status,
+                pct,
+                indicatif::HumanDuration(eta)
+            ),
+            (Some(pct), None) => format!("{} {}: {}%", label, status, pct),
+            (None, _) => format!("{} status: {}", label, status),
+        }),
+        EnterpriseProgressEvent::Completed { .. } => {
+            pb.set_message(format!("{} completed", label));
+        }
+        EnterpriseProgressEvent::Failed { error, .. } => {
+            pb.set_message(format!("{} failed: {}", label, error));
+        }
+    })
+}
+
+/// Re-run `tick` every `interval_secs` seconds until Ctrl+C.
+///
+/// Table output clears the screen and redraws from the top on each refresh;
+/// other formats leave earlier output in place so the stream can be piped.
+/// A failed first refresh is returned as the error, later failures are
+/// reported on stderr and the watch carries on.
+pub async fn watch<F, Fut>(
+    interval_secs: u64,
+    output_format: OutputFormat,
+    mut tick: F,
+) -> CliResult<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = CliResult<()>>,
+{
+    let redraw = matches!(resolve_auto(output_format), OutputFormat::Table);
+    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut first = true;
+
+    loop {
+        tokio::select! {
+            _ = signal::ctrl_c() => break,
+            _ = interval.tick() => {}
+        }
+
+        if redraw {
+            print!("\x1B[2J\x1B[H");
+            println!(
+                "[{}] refreshing every {}s (Ctrl+C to stop)\n",
+                chrono::Local::now().format("%H:%M:%S"),
+                interval_secs
+            );
+        }
+
+        let result = tokio::select! {
+            _ = signal::ctrl_c() => break,
+            result = tick() => result,
+        };
+        match result {
+            Ok(()) => {}
+            Err(e) if first => return Err(e),
+            Err(e) => eprintln!("Error refreshing: {}", e),
+        }
+        first = false;
+    }
+    Ok(())
+}
+
+/// Print one refresh of a `--watch` loop.
+///
+/// JSON is written as a single compact line and YAML as a `---` separated
+/// document, so consumers can split the stream per tick. Other formats print
+/// as usual.
+pub fn print_watch_document(data: Value, output_format: OutputFormat) -> CliResult<()> {
+    match render_watch_document(&data, output_format)? {
+        Some(document) => emit(&document).map_err(|e| RedisCtlError::OutputError {
+            message: e.to_string(),
+        }),
+        None => print_formatted_output(data, output_format),
+    }
+}
+
+fn render_watch_document(data: &Value, output_format: OutputFormat) -> CliResult<Option<String>> {
+    let document = match resolve_auto(output_format) {
+        OutputFormat::Json => {
+            serde_json::to_string(data).context("Failed to serialize watch output")?
+        }
+        OutputFormat::Yaml => format!(
+            "---\n{}",
+            serde_yaml::to_string(data)
+                .context("Failed to serialize watch output")?
+                .trim_end()
+        ),
+        _ => return Ok(None),
+    };
+    Ok(Some(document))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_watch_document_json_is_one_line() {
+        let data = json!({"name": "db1", "shards": [1, 2]});
+        let doc = render_watch_document(&data, OutputFormat::Json)
+            .unwrap()
+            .unwrap();
+        assert!(!doc.contains('\n'));
+        assert_eq!(serde_json::from_str::<Value>(&doc).unwrap(), data);
+    }
+
+    #[test]
+    fn test_watch_document_yaml_is_separated() {
+        let data = json!({"name": "db1"});
+        let doc = render_watch_document(&data, OutputFormat::Yaml)
+            .unwrap()
+            .unwrap();
+        assert_eq!(doc, "---\nname: db1");
+    }
+
+    #[test]
+    fn test_watch_document_table_uses_regular_output() {
+        let data = json!({"name": "db1"});
+        assert!(
+            render_watch_document(&data, OutputFormat::Table)
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -33,13 +33,16 @@ pub async fn handle_set(
     }
 
     // Store in config file
-    let mut config = Config::load().unwrap_or_default();
-
     if let Some(profile_name) = profile {
         // Store in specific profile
-        if let Some(prof) = config.profiles.get_mut(&profile_name) {
-            prof.files_api_key = Some(api_key);
-            config.save()?;
+        let stored = Config::update(|config| match config.profiles.get_mut(&profile_name) {
+            Some(prof) => {
+                prof.files_api_key = Some(api_key);
+                true
+            }
+            None => false,
+        })?;
+        if stored {
             println!("✓ Files.com API key stored in profile '{}'", profile_name);
             println!("\n⚠️  Warning: Key is stored in plaintext in config file");
             #[cfg(feature = "secure-storage")]
@@ -49,8 +52,7 @@ pub async fn handle_set(
         }
     } else if global {
         // Store globally
-        config.files_api_key = Some(api_key);
-        config.save()?;
+        Config::update(|config| config.files_api_key = Some(api_key))?;
         println!("✓ Files.com API key stored globally in config");
         println!("\n⚠️  Warning: Key is stored in plaintext in config file");
         #[cfg(feature = "secure-storage")]
@@ -69,8 +71,7 @@ pub async fn handle_set(
         #[cfg(not(feature = "secure-storage"))]
         {
             // Without secure-storage, default to global
-            config.files_api_key = Some(api_key);
-            config.save()?;
+            Config::update(|config| config.files_api_key = Some(api_key))?;
             println!("✓ Files.com API key stored globally in config");
         }
     }
@@ -216,30 +217,27 @@ pub async fn handle_remove(
         return Ok(());
     }
 
-    let mut config = Config::load().context("Failed to load config")?;
-    let mut modified = false;
-
     if let Some(profile_name) = profile {
         // Remove from specific profile
-        if let Some(prof) = config.profiles.get_mut(&profile_name) {
-            if prof.files_api_key.is_some() {
-                prof.files_api_key = None;
-                modified = true;
-                println!(
-                    "✓ Files.com API key removed from profile '{}'",
-                    profile_name
-                );
-            } else {
-                println!("No Files.com API key set for profile '{}'", profile_name);
+        let removed = Config::update(|config| {
+            config
+                .profiles
+                .get_mut(&profile_name)
+                .map(|prof| prof.files_api_key.take().is_some())
+        })?;
+        match removed {
+            Some(true) => println!(
+                "✓ Files.com API key removed from profile '{}'",
+                profile_name
+            ),
+            Some(false) => {
+                println!("No Files.com API key set for profile '{}'", profile_name)
             }
-        } else {
-            anyhow::bail!("Profile '{}' not found", profile_name);
+            None => anyhow::bail!("Profile '{}' not found", profile_name),
         }
     } else if global {
         // Remove global key
-        if config.files_api_key.is_some() {
-            config.files_api_key = None;
-            modified = true;
+        if Config::update(|config| config.files_api_key.take().is_some())? {
             println!("✓ Files.com API key removed from global config");
         } else {
             println!("No global Files.com API key set");
@@ -253,9 +251,5 @@ pub async fn handle_remove(
         anyhow::bail!("No removal target specified");
     }
 
-    if modified {
-        config.save()?;
-    }
-
     Ok(())
 }
@@ -1,9 +1,15 @@
 //! Command implementations for the modernized CLI
 
 pub mod api;
+pub mod api_paths;
+pub mod batch;
 pub mod cloud;
+pub mod compare;
+pub mod config;
 pub mod curl;
 pub mod db;
 pub mod enterprise;
 pub mod files_key;
 pub mod profile;
+pub mod request_preview;
+pub mod resilience;
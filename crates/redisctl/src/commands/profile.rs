@@ -8,7 +8,7 @@ use crate::error::RedisCtlError;
 use crate::output;
 use anyhow::Context;
 use colored::Colorize;
-use redisctl_core::Config;
+use redisctl_core::{Config, ConfigError, DeploymentType};
 use serde::Serialize;
 use std::time::{Duration, Instant};
 use tracing::{debug, info, trace};
@@ -44,7 +44,16 @@ pub async fn handle_profile_command(
             #[cfg(feature = "secure-storage")]
             use_keyring,
             tags,
+            kubernetes,
+            secret_path,
+            namespace,
+            cluster,
         } => {
+            let kubernetes = kubernetes.then(|| redisctl_core::KubernetesSource {
+                secret_path: secret_path.clone(),
+                namespace: namespace.clone(),
+                cluster: cluster.clone(),
+            });
             handle_set(
                 conn_mgr,
                 name,
@@ -64,13 +73,26 @@ pub async fn handle_profile_command(
                 #[cfg(feature = "secure-storage")]
                 use_keyring,
                 tags,
+                kubernetes,
             )
             .await
         }
         Remove { name } => handle_remove(conn_mgr, name).await,
+        Copy {
+            source,
+            dest,
+            force,
+        } => handle_copy(conn_mgr, source, dest, *force).await,
+        Export {
+            names,
+            output,
+            include_secrets,
+        } => handle_export(conn_mgr, names, output.as_deref(), *include_secrets).await,
+        Import { input, overwrite } => handle_import(conn_mgr, input, *overwrite).await,
         DefaultEnterprise { name } => handle_default_enterprise(conn_mgr, name).await,
         DefaultCloud { name } => handle_default_cloud(conn_mgr, name).await,
         DefaultDatabase { name } => handle_default_database(conn_mgr, name).await,
+        ClearDefault { r#type } => handle_clear_default(conn_mgr, r#type).await,
         Validate { connect } => handle_validate(conn_mgr, *connect, output_format).await,
         Init => handle_init(conn_mgr).await,
     }
@@ -93,198 +115,194 @@ async fn handle_list(
     };
     trace!("Found {} profiles", profiles.len());
 
-    match output_format {
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let config_path = conn_mgr
-                .config_path
-                .as_ref()
-                .map(|p| p.to_string_lossy().to_string())
-                .or_else(|| {
-                    Config::config_path()
-                        .ok()
-                        .and_then(|p| p.to_str().map(String::from))
+    if output_format.is_structured() {
+        let config_path = conn_mgr
+            .config_path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string())
+            .or_else(|| {
+                Config::config_path()
+                    .ok()
+                    .and_then(|p| p.to_str().map(String::from))
+            });
+
+        let profile_list: Vec<serde_json::Value> = profiles
+            .iter()
+            .map(|(name, profile)| {
+                let is_default_enterprise =
+                    conn_mgr.config.default_enterprise.as_deref() == Some(name);
+                let is_default_cloud = conn_mgr.config.default_cloud.as_deref() == Some(name);
+
+                let mut obj = serde_json::json!({
+                    "name": name,
+                    "deployment_type": profile.deployment_type.to_string(),
+                    "is_default_enterprise": is_default_enterprise,
+                    "is_default_cloud": is_default_cloud,
                 });
 
-            let profile_list: Vec<serde_json::Value> = profiles
-                .iter()
-                .map(|(name, profile)| {
-                    let is_default_enterprise =
-                        conn_mgr.config.default_enterprise.as_deref() == Some(name);
-                    let is_default_cloud = conn_mgr.config.default_cloud.as_deref() == Some(name);
-
-                    let mut obj = serde_json::json!({
-                        "name": name,
-                        "deployment_type": profile.deployment_type.to_string(),
-                        "is_default_enterprise": is_default_enterprise,
-                        "is_default_cloud": is_default_cloud,
-                    });
-
-                    if !profile.tags.is_empty() {
-                        obj["tags"] = serde_json::json!(&profile.tags);
-                    }
+                if !profile.tags.is_empty() {
+                    obj["tags"] = serde_json::json!(&profile.tags);
+                }
 
-                    match profile.deployment_type {
-                        redisctl_core::DeploymentType::Cloud => {
-                            if let Some((_, _, url)) = profile.cloud_credentials() {
-                                obj["api_url"] = serde_json::json!(url);
-                            }
+                match profile.deployment_type {
+                    redisctl_core::DeploymentType::Cloud => {
+                        if let Some((_, _, url)) = profile.cloud_credentials() {
+                            obj["api_url"] = serde_json::json!(url);
                         }
-                        redisctl_core::DeploymentType::Enterprise => {
-                            if let Some((url, username, _, insecure, ca_cert)) =
-                                profile.enterprise_credentials()
-                            {
-                                obj["url"] = serde_json::json!(url);
-                                obj["username"] = serde_json::json!(username);
-                                obj["insecure"] = serde_json::json!(insecure);
-                                if let Some(cert_path) = ca_cert {
-                                    obj["ca_cert"] = serde_json::json!(cert_path);
-                                }
+                    }
+                    redisctl_core::DeploymentType::Enterprise => {
+                        if let Some((url, username, _, insecure, ca_cert)) =
+                            profile.enterprise_credentials()
+                        {
+                            obj["url"] = serde_json::json!(url);
+                            obj["username"] = serde_json::json!(username);
+                            obj["insecure"] = serde_json::json!(insecure);
+                            if let Some(cert_path) = ca_cert {
+                                obj["ca_cert"] = serde_json::json!(cert_path);
                             }
                         }
-                        redisctl_core::DeploymentType::Database => {
-                            if let Some((host, port, _, tls, username, database)) =
-                                profile.database_credentials()
-                            {
-                                obj["host"] = serde_json::json!(host);
-                                obj["port"] = serde_json::json!(port);
-                                obj["tls"] = serde_json::json!(tls);
-                                obj["username"] = serde_json::json!(username);
-                                obj["database"] = serde_json::json!(database);
-                            }
+                    }
+                    redisctl_core::DeploymentType::Database => {
+                        if let Some((host, port, _, tls, username, database)) =
+                            profile.database_credentials()
+                        {
+                            obj["host"] = serde_json::json!(host);
+                            obj["port"] = serde_json::json!(port);
+                            obj["tls"] = serde_json::json!(tls);
+                            obj["username"] = serde_json::json!(username);
+                            obj["database"] = serde_json::json!(database);
                         }
                     }
+                }
 
-                    obj
-                })
-                .collect();
+                obj
+            })
+            .collect();
 
-            let output_data = serde_json::json!({
-                "config_path": config_path,
-                "profiles": profile_list,
-                "count": profiles.len()
-            });
+        let output_data = serde_json::json!({
+            "config_path": config_path,
+            "profiles": profile_list,
+            "count": profiles.len()
+        });
 
-            output::print_output(&output_data, output_format, None)?;
+        output::print_output(&output_data, output_format, None)?;
+    } else {
+        // Show config file path at the top
+        if let Some(ref path) = conn_mgr.config_path {
+            println!("Configuration file: {}", path.display());
+            println!();
+        } else if let Ok(config_path) = Config::config_path() {
+            println!("Configuration file: {}", config_path.display());
+            println!();
         }
-        _ => {
-            // Show config file path at the top
-            if let Some(ref path) = conn_mgr.config_path {
-                println!("Configuration file: {}", path.display());
-                println!();
-            } else if let Ok(config_path) = Config::config_path() {
-                println!("Configuration file: {}", config_path.display());
-                println!();
-            }
 
-            if profiles.is_empty() {
-                info!("No profiles configured");
-                println!("No profiles configured.");
-                println!("Use 'redisctl profile set' to create a profile.");
-                return Ok(());
-            }
+        if profiles.is_empty() {
+            info!("No profiles configured");
+            println!("No profiles configured.");
+            println!("Use 'redisctl profile set' to create a profile.");
+            return Ok(());
+        }
 
-            // Group profiles by deployment type
-            let mut cloud_profiles = Vec::new();
-            let mut enterprise_profiles = Vec::new();
-            let mut database_profiles = Vec::new();
+        // Group profiles by deployment type
+        let mut cloud_profiles = Vec::new();
+        let mut enterprise_profiles = Vec::new();
+        let mut database_profiles = Vec::new();
 
-            for (name, profile) in &profiles {
-                match profile.deployment_type {
-                    redisctl_core::DeploymentType::Cloud => cloud_profiles.push((*name, *profile)),
-                    redisctl_core::DeploymentType::Enterprise => {
-                        enterprise_profiles.push((*name, *profile))
-                    }
-                    redisctl_core::DeploymentType::Database => {
-                        database_profiles.push((*name, *profile))
-                    }
+        for (name, profile) in &profiles {
+            match profile.deployment_type {
+                redisctl_core::DeploymentType::Cloud => cloud_profiles.push((*name, *profile)),
+                redisctl_core::DeploymentType::Enterprise => {
+                    enterprise_profiles.push((*name, *profile))
+                }
+                redisctl_core::DeploymentType::Database => {
+                    database_profiles.push((*name, *profile))
                 }
             }
+        }
 
-            let print_section = |header: &str,
-                                 group: &[(&String, &redisctl_core::Profile)],
-                                 default_name: Option<&str>,
-                                 first: &mut bool| {
-                if group.is_empty() {
-                    return;
-                }
-                if !*first {
-                    println!();
+        let print_section = |header: &str,
+                             group: &[(&String, &redisctl_core::Profile)],
+                             default_name: Option<&str>,
+                             first: &mut bool| {
+            if group.is_empty() {
+                return;
+            }
+            if !*first {
+                println!();
+            }
+            *first = false;
+            println!("{}", header.bold());
+            for (name, profile) in group {
+                let tag_suffix = if profile.tags.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{}]", profile.tags.join(", "))
+                        .dimmed()
+                        .to_string()
+                };
+                if default_name == Some(name.as_str()) {
+                    println!(
+                        "  {} {}{}",
+                        name.bold().cyan(),
+                        "(default)".green(),
+                        tag_suffix
+                    );
+                } else {
+                    println!("  {}{}", name.bold().cyan(), tag_suffix);
                 }
-                *first = false;
-                println!("{}", header.bold());
-                for (name, profile) in group {
-                    let tag_suffix = if profile.tags.is_empty() {
-                        String::new()
-                    } else {
-                        format!(" [{}]", profile.tags.join(", "))
-                            .dimmed()
-                            .to_string()
-                    };
-                    if default_name == Some(name.as_str()) {
-                        println!(
-                            "  {} {}{}",
-                            name.bold().cyan(),
-                            "(default)".green(),
-                            tag_suffix
-                        );
-                    } else {
-                        println!("  {}{}", name.bold().cyan(), tag_suffix);
-                    }
-                    match profile.deployment_type {
-                        redisctl_core::DeploymentType::Cloud => {
-                            if let Some((_, _, url)) = profile.cloud_credentials() {
-                                println!("    {} {}", "URL:".dimmed(), url);
-                            }
+                match profile.deployment_type {
+                    redisctl_core::DeploymentType::Cloud => {
+                        if let Some((_, _, url)) = profile.cloud_credentials() {
+                            println!("    {} {}", "URL:".dimmed(), url);
                         }
-                        redisctl_core::DeploymentType::Enterprise => {
-                            if let Some((url, username, _, insecure, _ca_cert)) =
-                                profile.enterprise_credentials()
-                            {
-                                println!("    {}  {}", "URL:".dimmed(), url);
-                                println!(
-                                    "    {} {}{}",
-                                    "User:".dimmed(),
-                                    username,
-                                    if insecure { " (insecure)" } else { "" }
-                                );
-                            }
+                    }
+                    redisctl_core::DeploymentType::Enterprise => {
+                        if let Some((url, username, _, insecure, _ca_cert)) =
+                            profile.enterprise_credentials()
+                        {
+                            println!("    {}  {}", "URL:".dimmed(), url);
+                            println!(
+                                "    {} {}{}",
+                                "User:".dimmed(),
+                                username,
+                                if insecure { " (insecure)" } else { "" }
+                            );
                         }
-                        redisctl_core::DeploymentType::Database => {
-                            if let Some((host, port, _, tls, _, _)) = profile.database_credentials()
-                            {
-                                println!(
-                                    "    {} {}:{} {}",
-                                    "Host:".dimmed(),
-                                    host,
-                                    port,
-                                    if tls { "(TLS)" } else { "(no TLS)" }
-                                );
-                            }
+                    }
+                    redisctl_core::DeploymentType::Database => {
+                        if let Some((host, port, _, tls, _, _)) = profile.database_credentials() {
+                            println!(
+                                "    {} {}:{} {}",
+                                "Host:".dimmed(),
+                                host,
+                                port,
+                                if tls { "(TLS)" } else { "(no TLS)" }
+                            );
                         }
                     }
                 }
-            };
+            }
+        };
 
-            let mut first = true;
-            print_section(
-                "Cloud",
-                &cloud_profiles,
-                conn_mgr.config.default_cloud.as_deref(),
-                &mut first,
-            );
-            print_section(
-                "Enterprise",
-                &enterprise_profiles,
-                conn_mgr.config.default_enterprise.as_deref(),
-                &mut first,
-            );
-            print_section(
-                "Database",
-                &database_profiles,
-                conn_mgr.config.default_database.as_deref(),
-                &mut first,
-            );
-        }
+        let mut first = true;
+        print_section(
+            "Cloud",
+            &cloud_profiles,
+            conn_mgr.config.default_cloud.as_deref(),
+            &mut first,
+        );
+        print_section(
+            "Enterprise",
+            &enterprise_profiles,
+            conn_mgr.config.default_enterprise.as_deref(),
+            &mut first,
+        );
+        print_section(
+            "Database",
+            &database_profiles,
+            conn_mgr.config.default_database.as_deref(),
+            &mut first,
+        );
     }
 
     Ok(())
@@ -293,17 +311,14 @@ async fn handle_list(
 async fn handle_path(output_format: OutputFormat) -> Result<(), RedisCtlError> {
     let config_path = Config::config_path()?;
 
-    match output_format {
-        OutputFormat::Json | OutputFormat::Yaml => {
-            let output_data = serde_json::json!({
-                "config_path": config_path.to_str()
-            });
+    if output_format.is_structured() {
+        let output_data = serde_json::json!({
+            "config_path": config_path.to_str()
+        });
 
-            output::print_output(&output_data, output_format, None)?;
-        }
-        _ => {
-            println!("{}", config_path.display());
-        }
+        output::print_output(&output_data, output_format, None)?;
+    } else {
+        println!("{}", config_path.display());
     }
     Ok(())
 }
@@ -334,135 +349,156 @@ async fn handle_show(
         Some(profile) => {
             let is_default_enterprise = conn_mgr.config.default_enterprise.as_deref() == Some(name);
             let is_default_cloud = conn_mgr.config.default_cloud.as_deref() == Some(name);
+            let breaker = match profile.deployment_type {
+                redisctl_core::DeploymentType::Database => None,
+                _ => crate::resilience::CircuitBreaker::for_profile(conn_mgr, name)
+                    .map(|breaker| breaker.metrics()),
+            };
 
-            match output_format {
-                OutputFormat::Json | OutputFormat::Yaml => {
-                    let mut output_data = serde_json::json!({
-                        "name": name,
-                        "deployment_type": profile.deployment_type.to_string(),
-                        "is_default_enterprise": is_default_enterprise,
-                        "is_default_cloud": is_default_cloud,
-                    });
-
-                    if !profile.tags.is_empty() {
-                        output_data["tags"] = serde_json::json!(&profile.tags);
-                    }
+            if output_format.is_structured() {
+                let mut output_data = serde_json::json!({
+                    "name": name,
+                    "deployment_type": profile.deployment_type.to_string(),
+                    "is_default_enterprise": is_default_enterprise,
+                    "is_default_cloud": is_default_cloud,
+                });
 
-                    match profile.deployment_type {
-                        redisctl_core::DeploymentType::Cloud => {
-                            if let Some((api_key, _, api_url)) = profile.cloud_credentials() {
-                                output_data["api_key_preview"] = serde_json::json!(format!(
-                                    "{}...",
-                                    &api_key[..std::cmp::min(8, api_key.len())]
-                                ));
-                                output_data["api_url"] = serde_json::json!(api_url);
-                            }
+                if !profile.tags.is_empty() {
+                    output_data["tags"] = serde_json::json!(&profile.tags);
+                }
+                if let Some(base) = &profile.base {
+                    output_data["base"] = serde_json::json!(base);
+                }
+
+                match profile.deployment_type {
+                    redisctl_core::DeploymentType::Cloud => {
+                        if let Some((api_key, _, api_url)) = profile.cloud_credentials() {
+                            output_data["api_key_preview"] = serde_json::json!(format!(
+                                "{}...",
+                                &api_key[..std::cmp::min(8, api_key.len())]
+                            ));
+                            output_data["api_url"] = serde_json::json!(api_url);
                         }
-                        redisctl_core::DeploymentType::Enterprise => {
-                            if let Some((url, username, has_password, insecure, ca_cert)) =
-                                profile.enterprise_credentials()
-                            {
-                                output_data["url"] = serde_json::json!(url);
-                                output_data["username"] = serde_json::json!(username);
-                                output_data["password_configured"] =
-                                    serde_json::json!(has_password.is_some());
-                                output_data["insecure"] = serde_json::json!(insecure);
-                                if let Some(cert_path) = ca_cert {
-                                    output_data["ca_cert"] = serde_json::json!(cert_path);
-                                }
+                    }
+                    redisctl_core::DeploymentType::Enterprise => {
+                        if let Some((url, username, has_password, insecure, ca_cert)) =
+                            profile.enterprise_credentials()
+                        {
+                            output_data["url"] = serde_json::json!(url);
+                            output_data["username"] = serde_json::json!(username);
+                            output_data["password_configured"] =
+                                serde_json::json!(has_password.is_some());
+                            output_data["insecure"] = serde_json::json!(insecure);
+                            if let Some(cert_path) = ca_cert {
+                                output_data["ca_cert"] = serde_json::json!(cert_path);
                             }
                         }
-                        redisctl_core::DeploymentType::Database => {
-                            if let Some((host, port, has_password, tls, username, database)) =
-                                profile.database_credentials()
-                            {
-                                output_data["host"] = serde_json::json!(host);
-                                output_data["port"] = serde_json::json!(port);
-                                output_data["password_configured"] =
-                                    serde_json::json!(has_password.is_some());
-                                output_data["tls"] = serde_json::json!(tls);
-                                output_data["username"] = serde_json::json!(username);
-                                output_data["database"] = serde_json::json!(database);
-                            }
+                        if let Some(source) = &profile.kubernetes {
+                            output_data["kubernetes"] = serde_json::json!({
+                                "secret_path": source.secret_dir(),
+                                "namespace": source.namespace(),
+                                "cluster": source.cluster,
+                            });
                         }
                     }
+                    redisctl_core::DeploymentType::Database => {
+                        if let Some((host, port, has_password, tls, username, database)) =
+                            profile.database_credentials()
+                        {
+                            output_data["host"] = serde_json::json!(host);
+                            output_data["port"] = serde_json::json!(port);
+                            output_data["password_configured"] =
+                                serde_json::json!(has_password.is_some());
+                            output_data["tls"] = serde_json::json!(tls);
+                            output_data["username"] = serde_json::json!(username);
+                            output_data["database"] = serde_json::json!(database);
+                        }
+                    }
+                }
 
-                    let fmt = match output_format {
-                        OutputFormat::Json => output::OutputFormat::Json,
-                        OutputFormat::Yaml => output::OutputFormat::Yaml,
-                        _ => output::OutputFormat::Json,
-                    };
+                if let Some(metrics) = &breaker {
+                    output_data["circuit_breaker"] = serde_json::json!(metrics);
+                }
 
-                    output::print_output(&output_data, fmt, None)?;
+                output::print_output(&output_data, output_format, None)?;
+            } else {
+                println!("Profile: {}", name);
+                println!("Type: {}", profile.deployment_type);
+                if let Some(base) = &profile.base {
+                    println!("Inherits from: {}", base);
+                }
+                if !profile.tags.is_empty() {
+                    println!("Tags: {}", profile.tags.join(", "));
                 }
-                _ => {
-                    println!("Profile: {}", name);
-                    println!("Type: {}", profile.deployment_type);
-                    if !profile.tags.is_empty() {
-                        println!("Tags: {}", profile.tags.join(", "));
-                    }
 
-                    match profile.deployment_type {
-                        redisctl_core::DeploymentType::Cloud => {
-                            if let Some((api_key, _, api_url)) = profile.cloud_credentials() {
-                                println!(
-                                    "API Key: {}...",
-                                    &api_key[..std::cmp::min(8, api_key.len())]
-                                );
-                                println!("API URL: {}", api_url);
-                            }
+                match profile.deployment_type {
+                    redisctl_core::DeploymentType::Cloud => {
+                        if let Some((api_key, _, api_url)) = profile.cloud_credentials() {
+                            println!(
+                                "API Key: {}...",
+                                &api_key[..std::cmp::min(8, api_key.len())]
+                            );
+                            println!("API URL: {}", api_url);
                         }
-                        redisctl_core::DeploymentType::Enterprise => {
-                            if let Some((url, username, has_password, insecure, ca_cert)) =
-                                profile.enterprise_credentials()
-                            {
-                                println!("URL: {}", url);
-                                println!("Username: {}", username);
-                                println!(
-                                    "Password: {}",
-                                    if has_password.is_some() {
-                                        "configured"
-                                    } else {
-                                        "not set"
-                                    }
-                                );
-                                println!("Insecure: {}", insecure);
-                                if let Some(cert_path) = ca_cert {
-                                    println!("CA Cert: {}", cert_path);
+                    }
+                    redisctl_core::DeploymentType::Enterprise => {
+                        if let Some((url, username, has_password, insecure, ca_cert)) =
+                            profile.enterprise_credentials()
+                        {
+                            println!("URL: {}", url);
+                            println!("Username: {}", username);
+                            println!(
+                                "Password: {}",
+                                if profile.kubernetes.is_some() {
+                                    "from Kubernetes secret"
+                                } else if has_password.is_some() {
+                                    "configured"
+                                } else {
+                                    "not set"
                                 }
+                            );
+                            println!("Insecure: {}", insecure);
+                            if let Some(cert_path) = ca_cert {
+                                println!("CA Cert: {}", cert_path);
                             }
                         }
-                        redisctl_core::DeploymentType::Database => {
-                            if let Some((host, port, has_password, tls, username, database)) =
-                                profile.database_credentials()
-                            {
-                                println!("Host: {}", host);
-                                println!("Port: {}", port);
-                                println!("Username: {}", username);
-                                println!(
-                                    "Password: {}",
-                                    if has_password.is_some() {
-                                        "configured"
-                                    } else {
-                                        "not set"
-                                    }
-                                );
-                                println!("TLS: {}", tls);
-                                println!("Database: {}", database);
-                            }
+                        if let Some(source) = &profile.kubernetes {
+                            println!("Kubernetes secret: {}", source.secret_dir().display());
                         }
                     }
-
-                    if is_default_enterprise {
-                        println!("Default for enterprise: yes");
-                    }
-                    if is_default_cloud {
-                        println!("Default for cloud: yes");
-                    }
-                    if conn_mgr.config.default_database.as_deref() == Some(name) {
-                        println!("Default for database: yes");
+                    redisctl_core::DeploymentType::Database => {
+                        if let Some((host, port, has_password, tls, username, database)) =
+                            profile.database_credentials()
+                        {
+                            println!("Host: {}", host);
+                            println!("Port: {}", port);
+                            println!("Username: {}", username);
+                            println!(
+                                "Password: {}",
+                                if has_password.is_some() {
+                                    "configured"
+                                } else {
+                                    "not set"
+                                }
+                            );
+                            println!("TLS: {}", tls);
+                            println!("Database: {}", database);
+                        }
                     }
                 }
+
+                if is_default_enterprise {
+                    println!("Default for enterprise: yes");
+                }
+                if is_default_cloud {
+                    println!("Default for cloud: yes");
+                }
+                if conn_mgr.config.default_database.as_deref() == Some(name) {
+                    println!("Default for database: yes");
+                }
+                if let Some(metrics) = &breaker {
+                    print_circuit_breaker(metrics);
+                }
             }
 
             Ok(())
@@ -471,6 +507,67 @@ async fn handle_show(
     }
 }
 
+/// Human-readable circuit breaker state for `profile show`
+fn print_circuit_breaker(metrics: &redisctl_core::CircuitBreakerMetrics) {
+    println!(
+        "Circuit breaker: {} ({} of last {} calls failed)",
+        metrics.state, metrics.failures, metrics.calls
+    );
+    if let Some(secs) = metrics.retry_in_secs {
+        println!("  Half-open in: {}s", secs);
+    }
+    if let Some(trip) = metrics
+        .last_trip
+        .and_then(|t| chrono::DateTime::from_timestamp(t as i64, 0))
+    {
+        println!(
+            "  Last trip: {} ({} total)",
+            trip.to_rfc3339(),
+            metrics.trips
+        );
+    }
+}
+
+/// Build an Enterprise profile that reads its credentials from a mounted
+/// operator secret. The secret must be readable when the profile is created;
+/// its URL and username are stored as a fallback, the password never is.
+fn kubernetes_profile(
+    source: redisctl_core::KubernetesSource,
+    insecure: bool,
+    ca_cert: &Option<String>,
+    tags: Vec<String>,
+) -> Result<redisctl_core::Profile, RedisCtlError> {
+    let secret = source
+        .read_secret()
+        .map_err(|e| RedisCtlError::InvalidInput {
+            message: format!("{} (is the secret mounted? set --secret-path otherwise)", e),
+        })?;
+    let url = secret.url.ok_or_else(|| RedisCtlError::InvalidInput {
+        message: format!(
+            "The operator secret at {} has no url file; pass --cluster (and --namespace \
+             when running outside the pod) to derive the API URL",
+            source.secret_dir().display()
+        ),
+    })?;
+
+    Ok(redisctl_core::Profile {
+        deployment_type: redisctl_core::DeploymentType::Enterprise,
+        credentials: redisctl_core::ProfileCredentials::Enterprise {
+            url,
+            username: secret.username,
+            password: None,
+            insecure,
+            ca_cert: ca_cert.clone(),
+        },
+        files_api_key: None,
+        resilience: None,
+        tags,
+        kubernetes: Some(source),
+        output: None,
+        base: None,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn handle_set(
     conn_mgr: &ConnectionManager,
@@ -490,9 +587,38 @@ async fn handle_set(
     db: &Option<u8>,
     #[cfg(feature = "secure-storage")] use_keyring: &bool,
     tags: &[String],
+    kubernetes: Option<redisctl_core::KubernetesSource>,
 ) -> Result<(), RedisCtlError> {
     debug!("Setting profile: {}", name);
 
+    if kubernetes.is_some() && *deployment != redisctl_core::DeploymentType::Enterprise {
+        return Err(RedisCtlError::InvalidInput {
+            message: "--kubernetes only applies to Enterprise profiles".to_string(),
+        });
+    }
+
+    match deployment {
+        DeploymentType::Cloud => {
+            validate_cloud_args(api_key.as_deref(), api_secret.as_deref(), api_url)?
+        }
+        DeploymentType::Enterprise => {
+            validate_enterprise_args(url.as_deref(), *insecure, ca_cert.as_deref())?
+        }
+        DeploymentType::Database => validate_database_args(host.as_deref(), url.as_deref())?,
+    }
+    validate_fields_for_type(
+        *deployment,
+        &[
+            ("--api-key", api_key.is_some(), DeploymentType::Cloud),
+            ("--api-secret", api_secret.is_some(), DeploymentType::Cloud),
+            ("--url", url.is_some(), DeploymentType::Enterprise),
+            ("--host", host.is_some(), DeploymentType::Database),
+            ("--port", port.is_some(), DeploymentType::Database),
+            ("--no-tls", *no_tls, DeploymentType::Database),
+            ("--db", db.is_some(), DeploymentType::Database),
+        ],
+    )?;
+
     // Check if profile already exists
     if conn_mgr.config.profiles.contains_key(name) {
         // Ask for confirmation before updating
@@ -527,8 +653,11 @@ async fn handle_set(
     };
 
     // Create the profile based on deployment type
-    let profile = match deployment {
-        redisctl_core::DeploymentType::Cloud => {
+    let profile = match (*deployment, kubernetes) {
+        (redisctl_core::DeploymentType::Enterprise, Some(source)) => {
+            kubernetes_profile(source, *insecure, ca_cert, effective_tags.clone())?
+        }
+        (redisctl_core::DeploymentType::Cloud, _) => {
             let api_key = api_key
                 .clone()
                 .ok_or_else(|| anyhow::anyhow!("API key is required for Cloud profiles"))?;
@@ -569,15 +698,22 @@ async fn handle_set(
                 files_api_key: None,
                 resilience: None,
                 tags: effective_tags.clone(),
+                kubernetes: None,
+                output: None,
+                base: None,
             }
         }
-        redisctl_core::DeploymentType::Enterprise => {
-            let url = url
-                .clone()
-                .ok_or_else(|| anyhow::anyhow!("URL is required for Enterprise profiles"))?;
+        (redisctl_core::DeploymentType::Enterprise, None) => {
+            let url = url.clone().ok_or_else(|| RedisCtlError::InvalidInput {
+                message: "--url is required for Enterprise profiles (or use --kubernetes)"
+                    .to_string(),
+            })?;
             let username = username
                 .clone()
-                .ok_or_else(|| anyhow::anyhow!("Username is required for Enterprise profiles"))?;
+                .ok_or_else(|| RedisCtlError::InvalidInput {
+                    message: "--username is required for Enterprise profiles (or use --kubernetes)"
+                        .to_string(),
+                })?;
 
             // Prompt for password if not provided
             let password = match password {
@@ -631,9 +767,12 @@ async fn handle_set(
                 files_api_key: None,
                 resilience: None,
                 tags: effective_tags.clone(),
+                kubernetes: None,
+                output: None,
+                base: None,
             }
         }
-        redisctl_core::DeploymentType::Database => {
+        (redisctl_core::DeploymentType::Database, _) => {
             let host = host
                 .clone()
                 .ok_or_else(|| anyhow::anyhow!("Host is required for Database profiles"))?;
@@ -689,6 +828,9 @@ async fn handle_set(
                 files_api_key: None,
                 resilience: None,
                 tags: effective_tags,
+                kubernetes: None,
+                output: None,
+                base: None,
             }
         }
     };
@@ -698,6 +840,8 @@ async fn handle_set(
         redisctl_core::Profile {
             files_api_key: profile.files_api_key.or(existing.files_api_key.clone()),
             resilience: profile.resilience.or(existing.resilience.clone()),
+            output: profile.output.or(existing.output.clone()),
+            base: profile.base.or(existing.base.clone()),
             ..profile
         }
     } else {
@@ -705,29 +849,23 @@ async fn handle_set(
     };
 
     // Update the configuration
-    let mut config = conn_mgr.config.clone();
-    config.profiles.insert(name.to_string(), profile);
+    let profiles_of_type = conn_mgr.update_config(|config| {
+        config.profiles.insert(name.to_string(), profile);
+        config.get_profiles_of_type(*deployment).len()
+    })?;
 
-    // Save the configuration to the appropriate location
     if let Some(ref path) = conn_mgr.config_path {
-        config
-            .save_to_path(path)
-            .context("Failed to save configuration")?;
         println!("Profile '{}' saved successfully to:", name);
         println!("  {}", path.display());
+    } else if let Ok(config_path) = Config::config_path() {
+        println!("Profile '{}' saved successfully to:", name);
+        println!("  {}", config_path.display());
     } else {
-        config.save().context("Failed to save configuration")?;
-        if let Ok(config_path) = Config::config_path() {
-            println!("Profile '{}' saved successfully to:", name);
-            println!("  {}", config_path.display());
-        } else {
-            println!("Profile '{}' saved successfully.", name);
-        }
+        println!("Profile '{}' saved successfully.", name);
     }
 
     // Suggest setting as default if it's the only profile of its type
-    let profiles_of_type = config.get_profiles_of_type(*deployment);
-    if profiles_of_type.len() == 1 {
+    if profiles_of_type == 1 {
         println!();
         match deployment {
             redisctl_core::DeploymentType::Enterprise => {
@@ -748,6 +886,192 @@ async fn handle_set(
     Ok(())
 }
 
+fn invalid_field(field: &str, reason: String, suggestion: String) -> ConfigError {
+    ConfigError::InvalidField {
+        field: field.to_string(),
+        reason,
+        suggestion,
+    }
+}
+
+/// Check that `value` is an http(s) URL with a host and a usable port
+fn validate_http_url(field: &str, value: &str, example: &str) -> Result<url::Url, ConfigError> {
+    if !value.contains("://") {
+        return Err(invalid_field(
+            field,
+            format!("'{}' has no scheme", value),
+            format!("Include the scheme, e.g. {} https://{}", field, value),
+        ));
+    }
+    let parsed = url::Url::parse(value).map_err(|e| match e {
+        url::ParseError::InvalidPort => invalid_field(
+            field,
+            format!("'{}' has a port outside 1-65535", value),
+            format!("Use a valid port, e.g. {} {}", field, example),
+        ),
+        e => invalid_field(
+            field,
+            format!("'{}' is not a valid URL ({})", value, e),
+            format!("Use a URL such as {} {}", field, example),
+        ),
+    })?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid_field(
+            field,
+            format!("unsupported scheme '{}' in '{}'", parsed.scheme(), value),
+            format!("Use an https:// URL, e.g. {} {}", field, example),
+        ));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(invalid_field(
+            field,
+            format!("'{}' has no host", value),
+            format!("Use a URL such as {} {}", field, example),
+        ));
+    }
+    if parsed.port() == Some(0) {
+        return Err(invalid_field(
+            field,
+            format!("'{}' uses port 0", value),
+            format!("Use a valid port, e.g. {} {}", field, example),
+        ));
+    }
+    Ok(parsed)
+}
+
+fn validate_cloud_args(
+    api_key: Option<&str>,
+    api_secret: Option<&str>,
+    api_url: &str,
+) -> Result<(), ConfigError> {
+    for (field, value) in [("--api-key", api_key), ("--api-secret", api_secret)] {
+        if value.is_some_and(|v| v.trim().is_empty()) {
+            return Err(invalid_field(
+                field,
+                "value is empty".to_string(),
+                "Copy the key from the Redis Cloud console under Access Management > API Keys"
+                    .to_string(),
+            ));
+        }
+    }
+    validate_http_url("--api-url", api_url, "https://api.redislabs.com/v1")?;
+    Ok(())
+}
+
+fn validate_enterprise_args(
+    url: Option<&str>,
+    insecure: bool,
+    ca_cert: Option<&str>,
+) -> Result<(), ConfigError> {
+    if let Some(url) = url {
+        validate_http_url("--url", url, "https://cluster.example.com:9443")?;
+    }
+    if let Some(ca_cert) = ca_cert {
+        if insecure {
+            return Err(invalid_field(
+                "--ca-cert",
+                "--insecure disables certificate verification, so the CA certificate would be ignored"
+                    .to_string(),
+                "Drop --insecure to verify the cluster against --ca-cert, or drop --ca-cert"
+                    .to_string(),
+            ));
+        }
+        if !std::path::Path::new(ca_cert).is_file() {
+            return Err(invalid_field(
+                "--ca-cert",
+                format!("'{}' does not exist or is not a file", ca_cert),
+                "Pass the path of the cluster's PEM-encoded CA certificate".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_database_args(host: Option<&str>, url: Option<&str>) -> Result<(), ConfigError> {
+    if let Some(url) = url {
+        let suggestion = match url::Url::parse(url) {
+            Ok(parsed) if parsed.host_str().is_some() => format!(
+                "Database profiles take the address separately, e.g. --host {} --port {}",
+                parsed.host_str().unwrap_or_default(),
+                parsed.port().unwrap_or(6379)
+            ),
+            _ => "Database profiles take the address as --host and --port".to_string(),
+        };
+        return Err(invalid_field(
+            "--url",
+            "only applies to Enterprise profiles".to_string(),
+            suggestion,
+        ));
+    }
+    let Some(host) = host else {
+        return Ok(());
+    };
+    if host.trim().is_empty() {
+        return Err(invalid_field(
+            "--host",
+            "value is empty".to_string(),
+            "Pass the database endpoint hostname, e.g. --host redis-12345.example.com".to_string(),
+        ));
+    }
+    if let Some((_, rest)) = host.split_once("://") {
+        let bare = rest.split(['/', '@']).next_back().unwrap_or(rest);
+        return Err(invalid_field(
+            "--host",
+            format!("'{}' is a URL, not a hostname", host),
+            format!(
+                "Pass only the hostname and use --port and --no-tls for the rest, e.g. --host {}",
+                bare.split(':').next().unwrap_or(bare)
+            ),
+        ));
+    }
+    // host:port, but not a bare IPv6 address
+    if let Some((name, port)) = host.rsplit_once(':')
+        && !name.contains(':')
+    {
+        return Err(invalid_field(
+            "--host",
+            format!("'{}' includes a port", host),
+            format!(
+                "Pass the port separately, e.g. --host {} --port {}",
+                name, port
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject flags that only apply to another deployment type
+fn validate_fields_for_type(
+    deployment: DeploymentType,
+    fields: &[(&str, bool, DeploymentType)],
+) -> Result<(), ConfigError> {
+    let type_name = |t: DeploymentType| match t {
+        DeploymentType::Cloud => "cloud",
+        DeploymentType::Enterprise => "enterprise",
+        DeploymentType::Database => "database",
+    };
+    match fields
+        .iter()
+        .find(|(_, given, applies_to)| *given && *applies_to != deployment)
+    {
+        Some((field, _, applies_to)) => Err(invalid_field(
+            field,
+            format!(
+                "only applies to {} profiles, not {} profiles",
+                type_name(*applies_to),
+                type_name(deployment)
+            ),
+            format!(
+                "Remove {}, or use --type {} if this is a {} profile",
+                field,
+                type_name(*applies_to),
+                type_name(*applies_to)
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
 async fn handle_init(conn_mgr: &ConnectionManager) -> Result<(), RedisCtlError> {
     use dialoguer::{Input, Select};
 
@@ -847,6 +1171,9 @@ async fn handle_init(conn_mgr: &ConnectionManager) -> Result<(), RedisCtlError>
                 files_api_key: None,
                 resilience: None,
                 tags: vec![],
+                kubernetes: None,
+                output: None,
+                base: None,
             }
         }
         redisctl_core::DeploymentType::Enterprise => {
@@ -890,6 +1217,9 @@ async fn handle_init(conn_mgr: &ConnectionManager) -> Result<(), RedisCtlError>
                 files_api_key: None,
                 resilience: None,
                 tags: vec![],
+                kubernetes: None,
+                output: None,
+                base: None,
             }
         }
         redisctl_core::DeploymentType::Database => {
@@ -942,6 +1272,9 @@ async fn handle_init(conn_mgr: &ConnectionManager) -> Result<(), RedisCtlError>
                 files_api_key: None,
                 resilience: None,
                 tags: vec![],
+                kubernetes: None,
+                output: None,
+                base: None,
             }
         }
     };
@@ -1005,40 +1338,34 @@ async fn handle_init(conn_mgr: &ConnectionManager) -> Result<(), RedisCtlError>
     }
 
     // Step 5: Save
-    let mut config = conn_mgr.config.clone();
-    config.profiles.insert(name.clone(), profile);
+    let is_first = conn_mgr.update_config(|config| {
+        config.profiles.insert(name.clone(), profile);
 
-    // Auto-set as default if first profile of this type
-    let is_first = config.get_profiles_of_type(deployment_type).len() == 1;
-    if is_first {
-        match deployment_type {
-            redisctl_core::DeploymentType::Cloud => {
-                config.default_cloud = Some(name.clone());
-            }
-            redisctl_core::DeploymentType::Enterprise => {
-                config.default_enterprise = Some(name.clone());
-            }
-            redisctl_core::DeploymentType::Database => {
-                config.default_database = Some(name.clone());
+        // Auto-set as default if first profile of this type
+        let is_first = config.get_profiles_of_type(deployment_type).len() == 1;
+        if is_first {
+            match deployment_type {
+                redisctl_core::DeploymentType::Cloud => {
+                    config.default_cloud = Some(name.clone());
+                }
+                redisctl_core::DeploymentType::Enterprise => {
+                    config.default_enterprise = Some(name.clone());
+                }
+                redisctl_core::DeploymentType::Database => {
+                    config.default_database = Some(name.clone());
+                }
             }
         }
-    }
+        is_first
+    })?;
 
+    println!();
     if let Some(ref path) = conn_mgr.config_path {
-        config
-            .save_to_path(path)
-            .context("Failed to save configuration")?;
-        println!();
         println!("Profile '{}' saved to: {}", name, path.display());
+    } else if let Ok(config_path) = Config::config_path() {
+        println!("Profile '{}' saved to: {}", name, config_path.display());
     } else {
-        config.save().context("Failed to save configuration")?;
-        if let Ok(config_path) = Config::config_path() {
-            println!();
-            println!("Profile '{}' saved to: {}", name, config_path.display());
-        } else {
-            println!();
-            println!("Profile '{}' saved.", name);
-        }
+        println!("Profile '{}' saved.", name);
     }
 
     if is_first {
@@ -1094,33 +1421,558 @@ async fn handle_remove(conn_mgr: &ConnectionManager, name: &str) -> Result<(), R
         return Ok(());
     }
 
-    // Remove the profile
-    let mut config = conn_mgr.config.clone();
-    config.profiles.remove(name);
+    // Remove the profile, clearing defaults if this was a default profile
+    conn_mgr.update_config(|config| {
+        config.profiles.remove(name);
+        if is_default_enterprise {
+            config.default_enterprise = None;
+        }
+        if is_default_cloud {
+            config.default_cloud = None;
+        }
+    })?;
 
-    // Clear defaults if this was a default profile
     if is_default_enterprise {
-        config.default_enterprise = None;
         println!("Default enterprise profile cleared.");
     }
     if is_default_cloud {
-        config.default_cloud = None;
         println!("Default cloud profile cleared.");
     }
 
-    // Save the configuration to the appropriate location
-    if let Some(ref path) = conn_mgr.config_path {
-        config
-            .save_to_path(path)
-            .context("Failed to save configuration")?;
+    println!("Profile '{}' removed successfully.", name);
+    Ok(())
+}
+
+async fn handle_copy(
+    conn_mgr: &ConnectionManager,
+    source: &str,
+    dest: &str,
+    force: bool,
+) -> Result<(), RedisCtlError> {
+    debug!("Copying profile '{}' to '{}'", source, dest);
+
+    if source == dest {
+        return Err(RedisCtlError::InvalidInput {
+            message: "Source and destination profile names are the same".to_string(),
+        });
+    }
+    let Some(source_profile) = conn_mgr.config.profiles.get(source) else {
+        return Err(RedisCtlError::ProfileNotFound {
+            name: source.into(),
+        });
+    };
+    if conn_mgr.config.profiles.contains_key(dest) && !force {
+        return Err(RedisCtlError::InvalidInput {
+            message: format!(
+                "Profile '{}' already exists. Use --force to overwrite it",
+                dest
+            ),
+        });
+    }
+
+    if let Some(deployment_type) =
+        conflicting_default(&conn_mgr.config, dest, source_profile.deployment_type)
+    {
+        return Err(RedisCtlError::InvalidInput {
+            message: format!(
+                "Profile '{}' is the default {} profile and '{}' is a {} profile. \
+                 Clear the default first with: redisctl profile clear-default --type {}",
+                dest, deployment_type, source, source_profile.deployment_type, deployment_type
+            ),
+        });
+    }
+
+    let mut profile = source_profile.clone();
+    let copied_secrets = copy_keyring_secrets(&mut profile, source, dest)?;
+
+    conn_mgr.update_config(|config| {
+        config.profiles.insert(dest.to_string(), profile);
+    })?;
+
+    if copied_secrets > 0 {
+        println!(
+            "Copied {} keyring secret(s) to new entries for '{}'",
+            copied_secrets, dest
+        );
+    }
+    println!("Profile '{}' copied to '{}'.", source, dest);
+    Ok(())
+}
+
+/// Give a copied profile its own keyring entries.
+///
+/// Each `keyring:` reference is resolved and re-stored under the destination
+/// profile's key, so updating or removing one profile's secret doesn't affect
+/// the other. Returns the number of secrets copied. Without secure-storage the
+/// references are kept as-is and shared between both profiles.
+fn copy_keyring_secrets(
+    profile: &mut redisctl_core::Profile,
+    source: &str,
+    dest: &str,
+) -> Result<usize, RedisCtlError> {
+    use redisctl_core::{CredentialStore, ProfileCredentials};
+
+    let mut fields: Vec<&mut String> = match &mut profile.credentials {
+        ProfileCredentials::Cloud {
+            api_key,
+            api_secret,
+            ..
+        } => vec![api_key, api_secret],
+        ProfileCredentials::Enterprise {
+            username, password, ..
+        } => std::iter::once(username).chain(password.as_mut()).collect(),
+        ProfileCredentials::Database { password, .. } => password.as_mut().into_iter().collect(),
+    };
+    fields.extend(profile.files_api_key.as_mut());
+    fields.retain(|value| CredentialStore::is_keyring_reference(value));
+
+    if fields.is_empty() {
+        return Ok(0);
+    }
+
+    #[cfg(feature = "secure-storage")]
+    {
+        let store = CredentialStore::new();
+        let count = fields.len();
+        for value in fields {
+            let key = value.trim_start_matches("keyring:");
+            let new_key = match key.strip_prefix(&format!("{}-", source)) {
+                Some(suffix) => format!("{}-{}", dest, suffix),
+                None => format!("{}-{}", dest, key),
+            };
+            let secret = store
+                .get_credential(value, None)
+                .with_context(|| format!("Failed to read '{}' from keyring", key))?;
+            let reference = store
+                .store_credential(&new_key, &secret)
+                .with_context(|| format!("Failed to store '{}' in keyring", new_key))?;
+            // Never write a secret that came from the keyring into the config file
+            if !CredentialStore::is_keyring_reference(&reference) {
+                return Err(RedisCtlError::Configuration(format!(
+                    "OS keyring is not available to store '{}'",
+                    new_key
+                )));
+            }
+            *value = reference;
+        }
+        Ok(count)
+    }
+
+    #[cfg(not(feature = "secure-storage"))]
+    {
+        println!(
+            "Warning: '{}' uses keyring references; without secure-storage they are shared with '{}'",
+            source, dest
+        );
+        Ok(0)
+    }
+}
+
+/// Version written to and accepted from profile bundles
+const PROFILE_BUNDLE_VERSION: u64 = 1;
+
+/// Secret fields of a serialized profile, with the suffix used for their
+/// keyring entry (`<profile>-<suffix>`) and placeholder variable.
+fn secret_fields(
+    deployment_type: redisctl_core::DeploymentType,
+) -> &'static [(&'static str, &'static str)] {
+    use redisctl_core::DeploymentType;
+
+    match deployment_type {
+        DeploymentType::Cloud => &[
+            ("api_key", "api-key"),
+            ("api_secret", "api-secret"),
+            ("files_api_key", "files-api-key"),
+        ],
+        DeploymentType::Enterprise => &[
+            ("username", "username"),
+            ("password", "password"),
+            ("files_api_key", "files-api-key"),
+        ],
+        DeploymentType::Database => &[("password", "password"), ("files_api_key", "files-api-key")],
+    }
+}
+
+/// Environment variable used as the placeholder for a profile secret,
+/// e.g. `REDISCTL_ENT_PROD_PASSWORD` for the `ent-prod` password.
+fn placeholder_var(profile: &str, suffix: &str) -> String {
+    format!("REDISCTL_{}_{}", profile, suffix)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Variable name of a value that is exactly a `${VAR}` placeholder
+fn placeholder_name(value: &str) -> Option<&str> {
+    value
+        .strip_prefix("${")
+        .and_then(|rest| rest.strip_suffix('}'))
+        .filter(|var| !var.is_empty() && !var.contains(":-"))
+}
+
+async fn handle_export(
+    conn_mgr: &ConnectionManager,
+    names: &[String],
+    output: Option<&std::path::Path>,
+    include_secrets: bool,
+) -> Result<(), RedisCtlError> {
+    use redisctl_core::CredentialStore;
+
+    debug!("Exporting profiles: {:?}", names);
+
+    let selected: Vec<&String> = if names.is_empty() {
+        let mut all: Vec<&String> = conn_mgr.config.profiles.keys().collect();
+        all.sort();
+        all
     } else {
-        config.save().context("Failed to save configuration")?;
+        names.iter().collect()
+    };
+
+    let store = CredentialStore::new();
+    let mut profiles = serde_json::Map::new();
+    for name in selected {
+        let Some(profile) = conn_mgr.config.profiles.get(name.as_str()) else {
+            return Err(RedisCtlError::ProfileNotFound { name: name.clone() });
+        };
+        let mut value = serde_json::to_value(profile).context("Failed to serialize profile")?;
+        if let Some(fields) = value.as_object_mut() {
+            for (field, suffix) in secret_fields(profile.deployment_type) {
+                let Some(serde_json::Value::String(secret)) = fields.get_mut(*field) else {
+                    continue;
+                };
+                let is_reference = CredentialStore::is_keyring_reference(secret);
+                // A plain username isn't secret; only keyring-stored ones need handling
+                if *field == "username" && !is_reference {
+                    continue;
+                }
+                if !include_secrets {
+                    *secret = format!("${{{}}}", placeholder_var(name, suffix));
+                } else if is_reference {
+                    *secret = store.get_credential(secret, None).with_context(|| {
+                        format!("Failed to read {} for '{}' from keyring", field, name)
+                    })?;
+                }
+            }
+        }
+        profiles.insert(name.clone(), value);
     }
 
-    println!("Profile '{}' removed successfully.", name);
+    let count = profiles.len();
+    let bundle = serde_json::json!({
+        "version": PROFILE_BUNDLE_VERSION,
+        "profiles": profiles,
+    });
+    let content =
+        serde_json::to_string_pretty(&bundle).context("Failed to serialize profile bundle")?;
+
+    match output {
+        Some(path) => {
+            let content = format!("{}\n", content);
+            let written = if include_secrets {
+                write_private_file(path, &content)
+            } else {
+                std::fs::write(path, content)
+            };
+            written.map_err(|e| RedisCtlError::FileError {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            })?;
+            println!("Exported {} profile(s) to {}", count, path.display());
+            if include_secrets {
+                println!(
+                    "{}",
+                    "Warning: the bundle contains secrets in plain text".yellow()
+                );
+            }
+        }
+        None => {
+            if include_secrets && !confirm_secrets_on_terminal()? {
+                println!("Export cancelled.");
+                return Ok(());
+            }
+            println!("{}", content)
+        }
+    }
+    Ok(())
+}
+
+/// Write `content` to `path` readable by the owner only, since it holds secrets
+fn write_private_file(path: &std::path::Path, content: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // The mode above only applies to new files
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(content.as_bytes())
+}
+
+/// Ask before printing secrets to a terminal; piped or redirected output is not asked about
+fn confirm_secrets_on_terminal() -> Result<bool, RedisCtlError> {
+    use std::io::IsTerminal;
+
+    if !std::io::stdout().is_terminal() {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(RedisCtlError::InvalidInput {
+            message: "Refusing to print secrets to the terminal without confirmation. \
+                      Use --file or redirect the output."
+                .to_string(),
+        });
+    }
+    dialoguer::Confirm::new()
+        .with_prompt("Print secrets in plain text to the terminal?")
+        .default(false)
+        .interact()
+        .map_err(|e| RedisCtlError::InvalidInput {
+            message: format!("Input cancelled: {}", e),
+        })
+}
+
+async fn handle_import(
+    conn_mgr: &ConnectionManager,
+    input: &std::path::Path,
+    overwrite: bool,
+) -> Result<(), RedisCtlError> {
+    debug!("Importing profiles from {}", input.display());
+
+    let content = std::fs::read_to_string(input).map_err(|e| RedisCtlError::FileError {
+        path: input.display().to_string(),
+        message: e.to_string(),
+    })?;
+    let bundle: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| RedisCtlError::InvalidInput {
+            message: format!("{} is not valid JSON: {}", input.display(), e),
+        })?;
+
+    match bundle.get("version").and_then(|v| v.as_u64()) {
+        Some(PROFILE_BUNDLE_VERSION) => {}
+        Some(version) => {
+            return Err(RedisCtlError::InvalidInput {
+                message: format!(
+                    "Unsupported profile bundle version {} (expected {})",
+                    version, PROFILE_BUNDLE_VERSION
+                ),
+            });
+        }
+        None => {
+            return Err(RedisCtlError::InvalidInput {
+                message: "Profile bundle is missing a numeric \"version\" field".to_string(),
+            });
+        }
+    }
+    let Some(entries) = bundle.get("profiles").and_then(|p| p.as_object()) else {
+        return Err(RedisCtlError::InvalidInput {
+            message: "Profile bundle is missing a \"profiles\" object".to_string(),
+        });
+    };
+    if entries.is_empty() {
+        return Err(RedisCtlError::InvalidInput {
+            message: "Profile bundle contains no profiles".to_string(),
+        });
+    }
+
+    // Validate everything first so a bad entry never leaves a half-imported config
+    let mut imported = Vec::new();
+    let mut errors = Vec::new();
+    for (name, entry) in entries {
+        match validate_bundle_entry(conn_mgr, name, entry, overwrite) {
+            Ok(profile) => imported.push((name.clone(), profile)),
+            Err(problems) => errors.push(format!("  {}: {}", name, problems.join("; "))),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(RedisCtlError::InvalidInput {
+            message: format!(
+                "Nothing imported; {} of {} profile(s) are invalid:\n{}",
+                errors.len(),
+                entries.len(),
+                errors.join("\n")
+            ),
+        });
+    }
+
+    let mut unresolved = Vec::new();
+    for (name, profile) in &mut imported {
+        unresolved.extend(resolve_placeholders(name, profile));
+    }
+
+    let names: Vec<String> = imported.iter().map(|(name, _)| name.clone()).collect();
+    conn_mgr.update_config(|config| {
+        config.profiles.extend(imported);
+    })?;
+
+    println!("Imported {} profile(s): {}", names.len(), names.join(", "));
+    if !unresolved.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "Warning: no value found for {}; set these variables before using the profiles",
+                unresolved.join(", ")
+            )
+            .yellow()
+        );
+    }
     Ok(())
 }
 
+/// Check one bundle entry and turn it into a profile, collecting every problem
+fn validate_bundle_entry(
+    conn_mgr: &ConnectionManager,
+    name: &str,
+    entry: &serde_json::Value,
+    overwrite: bool,
+) -> Result<redisctl_core::Profile, Vec<String>> {
+    use redisctl_core::DeploymentType;
+
+    let Some(fields) = entry.as_object() else {
+        return Err(vec!["entry is not an object".to_string()]);
+    };
+
+    let mut problems = Vec::new();
+    if name.trim().is_empty() {
+        problems.push("profile name is empty".to_string());
+    }
+
+    let deployment_type = match fields.get("deployment_type").and_then(|v| v.as_str()) {
+        Some("cloud") => Some(DeploymentType::Cloud),
+        Some("enterprise") => Some(DeploymentType::Enterprise),
+        Some("database") => Some(DeploymentType::Database),
+        Some(other) => {
+            problems.push(format!("unknown deployment_type '{}'", other));
+            None
+        }
+        None => {
+            problems.push("missing deployment_type".to_string());
+            None
+        }
+    };
+
+    if let Some(deployment_type) = deployment_type {
+        let required: &[&str] = match deployment_type {
+            DeploymentType::Cloud => &["api_key", "api_secret"],
+            DeploymentType::Enterprise => &["url", "username"],
+            DeploymentType::Database => &["host"],
+        };
+        for field in required {
+            match fields.get(*field).and_then(|v| v.as_str()) {
+                Some(value) if !value.trim().is_empty() => {}
+                _ => problems.push(format!("missing {}", field)),
+            }
+        }
+        if deployment_type == DeploymentType::Database
+            && !fields.get("port").is_some_and(|v| v.is_u64())
+        {
+            problems.push("missing port".to_string());
+        }
+
+        if conn_mgr.config.profiles.contains_key(name) && !overwrite {
+            problems.push("profile already exists (use --overwrite to replace it)".to_string());
+        } else if let Some(default_type) =
+            conflicting_default(&conn_mgr.config, name, deployment_type)
+        {
+            problems.push(format!(
+                "is the default {} profile and cannot become a {} profile",
+                default_type, deployment_type
+            ));
+        }
+    }
+
+    if !problems.is_empty() {
+        return Err(problems);
+    }
+
+    serde_json::from_value(entry.clone()).map_err(|e| vec![e.to_string()])
+}
+
+/// Resolve `${VAR}` secret placeholders for an imported profile.
+///
+/// Placeholders are kept as written when the variable is set, so the secret is
+/// expanded from the environment when the config is loaded rather than being
+/// copied into the file. Otherwise an existing `<profile>-<field>` keyring
+/// entry replaces the placeholder. Placeholders with neither are also kept, and
+/// returned by variable name.
+fn resolve_placeholders(name: &str, profile: &mut redisctl_core::Profile) -> Vec<String> {
+    use redisctl_core::{CredentialStore, ProfileCredentials};
+
+    let store = CredentialStore::new();
+    let mut fields: Vec<(&str, &mut String)> = match &mut profile.credentials {
+        ProfileCredentials::Cloud {
+            api_key,
+            api_secret,
+            ..
+        } => vec![("api-key", api_key), ("api-secret", api_secret)],
+        ProfileCredentials::Enterprise {
+            username, password, ..
+        } => std::iter::once(("username", username))
+            .chain(password.as_mut().map(|p| ("password", p)))
+            .collect(),
+        ProfileCredentials::Database { password, .. } => password
+            .as_mut()
+            .map(|p| ("password", p))
+            .into_iter()
+            .collect(),
+    };
+    fields.extend(profile.files_api_key.as_mut().map(|k| ("files-api-key", k)));
+
+    let mut unresolved = Vec::new();
+    for (suffix, value) in fields {
+        let Some(var) = placeholder_name(value).map(str::to_string) else {
+            continue;
+        };
+        if std::env::var_os(&var).is_some() {
+            continue;
+        }
+        let reference = format!("keyring:{}-{}", name, suffix);
+        if store.storage_backend() == "keyring" && store.get_credential(&reference, None).is_ok() {
+            *value = reference;
+            continue;
+        }
+        unresolved.push(var);
+    }
+    unresolved
+}
+
+/// Default pointer (other than `deployment_type`'s own) that names `profile`.
+///
+/// A default must keep pointing at a profile of its own type, so writing a
+/// profile of a different type under that name is refused.
+fn conflicting_default(
+    config: &Config,
+    profile: &str,
+    deployment_type: redisctl_core::DeploymentType,
+) -> Option<redisctl_core::DeploymentType> {
+    use redisctl_core::DeploymentType;
+
+    [
+        (DeploymentType::Cloud, &config.default_cloud),
+        (DeploymentType::Enterprise, &config.default_enterprise),
+        (DeploymentType::Database, &config.default_database),
+    ]
+    .into_iter()
+    .find(|(default_type, default)| {
+        default.as_deref() == Some(profile) && *default_type != deployment_type
+    })
+    .map(|(default_type, _)| default_type)
+}
+
 async fn handle_default_enterprise(
     conn_mgr: &ConnectionManager,
     name: &str,
@@ -1142,17 +1994,9 @@ async fn handle_default_enterprise(
     }
 
     // Update the configuration
-    let mut config = conn_mgr.config.clone();
-    config.default_enterprise = Some(name.to_string());
-
-    // Save the configuration to the appropriate location
-    if let Some(ref path) = conn_mgr.config_path {
-        config
-            .save_to_path(path)
-            .context("Failed to save configuration")?;
-    } else {
-        config.save().context("Failed to save configuration")?;
-    }
+    conn_mgr.update_config(|config| {
+        config.default_enterprise = Some(name.to_string());
+    })?;
 
     println!("Default enterprise profile set to '{}'.", name);
     Ok(())
@@ -1179,17 +2023,9 @@ async fn handle_default_cloud(
     }
 
     // Update the configuration
-    let mut config = conn_mgr.config.clone();
-    config.default_cloud = Some(name.to_string());
-
-    // Save the configuration to the appropriate location
-    if let Some(ref path) = conn_mgr.config_path {
-        config
-            .save_to_path(path)
-            .context("Failed to save configuration")?;
-    } else {
-        config.save().context("Failed to save configuration")?;
-    }
+    conn_mgr.update_config(|config| {
+        config.default_cloud = Some(name.to_string());
+    })?;
 
     println!("Default cloud profile set to '{}'.", name);
     Ok(())
@@ -1212,22 +2048,45 @@ async fn handle_default_database(
     }
 
     // Update the configuration
-    let mut config = conn_mgr.config.clone();
-    config.default_database = Some(name.to_string());
-
-    // Save the configuration to the appropriate location
-    if let Some(ref path) = conn_mgr.config_path {
-        config
-            .save_to_path(path)
-            .context("Failed to save configuration")?;
-    } else {
-        config.save().context("Failed to save configuration")?;
-    }
+    conn_mgr.update_config(|config| {
+        config.default_database = Some(name.to_string());
+    })?;
 
     println!("Default database profile set to '{}'.", name);
     Ok(())
 }
 
+async fn handle_clear_default(
+    conn_mgr: &ConnectionManager,
+    deployment_type: &redisctl_core::DeploymentType,
+) -> Result<(), RedisCtlError> {
+    debug!("Clearing default {} profile", deployment_type);
+
+    let previous = match deployment_type {
+        redisctl_core::DeploymentType::Cloud => conn_mgr.config.default_cloud.clone(),
+        redisctl_core::DeploymentType::Enterprise => conn_mgr.config.default_enterprise.clone(),
+        redisctl_core::DeploymentType::Database => conn_mgr.config.default_database.clone(),
+    };
+
+    let Some(previous) = previous else {
+        println!("No default {} profile is set.", deployment_type);
+        return Ok(());
+    };
+
+    // Update the configuration
+    conn_mgr.update_config(|config| match deployment_type {
+        redisctl_core::DeploymentType::Cloud => config.default_cloud = None,
+        redisctl_core::DeploymentType::Enterprise => config.default_enterprise = None,
+        redisctl_core::DeploymentType::Database => config.default_database = None,
+    })?;
+
+    println!(
+        "Default {} profile cleared (was '{}').",
+        deployment_type, previous
+    );
+    Ok(())
+}
+
 /// Result of a connectivity test for a single profile
 #[derive(Debug, Serialize)]
 struct ConnectResult {
@@ -1249,12 +2108,31 @@ enum ConnectStatus {
     Error,
 }
 
+impl ConnectResult {
+    /// Whether the endpoint answered at all, even if it rejected the credentials
+    fn reachable(&self) -> bool {
+        matches!(self.status, ConnectStatus::Ok | ConnectStatus::AuthFailed)
+    }
+
+    fn authenticated(&self) -> bool {
+        matches!(self.status, ConnectStatus::Ok)
+    }
+}
+
 /// Structured result for a single profile validation
 #[derive(Debug, Serialize)]
 struct ProfileValidationResult {
     name: String,
+    #[serde(rename = "type")]
     deployment_type: String,
+    #[serde(flatten)]
     structural: StructuralResult,
+    /// Deployment types this profile is the default for
+    default_for: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reachable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authenticated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     connectivity: Option<ConnectResult>,
 }
@@ -1545,10 +2423,36 @@ async fn test_database_connectivity(profile: &redisctl_core::Profile) -> Connect
 }
 
 /// Perform structural validation of a single profile
-fn validate_profile_structure(name: &str, profile: &redisctl_core::Profile) -> StructuralResult {
+fn validate_profile_structure(
+    config: &Config,
+    name: &str,
+    profile: &redisctl_core::Profile,
+) -> StructuralResult {
     let mut errors = Vec::new();
     let mut warnings = Vec::new();
 
+    if let Some(error) = config.inheritance_errors.get(name) {
+        errors.push(error.clone());
+    }
+    if let Some(base) = &profile.base
+        && let Some(base_profile) = config.profiles.get(base)
+        && base_profile.deployment_type != profile.deployment_type
+    {
+        errors.push(format!(
+            "Base profile '{}' is type '{}', not '{}'",
+            base, base_profile.deployment_type, profile.deployment_type
+        ));
+    }
+
+    if let Some(output) = &profile.output
+        && <OutputFormat as clap::ValueEnum>::from_str(output, true).is_err()
+    {
+        errors.push(format!(
+            "Output format '{}' is not one of auto, json, yaml, table, csv, tsv, jsonl",
+            output
+        ));
+    }
+
     match profile.deployment_type {
         redisctl_core::DeploymentType::Cloud => match profile.cloud_credentials() {
             Some((api_key, api_secret, api_url)) => {
@@ -1574,7 +2478,13 @@ fn validate_profile_structure(name: &str, profile: &redisctl_core::Profile) -> S
                 if username.is_empty() {
                     errors.push("Missing username".to_string());
                 }
-                if password.is_none() || password.as_ref().is_none_or(|p: &&str| p.is_empty()) {
+                if let Some(source) = &profile.kubernetes {
+                    if let Err(e) = source.read_secret() {
+                        errors.push(e.to_string());
+                    }
+                } else if password.is_none()
+                    || password.as_ref().is_none_or(|p: &&str| p.is_empty())
+                {
                     warnings.push("Missing password (will be prompted)".to_string());
                 }
                 if !url.starts_with("http://") && !url.starts_with("https://") {
@@ -1659,7 +2569,7 @@ async fn handle_validate(
     let mut profile_results = Vec::new();
 
     for (name, profile) in &profiles {
-        let structural = validate_profile_structure(name, profile);
+        let structural = validate_profile_structure(&conn_mgr.config, name, profile);
 
         let connectivity = if connect && structural.valid {
             Some(match profile.deployment_type {
@@ -1677,14 +2587,47 @@ async fn handle_validate(
             None
         };
 
+        let default_for = [
+            (&conn_mgr.config.default_cloud, "cloud"),
+            (&conn_mgr.config.default_enterprise, "enterprise"),
+            (&conn_mgr.config.default_database, "database"),
+        ]
+        .into_iter()
+        .filter(|(default, _)| default.as_deref() == Some(name.as_str()))
+        .map(|(_, deployment)| deployment.to_string())
+        .collect();
+
         profile_results.push(ProfileValidationResult {
             name: (*name).clone(),
             deployment_type: profile.deployment_type.to_string(),
             structural,
+            default_for,
+            reachable: connectivity.as_ref().map(ConnectResult::reachable),
+            authenticated: connectivity.as_ref().map(ConnectResult::authenticated),
             connectivity,
         });
     }
 
+    // Profiles whose inheritance failed and that are incomplete on their own
+    for (name, error) in &conn_mgr.config.inheritance_errors {
+        if conn_mgr.config.profiles.contains_key(name) {
+            continue;
+        }
+        profile_results.push(ProfileValidationResult {
+            name: name.clone(),
+            deployment_type: "unknown".to_string(),
+            structural: StructuralResult {
+                valid: false,
+                errors: vec![error.clone()],
+                warnings: vec![],
+            },
+            default_for: vec![],
+            reachable: None,
+            authenticated: None,
+            connectivity: None,
+        });
+    }
+
     // Validate defaults
     let cloud_default = conn_mgr
         .config
@@ -1721,7 +2664,7 @@ async fn handle_validate(
     let result = ValidationOutput {
         config_path: config_path_str,
         config_exists: true,
-        profile_count: profiles.len(),
+        profile_count: profile_results.len(),
         profiles: profile_results,
         defaults: DefaultsValidation {
             cloud: cloud_default,
@@ -1739,13 +2682,10 @@ fn output_validation(
     result: ValidationOutput,
     output_format: OutputFormat,
 ) -> Result<(), RedisCtlError> {
-    match output_format {
-        OutputFormat::Json | OutputFormat::Yaml => {
-            output::print_output(&result, output_format, None)?;
-        }
-        _ => {
-            print_validation_human(&result);
-        }
+    if output_format.is_structured() {
+        output::print_output(&result, output_format, None)?;
+    } else {
+        print_validation_human(&result);
     }
     Ok(())
 }
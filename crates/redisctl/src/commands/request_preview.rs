@@ -0,0 +1,100 @@
+//! Request previews for the top-level `--print-request` flag
+//!
+//! Write commands build their request body first and then offer it to
+//! [`preview_cloud_request`] or [`preview_enterprise_request`]. With
+//! `--print-request` the request is printed and the command stops before sending it.
+
+#![allow(dead_code)] // Used by binary target
+
+use serde_json::{Value, json};
+
+use crate::cli::OutputFormat;
+use crate::connection::ConnectionManager;
+use crate::error::Result as CliResult;
+use crate::output::print_output;
+
+/// Print the Cloud API request when `--print-request` is set
+///
+/// Returns `true` when the request was printed and must not be sent.
+pub fn preview_cloud_request(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    method: &str,
+    path: &str,
+    body: &Value,
+    output_format: OutputFormat,
+) -> CliResult<bool> {
+    if !conn_mgr.print_request {
+        return Ok(false);
+    }
+    let info = conn_mgr.resolve_cloud_connection(profile_name)?;
+    print_request(method, &info.base_url, path, body, output_format)?;
+    Ok(true)
+}
+
+/// Print the Enterprise API request when `--print-request` is set
+///
+/// Returns `true` when the request was printed and must not be sent.
+pub fn preview_enterprise_request(
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    method: &str,
+    path: &str,
+    body: &Value,
+    output_format: OutputFormat,
+) -> CliResult<bool> {
+    if !conn_mgr.print_request {
+        return Ok(false);
+    }
+    let info = conn_mgr.resolve_enterprise_connection(profile_name)?;
+    print_request(method, &info.base_url, path, body, output_format)?;
+    Ok(true)
+}
+
+fn print_request(
+    method: &str,
+    base_url: &str,
+    path: &str,
+    body: &Value,
+    output_format: OutputFormat,
+) -> CliResult<()> {
+    let request = request_preview(method, base_url, path, body);
+    // JSON unless YAML was asked for; tables and CSV don't fit a request
+    let format = match output_format {
+        OutputFormat::Yaml => OutputFormat::Yaml,
+        _ => OutputFormat::Json,
+    };
+    print_output(&request, format, None)?;
+    Ok(())
+}
+
+fn request_preview(method: &str, base_url: &str, path: &str, body: &Value) -> Value {
+    json!({
+        "method": method,
+        "url": format!("{}{}", base_url.trim_end_matches('/'), path),
+        "body": body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_preview_joins_base_url_and_path() {
+        let preview = request_preview(
+            "POST",
+            "https://api.redislabs.com/v1/",
+            "/subscriptions/123/databases",
+            &json!({"name": "cache"}),
+        );
+        assert_eq!(
+            preview,
+            json!({
+                "method": "POST",
+                "url": "https://api.redislabs.com/v1/subscriptions/123/databases",
+                "body": {"name": "cache"},
+            })
+        );
+    }
+}
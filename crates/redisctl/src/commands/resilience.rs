@@ -0,0 +1,148 @@
+//! Resilience command implementations
+
+#![allow(dead_code)] // Functions called from bin target
+
+use crate::cli::{OutputFormat, ResilienceCommands};
+use crate::connection::ConnectionManager;
+use crate::error::RedisCtlError;
+use crate::output;
+use redisctl_core::config::resilience::RetryConfig;
+use tabled::{Table, Tabled, settings::Style};
+
+/// Row of the simulated retry schedule
+#[derive(Tabled)]
+struct RetryRow {
+    #[tabled(rename = "RETRY")]
+    retry: u32,
+    #[tabled(rename = "DELAY")]
+    delay: String,
+    #[tabled(rename = "TOTAL WAIT")]
+    total: String,
+}
+
+/// Handle resilience commands
+pub fn handle_resilience_command(
+    resilience_cmd: &ResilienceCommands,
+    conn_mgr: &ConnectionManager,
+    profile_name: Option<&str>,
+    output_format: OutputFormat,
+) -> Result<(), RedisCtlError> {
+    match resilience_cmd {
+        ResilienceCommands::Simulate {
+            attempts,
+            backoff_ms,
+            max_backoff_ms,
+            jitter,
+        } => {
+            let mut retry = conn_mgr.resilience_config(profile_name).retry;
+            if let Some(attempts) = attempts {
+                retry.max_attempts = *attempts;
+                retry.enabled = true;
+            }
+            if let Some(backoff_ms) = backoff_ms {
+                retry.backoff_ms = *backoff_ms;
+            }
+            if let Some(max_backoff_ms) = max_backoff_ms {
+                retry.max_backoff_ms = *max_backoff_ms;
+            }
+            if retry.max_attempts == 0 {
+                return Err(RedisCtlError::InvalidInput {
+                    message: "--attempts must be at least 1".to_string(),
+                });
+            }
+            simulate(&retry, *jitter, output_format)
+        }
+    }
+}
+
+/// Print the delays `retry` would wait between attempts
+fn simulate(
+    retry: &RetryConfig,
+    jitter: bool,
+    output_format: OutputFormat,
+) -> Result<(), RedisCtlError> {
+    let schedule: Vec<u64> = if retry.enabled {
+        retry
+            .backoff_schedule()
+            .iter()
+            .map(|d| d.as_millis() as u64)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    if output::resolve_auto(output_format).is_structured() {
+        let mut elapsed = 0;
+        let retries: Vec<_> = schedule
+            .iter()
+            .zip(1..)
+            .map(|(delay, n)| {
+                elapsed += delay;
+                let mut row = serde_json::json!({
+                    "retry": n,
+                    "delay_ms": delay,
+                    "elapsed_ms": elapsed,
+                });
+                if jitter {
+                    row["min_delay_ms"] = serde_json::json!(0);
+                }
+                row
+            })
+            .collect();
+        let output_data = serde_json::json!({
+            "enabled": retry.enabled,
+            "attempts": retry.max_attempts,
+            "backoff_ms": retry.backoff_ms,
+            "max_backoff_ms": retry.max_backoff_ms,
+            "jitter": jitter,
+            "retries": retries,
+            "total_delay_ms": elapsed,
+        });
+        output::print_output(&output_data, output_format, None)?;
+    } else {
+        if !retry.enabled {
+            println!("Retries are disabled; a failed request is not retried");
+            return Ok(());
+        }
+        println!(
+            "{} attempt(s), {}ms initial backoff, {}ms cap{}",
+            retry.max_attempts,
+            retry.backoff_ms,
+            retry.max_backoff_ms,
+            if jitter { ", full jitter" } else { "" }
+        );
+        if schedule.is_empty() {
+            println!("No retries: the first failure is returned");
+            return Ok(());
+        }
+
+        let mut elapsed = 0;
+        let rows: Vec<RetryRow> = schedule
+            .iter()
+            .zip(1..)
+            .map(|(delay, n)| {
+                elapsed += delay;
+                if jitter {
+                    RetryRow {
+                        retry: n,
+                        delay: format!("0-{}ms", delay),
+                        total: format!("up to {}ms", elapsed),
+                    }
+                } else {
+                    RetryRow {
+                        retry: n,
+                        delay: format!("{}ms", delay),
+                        total: format!("{}ms", elapsed),
+                    }
+                }
+            })
+            .collect();
+
+        println!();
+        let mut table = Table::new(&rows);
+        table.with(Style::blank());
+        println!("{}", table);
+    }
+
+    Ok(())
+}
@@ -1,13 +1,35 @@
 //! Connection management for Redis Cloud and Enterprise clients
 
-use crate::error::Result as CliResult;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::error::{RedisCtlError, Result as CliResult};
+use crate::resilience::ResilienceFlags;
 use anyhow::Context;
-use redisctl_core::{Config, DeploymentType};
+use redisctl_core::{Config, DeploymentType, ResilienceConfig};
 use tracing::{debug, info, trace};
 
 /// User agent string for redisctl HTTP requests
 const REDISCTL_USER_AGENT: &str = concat!("redisctl/", env!("CARGO_PKG_VERSION"));
 
+/// Header and value sent with `--no-compression`
+const ACCEPT_ENCODING: &str = "Accept-Encoding";
+const IDENTITY_ENCODING: &str = "identity";
+
+/// Upper bound on the `--preflight` connectivity check
+const PREFLIGHT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Client cache key used when no profile is named
+const DEFAULT_CLIENT_KEY: &str = "_default";
+
+/// API clients already built by a [`ConnectionManager`], keyed by profile name
+#[derive(Default)]
+struct ClientCache {
+    cloud: HashMap<String, redis_cloud::CloudClient>,
+    enterprise: HashMap<String, redis_enterprise::EnterpriseClient>,
+}
+
 /// Resolved Cloud connection details (without creating an HTTP client)
 #[allow(dead_code)] // Used by binary target
 pub struct CloudConnectionInfo {
@@ -28,12 +50,56 @@ pub struct EnterpriseConnectionInfo {
     pub user_agent: String,
 }
 
+impl CloudConnectionInfo {
+    /// HTTP client builder for direct requests to the Cloud API, with the
+    /// user agent applied. Callers add their own timeouts.
+    #[allow(dead_code)] // Used by binary target
+    pub fn http_client_builder(&self) -> reqwest::ClientBuilder {
+        reqwest::Client::builder().user_agent(self.user_agent.as_str())
+    }
+}
+
+impl EnterpriseConnectionInfo {
+    /// HTTP client builder for direct requests to the cluster, with the user
+    /// agent and the profile's TLS settings (`insecure`, `ca_cert`) applied.
+    /// Callers add their own timeouts.
+    #[allow(dead_code)] // Used by binary target
+    pub fn http_client_builder(&self) -> CliResult<reqwest::ClientBuilder> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(self.user_agent.as_str())
+            .danger_accept_invalid_certs(self.insecure);
+        if let Some(ref ca_cert_path) = self.ca_cert {
+            let pem = std::fs::read(ca_cert_path)
+                .with_context(|| format!("Failed to read CA certificate {}", ca_cert_path))?;
+            let cert = reqwest::Certificate::from_pem(&pem).context("Invalid CA certificate")?;
+            builder = builder.add_root_certificate(cert);
+        }
+        Ok(builder)
+    }
+}
+
 /// Connection manager for creating authenticated clients
 #[allow(dead_code)] // Used by binary target
 #[derive(Clone)]
 pub struct ConnectionManager {
     pub config: Config,
     pub config_path: Option<std::path::PathBuf>,
+    /// Fall back to credentials detected from the environment when no profile exists
+    pub profile_from_context: bool,
+    /// Verify connectivity and credentials before handing out a client
+    pub preflight: bool,
+    /// Request uncompressed responses (`Accept-Encoding: identity`)
+    pub no_compression: bool,
+    /// Per-request HTTP timeout (`--http-timeout`); `None` keeps the client default
+    pub http_timeout: Option<Duration>,
+    /// Print write requests instead of sending them (top-level `--print-request`)
+    pub print_request: bool,
+    /// API URLs that already passed the preflight check
+    preflight_passed: Arc<Mutex<HashSet<String>>>,
+    /// Clients built so far, reused for later requests on the same profile
+    clients: Arc<Mutex<ClientCache>>,
+    /// Resilience flags from the command line, applied over profile settings
+    pub resilience_flags: ResilienceFlags,
 }
 
 impl ConnectionManager {
@@ -43,6 +109,14 @@ impl ConnectionManager {
         Self {
             config,
             config_path: None,
+            profile_from_context: false,
+            preflight: false,
+            no_compression: false,
+            http_timeout: None,
+            print_request: false,
+            preflight_passed: Arc::default(),
+            clients: Arc::default(),
+            resilience_flags: ResilienceFlags::default(),
         }
     }
 
@@ -52,20 +126,118 @@ impl ConnectionManager {
         Self {
             config,
             config_path,
+            profile_from_context: false,
+            preflight: false,
+            no_compression: false,
+            http_timeout: None,
+            print_request: false,
+            preflight_passed: Arc::default(),
+            clients: Arc::default(),
+            resilience_flags: ResilienceFlags::default(),
         }
     }
 
-    /// Save the configuration to the appropriate location
+    /// Enable falling back to credentials detected from the runtime environment
+    /// (see [`crate::context`]) when no profile of the required type is configured
     #[allow(dead_code)] // Used by binary target
-    pub fn save_config(&self) -> CliResult<()> {
-        if let Some(ref path) = self.config_path {
-            self.config
-                .save_to_path(path)
-                .context("Failed to save configuration")?;
-        } else {
-            self.config.save().context("Failed to save configuration")?;
+    pub fn with_profile_from_context(mut self, enabled: bool) -> Self {
+        self.profile_from_context = enabled;
+        self
+    }
+
+    /// Run a lightweight authenticated request against each API before the first
+    /// client for it is returned, so unreachable clusters and bad credentials fail
+    /// fast with a categorized error instead of midway through a command
+    #[allow(dead_code)] // Used by binary target
+    pub fn with_preflight(mut self, enabled: bool) -> Self {
+        self.preflight = enabled;
+        self
+    }
+
+    /// Ask the APIs for uncompressed responses instead of gzip/deflate
+    #[allow(dead_code)] // Used by binary target
+    pub fn with_no_compression(mut self, enabled: bool) -> Self {
+        self.no_compression = enabled;
+        self
+    }
+
+    /// Override the per-request HTTP timeout of the API clients
+    #[allow(dead_code)] // Used by binary target
+    pub fn with_http_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.http_timeout = timeout;
+        self
+    }
+
+    /// Show the requests of write commands instead of sending them
+    #[allow(dead_code)] // Used by binary target
+    pub fn with_print_request(mut self, enabled: bool) -> Self {
+        self.print_request = enabled;
+        self
+    }
+
+    /// Apply `--no-resilience`, `--retry-attempts` and related flags on top of
+    /// the resilience settings from the config file
+    #[allow(dead_code)] // Used by binary target
+    pub fn with_resilience_flags(mut self, flags: ResilienceFlags) -> Self {
+        self.resilience_flags = flags;
+        self
+    }
+
+    /// Effective resilience settings for a profile: the global `[resilience]`
+    /// section, then the profile's overrides, then command-line flags
+    pub fn resilience_config(&self, profile_name: Option<&str>) -> ResilienceConfig {
+        let mut config = self.config.resilience_for_profile(profile_name);
+        self.resilience_flags.apply(&mut config);
+        config
+    }
+
+    /// Whether the preflight check still needs to run for `url`
+    fn needs_preflight(&self, url: &str) -> bool {
+        self.preflight
+            && !self
+                .preflight_passed
+                .lock()
+                .map(|passed| passed.contains(url))
+                .unwrap_or(false)
+    }
+
+    fn mark_preflight_passed(&self, url: &str) {
+        if let Ok(mut passed) = self.preflight_passed.lock() {
+            passed.insert(url.to_string());
+        }
+    }
+
+    /// Drop cached clients so the next request resolves its profile again
+    ///
+    /// Called whenever the configuration is written, since a profile may have
+    /// changed its credentials or URL.
+    pub fn invalidate_clients(&self) {
+        if let Ok(mut clients) = self.clients.lock() {
+            *clients = ClientCache::default();
         }
-        Ok(())
+    }
+
+    /// Whether clients for a resolved profile may be reused
+    ///
+    /// Credentials read from a Kubernetes secret can rotate while a process
+    /// runs, so those profiles (and credentials found without a profile) are
+    /// resolved again for every client.
+    fn is_cacheable_profile(&self, resolved_profile: Option<&str>) -> bool {
+        resolved_profile
+            .and_then(|name| self.config.profiles.get(name))
+            .is_some_and(|profile| profile.kubernetes.is_none())
+    }
+
+    /// Apply a change to the config file under the config lock, re-reading the
+    /// file first so concurrent updates from other processes are not lost
+    #[allow(dead_code)] // Used by binary target
+    pub fn update_config<T>(&self, update: impl FnOnce(&mut Config) -> T) -> CliResult<T> {
+        self.invalidate_clients();
+        let result = match self.config_path {
+            Some(ref path) => Config::update_at_path(path, update),
+            None => Config::update(update),
+        };
+        Ok(result.context("Failed to save configuration")?)
     }
 
     /// Resolve Cloud connection info without creating an HTTP client.
@@ -96,6 +268,17 @@ impl ConnectionManager {
         &self,
         profile_name: Option<&str>,
     ) -> CliResult<redis_cloud::CloudClient> {
+        let cache_key = profile_name.unwrap_or(DEFAULT_CLIENT_KEY);
+        if let Some(client) = self
+            .clients
+            .lock()
+            .ok()
+            .and_then(|clients| clients.cloud.get(cache_key).cloned())
+        {
+            debug!("Reusing Redis Cloud client for {}", cache_key);
+            return Ok(client);
+        }
+
         debug!("Creating Redis Cloud client");
 
         let (final_api_key, final_api_secret, final_api_url) =
@@ -108,15 +291,46 @@ impl ConnectionManager {
         );
 
         // Create and configure the Cloud client
-        let client = redis_cloud::CloudClient::builder()
+        let mut builder = redis_cloud::CloudClient::builder()
             .api_key(&final_api_key)
             .api_secret(&final_api_secret)
             .base_url(&final_api_url)
-            .user_agent(REDISCTL_USER_AGENT)
+            .user_agent(REDISCTL_USER_AGENT);
+
+        if self.no_compression {
+            builder = builder.header(ACCEPT_ENCODING, IDENTITY_ENCODING);
+            debug!("Response compression disabled");
+        }
+
+        if let Some(timeout) = self.http_timeout {
+            builder = builder.timeout(timeout);
+            debug!("HTTP request timeout: {}s", timeout.as_secs());
+        }
+
+        let client = builder
             .build()
             .context("Failed to create Redis Cloud client")?;
 
         debug!("Redis Cloud client created successfully");
+
+        let resolved_profile = self.config.resolve_cloud_profile(profile_name).ok();
+        let resilience = self.resilience_config(resolved_profile.as_deref());
+        debug!(
+            "Effective resilience for profile {:?}: {:?}",
+            resolved_profile, resilience
+        );
+
+        let cacheable = self.is_cacheable_profile(resolved_profile.as_deref());
+        if self.needs_preflight(&final_api_url) {
+            let profile = resolved_profile.unwrap_or_else(|| "<environment>".to_string());
+            preflight_cloud(&client, &final_api_url, &profile).await?;
+            self.mark_preflight_passed(&final_api_url);
+        }
+
+        if cacheable && let Ok(mut clients) = self.clients.lock() {
+            clients.cloud.insert(cache_key.to_string(), client.clone());
+        }
+
         Ok(client)
     }
 
@@ -175,6 +389,9 @@ impl ConnectionManager {
             info!("Using Redis Cloud credentials from environment variables");
             let url = env_api_url.unwrap_or_else(|| "https://api.redislabs.com/v1".to_string());
             (key.clone(), secret.clone(), url)
+        } else if let Some(ctx) = self.cloud_context(profile_name) {
+            info!("Using Redis Cloud credentials detected from environment context");
+            (ctx.api_key, ctx.api_secret, ctx.api_url)
         } else {
             // Resolve the profile using type-specific logic
             let resolved_profile_name = self.config.resolve_cloud_profile(profile_name)?;
@@ -209,7 +426,12 @@ impl ConnectionManager {
             // Use the new resolve method which handles keyring lookup
             let (api_key, api_secret, api_url) = profile
                 .resolve_cloud_credentials()
-                .context("Failed to resolve Cloud credentials")?
+                .map_err(|e| {
+                    crate::error::RedisCtlError::Configuration(format!(
+                        "Failed to resolve Cloud credentials: {}",
+                        e
+                    ))
+                })?
                 .context("Profile is not configured for Redis Cloud")?;
 
             // Check for partial overrides before consuming the Options
@@ -262,6 +484,17 @@ impl ConnectionManager {
         &self,
         profile_name: Option<&str>,
     ) -> CliResult<redis_enterprise::EnterpriseClient> {
+        let cache_key = profile_name.unwrap_or(DEFAULT_CLIENT_KEY);
+        if let Some(client) = self
+            .clients
+            .lock()
+            .ok()
+            .and_then(|clients| clients.enterprise.get(cache_key).cloned())
+        {
+            debug!("Reusing Redis Enterprise client for {}", cache_key);
+            return Ok(client);
+        }
+
         debug!("Creating Redis Enterprise client");
 
         let (final_url, final_username, final_password, final_insecure, final_ca_cert) =
@@ -311,14 +544,149 @@ impl ConnectionManager {
             debug!("Using custom CA certificate: {}", ca_cert_path);
         }
 
+        if self.no_compression {
+            builder = builder.header(ACCEPT_ENCODING, IDENTITY_ENCODING);
+            debug!("Response compression disabled");
+        }
+
+        if let Some(timeout) = self.http_timeout {
+            builder = builder.timeout(timeout);
+            debug!("HTTP request timeout: {}s", timeout.as_secs());
+        }
+
         let client = builder
             .build()
             .context("Failed to create Redis Enterprise client")?;
 
         debug!("Redis Enterprise client created successfully");
+
+        let resolved_profile = self.config.resolve_enterprise_profile(profile_name).ok();
+        let resilience = self.resilience_config(resolved_profile.as_deref());
+        debug!(
+            "Effective resilience for profile {:?}: {:?}",
+            resolved_profile, resilience
+        );
+
+        let cacheable = self.is_cacheable_profile(resolved_profile.as_deref());
+        if self.needs_preflight(&final_url) {
+            let profile = resolved_profile.unwrap_or_else(|| "<environment>".to_string());
+            preflight_enterprise(&client, &final_url, &profile).await?;
+            self.mark_preflight_passed(&final_url);
+        }
+
+        if cacheable && let Ok(mut clients) = self.clients.lock() {
+            clients
+                .enterprise
+                .insert(cache_key.to_string(), client.clone());
+        }
+
         Ok(client)
     }
 
+    /// Create an Enterprise client for a node that isn't part of a configured profile
+    ///
+    /// Used to talk to a node before it belongs to a cluster (for example when
+    /// joining it to one). Honors --http-timeout and --no-compression, but no
+    /// profile settings or environment variables.
+    #[allow(dead_code)] // Used by binary target
+    pub fn create_enterprise_node_client(
+        &self,
+        url: &str,
+        username: &str,
+        password: &str,
+        insecure: bool,
+    ) -> CliResult<redis_enterprise::EnterpriseClient> {
+        debug!("Creating Redis Enterprise client for node {}", url);
+
+        let mut builder = redis_enterprise::EnterpriseClient::builder()
+            .base_url(url)
+            .username(username)
+            .password(password)
+            .user_agent(REDISCTL_USER_AGENT);
+
+        if insecure {
+            builder = builder.insecure(true);
+            debug!("SSL certificate verification disabled");
+        }
+
+        if self.no_compression {
+            builder = builder.header(ACCEPT_ENCODING, IDENTITY_ENCODING);
+        }
+
+        if let Some(timeout) = self.http_timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Ok(builder
+            .build()
+            .context("Failed to create Redis Enterprise client")?)
+    }
+
+    /// Build an authenticated request for an API path, bypassing the typed
+    /// clients so arbitrary headers can be attached or the body streamed
+    ///
+    /// Runs the `--preflight` check first when it is enabled, and honors
+    /// --http-timeout and --no-compression like the typed clients do.
+    #[allow(dead_code)] // Used by binary target
+    pub async fn raw_request(
+        &self,
+        profile_name: Option<&str>,
+        deployment: DeploymentType,
+        method: reqwest::Method,
+        path: &str,
+    ) -> CliResult<reqwest::RequestBuilder> {
+        let with_timeout = |mut http: reqwest::ClientBuilder| {
+            if let Some(timeout) = self.http_timeout {
+                http = http.timeout(timeout);
+            }
+            http.build().context("Failed to create HTTP client")
+        };
+
+        let request = match deployment {
+            DeploymentType::Cloud => {
+                let info = self.resolve_cloud_connection(profile_name)?;
+                if self.needs_preflight(&info.base_url) {
+                    self.create_cloud_client(profile_name).await?;
+                }
+                let path = if path.starts_with('/') {
+                    path.to_string()
+                } else {
+                    format!("/{}", path)
+                };
+                with_timeout(info.http_client_builder())?
+                    .request(
+                        method,
+                        format!("{}{}", info.base_url.trim_end_matches('/'), path),
+                    )
+                    .header("x-api-key", &info.api_key)
+                    .header("x-api-secret-key", &info.api_secret)
+            }
+            DeploymentType::Enterprise => {
+                let info = self.resolve_enterprise_connection(profile_name)?;
+                if self.needs_preflight(&info.base_url) {
+                    self.create_enterprise_client(profile_name).await?;
+                }
+                with_timeout(info.http_client_builder()?)?
+                    .request(
+                        method,
+                        format!("{}{}", info.base_url.trim_end_matches('/'), path),
+                    )
+                    .basic_auth(&info.username, info.password.as_deref())
+            }
+            DeploymentType::Database => {
+                return Err(RedisCtlError::InvalidInput {
+                    message: "Raw API access is not supported for database profiles".to_string(),
+                });
+            }
+        };
+
+        let mut request = request.header("Accept", "application/json");
+        if self.no_compression {
+            request = request.header(ACCEPT_ENCODING, IDENTITY_ENCODING);
+        }
+        Ok(request)
+    }
+
     /// Resolve Enterprise credentials from profile and/or environment variables.
     #[allow(clippy::type_complexity)]
     fn resolve_enterprise_credentials(
@@ -392,6 +760,22 @@ impl ConnectionManager {
                 .unwrap_or(false);
             let ca_cert = env_ca_cert.clone();
             (url.clone(), user.clone(), password, insecure, ca_cert)
+        } else if let Some(ctx) = self.enterprise_context(profile_name) {
+            info!(
+                "Using Redis Enterprise credentials detected from {}",
+                ctx.source
+            );
+            let insecure = env_insecure
+                .as_ref()
+                .map(|s| s.to_lowercase() == "true" || s == "1")
+                .unwrap_or(false);
+            (
+                ctx.url,
+                ctx.username,
+                Some(ctx.password),
+                insecure,
+                env_ca_cert.or(ctx.ca_cert),
+            )
         } else {
             // Resolve the profile using type-specific logic
             let resolved_profile_name = self.config.resolve_enterprise_profile(profile_name)?;
@@ -426,7 +810,12 @@ impl ConnectionManager {
             // Use the new resolve method which handles keyring lookup
             let (url, username, password, insecure, profile_ca_cert) = profile
                 .resolve_enterprise_credentials()
-                .context("Failed to resolve Enterprise credentials")?
+                .map_err(|e| {
+                    crate::error::RedisCtlError::Configuration(format!(
+                        "Failed to resolve Enterprise credentials: {}",
+                        e
+                    ))
+                })?
                 .context("Profile is not configured for Redis Enterprise")?;
 
             // Check for partial overrides before consuming the Options
@@ -461,4 +850,168 @@ impl ConnectionManager {
 
         Ok(result)
     }
+
+    /// Detected Cloud credentials, if context detection applies to this lookup.
+    ///
+    /// Only used when enabled, no profile was requested explicitly, and no Cloud
+    /// profile is configured.
+    fn cloud_context(&self, profile_name: Option<&str>) -> Option<crate::context::CloudContext> {
+        if !self.profile_from_context
+            || profile_name.is_some()
+            || !self
+                .config
+                .get_profiles_of_type(DeploymentType::Cloud)
+                .is_empty()
+        {
+            return None;
+        }
+        crate::context::detect_cloud_context()
+    }
+
+    /// Detected Enterprise credentials, if context detection applies to this lookup.
+    ///
+    /// Only used when enabled, no profile was requested explicitly, and no
+    /// Enterprise profile is configured.
+    fn enterprise_context(
+        &self,
+        profile_name: Option<&str>,
+    ) -> Option<crate::context::EnterpriseContext> {
+        if !self.profile_from_context
+            || profile_name.is_some()
+            || !self
+                .config
+                .get_profiles_of_type(DeploymentType::Enterprise)
+                .is_empty()
+        {
+            return None;
+        }
+        crate::context::detect_enterprise_context()
+    }
+}
+
+/// Preflight check for Redis Cloud: fetch the current account
+async fn preflight_cloud(
+    client: &redis_cloud::CloudClient,
+    url: &str,
+    profile: &str,
+) -> CliResult<()> {
+    debug!("Running preflight check against {}", url);
+    match tokio::time::timeout(PREFLIGHT_TIMEOUT, client.get_raw("/")).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(preflight_error(e.into(), url, profile)),
+        Err(_) => Err(preflight_timeout(url)),
+    }
+}
+
+/// Preflight check for Redis Enterprise: fetch the cluster object
+async fn preflight_enterprise(
+    client: &redis_enterprise::EnterpriseClient,
+    url: &str,
+    profile: &str,
+) -> CliResult<()> {
+    debug!("Running preflight check against {}", url);
+    match tokio::time::timeout(PREFLIGHT_TIMEOUT, client.get_raw("/v1/cluster")).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => Err(preflight_error(e.into(), url, profile)),
+        Err(_) => Err(preflight_timeout(url)),
+    }
+}
+
+/// Keep the error category of a failed preflight request and say what was checked
+fn preflight_error(err: RedisCtlError, url: &str, profile: &str) -> RedisCtlError {
+    match err {
+        RedisCtlError::AuthenticationFailed { message, .. } => {
+            RedisCtlError::AuthenticationFailed {
+                message: format!("preflight check against {} was rejected: {}", url, message),
+                profile_name: profile.to_string(),
+            }
+        }
+        RedisCtlError::ConnectionError { message } => RedisCtlError::ConnectionError {
+            message: format!("preflight check could not reach {}: {}", url, message),
+        },
+        other => RedisCtlError::ApiError {
+            message: format!("preflight check against {} failed: {}", url, other),
+        },
+    }
+}
+
+fn preflight_timeout(url: &str) -> RedisCtlError {
+    RedisCtlError::Timeout {
+        message: format!(
+            "preflight check against {} timed out after {}s",
+            url,
+            PREFLIGHT_TIMEOUT.as_secs()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cloud_config() -> Config {
+        let mut config = Config::default();
+        config.set_profile(
+            "test".to_string(),
+            redisctl_core::Profile {
+                deployment_type: DeploymentType::Cloud,
+                credentials: redisctl_core::ProfileCredentials::Cloud {
+                    api_key: "key".to_string(),
+                    api_secret: "secret".to_string(),
+                    api_url: "https://api.redislabs.com/v1".to_string(),
+                },
+                files_api_key: None,
+                resilience: None,
+                tags: Vec::new(),
+                kubernetes: None,
+                output: None,
+                base: None,
+            },
+        );
+        config
+    }
+
+    #[tokio::test]
+    async fn test_clients_are_reused_per_profile() {
+        // An explicit config path keeps environment credentials out of the test
+        let mut conn_mgr =
+            ConnectionManager::with_config_path(cloud_config(), Some("unused.toml".into()));
+        conn_mgr.create_cloud_client(Some("test")).await.unwrap();
+
+        // The second call is served from the cache, without resolving the profile
+        conn_mgr.config.profiles.clear();
+        conn_mgr.create_cloud_client(Some("test")).await.unwrap();
+        assert_eq!(conn_mgr.clients.lock().unwrap().cloud.len(), 1);
+
+        conn_mgr.invalidate_clients();
+        assert!(conn_mgr.create_cloud_client(Some("test")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_changed_profile_gets_a_new_client() {
+        let mut conn_mgr =
+            ConnectionManager::with_config_path(cloud_config(), Some("unused.toml".into()));
+        conn_mgr.create_cloud_client(Some("test")).await.unwrap();
+
+        // Saving the config drops cached clients, so the renamed profile is resolved anew
+        let profile = conn_mgr.config.profiles.remove("test").unwrap();
+        conn_mgr.config.set_profile("renamed".to_string(), profile);
+        conn_mgr.invalidate_clients();
+        conn_mgr.create_cloud_client(Some("renamed")).await.unwrap();
+        assert!(conn_mgr.create_cloud_client(Some("test")).await.is_err());
+
+        let clients = conn_mgr.clients.lock().unwrap();
+        assert_eq!(clients.cloud.keys().collect::<Vec<_>>(), ["renamed"]);
+    }
+
+    #[tokio::test]
+    async fn test_kubernetes_profiles_are_not_cached() {
+        let mut config = cloud_config();
+        if let Some(profile) = config.profiles.get_mut("test") {
+            profile.kubernetes = Some(redisctl_core::KubernetesSource::default());
+        }
+        let conn_mgr = ConnectionManager::with_config_path(config, Some("unused.toml".into()));
+        assert!(!conn_mgr.is_cacheable_profile(Some("test")));
+        assert!(!conn_mgr.is_cacheable_profile(None));
+    }
 }
@@ -0,0 +1,167 @@
+//! Credential detection from the runtime environment
+//!
+//! With `--profile-from-context`, commands that find no profile of the required
+//! type fall back to credentials discovered here:
+//!
+//! - **Enterprise**: a Redis Enterprise operator admin secret mounted as files
+//!   (`username`, `password`, and optionally `url` and `ca.crt`) under
+//!   `/var/run/secrets/redis-enterprise`, or the directory named by
+//!   `REDIS_ENTERPRISE_SECRET_DIR`. When no `url` file is present and the process
+//!   runs in a Kubernetes pod, the cluster API URL is derived from
+//!   `REDIS_ENTERPRISE_CLUSTER` (the REC name) and the pod namespace.
+//! - **Cloud**: the `REDISCLOUD_ACCESS_KEY` / `REDISCLOUD_SECRET_KEY` variables
+//!   used by the Redis Cloud Terraform provider, with optional `REDISCLOUD_URL`.
+
+#![allow(dead_code)] // Used by binary target
+
+use std::fs;
+use std::path::Path;
+
+use tracing::debug;
+
+/// Default mount point for the operator admin secret
+const DEFAULT_ENTERPRISE_SECRET_DIR: &str = "/var/run/secrets/redis-enterprise";
+
+/// Namespace file mounted into every pod with a service account
+const SERVICE_ACCOUNT_NAMESPACE_FILE: &str =
+    "/var/run/secrets/kubernetes.io/serviceaccount/namespace";
+
+/// Default Redis Cloud API URL
+const DEFAULT_CLOUD_API_URL: &str = "https://api.redislabs.com/v1";
+
+/// Enterprise credentials discovered from the environment
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnterpriseContext {
+    pub url: String,
+    pub username: String,
+    pub password: String,
+    pub ca_cert: Option<String>,
+    /// Where the credentials were found, for logging
+    pub source: String,
+}
+
+/// Cloud credentials discovered from the environment
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloudContext {
+    pub api_key: String,
+    pub api_secret: String,
+    pub api_url: String,
+}
+
+/// Detect Enterprise credentials from a mounted operator secret
+pub fn detect_enterprise_context() -> Option<EnterpriseContext> {
+    let secret_dir = std::env::var("REDIS_ENTERPRISE_SECRET_DIR")
+        .unwrap_or_else(|_| DEFAULT_ENTERPRISE_SECRET_DIR.to_string());
+    let cluster = std::env::var("REDIS_ENTERPRISE_CLUSTER").ok();
+    let namespace = if std::env::var("KUBERNETES_SERVICE_HOST").is_ok() {
+        read_trimmed(Path::new(SERVICE_ACCOUNT_NAMESPACE_FILE))
+    } else {
+        None
+    };
+
+    detect_enterprise_from(
+        Path::new(&secret_dir),
+        cluster.as_deref(),
+        namespace.as_deref(),
+    )
+}
+
+/// Detect Enterprise credentials from `secret_dir`, deriving the URL from the
+/// cluster name and namespace when the secret does not carry one
+pub fn detect_enterprise_from(
+    secret_dir: &Path,
+    cluster: Option<&str>,
+    namespace: Option<&str>,
+) -> Option<EnterpriseContext> {
+    let username = read_trimmed(&secret_dir.join("username"))?;
+    let password = read_trimmed(&secret_dir.join("password"))?;
+
+    let url = match read_trimmed(&secret_dir.join("url")) {
+        Some(url) => url,
+        None => {
+            let (Some(cluster), Some(namespace)) = (cluster, namespace) else {
+                debug!(
+                    "Found operator secret in {} but no url file or cluster name/namespace",
+                    secret_dir.display()
+                );
+                return None;
+            };
+            format!("https://{}.{}.svc.cluster.local:9443", cluster, namespace)
+        }
+    };
+
+    let ca_path = secret_dir.join("ca.crt");
+    let ca_cert = ca_path
+        .is_file()
+        .then(|| ca_path.to_string_lossy().into_owned());
+
+    Some(EnterpriseContext {
+        url,
+        username,
+        password,
+        ca_cert,
+        source: secret_dir.display().to_string(),
+    })
+}
+
+/// Detect Cloud credentials from Terraform provider environment variables
+pub fn detect_cloud_context() -> Option<CloudContext> {
+    let api_key = std::env::var("REDISCLOUD_ACCESS_KEY").ok()?;
+    let api_secret = std::env::var("REDISCLOUD_SECRET_KEY").ok()?;
+    let api_url =
+        std::env::var("REDISCLOUD_URL").unwrap_or_else(|_| DEFAULT_CLOUD_API_URL.to_string());
+
+    Some(CloudContext {
+        api_key,
+        api_secret,
+        api_url,
+    })
+}
+
+/// Read a secret file, ignoring surrounding whitespace; empty files count as absent
+fn read_trimmed(path: &Path) -> Option<String> {
+    let value = fs::read_to_string(path).ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_enterprise_from_secret_with_url() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("username"), "admin@example.com\n").unwrap();
+        fs::write(dir.path().join("password"), "secret").unwrap();
+        fs::write(dir.path().join("url"), "https://rec.example.com:9443").unwrap();
+
+        let ctx = detect_enterprise_from(dir.path(), None, None).unwrap();
+        assert_eq!(ctx.url, "https://rec.example.com:9443");
+        assert_eq!(ctx.username, "admin@example.com");
+        assert_eq!(ctx.password, "secret");
+        assert_eq!(ctx.ca_cert, None);
+    }
+
+    #[test]
+    fn test_detect_enterprise_derives_service_url() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("username"), "admin").unwrap();
+        fs::write(dir.path().join("password"), "secret").unwrap();
+        fs::write(dir.path().join("ca.crt"), "-----BEGIN CERTIFICATE-----").unwrap();
+
+        let ctx = detect_enterprise_from(dir.path(), Some("rec"), Some("redis")).unwrap();
+        assert_eq!(ctx.url, "https://rec.redis.svc.cluster.local:9443");
+        assert!(ctx.ca_cert.is_some());
+
+        // Without a cluster name there is no way to find the API
+        assert!(detect_enterprise_from(dir.path(), None, Some("redis")).is_none());
+    }
+
+    #[test]
+    fn test_detect_enterprise_requires_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("username"), "admin").unwrap();
+        assert!(detect_enterprise_from(dir.path(), Some("rec"), Some("redis")).is_none());
+    }
+}
@@ -49,9 +49,18 @@ impl CliDiagnostic {
         self
     }
 
-    /// Print the diagnostic to stderr with colored formatting.
+    /// Print the diagnostic to stderr, colored when stderr is a terminal.
     pub fn print(&self) {
-        eprint!("{}{}", "error".red().bold(), ": ".bold());
+        let color = crate::output::color_enabled(&std::io::stderr());
+        let label = |text: &str, style: fn(&str) -> colored::ColoredString| {
+            if color {
+                format!("{}{}", style(text).bold(), ": ".bold())
+            } else {
+                format!("{}: ", text)
+            }
+        };
+
+        eprint!("{}", label("error", |s| s.red()));
         eprintln!("{}", self.message);
 
         if let Some(detail) = &self.detail {
@@ -60,7 +69,7 @@ impl CliDiagnostic {
 
         for (description, commands) in &self.tips {
             eprintln!();
-            eprint!("  {}{}", "tip".yellow().bold(), ": ".bold());
+            eprint!("  {}", label("tip", |s| s.yellow()));
             eprintln!("{}", description);
             for cmd in commands {
                 eprintln!("      {}", cmd);
@@ -106,9 +115,22 @@ pub enum RedisCtlError {
     #[error("API error: {message}")]
     ApiError { message: String },
 
+    #[error("API error: {message}")]
+    NotFound { message: String },
+
     #[error("Invalid input: {message}")]
     InvalidInput { message: String },
 
+    #[error("Invalid {field}: {reason}")]
+    InvalidField {
+        field: String,
+        reason: String,
+        suggestion: String,
+    },
+
+    #[error("Invalid JMESPath query '{query}': {message}")]
+    InvalidQuery { query: String, message: String },
+
     #[error("Command not supported for deployment type '{deployment_type}'")]
     UnsupportedDeploymentType { deployment_type: String },
     #[error("File error for '{path}': {message}")]
@@ -122,12 +144,83 @@ pub enum RedisCtlError {
 
     #[error("Output formatting error: {message}")]
     OutputError { message: String },
+
+    #[error("Expectation failed: {message}")]
+    ExpectationFailed { message: String },
 }
 
+/// Exit code for failures without a more specific class
+pub const EXIT_GENERIC: i32 = 1;
+/// Exit code for configuration and input validation errors
+pub const EXIT_USAGE: i32 = 2;
+/// Exit code for authentication failures
+pub const EXIT_AUTH: i32 = 3;
+/// Exit code when a profile or API resource does not exist
+pub const EXIT_NOT_FOUND: i32 = 4;
+/// Exit code for network errors and timeouts
+pub const EXIT_NETWORK: i32 = 5;
+
 /// Result type for redisctl operations
 pub type Result<T> = std::result::Result<T, RedisCtlError>;
 
 impl RedisCtlError {
+    /// Stable machine-readable name for this error's category
+    pub fn kind(&self) -> &'static str {
+        match self {
+            RedisCtlError::Config(_) | RedisCtlError::Configuration(_) => "configuration",
+            RedisCtlError::ProfileNotFound { .. } => "profile_not_found",
+            RedisCtlError::ProfileTypeMismatch { .. } => "profile_type_mismatch",
+            RedisCtlError::NoProfileConfigured => "no_profile_configured",
+            RedisCtlError::MissingCredentials { .. } => "missing_credentials",
+            RedisCtlError::AuthenticationFailed { .. } => "authentication_failed",
+            RedisCtlError::ApiError { .. } => "api_error",
+            RedisCtlError::NotFound { .. } => "not_found",
+            RedisCtlError::InvalidInput { .. } => "invalid_input",
+            RedisCtlError::InvalidField { .. } => "invalid_field",
+            RedisCtlError::InvalidQuery { .. } => "invalid_query",
+            RedisCtlError::UnsupportedDeploymentType { .. } => "unsupported_deployment_type",
+            RedisCtlError::FileError { .. } => "file_error",
+            RedisCtlError::ConnectionError { .. } => "connection_error",
+            RedisCtlError::Timeout { .. } => "timeout",
+            RedisCtlError::OutputError { .. } => "output_error",
+            RedisCtlError::ExpectationFailed { .. } => "expectation_failed",
+        }
+    }
+
+    /// Process exit code for this error, so scripts can branch on the failure class
+    ///
+    /// | Code | Meaning |
+    /// |------|---------|
+    /// | 1 | Generic failure (API errors, file and output errors, failed `--expect`) |
+    /// | 2 | Configuration or invalid input (also used by clap for usage errors) |
+    /// | 3 | Authentication failed |
+    /// | 4 | Profile or API resource not found |
+    /// | 5 | Network error or timeout |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            RedisCtlError::Configuration(_)
+            | RedisCtlError::NoProfileConfigured
+            | RedisCtlError::MissingCredentials { .. }
+            | RedisCtlError::ProfileTypeMismatch { .. }
+            | RedisCtlError::InvalidInput { .. }
+            | RedisCtlError::InvalidField { .. }
+            | RedisCtlError::InvalidQuery { .. }
+            | RedisCtlError::UnsupportedDeploymentType { .. } => EXIT_USAGE,
+            RedisCtlError::AuthenticationFailed { .. } => EXIT_AUTH,
+            RedisCtlError::ProfileNotFound { .. } | RedisCtlError::NotFound { .. } => {
+                EXIT_NOT_FOUND
+            }
+            RedisCtlError::ConnectionError { .. } | RedisCtlError::Timeout { .. } => EXIT_NETWORK,
+            // `Config` also carries errors converted from anyhow, so it isn't
+            // necessarily a configuration problem
+            RedisCtlError::Config(_)
+            | RedisCtlError::ApiError { .. }
+            | RedisCtlError::FileError { .. }
+            | RedisCtlError::OutputError { .. }
+            | RedisCtlError::ExpectationFailed { .. } => EXIT_GENERIC,
+        }
+    }
+
     /// Get helpful suggestions for resolving this error
     pub fn suggestions(&self) -> Vec<String> {
         match self {
@@ -183,7 +276,7 @@ impl RedisCtlError {
                 "Verify the URL: redisctl profile show <profile>".to_string(),
                 "Test connectivity: redisctl profile validate --connect".to_string(),
             ],
-            RedisCtlError::ApiError { message } if message.contains("404") => vec![
+            RedisCtlError::NotFound { .. } => vec![
                 "Verify the resource ID is correct".to_string(),
                 "List available resources to find the correct ID".to_string(),
                 "Check that you're using the correct profile".to_string(),
@@ -221,6 +314,13 @@ impl RedisCtlError {
                 "Check the command syntax: redisctl <command> --help".to_string(),
                 "Verify input file format is correct (JSON/YAML)".to_string(),
             ],
+            RedisCtlError::InvalidField { suggestion, .. } => vec![suggestion.clone()],
+            RedisCtlError::InvalidQuery { .. } => vec![
+                "Quote string literals with backticks or single quotes: [?status==`active`]"
+                    .to_string(),
+                "Run without -q to see the response shape: redisctl <command> -o json"
+                    .to_string(),
+            ],
             RedisCtlError::FileError { path, .. } => vec![
                 format!("Check that file exists: {}", path),
                 "Verify file permissions are correct".to_string(),
@@ -254,6 +354,9 @@ impl From<redis_cloud::CloudError> for RedisCtlError {
             redis_cloud::CloudError::ConnectionError(message) => {
                 RedisCtlError::ConnectionError { message }
             }
+            _ if err.is_not_found() => RedisCtlError::NotFound {
+                message: err.to_string(),
+            },
             _ => RedisCtlError::ApiError {
                 message: err.to_string(),
             },
@@ -275,9 +378,14 @@ impl From<redis_enterprise::RestError> for RedisCtlError {
                     .to_string(),
                 profile_name: "<unknown>".to_string(),
             },
-            redis_enterprise::RestError::NotFound => RedisCtlError::ApiError {
+            redis_enterprise::RestError::NotFound => RedisCtlError::NotFound {
                 message: "404 Not Found: The requested resource does not exist".to_string(),
             },
+            redis_enterprise::RestError::ApiError { code, message } if code == 404 => {
+                RedisCtlError::NotFound {
+                    message: format!("HTTP {}: {}", code, message),
+                }
+            }
             redis_enterprise::RestError::ApiError { code, message } => RedisCtlError::ApiError {
                 message: format!("HTTP {}: {}", code, message),
             },
@@ -318,15 +426,127 @@ impl From<std::io::Error> for RedisCtlError {
     }
 }
 
+/// Exit code implied by a structured error found in an anyhow chain, if any
+fn exit_code_of(cause: &(dyn std::error::Error + 'static)) -> Option<i32> {
+    use redis_cloud::CloudError;
+    use redis_enterprise::RestError;
+    use redisctl_core::error::CoreError;
+
+    if let Some(err) = cause.downcast_ref::<RedisCtlError>() {
+        return Some(err.exit_code()).filter(|code| *code != EXIT_GENERIC);
+    }
+    if let Some(err) = cause.downcast_ref::<redisctl_core::ConfigError>() {
+        return Some(match err {
+            redisctl_core::ConfigError::ProfileNotFound { .. } => EXIT_NOT_FOUND,
+            _ => EXIT_USAGE,
+        });
+    }
+    if let Some(err) = cause.downcast_ref::<CoreError>() {
+        return Some(match err {
+            CoreError::Cloud(err) => return exit_code_of(err),
+            CoreError::Enterprise(err) => return exit_code_of(err),
+            CoreError::TaskTimeout(_) | CoreError::PollLimitExceeded { .. } => EXIT_NETWORK,
+            CoreError::Validation(_) | CoreError::Config(_) => EXIT_USAGE,
+            CoreError::TaskFailed(_) => EXIT_GENERIC,
+        });
+    }
+    if let Some(err) = cause.downcast_ref::<RestError>() {
+        return Some(if err.is_not_found() {
+            EXIT_NOT_FOUND
+        } else if err.is_unauthorized() {
+            EXIT_AUTH
+        } else if err.is_timeout()
+            || matches!(
+                err,
+                RestError::ConnectionError(_) | RestError::RequestFailed(_)
+            )
+        {
+            EXIT_NETWORK
+        } else {
+            EXIT_GENERIC
+        });
+    }
+    if let Some(err) = cause.downcast_ref::<CloudError>() {
+        return Some(if err.is_not_found() {
+            EXIT_NOT_FOUND
+        } else if err.is_unauthorized() {
+            EXIT_AUTH
+        } else if err.is_timeout() || matches!(err, CloudError::ConnectionError(_)) {
+            EXIT_NETWORK
+        } else {
+            EXIT_GENERIC
+        });
+    }
+    None
+}
+
+/// Take the error out of `err` when it is an `E` that wasn't wrapped with context
+fn take_outermost<E>(err: anyhow::Error) -> std::result::Result<E, anyhow::Error>
+where
+    E: std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+{
+    if err.chain().next().is_some_and(|cause| cause.is::<E>()) {
+        err.downcast::<E>()
+    } else {
+        Err(err)
+    }
+}
+
 impl From<anyhow::Error> for RedisCtlError {
+    /// Converts structured API, core and config errors as if they had never
+    /// been wrapped. When they were wrapped with context, the first one in the
+    /// chain still decides the exit code, and the message keeps every layer.
     fn from(err: anyhow::Error) -> Self {
-        RedisCtlError::Config(err.to_string())
+        let err = match take_outermost::<RedisCtlError>(err) {
+            Ok(err) => return err,
+            Err(err) => err,
+        };
+        let err = match take_outermost::<redis_enterprise::RestError>(err) {
+            Ok(err) => return err.into(),
+            Err(err) => err,
+        };
+        let err = match take_outermost::<redis_cloud::CloudError>(err) {
+            Ok(err) => return err.into(),
+            Err(err) => err,
+        };
+        let err = match take_outermost::<redisctl_core::error::CoreError>(err) {
+            Ok(err) => return err.into(),
+            Err(err) => err,
+        };
+        let err = match take_outermost::<redisctl_core::ConfigError>(err) {
+            Ok(err) => return err.into(),
+            Err(err) => err,
+        };
+
+        let message = format!("{:#}", err);
+        match err.chain().find_map(exit_code_of) {
+            Some(EXIT_NOT_FOUND) => RedisCtlError::NotFound { message },
+            Some(EXIT_AUTH) => RedisCtlError::AuthenticationFailed {
+                message,
+                profile_name: "<unknown>".to_string(),
+            },
+            Some(EXIT_NETWORK) => RedisCtlError::ConnectionError { message },
+            Some(EXIT_USAGE) => RedisCtlError::Configuration(message),
+            Some(_) => RedisCtlError::ApiError { message },
+            None => RedisCtlError::Config(err.to_string()),
+        }
     }
 }
 
 impl From<redisctl_core::ConfigError> for RedisCtlError {
     fn from(err: redisctl_core::ConfigError) -> Self {
-        RedisCtlError::Configuration(err.to_string())
+        match err {
+            redisctl_core::ConfigError::InvalidField {
+                field,
+                reason,
+                suggestion,
+            } => RedisCtlError::InvalidField {
+                field,
+                reason,
+                suggestion,
+            },
+            err => RedisCtlError::Configuration(err.to_string()),
+        }
     }
 }
 
@@ -336,6 +556,15 @@ impl From<redisctl_core::error::CoreError> for RedisCtlError {
             redisctl_core::error::CoreError::TaskTimeout(duration) => RedisCtlError::Timeout {
                 message: format!("Operation timed out after {} seconds", duration.as_secs()),
             },
+            redisctl_core::error::CoreError::PollLimitExceeded {
+                attempts,
+                last_status,
+            } => RedisCtlError::Timeout {
+                message: format!(
+                    "Gave up after {} poll attempts (last status: {})",
+                    attempts, last_status
+                ),
+            },
             redisctl_core::error::CoreError::TaskFailed(msg) => RedisCtlError::ApiError {
                 message: format!("Task failed: {}", msg),
             },
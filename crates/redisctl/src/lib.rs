@@ -84,6 +84,7 @@
 pub(crate) mod cli;
 pub(crate) mod commands;
 pub(crate) mod connection;
+pub(crate) mod context;
 pub(crate) mod error;
 pub(crate) mod output;
 pub(crate) mod resilience;
@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, FromArgMatches};
 use clap_complete::{generate, shells};
 use redisctl_core::{Config, ConfigError, DeploymentType};
 use tracing::{debug, error, info, trace};
@@ -8,8 +8,10 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 mod cli;
 mod commands;
 mod connection;
+mod context;
 mod error;
 mod output;
+mod resilience;
 mod workflows;
 
 use cli::{Cli, Commands};
@@ -27,6 +29,8 @@ const PASSTHROUGH_COMMANDS: &[&str] = &[
     "profile",
     "prof", // profile alias
     "pr",   // profile alias
+    "config",
+    "resilience",
     "api",
     "db",
     "version",
@@ -37,6 +41,7 @@ const PASSTHROUGH_COMMANDS: &[&str] = &[
     "help",
     "files-key",
     "fk", // files-key alias
+    "compare-platforms",
 ];
 
 /// Commands that exist only under `cloud`.
@@ -105,12 +110,21 @@ const GLOBAL_VALUE_FLAGS: &[&str] = &[
     "-q",
     "--retry-attempts",
     "--rate-limit",
+    "--http-timeout",
+    "--pipe-through",
+    "--sort-by",
+    "--fields",
+    "--exclude",
+    "--max-items",
+    "--csv-delimiter",
+    "--notify-webhook",
+    "--expect",
 ];
 
 /// Rewrite `args` to inject the platform prefix when omitted.
 ///
 /// Returns the (possibly modified) arg list that should be passed to
-/// `Cli::parse_from()`.
+/// `Cli::command().get_matches_from()`.
 fn maybe_inject_prefix(args: Vec<String>) -> Vec<String> {
     // Parse out global flags to find the first positional arg and any --profile value.
     let mut first_positional_idx: Option<usize> = None;
@@ -138,6 +152,15 @@ fn maybe_inject_prefix(args: Vec<String>) -> Vec<String> {
             || arg == "--no-resilience"
             || arg == "--no-circuit-breaker"
             || arg == "--no-retry"
+            || arg == "--field-order"
+            || arg == "--omit-null"
+            || arg == "--profile-from-context"
+            || arg == "--preflight"
+            || arg == "--reverse"
+            || arg == "--no-compression"
+            || arg == "--print-request"
+            || arg == "--wrap-lists"
+            || arg == "--coerce-bools"
         {
             i += 1;
             continue;
@@ -317,7 +340,11 @@ fn maybe_inject_prefix(args: Vec<String>) -> Vec<String> {
                                 error::CliDiagnostic::error(&format!("{}", e)).print();
                             }
                         }
-                        std::process::exit(1);
+                        let code = match e {
+                            ConfigError::ProfileNotFound { .. } => error::EXIT_NOT_FOUND,
+                            _ => error::EXIT_USAGE,
+                        };
+                        std::process::exit(code);
                     }
                 }
             }
@@ -342,7 +369,7 @@ fn maybe_inject_prefix(args: Vec<String>) -> Vec<String> {
                     ],
                 )
                 .print();
-                std::process::exit(1);
+                std::process::exit(error::EXIT_USAGE);
             }
         }
     } else {
@@ -376,43 +403,244 @@ fn load_config_for_prefix(config_file: Option<&str>) -> Option<Config> {
     }
 }
 
+/// `--wrap-lists` keys for commands with an MCP list tool counterpart, so both
+/// name the list the same way
+const WRAP_LIST_KEYS: &[(&[&str], &str)] = &[
+    (&["cloud", "provider-account", "list"], "cloud_accounts"),
+    (&["cloud", "task", "list"], "tasks"),
+    (&["enterprise", "acl", "list"], "acls"),
+    (&["enterprise", "alerts", "database"], "alerts"),
+    (&["enterprise", "alerts", "list"], "alerts"),
+    (&["enterprise", "crdb", "list"], "crdbs"),
+    (&["enterprise", "database", "list"], "databases"),
+    (&["enterprise", "logs", "list"], "logs"),
+    (&["enterprise", "module", "list"], "modules"),
+    (&["enterprise", "node", "list"], "nodes"),
+    (&["enterprise", "proxy", "list"], "proxies"),
+    (&["enterprise", "role", "list"], "roles"),
+    (&["enterprise", "services", "list"], "services"),
+    (&["enterprise", "shard", "list"], "shards"),
+    (&["enterprise", "shard", "list-by-database"], "shards"),
+    (&["enterprise", "user", "list"], "users"),
+];
+
+/// Key for `--wrap-lists`: the MCP tool's key for the invoked command, or
+/// `items` for commands without one
+fn list_key(matches: &clap::ArgMatches) -> String {
+    let mut path = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        path.push(name);
+        current = sub;
+    }
+    list_key_for_path(&path).to_string()
+}
+
+fn list_key_for_path(path: &[&str]) -> &'static str {
+    WRAP_LIST_KEYS
+        .iter()
+        .find(|(command, _)| *command == path)
+        .map_or("items", |(_, key)| *key)
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     clap_complete::CompleteEnv::with_factory(cli::Cli::command).complete();
 
     let args: Vec<String> = std::env::args().collect();
     let args = maybe_inject_prefix(args);
-    let mut cli = Cli::parse_from(args);
+    let matches = Cli::command().get_matches_from(args);
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
     cli.query = resolve_query(cli.query)?;
+    output::set_field_order(cli.field_order);
+    output::set_omit_null(cli.omit_null);
+    output::set_pipe_through(cli.pipe_through.clone());
+    output::set_sort_by(cli.sort_by.clone(), cli.reverse);
+    output::set_fields(cli.fields.clone());
+    output::set_exclude(cli.exclude.clone());
+    output::set_limit(cli.max_items.map(|n| n as usize));
+    output::set_csv_options(cli.csv_delimiter, cli.csv_quote_all);
+    output::set_no_color(cli.no_color);
+    output::set_expectations(cli.expect.clone());
+    output::set_wrap_lists(cli.wrap_lists.then(|| list_key(&matches)));
+    redisctl_core::CredentialStore::set_keyring_fallback(prompt_for_keyring_credential);
 
     // Initialize tracing based on verbosity level
     init_tracing(cli.verbose);
 
     // Load configuration from specified path or default location
-    let (config, config_path) = if let Some(config_file) = &cli.config_file {
+    let loaded = if let Some(config_file) = &cli.config_file {
         let path = std::path::PathBuf::from(config_file);
         debug!("Loading config from explicit path: {:?}", path);
-        let config = Config::load_from_path(&path)?;
-        (config, Some(path))
+        Config::load_from_path(&path).map(|config| (config, Some(path)))
     } else {
         debug!("Loading config from default location");
-        (Config::load()?, None)
+        Config::load().map(|config| (config, None))
+    };
+    let (config, config_path) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            // Same report as returning the error from main, with the config exit code
+            eprintln!("Error: {:?}", anyhow::Error::from(e));
+            std::process::exit(error::EXIT_USAGE);
+        }
     };
+    let timestamps = config.timestamps.clone().unwrap_or_default();
+    if cli.normalize_timestamps || timestamps.normalize {
+        output::set_normalize_timestamps(Some(timestamps.fields));
+    }
+    let booleans = config.booleans.clone().unwrap_or_default();
+    if cli.coerce_bools || booleans.coerce {
+        output::set_coerce_bools(Some(booleans.fields));
+    }
     debug!(
         "Creating ConnectionManager with config_path: {:?}",
         config_path
     );
-    let conn_mgr = ConnectionManager::with_config_path(config, config_path);
+    let conn_mgr = ConnectionManager::with_config_path(config, config_path)
+        .with_profile_from_context(cli.profile_from_context)
+        .with_preflight(cli.preflight)
+        .with_no_compression(cli.no_compression)
+        .with_http_timeout(cli.http_timeout.map(std::time::Duration::from_secs))
+        .with_print_request(cli.print_request)
+        .with_resilience_flags(resilience::ResilienceFlags {
+            no_resilience: cli.no_resilience,
+            no_circuit_breaker: cli.no_circuit_breaker,
+            no_retry: cli.no_retry,
+            retry_attempts: cli.retry_attempts,
+            rate_limit: cli.rate_limit,
+        });
+
+    cli.output = match resolve_output_format(&cli, &conn_mgr) {
+        Ok(format) => Some(format),
+        Err(e) => {
+            e.print_diagnostic();
+            std::process::exit(e.exit_code());
+        }
+    };
+    if cli.wrap_lists
+        && !matches!(
+            output::resolve_auto(cli.output_format()),
+            cli::OutputFormat::Json | cli::OutputFormat::Yaml
+        )
+    {
+        let e = RedisCtlError::InvalidInput {
+            message: "--wrap-lists only applies to JSON and YAML output; pass -o json or -o yaml"
+                .to_string(),
+        };
+        e.print_diagnostic();
+        std::process::exit(e.exit_code());
+    }
 
     // Execute command
-    if let Err(e) = execute_command(&cli, &conn_mgr).await {
+    let start = std::time::Instant::now();
+    let result = execute_command(&cli, &conn_mgr)
+        .await
+        .and_then(|()| output::check_expectations());
+
+    if let Some(url) = &cli.notify_webhook {
+        notify_webhook(url, &cli.command, &result, start.elapsed()).await;
+    }
+
+    if let Err(e) = result {
         e.print_diagnostic();
-        std::process::exit(1);
+        std::process::exit(e.exit_code());
     }
 
     Ok(())
 }
 
+/// Timeout for the completion webhook so a dead endpoint can't hang the CLI
+const NOTIFY_WEBHOOK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Build the JSON summary posted by `--notify-webhook`.
+///
+/// Includes a `text` field so Slack-style incoming webhooks render it as-is.
+fn webhook_payload(
+    command: &Commands,
+    result: &Result<(), RedisCtlError>,
+    duration: std::time::Duration,
+) -> serde_json::Value {
+    let command = format_command(command);
+    let status = if result.is_ok() { "success" } else { "failure" };
+    let duration_ms = duration.as_millis() as u64;
+    let text = match result {
+        Ok(()) => format!(
+            "redisctl {} succeeded in {:.1}s",
+            command,
+            duration.as_secs_f64()
+        ),
+        Err(e) => format!(
+            "redisctl {} failed after {:.1}s: {}",
+            command,
+            duration.as_secs_f64(),
+            e
+        ),
+    };
+
+    let mut payload = serde_json::json!({
+        "text": text,
+        "command": command,
+        "status": status,
+        "duration_ms": duration_ms,
+        "version": env!("CARGO_PKG_VERSION"),
+    });
+    if let Err(e) = result {
+        payload["error"] = serde_json::Value::String(e.to_string());
+    }
+    payload
+}
+
+/// POST the completion summary to the webhook. Delivery problems are reported
+/// as warnings and never change the command's exit status.
+async fn notify_webhook(
+    url: &str,
+    command: &Commands,
+    result: &Result<(), RedisCtlError>,
+    duration: std::time::Duration,
+) {
+    let payload = webhook_payload(command, result, duration);
+    debug!("Sending completion webhook to {}", url);
+
+    let client = match reqwest::Client::builder()
+        .timeout(NOTIFY_WEBHOOK_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Warning: failed to create webhook client: {}", e);
+            return;
+        }
+    };
+
+    match client.post(url).json(&payload).send().await {
+        Ok(response) if !response.status().is_success() => {
+            eprintln!(
+                "Warning: notify webhook returned HTTP {}",
+                response.status()
+            );
+        }
+        Ok(_) => debug!("Completion webhook delivered"),
+        Err(e) => eprintln!("Warning: failed to send notify webhook: {}", e),
+    }
+}
+
+/// Prompt for a keyring-stored credential when the OS keyring is unavailable
+/// or locked.
+///
+/// Only prompts on an interactive terminal; otherwise the lookup fails with
+/// guidance to set the matching environment variable instead.
+fn prompt_for_keyring_credential(key: &str, reason: &str) -> Option<String> {
+    use std::io::IsTerminal;
+    if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        return None;
+    }
+    eprintln!("Warning: OS keyring is unavailable or locked ({})", reason);
+    rpassword::prompt_password(format!("Enter value for '{}': ", key))
+        .ok()
+        .filter(|value| !value.is_empty())
+}
+
 fn init_tracing(verbose: u8) {
     // Check for RUST_LOG env var first, then fall back to verbosity flag
     let filter = if std::env::var("RUST_LOG").is_ok() {
@@ -432,6 +660,7 @@ fn init_tracing(verbose: u8) {
         .with(
             tracing_subscriber::fmt::layer()
                 .with_target(true)
+                .with_ansi(output::color_enabled(&std::io::stdout()))
                 .with_thread_ids(false)
                 .with_thread_names(false)
                 .compact(),
@@ -446,22 +675,30 @@ async fn execute_command(cli: &Cli, conn_mgr: &ConnectionManager) -> Result<(),
     trace!("Executing command: {:?}", cli.command);
     info!("Command: {}", format_command(&cli.command));
 
+    // Reject a malformed -q before any network I/O
+    if let Some(query) = &cli.query {
+        output::validate_query(query)?;
+    }
+    for expectation in &cli.expect {
+        output::validate_expectation(expectation)?;
+    }
+
+    let breaker = api_profile(cli, conn_mgr)
+        .and_then(|profile| resilience::CircuitBreaker::for_profile(conn_mgr, &profile));
+
     let start = std::time::Instant::now();
     let result = match &cli.command {
         Commands::Version => {
             debug!("Showing version information");
-            match cli.output {
-                cli::OutputFormat::Json | cli::OutputFormat::Yaml => {
-                    let output_data = serde_json::json!({
-                        "version": env!("CARGO_PKG_VERSION"),
-                        "name": env!("CARGO_PKG_NAME"),
-                    });
+            if cli.output_format().is_structured() {
+                let output_data = serde_json::json!({
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "name": env!("CARGO_PKG_NAME"),
+                });
 
-                    crate::output::print_output(&output_data, cli.output, None)?;
-                }
-                _ => {
-                    println!("redisctl {}", env!("CARGO_PKG_VERSION"));
-                }
+                crate::output::print_output(&output_data, cli.output_format(), None)?;
+            } else {
+                println!("redisctl {}", env!("CARGO_PKG_VERSION"));
             }
             Ok(())
         }
@@ -478,7 +715,23 @@ async fn execute_command(cli: &Cli, conn_mgr: &ConnectionManager) -> Result<(),
 
         Commands::Profile(profile_cmd) => {
             debug!("Executing profile command");
-            commands::profile::handle_profile_command(profile_cmd, conn_mgr, cli.output).await
+            commands::profile::handle_profile_command(profile_cmd, conn_mgr, cli.output_format())
+                .await
+        }
+
+        Commands::Config(config_cmd) => {
+            debug!("Executing config command");
+            commands::config::handle_config_command(config_cmd, conn_mgr, cli.output_format()).await
+        }
+
+        Commands::Resilience(resilience_cmd) => {
+            debug!("Executing resilience command");
+            commands::resilience::handle_resilience_command(
+                resilience_cmd,
+                conn_mgr,
+                cli.profile.as_deref(),
+                cli.output_format(),
+            )
         }
 
         Commands::FilesKey(files_key_cmd) => {
@@ -488,11 +741,31 @@ async fn execute_command(cli: &Cli, conn_mgr: &ConnectionManager) -> Result<(),
 
         Commands::Api {
             deployment,
-            method,
+            method: cli::ApiAction::Paths,
+            path,
+            ..
+        } => commands::api_paths::print_api_paths(
+            *deployment,
+            path.as_deref(),
+            cli.output_format(),
+            cli.query.as_deref(),
+        ),
+
+        Commands::Api {
+            deployment,
+            method: cli::ApiAction::Request(method),
             path,
             data,
             curl,
+            stream,
+            headers,
         } => {
+            let Some(path) = path else {
+                return Err(RedisCtlError::InvalidInput {
+                    message: "API endpoint path is required; use `paths` to list known endpoints"
+                        .to_string(),
+                });
+            };
             info!(
                 "API call: {} {} {} (deployment: {:?})",
                 method,
@@ -512,6 +785,8 @@ async fn execute_command(cli: &Cli, conn_mgr: &ConnectionManager) -> Result<(),
                 path,
                 data.as_deref(),
                 *curl,
+                *stream,
+                headers,
             )
             .await
         }
@@ -523,13 +798,60 @@ async fn execute_command(cli: &Cli, conn_mgr: &ConnectionManager) -> Result<(),
                 enterprise_cmd,
                 conn_mgr,
                 cli.profile.as_deref(),
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
         }
 
-        Commands::Db(db_cmd) => commands::db::handle_db_command(db_cmd, conn_mgr, cli.output).await,
+        Commands::Db(db_cmd) => {
+            commands::db::handle_db_command(db_cmd, conn_mgr, cli.output_format()).await
+        }
+
+        Commands::ComparePlatforms {
+            cloud,
+            cloud_spec,
+            cloud_profile,
+            enterprise,
+            enterprise_spec,
+            enterprise_profile,
+        } => {
+            use commands::compare::Source;
+
+            let enterprise_id = enterprise.map(|id| id.to_string());
+            let cloud_source = match (cloud, cloud_spec) {
+                (Some(id), _) => Source::Live {
+                    id,
+                    profile: cloud_profile.as_deref(),
+                },
+                (None, Some(spec)) => Source::Spec(spec),
+                (None, None) => {
+                    return Err(RedisCtlError::InvalidInput {
+                        message: "Either --cloud or --cloud-spec is required".to_string(),
+                    });
+                }
+            };
+            let enterprise_source = match (&enterprise_id, enterprise_spec) {
+                (Some(id), _) => Source::Live {
+                    id,
+                    profile: enterprise_profile.as_deref(),
+                },
+                (None, Some(spec)) => Source::Spec(spec),
+                (None, None) => {
+                    return Err(RedisCtlError::InvalidInput {
+                        message: "Either --enterprise or --enterprise-spec is required".to_string(),
+                    });
+                }
+            };
+            commands::compare::handle_compare_platforms(
+                conn_mgr,
+                cloud_source,
+                enterprise_source,
+                cli.output_format(),
+                cli.query.as_deref(),
+            )
+            .await
+        }
     };
 
     let duration = start.elapsed();
@@ -537,38 +859,199 @@ async fn execute_command(cli: &Cli, conn_mgr: &ConnectionManager) -> Result<(),
         Ok(_) => info!("Command completed successfully in {:?}", duration),
         Err(e) => error!("Command failed after {:?}: {}", duration, e),
     }
+    if let Some(breaker) = &breaker {
+        breaker.record(&result);
+    }
 
     result
 }
 
+/// Profile whose API the command calls, for circuit breaker bookkeeping
+fn api_profile(cli: &Cli, conn_mgr: &ConnectionManager) -> Option<String> {
+    let profile = cli.profile.as_deref();
+    match &cli.command {
+        Commands::Cloud(_)
+        | Commands::Api {
+            deployment: DeploymentType::Cloud,
+            ..
+        } => conn_mgr.config.resolve_cloud_profile(profile).ok(),
+        Commands::Enterprise(_)
+        | Commands::Api {
+            deployment: DeploymentType::Enterprise,
+            ..
+        } => conn_mgr.config.resolve_enterprise_profile(profile).ok(),
+        _ => None,
+    }
+}
+
+/// Profile the command runs against, if it uses one
+fn command_profile(cli: &Cli, conn_mgr: &ConnectionManager) -> Option<String> {
+    match &cli.command {
+        Commands::Db(_) => conn_mgr
+            .config
+            .resolve_database_profile(cli.profile.as_deref())
+            .ok(),
+        _ => api_profile(cli, conn_mgr),
+    }
+}
+
+/// Output format for the command: an explicit `--output` (even `auto`) wins,
+/// then the `output` setting of the command's profile, then auto-detection
+fn resolve_output_format(
+    cli: &Cli,
+    conn_mgr: &ConnectionManager,
+) -> Result<cli::OutputFormat, RedisCtlError> {
+    if let Some(format) = cli.output {
+        return Ok(format);
+    }
+    let Some(name) = command_profile(cli, conn_mgr) else {
+        return Ok(cli::OutputFormat::Auto);
+    };
+    let Some(value) = conn_mgr
+        .config
+        .profiles
+        .get(&name)
+        .and_then(|profile| profile.output.as_deref())
+    else {
+        return Ok(cli::OutputFormat::Auto);
+    };
+    <cli::OutputFormat as clap::ValueEnum>::from_str(value, true).map_err(|_| {
+        RedisCtlError::from(ConfigError::InvalidField {
+            field: format!("output of profile '{}'", name),
+            reason: format!("'{}' is not an output format", value),
+            suggestion: "Use one of auto, json, yaml, table, csv, tsv or jsonl".to_string(),
+        })
+    })
+}
+
+/// Shell to generate completions for, once `auto` has been resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+    Elvish,
+}
+
 /// Generate shell completions
 fn generate_completions(shell: cli::Shell) {
     let mut cmd = cli::Cli::command();
     let name = cmd.get_name().to_string();
 
-    match shell {
-        cli::Shell::Bash => generate(shells::Bash, &mut cmd, name, &mut std::io::stdout()),
-        cli::Shell::Zsh => generate(shells::Zsh, &mut cmd, name, &mut std::io::stdout()),
-        cli::Shell::Fish => generate(shells::Fish, &mut cmd, name, &mut std::io::stdout()),
-        cli::Shell::PowerShell => {
+    match resolve_shell(shell) {
+        CompletionShell::Bash => generate(shells::Bash, &mut cmd, name, &mut std::io::stdout()),
+        CompletionShell::Zsh => generate(shells::Zsh, &mut cmd, name, &mut std::io::stdout()),
+        CompletionShell::Fish => generate(shells::Fish, &mut cmd, name, &mut std::io::stdout()),
+        CompletionShell::PowerShell => {
             generate(shells::PowerShell, &mut cmd, name, &mut std::io::stdout())
         }
-        cli::Shell::Elvish => generate(shells::Elvish, &mut cmd, name, &mut std::io::stdout()),
+        CompletionShell::Elvish => generate(shells::Elvish, &mut cmd, name, &mut std::io::stdout()),
     }
 }
 
 /// Print the shell command to register dynamic completions
 fn print_registration_command(shell: cli::Shell) {
-    let cmd = match shell {
-        cli::Shell::Bash => "source <(COMPLETE=bash redisctl)",
-        cli::Shell::Zsh => "source <(COMPLETE=zsh redisctl)",
-        cli::Shell::Fish => "source (COMPLETE=fish redisctl | psub)",
-        cli::Shell::PowerShell => "COMPLETE=powershell redisctl | Invoke-Expression",
-        cli::Shell::Elvish => "eval (E:COMPLETE=elvish redisctl)",
+    let cmd = match resolve_shell(shell) {
+        CompletionShell::Bash => "source <(COMPLETE=bash redisctl)",
+        CompletionShell::Zsh => "source <(COMPLETE=zsh redisctl)",
+        CompletionShell::Fish => "source (COMPLETE=fish redisctl | psub)",
+        CompletionShell::PowerShell => "COMPLETE=powershell redisctl | Invoke-Expression",
+        CompletionShell::Elvish => "eval (E:COMPLETE=elvish redisctl)",
     };
     println!("{cmd}");
 }
 
+/// Resolve `auto` to the shell redisctl was started from
+///
+/// The parent process is checked first since `$SHELL` names the login shell,
+/// which may not be the one in use. On Windows, where the parent process is not
+/// inspected, `PSModulePath` identifies PowerShell. Falls back to bash with a
+/// warning.
+fn resolve_shell(shell: cli::Shell) -> CompletionShell {
+    let detected = match shell {
+        cli::Shell::Bash => return CompletionShell::Bash,
+        cli::Shell::Zsh => return CompletionShell::Zsh,
+        cli::Shell::Fish => return CompletionShell::Fish,
+        cli::Shell::PowerShell => return CompletionShell::PowerShell,
+        cli::Shell::Elvish => return CompletionShell::Elvish,
+        cli::Shell::Auto => parent_process_name()
+            .as_deref()
+            .and_then(shell_from_name)
+            .or_else(|| {
+                std::env::var("SHELL")
+                    .ok()
+                    .as_deref()
+                    .and_then(shell_from_name)
+            })
+            .or_else(powershell_from_env),
+    };
+    match detected {
+        Some(shell) => {
+            debug!("Detected shell {:?}", shell);
+            shell
+        }
+        None => {
+            eprintln!(
+                "Warning: could not detect your shell, generating bash completions \
+                 (pass the shell name to choose another)"
+            );
+            CompletionShell::Bash
+        }
+    }
+}
+
+/// Shell named by a process name or path such as `/bin/zsh`, `-bash` or `pwsh.exe`
+fn shell_from_name(name: &str) -> Option<CompletionShell> {
+    let name = name.trim();
+    let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    // Login shells are started with a leading dash
+    let name = name.trim_start_matches('-').to_lowercase();
+    match name.strip_suffix(".exe").unwrap_or(&name) {
+        "bash" => Some(CompletionShell::Bash),
+        "zsh" => Some(CompletionShell::Zsh),
+        "fish" => Some(CompletionShell::Fish),
+        "pwsh" | "powershell" => Some(CompletionShell::PowerShell),
+        "elvish" => Some(CompletionShell::Elvish),
+        _ => None,
+    }
+}
+
+/// PowerShell on Windows, which sets `PSModulePath` for its sessions
+#[cfg(windows)]
+fn powershell_from_env() -> Option<CompletionShell> {
+    std::env::var_os("PSModulePath").map(|_| CompletionShell::PowerShell)
+}
+
+#[cfg(not(windows))]
+fn powershell_from_env() -> Option<CompletionShell> {
+    None
+}
+
+/// Name of the process that started redisctl, when it can be determined
+#[cfg(unix)]
+fn parent_process_name() -> Option<String> {
+    let ppid = std::os::unix::process::parent_id();
+    if let Ok(comm) = std::fs::read_to_string(format!("/proc/{}/comm", ppid)) {
+        return Some(comm.trim().to_string());
+    }
+    // No /proc (macOS and the BSDs)
+    let output = std::process::Command::new("ps")
+        .args(["-o", "comm=", "-p", &ppid.to_string()])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(not(unix))]
+fn parent_process_name() -> Option<String> {
+    None
+}
+
 /// Format command for human-readable logging (without sensitive data)
 fn format_command(command: &Commands) -> String {
     match command {
@@ -589,9 +1072,13 @@ fn format_command(command: &Commands) -> String {
                 Show { name } => format!("profile show {}", name),
                 Set { name, .. } => format!("profile set {} [credentials redacted]", name),
                 Remove { name } => format!("profile remove {}", name),
+                Copy { source, dest, .. } => format!("profile copy {} {}", source, dest),
+                Export { .. } => "profile export".to_string(),
+                Import { input, .. } => format!("profile import {}", input.display()),
                 DefaultEnterprise { name } => format!("profile default-enterprise {}", name),
                 DefaultCloud { name } => format!("profile default-cloud {}", name),
                 DefaultDatabase { name } => format!("profile default-database {}", name),
+                ClearDefault { r#type } => format!("profile clear-default --type {}", r#type),
                 Validate { connect } => {
                     if *connect {
                         "profile validate --connect".to_string()
@@ -602,13 +1089,36 @@ fn format_command(command: &Commands) -> String {
                 Init => "profile init".to_string(),
             }
         }
+        Commands::Config(cmd) => {
+            use cli::ConfigCommands::*;
+            match cmd {
+                Migrate { dry_run, .. } => {
+                    if *dry_run {
+                        "config migrate --dry-run".to_string()
+                    } else {
+                        "config migrate".to_string()
+                    }
+                }
+            }
+        }
+        Commands::Resilience(cmd) => {
+            use cli::ResilienceCommands::*;
+            match cmd {
+                Simulate { .. } => "resilience simulate".to_string(),
+            }
+        }
         Commands::Api {
             deployment,
             method,
             path,
             ..
         } => {
-            format!("api {:?} {} {}", deployment, method, path)
+            format!(
+                "api {:?} {} {}",
+                deployment,
+                method,
+                path.as_deref().unwrap_or_default()
+            )
         }
         Commands::Cloud(cmd) => format!("cloud {:?}", cmd),
         Commands::Enterprise(cmd) => format!("enterprise {:?}", cmd),
@@ -626,6 +1136,12 @@ fn format_command(command: &Commands) -> String {
                 Open { profile, .. } => format!("db open --profile {}", profile),
             }
         }
+        Commands::ComparePlatforms {
+            cloud, enterprise, ..
+        } => format!(
+            "compare-platforms --cloud {:?} --enterprise {:?}",
+            cloud, enterprise
+        ),
     }
 }
 
@@ -906,6 +1422,7 @@ async fn execute_enterprise_command(
             databases,
             shards,
             brief,
+            watch,
         } => {
             let sections = commands::enterprise::status::StatusSections {
                 cluster: *cluster,
@@ -914,7 +1431,7 @@ async fn execute_enterprise_command(
                 shards: *shards,
             };
             commands::enterprise::status::get_status(
-                conn_mgr, profile, sections, *brief, output, query,
+                conn_mgr, profile, sections, *brief, *watch, output, query,
             )
             .await
         }
@@ -959,7 +1476,7 @@ async fn handle_cloud_workflow_command(
     use cli::CloudWorkflowCommands::*;
     use workflows::{WorkflowArgs, WorkflowContext, WorkflowRegistry};
 
-    let output = cli.output;
+    let output = cli.output_format();
     let profile = cli.profile.as_deref();
 
     match workflow_cmd {
@@ -973,25 +1490,22 @@ async fn handle_cloud_workflow_command(
                 .filter(|(name, _)| name.contains("subscription") || name.contains("cloud"))
                 .collect();
 
-            match output {
-                cli::OutputFormat::Json | cli::OutputFormat::Yaml => {
-                    let workflow_list: Vec<serde_json::Value> = cloud_workflows
-                        .into_iter()
-                        .map(|(name, description)| {
-                            serde_json::json!({
-                                "name": name,
-                                "description": description
-                            })
+            if output.is_structured() {
+                let workflow_list: Vec<serde_json::Value> = cloud_workflows
+                    .into_iter()
+                    .map(|(name, description)| {
+                        serde_json::json!({
+                            "name": name,
+                            "description": description
                         })
-                        .collect();
-                    crate::output::print_output(serde_json::json!(workflow_list), output, None)?;
-                }
-                _ => {
-                    println!("Available Cloud Workflows:");
-                    println!();
-                    for (name, description) in cloud_workflows {
-                        println!("  {} - {}", name, description);
-                    }
+                    })
+                    .collect();
+                crate::output::print_output(serde_json::json!(workflow_list), output, None)?;
+            } else {
+                println!("Available Cloud Workflows:");
+                println!();
+                for (name, description) in cloud_workflows {
+                    println!("  {} - {}", name, description);
                 }
             }
             Ok(())
@@ -1029,19 +1543,16 @@ async fn handle_cloud_workflow_command(
             }
 
             // Print result as JSON/YAML if requested
-            match output {
-                cli::OutputFormat::Json | cli::OutputFormat::Yaml => {
-                    let result_json = serde_json::json!({
-                        "success": result.success,
-                        "message": result.message,
-                        "outputs": result.outputs,
-                    });
-                    crate::output::print_output(&result_json, output, None)?;
-                }
-                _ => {
-                    // Human output
-                    println!("{}", result.message);
-                }
+            if output.is_structured() {
+                let result_json = serde_json::json!({
+                    "success": result.success,
+                    "message": result.message,
+                    "outputs": result.outputs,
+                });
+                crate::output::print_output(&result_json, output, None)?;
+            } else {
+                // Human output
+                println!("{}", result.message);
             }
 
             Ok(())
@@ -1063,25 +1574,22 @@ async fn handle_enterprise_workflow_command(
             let registry = WorkflowRegistry::new();
             let workflows = registry.list();
 
-            match output {
-                cli::OutputFormat::Json | cli::OutputFormat::Yaml => {
-                    let workflow_list: Vec<serde_json::Value> = workflows
-                        .into_iter()
-                        .map(|(name, description)| {
-                            serde_json::json!({
-                                "name": name,
-                                "description": description
-                            })
+            if output.is_structured() {
+                let workflow_list: Vec<serde_json::Value> = workflows
+                    .into_iter()
+                    .map(|(name, description)| {
+                        serde_json::json!({
+                            "name": name,
+                            "description": description
                         })
-                        .collect();
-                    crate::output::print_output(serde_json::json!(workflow_list), output, None)?;
-                }
-                _ => {
-                    println!("Available Enterprise Workflows:");
-                    println!();
-                    for (name, description) in workflows {
-                        println!("  {} - {}", name, description);
-                    }
+                    })
+                    .collect();
+                crate::output::print_output(serde_json::json!(workflow_list), output, None)?;
+            } else {
+                println!("Available Enterprise Workflows:");
+                println!();
+                for (name, description) in workflows {
+                    println!("  {} - {}", name, description);
                 }
             }
             Ok(())
@@ -1140,19 +1648,15 @@ async fn handle_enterprise_workflow_command(
             }
 
             // Print result as JSON/YAML if requested
-            match output {
-                cli::OutputFormat::Json | cli::OutputFormat::Yaml => {
-                    let result_json = serde_json::json!({
-                        "success": result.success,
-                        "message": result.message,
-                        "outputs": result.outputs,
-                    });
-                    crate::output::print_output(&result_json, output, None)?;
-                }
-                _ => {
-                    // Human output was already printed by the workflow
-                }
+            if output.is_structured() {
+                let result_json = serde_json::json!({
+                    "success": result.success,
+                    "message": result.message,
+                    "outputs": result.outputs,
+                });
+                crate::output::print_output(&result_json, output, None)?;
             }
+            // Human output was already printed by the workflow
 
             Ok(())
         }
@@ -1199,6 +1703,7 @@ async fn execute_files_key_command(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn execute_api_command(
     cli: &Cli,
     conn_mgr: &ConnectionManager,
@@ -1207,18 +1712,21 @@ async fn execute_api_command(
     path: &str,
     data: Option<&str>,
     curl: bool,
+    stream: bool,
+    headers: &[(String, String)],
 ) -> Result<(), RedisCtlError> {
     commands::api::handle_api_command(commands::api::ApiCommandParams {
-        config: conn_mgr.config.clone(),
-        config_path: conn_mgr.config_path.clone(),
+        connection_manager: conn_mgr.clone(),
         profile_name: cli.profile.clone(),
         deployment: *deployment,
         method: method.clone(),
         path: path.to_string(),
         data: data.map(|s| s.to_string()),
         query: cli.query.clone(),
-        output_format: cli.output,
+        output_format: cli.output_format(),
         curl,
+        stream,
+        headers: headers.to_vec(),
     })
     .await
 }
@@ -1236,7 +1744,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 account_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1247,7 +1755,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 payment_method_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1258,7 +1766,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 sub_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1269,7 +1777,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 db_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1280,7 +1788,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 user_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1290,7 +1798,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 acl_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1300,7 +1808,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 provider_account_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1310,7 +1818,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 task_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1320,7 +1828,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 connectivity_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1330,7 +1838,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 fixed_db_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1340,7 +1848,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 fixed_sub_cmd,
-                cli.output,
+                cli.output_format(),
                 cli.query.as_deref(),
             )
             .await
@@ -1351,7 +1859,7 @@ async fn execute_cloud_command(
                 conn_mgr,
                 cli.profile.as_deref(),
                 cost_report_cmd.clone(),
-                cli.output,
+                cli.output_format(),
             )
             .await
         }
@@ -1366,6 +1874,31 @@ mod tests {
         s.split_whitespace().map(String::from).collect()
     }
 
+    #[test]
+    fn list_key_matches_mcp_tools() {
+        assert_eq!(
+            list_key_for_path(&["enterprise", "database", "list"]),
+            "databases"
+        );
+        assert_eq!(
+            list_key_for_path(&["enterprise", "proxy", "list"]),
+            "proxies"
+        );
+        assert_eq!(
+            list_key_for_path(&["cloud", "provider-account", "list"]),
+            "cloud_accounts"
+        );
+        assert_eq!(
+            list_key_for_path(&["enterprise", "shard", "list-by-database"]),
+            "shards"
+        );
+        assert_eq!(list_key_for_path(&["enterprise", "node", "get"]), "items");
+        assert_eq!(
+            list_key_for_path(&["cloud", "subscription", "list"]),
+            "items"
+        );
+    }
+
     // --- Passthrough tests ---
 
     #[test]
@@ -1646,4 +2179,54 @@ mod tests {
             "unexpected error: {err}"
         );
     }
+
+    #[test]
+    fn webhook_payload_reports_failure() {
+        let result = Err(RedisCtlError::InvalidInput {
+            message: "bad id".to_string(),
+        });
+        let payload = webhook_payload(
+            &Commands::Version,
+            &result,
+            std::time::Duration::from_millis(1500),
+        );
+
+        assert_eq!(payload["command"], "version");
+        assert_eq!(payload["status"], "failure");
+        assert_eq!(payload["duration_ms"], 1500);
+        assert!(payload["error"].as_str().unwrap().contains("bad id"));
+        assert!(
+            payload["text"]
+                .as_str()
+                .unwrap()
+                .starts_with("redisctl version failed after 1.5s")
+        );
+    }
+
+    #[test]
+    fn webhook_payload_omits_error_on_success() {
+        let payload = webhook_payload(
+            &Commands::Version,
+            &Ok(()),
+            std::time::Duration::from_millis(20),
+        );
+        assert_eq!(payload["status"], "success");
+        assert!(payload.get("error").is_none());
+    }
+
+    #[test]
+    fn shell_from_name_recognizes_paths_and_login_shells() {
+        assert_eq!(shell_from_name("/bin/zsh"), Some(CompletionShell::Zsh));
+        assert_eq!(shell_from_name("-bash"), Some(CompletionShell::Bash));
+        assert_eq!(
+            shell_from_name("/usr/local/bin/fish\n"),
+            Some(CompletionShell::Fish)
+        );
+        assert_eq!(
+            shell_from_name("C:\\Program Files\\PowerShell\\7\\pwsh.exe"),
+            Some(CompletionShell::PowerShell)
+        );
+        assert!(shell_from_name("sh").is_none());
+        assert!(shell_from_name("cargo").is_none());
+    }
 }
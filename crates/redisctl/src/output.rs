@@ -3,10 +3,13 @@
 use anyhow::{Context, Result};
 use jpx_core::Runtime;
 use regex::Regex;
-use serde::Serialize;
-use serde_json::Value;
-use std::io::IsTerminal;
-use std::sync::OnceLock;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
 use tabled::builder::Builder;
 use tabled::settings::Style;
 
@@ -73,6 +76,820 @@ pub fn compile_jmespath(
     get_jmespath_runtime().compile(&normalized)
 }
 
+/// Check that a `-q` expression compiles, so a typo fails before any request is sent
+///
+/// The error message carries the parser's description of the problem,
+/// including the line and column it was found at.
+pub fn validate_query(query: &str) -> CliResult<()> {
+    compile_jmespath(query)
+        .map(|_| ())
+        .map_err(|e| RedisCtlError::InvalidQuery {
+            query: query.to_string(),
+            message: e.to_string().trim_end().to_string(),
+        })
+}
+
+/// `--expect` assertions, checked against the last value printed
+static EXPECTATIONS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Last value passed through [`print_output`] while `--expect` is in use
+static EXPECT_SUBJECT: Mutex<Option<Value>> = Mutex::new(None);
+
+/// Check the output of this run against these `--expect` assertions.
+///
+/// Each is either `<jmespath>==<value>`, where the value is a JMESPath
+/// literal (`` `prod` ``, `` `3` ``) or bare text, or a JMESPath expression
+/// that must evaluate to something truthy.
+pub fn set_expectations(expectations: Vec<String>) {
+    if !expectations.is_empty() {
+        let _ = EXPECTATIONS.set(expectations);
+    }
+}
+
+/// Compile the path of an `--expect` assertion, so a typo fails before any
+/// request is sent
+pub fn validate_expectation(expectation: &str) -> CliResult<()> {
+    let (path, _) = split_expectation(expectation);
+    validate_query(path)
+}
+
+/// Evaluate the `--expect` assertions against the last printed output.
+///
+/// Every failed assertion is reported with the actual value, so a CI log
+/// shows what the command returned.
+pub fn check_expectations() -> CliResult<()> {
+    let Some(expectations) = EXPECTATIONS.get() else {
+        return Ok(());
+    };
+    let subject = EXPECT_SUBJECT
+        .lock()
+        .ok()
+        .and_then(|subject| subject.clone())
+        .ok_or_else(|| RedisCtlError::ExpectationFailed {
+            message: "the command printed no data to check".to_string(),
+        })?;
+
+    let mut failures = Vec::new();
+    for expectation in expectations {
+        let (path, expected) = split_expectation(expectation);
+        let actual = apply_jmespath(&subject, path)?;
+        match expected {
+            Some(expected) => {
+                let expected = parse_expected_value(expected);
+                if actual != expected {
+                    failures.push(format!("{} == {} (actual: {})", path, expected, actual));
+                }
+            }
+            None if !is_truthy(&actual) => {
+                failures.push(format!("{} (actual: {})", path, actual));
+            }
+            None => {}
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(RedisCtlError::ExpectationFailed {
+            message: failures.join("; "),
+        })
+    }
+}
+
+/// Split `lhs==rhs` at the first `==` outside literals, quotes and brackets,
+/// so comparisons inside filters (`nodes[?status=='ok']`) stay in the path
+fn split_expectation(expectation: &str) -> (&str, Option<&str>) {
+    let bytes = expectation.as_bytes();
+    let mut depth = 0i32;
+    let mut delimiter: Option<u8> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match delimiter {
+            Some(_) if b == b'\\' => i += 1,
+            Some(d) if b == d => delimiter = None,
+            Some(_) => {}
+            None => match b {
+                b'`' | b'\'' | b'"' => delimiter = Some(b),
+                b'[' | b'(' | b'{' => depth += 1,
+                b']' | b')' | b'}' => depth -= 1,
+                b'=' if depth == 0 && bytes.get(i + 1) == Some(&b'=') => {
+                    return (expectation[..i].trim(), Some(expectation[i + 2..].trim()));
+                }
+                _ => {}
+            },
+        }
+        i += 1;
+    }
+    (expectation.trim(), None)
+}
+
+/// The right-hand side of an expectation: a `'raw string'`, a backtick or
+/// bare JSON literal, or otherwise plain text
+fn parse_expected_value(raw: &str) -> Value {
+    if let Some(inner) = raw.strip_prefix('\'').and_then(|r| r.strip_suffix('\'')) {
+        return Value::String(inner.to_string());
+    }
+    let inner = raw
+        .strip_prefix('`')
+        .and_then(|r| r.strip_suffix('`'))
+        .unwrap_or(raw)
+        .trim();
+    serde_json::from_str(inner).unwrap_or_else(|_| Value::String(inner.to_string()))
+}
+
+/// JMESPath truthiness: false, null and empty strings, arrays and objects are false
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(false) => false,
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+        _ => true,
+    }
+}
+
+/// Whether canonical field ordering (`--field-order`) is enabled for this process
+static FIELD_ORDER: AtomicBool = AtomicBool::new(false);
+
+/// Whether null-valued object fields are stripped (`--omit-null`) for this process
+static OMIT_NULL: AtomicBool = AtomicBool::new(false);
+
+/// Shell command that formatted output is piped through (`--pipe-through`)
+static PIPE_THROUGH: OnceLock<String> = OnceLock::new();
+
+/// Field that top-level arrays are sorted by (`--sort-by`), and whether to reverse
+static SORT_BY: OnceLock<(String, bool)> = OnceLock::new();
+
+/// CSV writer settings (`--csv-delimiter`, `--csv-quote-all`)
+static CSV_OPTIONS: OnceLock<CsvOptions> = OnceLock::new();
+
+/// Delimiter and quoting used when rendering CSV/TSV
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    pub delimiter: u8,
+    pub quote_all: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote_all: false,
+        }
+    }
+}
+
+/// Maximum number of list items displayed (`--max-items`)
+static LIMIT: OnceLock<usize> = OnceLock::new();
+
+/// Columns that table output is restricted to (`--fields`), in display order
+static FIELDS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Dotted paths removed from every object in output (`--exclude`)
+static EXCLUDE: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Key that top-level arrays are wrapped under with a count (`--wrap-lists`)
+static WRAP_LISTS: OnceLock<String> = OnceLock::new();
+
+/// Field names rewritten as ISO 8601 UTC (`--normalize-timestamps`); unset when disabled
+static TIMESTAMP_FIELDS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Field names coerced to JSON booleans (`--coerce-bools`); unset when disabled
+static BOOLEAN_FIELDS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// Boolean fields coerced when the config does not name its own.
+///
+/// Flags that some Cloud and Enterprise endpoints report as `"true"`/`"false"`
+/// strings or as 0/1.
+pub const DEFAULT_BOOLEAN_FIELDS: &[&str] = &[
+    "enabled",
+    "replication",
+    "sharding",
+    "oss_cluster",
+    "rack_aware",
+    "accept_servers",
+    "crdt",
+    "tls",
+    "ssl",
+    "enableTls",
+    "supportOSSClusterApi",
+    "useExternalEndpointForOSSClusterApi",
+    "activeActiveRedis",
+    "enableDefaultUser",
+];
+
+/// Timestamp fields normalized when the config does not name its own.
+///
+/// Covers the Cloud (camelCase) and Enterprise (snake_case) spellings of
+/// creation, modification and start/end times.
+pub const DEFAULT_TIMESTAMP_FIELDS: &[&str] = &[
+    "timestamp",
+    "created_time",
+    "creation_date",
+    "last_changed_time",
+    "last_login",
+    "start_time",
+    "end_time",
+    "expiration_date",
+    "createdTimestamp",
+    "lastModifiedTimestamp",
+    "activatedOn",
+    "lastUpdated",
+];
+
+/// Canonical leading fields per resource kind.
+///
+/// Fields listed here are emitted first, in this order; any remaining fields
+/// follow alphabetically. Kinds are matched by `detect_resource_kind`.
+const FIELD_ORDERS: &[(&str, &[&str])] = &[
+    (
+        "task",
+        &[
+            "taskId",
+            "commandType",
+            "status",
+            "description",
+            "timestamp",
+            "response",
+        ],
+    ),
+    (
+        "subscription",
+        &[
+            "id",
+            "name",
+            "status",
+            "paymentMethodType",
+            "numberOfDatabases",
+            "cloudDetails",
+        ],
+    ),
+    (
+        "cloud_database",
+        &[
+            "databaseId",
+            "name",
+            "status",
+            "protocol",
+            "memoryLimitInGb",
+            "publicEndpoint",
+            "privateEndpoint",
+        ],
+    ),
+    (
+        "enterprise_database",
+        &[
+            "uid",
+            "name",
+            "status",
+            "type",
+            "memory_size",
+            "port",
+            "endpoints",
+        ],
+    ),
+    (
+        "node",
+        &[
+            "uid",
+            "addr",
+            "status",
+            "role",
+            "total_memory",
+            "software_version",
+        ],
+    ),
+    ("user", &["uid", "id", "name", "email", "role", "status"]),
+    ("default", &["id", "uid", "name", "status"]),
+];
+
+/// Enable or disable canonical field ordering for subsequent output.
+pub fn set_field_order(enabled: bool) {
+    FIELD_ORDER.store(enabled, Ordering::Relaxed);
+}
+
+fn field_order_enabled() -> bool {
+    FIELD_ORDER.load(Ordering::Relaxed)
+}
+
+/// Set by `--no-color`
+static NO_COLOR: AtomicBool = AtomicBool::new(false);
+
+/// Turn ANSI styling off for the rest of the run (`--no-color`), and apply
+/// the same rules to everything styled with `colored`
+pub fn set_no_color(no_color: bool) {
+    NO_COLOR.store(no_color, Ordering::Relaxed);
+    colored::control::set_override(color_enabled(&std::io::stdout()));
+}
+
+/// Whether ANSI styling may be written to `stream`: not when `--no-color` is
+/// given, when `NO_COLOR` is set to a non-empty value, or when the stream is
+/// redirected to a file or pipe
+pub fn color_enabled(stream: &impl IsTerminal) -> bool {
+    !NO_COLOR.load(Ordering::Relaxed)
+        && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+        && stream.is_terminal()
+}
+
+/// Enable or disable stripping of null-valued fields for subsequent output.
+pub fn set_omit_null(enabled: bool) {
+    OMIT_NULL.store(enabled, Ordering::Relaxed);
+}
+
+/// Rewrite the given timestamp fields as ISO 8601 UTC in subsequent output.
+/// An empty list selects [`DEFAULT_TIMESTAMP_FIELDS`].
+///
+/// Only the first call takes effect.
+pub fn set_normalize_timestamps(fields: Option<Vec<String>>) {
+    if let Some(fields) = fields {
+        let fields = if fields.is_empty() {
+            DEFAULT_TIMESTAMP_FIELDS
+                .iter()
+                .map(|f| f.to_string())
+                .collect()
+        } else {
+            fields
+        };
+        let _ = TIMESTAMP_FIELDS.set(fields);
+    }
+}
+
+/// Rewrite the given boolean-ish fields as JSON booleans in subsequent output.
+/// An empty list selects [`DEFAULT_BOOLEAN_FIELDS`].
+///
+/// Only the first call takes effect.
+pub fn set_coerce_bools(fields: Option<Vec<String>>) {
+    if let Some(fields) = fields {
+        let fields = if fields.is_empty() {
+            DEFAULT_BOOLEAN_FIELDS
+                .iter()
+                .map(|f| f.to_string())
+                .collect()
+        } else {
+            fields
+        };
+        let _ = BOOLEAN_FIELDS.set(fields);
+    }
+}
+
+/// Pipe all subsequent formatted output through `command` instead of printing it.
+///
+/// Only the first call takes effect; the command is fixed for the process.
+pub fn set_pipe_through(command: Option<String>) {
+    if let Some(command) = command {
+        let _ = PIPE_THROUGH.set(command);
+    }
+}
+
+/// Sort all subsequent list output by `field` (a dotted path such as
+/// `memory.used`), descending when `reverse` is set.
+///
+/// Only the first call takes effect.
+pub fn set_sort_by(field: Option<String>, reverse: bool) {
+    if let Some(field) = field {
+        let _ = SORT_BY.set((field, reverse));
+    }
+}
+
+/// Display at most `limit` items of subsequent list output.
+///
+/// Only the first call takes effect.
+pub fn set_limit(limit: Option<usize>) {
+    if let Some(limit) = limit {
+        let _ = LIMIT.set(limit);
+    }
+}
+
+/// Wrap top-level arrays in JSON and YAML output as `{key: [...], "count": N}`,
+/// the shape MCP list tools return.
+///
+/// Only the first call takes effect.
+pub fn set_wrap_lists(key: Option<String>) {
+    if let Some(key) = key {
+        let _ = WRAP_LISTS.set(key);
+    }
+}
+
+/// Put a top-level array under `key` next to its length; other values are
+/// returned unchanged
+pub fn wrap_list(value: Value, key: &str) -> Value {
+    match value {
+        Value::Array(items) => {
+            let count = items.len();
+            let mut wrapped = serde_json::Map::new();
+            wrapped.insert(key.to_string(), Value::Array(items));
+            wrapped.insert("count".to_string(), count.into());
+            Value::Object(wrapped)
+        }
+        other => other,
+    }
+}
+
+/// Keep the first `limit` items of a top-level array, returning how many were
+/// dropped. Non-array values are left untouched.
+pub fn truncate_list(value: &mut Value, limit: usize) -> usize {
+    match value {
+        Value::Array(items) if items.len() > limit => {
+            let omitted = items.len() - limit;
+            items.truncate(limit);
+            omitted
+        }
+        _ => 0,
+    }
+}
+
+/// Apply `--sort-by` and `--max-items` to list output, noting omitted items on stderr
+fn sort_and_limit(value: &mut Value) {
+    if let Some((field, reverse)) = SORT_BY.get() {
+        sort_array_by(value, field, *reverse);
+    }
+    if let Some(limit) = LIMIT.get() {
+        note_omitted(truncate_list(value, *limit));
+    }
+}
+
+/// The `--max-items` limit, if any
+pub fn max_items() -> Option<usize> {
+    LIMIT.get().copied()
+}
+
+/// Note on stderr how many list items `--max-items` dropped
+pub fn note_omitted(omitted: usize) {
+    if let Some(limit) = LIMIT.get()
+        && omitted > 0
+    {
+        eprintln!(
+            "... {} more item(s) not shown (--max-items {})",
+            omitted, limit
+        );
+    }
+}
+
+/// Configure the delimiter and quoting of subsequent CSV output.
+///
+/// TSV output always uses a tab delimiter but honors `quote_all`. Only the
+/// first call takes effect.
+pub fn set_csv_options(delimiter: Option<u8>, quote_all: bool) {
+    let defaults = CsvOptions::default();
+    let _ = CSV_OPTIONS.set(CsvOptions {
+        delimiter: delimiter.unwrap_or(defaults.delimiter),
+        quote_all,
+    });
+}
+
+fn csv_options() -> CsvOptions {
+    CSV_OPTIONS.get().copied().unwrap_or_default()
+}
+
+/// Restrict subsequent table output to the comma-separated `fields`, in order.
+///
+/// Only the first call takes effect. Blank entries are ignored.
+pub fn set_fields(fields: Option<String>) {
+    if let Some(fields) = fields {
+        let fields = parse_fields(&fields);
+        if !fields.is_empty() {
+            let _ = FIELDS.set(fields);
+        }
+    }
+}
+
+/// Remove the comma-separated dotted paths in `fields` from subsequent output.
+///
+/// Only the first call takes effect. Blank entries are ignored.
+pub fn set_exclude(fields: Option<String>) {
+    if let Some(fields) = fields {
+        let fields = parse_fields(&fields);
+        if !fields.is_empty() {
+            let _ = EXCLUDE.set(fields);
+        }
+    }
+}
+
+fn parse_fields(fields: &str) -> Vec<String> {
+    fields
+        .split(',')
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether a command should print its own hand-built table for `format`.
+///
+/// False when `--fields` or `--exclude` is set, so the generic renderer can
+/// pick the columns.
+pub fn use_custom_table(format: OutputFormat) -> bool {
+    matches!(resolve_auto(format), OutputFormat::Table)
+        && FIELDS.get().is_none()
+        && EXCLUDE.get().is_none()
+}
+
+/// Sort a top-level array of objects by `field`.
+///
+/// Numbers, and strings that parse as numbers, compare numerically so `9`
+/// sorts before `10`; other strings compare lexically. Items without the field
+/// go last in either direction. The sort is stable, so ties keep API order.
+/// Non-array values are left untouched.
+pub fn sort_array_by(value: &mut Value, field: &str, reverse: bool) {
+    let Value::Array(items) = value else {
+        return;
+    };
+    items.sort_by(
+        |a, b| match (lookup_path(a, field), lookup_path(b, field)) {
+            (Some(a), Some(b)) => {
+                let ord = compare_values(a, b);
+                if reverse { ord.reverse() } else { ord }
+            }
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        },
+    );
+}
+
+/// Follow a dotted path into nested objects, treating null as missing
+fn lookup_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |v, key| v.get(key))
+        .filter(|v| !v.is_null())
+}
+
+fn compare_values(a: &Value, b: &Value) -> std::cmp::Ordering {
+    fn as_number(v: &Value) -> Option<f64> {
+        match v {
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => s.trim().parse().ok(),
+            Value::Bool(b) => Some(u8::from(*b).into()),
+            _ => None,
+        }
+    }
+    match (as_number(a), as_number(b)) {
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        // Numbers before text when a field mixes the two
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => match (a, b) {
+            (Value::String(x), Value::String(y)) => x.cmp(y),
+            _ => a.to_string().cmp(&b.to_string()),
+        },
+    }
+}
+
+/// Write rendered output to stdout, or through the `--pipe-through` command
+pub fn emit(rendered: &str) -> Result<()> {
+    match PIPE_THROUGH.get() {
+        Some(command) => pipe_through(command, rendered),
+        None => {
+            println!("{}", rendered);
+            Ok(())
+        }
+    }
+}
+
+/// Run `command` in the platform shell with `input` on stdin, inheriting stdout
+/// and stderr, and fail if the command cannot start or exits unsuccessfully
+fn pipe_through(command: &str, input: &str) -> Result<()> {
+    let mut child = shell_command(command)
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run output command '{}'", command))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that exits without reading all input (e.g. `head`) closes the
+        // pipe early; its exit status decides success, not the broken pipe.
+        if let Err(e) = writeln!(stdin, "{}", input)
+            && e.kind() != std::io::ErrorKind::BrokenPipe
+        {
+            return Err(e).with_context(|| format!("Failed to write to '{}'", command));
+        }
+    }
+
+    let status = child
+        .wait()
+        .with_context(|| format!("Failed to wait for output command '{}'", command))?;
+    if !status.success() {
+        anyhow::bail!(
+            "Output command '{}' exited with {}",
+            command,
+            status
+                .code()
+                .map(|c| format!("status {}", c))
+                .unwrap_or_else(|| "a signal".to_string())
+        );
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.args(["/C", command]);
+    cmd
+}
+
+#[cfg(not(windows))]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", command]);
+    cmd
+}
+
+/// Recursively rewrite values of the named fields as ISO 8601 UTC strings.
+///
+/// Epoch numbers (or numeric strings) are read as milliseconds when they are
+/// too large to be seconds. Values that don't look like timestamps are left
+/// alone.
+pub fn normalize_timestamps(value: &mut Value, fields: &[String]) {
+    match value {
+        Value::Object(obj) => {
+            for (key, v) in obj.iter_mut() {
+                if fields.iter().any(|f| f == key) {
+                    if let Some(iso) = to_iso8601(v) {
+                        *v = Value::String(iso);
+                    }
+                } else {
+                    normalize_timestamps(v, fields);
+                }
+            }
+        }
+        Value::Array(arr) => arr.iter_mut().for_each(|v| normalize_timestamps(v, fields)),
+        _ => {}
+    }
+}
+
+/// Recursively rewrite values of the named fields as JSON booleans.
+///
+/// `"true"`/`"false"`, `"yes"`/`"no"` (any case) and 0/1 as numbers or strings
+/// are converted. Other values are left alone.
+pub fn coerce_bools(value: &mut Value, fields: &[String]) {
+    match value {
+        Value::Object(obj) => {
+            for (key, v) in obj.iter_mut() {
+                if fields.iter().any(|f| f == key) {
+                    if let Some(b) = to_bool(v) {
+                        *v = Value::Bool(b);
+                    }
+                } else {
+                    coerce_bools(v, fields);
+                }
+            }
+        }
+        Value::Array(arr) => arr.iter_mut().for_each(|v| coerce_bools(v, fields)),
+        _ => {}
+    }
+}
+
+fn to_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(b) => Some(*b),
+        Value::Number(n) => match n.as_u64()? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        },
+        Value::String(s) => match s.trim().to_lowercase().as_str() {
+            "true" | "yes" | "1" => Some(true),
+            "false" | "no" | "0" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Epoch seconds beyond this are taken to be milliseconds (year 5138 in seconds)
+const EPOCH_MILLIS_THRESHOLD: f64 = 1e11;
+
+fn to_iso8601(value: &Value) -> Option<String> {
+    use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+
+    let epoch = |n: f64| -> Option<DateTime<Utc>> {
+        let millis = if n.abs() >= EPOCH_MILLIS_THRESHOLD {
+            n
+        } else {
+            n * 1000.0
+        };
+        DateTime::from_timestamp_millis(millis as i64)
+    };
+
+    let parsed = match value {
+        Value::Number(n) => epoch(n.as_f64()?),
+        Value::String(s) => {
+            let s = s.trim();
+            if let Ok(n) = s.parse::<f64>() {
+                epoch(n)
+            } else if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+                Some(dt.with_timezone(&Utc))
+            } else {
+                // Enterprise sometimes omits the offset; those times are UTC
+                ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+                    .iter()
+                    .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+                    .map(|dt| dt.and_utc())
+            }
+        }
+        _ => None,
+    }?;
+    Some(parsed.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+}
+
+/// Remove the dotted `path` (e.g. `links` or `endpoint.password`) from `value`.
+///
+/// Arrays are descended into, so a path applies to every item of a list and
+/// to every element of a nested array along the way. Missing paths are ignored.
+pub fn exclude_path(value: &mut Value, path: &str) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(|v| exclude_path(v, path)),
+        Value::Object(obj) => match path.split_once('.') {
+            Some((key, rest)) => {
+                if let Some(child) = obj.get_mut(key) {
+                    exclude_path(child, rest);
+                }
+            }
+            None => {
+                obj.remove(path);
+            }
+        },
+        _ => {}
+    }
+}
+
+/// Recursively remove object keys whose value is `null`.
+///
+/// Nulls inside arrays are kept so that element positions are preserved.
+pub fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Object(obj) => {
+            obj.retain(|_, v| !v.is_null());
+            obj.values_mut().for_each(strip_nulls);
+        }
+        Value::Array(arr) => arr.iter_mut().for_each(strip_nulls),
+        _ => {}
+    }
+}
+
+/// Guess the resource kind of a JSON object from its distinguishing fields.
+fn detect_resource_kind(obj: &Map<String, Value>) -> &'static str {
+    if obj.contains_key("taskId") {
+        "task"
+    } else if obj.contains_key("databaseId") {
+        "cloud_database"
+    } else if obj.contains_key("numberOfDatabases") || obj.contains_key("paymentMethodType") {
+        "subscription"
+    } else if obj.contains_key("memory_size") || obj.contains_key("shards_count") {
+        "enterprise_database"
+    } else if obj.contains_key("addr") && obj.contains_key("total_memory") {
+        "node"
+    } else if obj.contains_key("email") {
+        "user"
+    } else {
+        "default"
+    }
+}
+
+/// Return the keys of `obj` in canonical order for its resource kind.
+fn ordered_keys(obj: &Map<String, Value>) -> Vec<&String> {
+    let kind = detect_resource_kind(obj);
+    let leading = FIELD_ORDERS
+        .iter()
+        .find(|(k, _)| *k == kind)
+        .map(|(_, fields)| *fields)
+        .unwrap_or_default();
+
+    let mut keys: Vec<&String> = leading
+        .iter()
+        .filter_map(|field| obj.get_key_value(*field).map(|(k, _)| k))
+        .collect();
+    keys.extend(obj.keys().filter(|k| !leading.contains(&k.as_str())));
+    keys
+}
+
+/// Serialization wrapper that emits object fields in canonical order.
+struct Canonical<'a>(&'a Value);
+
+impl Serialize for Canonical<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Object(obj) => {
+                let keys = ordered_keys(obj);
+                let mut map = serializer.serialize_map(Some(keys.len()))?;
+                for key in keys {
+                    map.serialize_entry(key, &Canonical(&obj[key.as_str()]))?;
+                }
+                map.end()
+            }
+            Value::Array(arr) => {
+                let mut seq = serializer.serialize_seq(Some(arr.len()))?;
+                for item in arr {
+                    seq.serialize_element(&Canonical(item))?;
+                }
+                seq.end()
+            }
+            other => other.serialize(serializer),
+        }
+    }
+}
+
 /// Resolve `Auto` format to a concrete format.
 ///
 /// `Auto` resolves to `Table` when stdout is a TTY, `Json` when piped.
@@ -103,20 +920,193 @@ pub fn print_output<T: Serialize>(
         json_value = expr.search(&json_value).context("JMESPath query failed")?;
     }
 
+    // Normalize first so --sort-by compares the values that are printed
+    apply_value_options(&mut json_value);
+    sort_and_limit(&mut json_value);
+    let excluded = EXCLUDE.get().map(Vec::as_slice).unwrap_or_default();
+
+    if EXPECTATIONS.get().is_some()
+        && let Ok(mut subject) = EXPECT_SUBJECT.lock()
+    {
+        *subject = Some(json_value.clone());
+    }
+
     let resolved = resolve_auto(format);
-    match resolved {
-        OutputFormat::Json | OutputFormat::Auto => {
-            println!("{}", serde_json::to_string_pretty(&json_value)?);
+    if let Some(key) = WRAP_LISTS.get()
+        && matches!(resolved, OutputFormat::Json | OutputFormat::Yaml)
+    {
+        json_value = wrap_list(json_value, key);
+    }
+    let ordered = field_order_enabled();
+    let rendered = match resolved {
+        OutputFormat::Json => {
+            if ordered {
+                serde_json::to_string_pretty(&Canonical(&json_value))?
+            } else {
+                serde_json::to_string_pretty(&json_value)?
+            }
         }
         OutputFormat::Yaml => {
-            println!("{}", serde_yaml::to_string(&json_value)?);
+            if ordered {
+                serde_yaml::to_string(&Canonical(&json_value))?
+            } else {
+                serde_yaml::to_string(&json_value)?
+            }
         }
-        OutputFormat::Table => {
-            print_as_table(&json_value)?;
+        OutputFormat::Table => match FIELDS.get() {
+            // An excluded column is dropped even when --fields names it
+            Some(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .filter(|f| !excluded.contains(f))
+                    .cloned()
+                    .collect();
+                render_table_fields(&json_value, &fields)
+            }
+            None => render_table(&json_value),
+        },
+        OutputFormat::Csv => render_csv(&json_value, csv_options())?,
+        OutputFormat::Tsv => render_csv(
+            &json_value,
+            CsvOptions {
+                delimiter: b'\t',
+                ..csv_options()
+            },
+        )?,
+        OutputFormat::Jsonl => {
+            if let Some(fields) = FIELDS.get() {
+                // As in tables, an excluded field is dropped even when --fields names it
+                let fields: Vec<String> = fields
+                    .iter()
+                    .filter(|f| !excluded.contains(f))
+                    .cloned()
+                    .collect();
+                match &mut json_value {
+                    Value::Array(items) => {
+                        for item in items {
+                            select_fields(item, &fields);
+                        }
+                    }
+                    other => select_fields(other, &fields),
+                }
+            }
+            let rendered = render_jsonl(&json_value, ordered)?;
+            // An empty list has no lines at all, not one blank line
+            if rendered.is_empty() {
+                return Ok(());
+            }
+            rendered
         }
+        OutputFormat::Auto => unreachable!("resolve_auto never returns Auto"),
+    };
+
+    emit(&rendered)
+}
+
+/// Apply the options that transform each value independently of the rest of
+/// the result: `--normalize-timestamps`, `--coerce-bools`, `--omit-null` and
+/// `--exclude`
+fn apply_value_options(value: &mut Value) {
+    if let Some(fields) = TIMESTAMP_FIELDS.get() {
+        normalize_timestamps(value, fields);
     }
 
-    Ok(())
+    if let Some(fields) = BOOLEAN_FIELDS.get() {
+        coerce_bools(value, fields);
+    }
+
+    if OMIT_NULL.load(Ordering::Relaxed) {
+        strip_nulls(value);
+    }
+
+    for path in EXCLUDE.get().map(Vec::as_slice).unwrap_or_default() {
+        exclude_path(value, path);
+    }
+}
+
+/// Render a JSON value as JSON Lines: one compact line per array element, or a
+/// single line for anything else
+fn render_jsonl(value: &Value, ordered: bool) -> Result<String> {
+    let lines = match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| jsonl_line(item, ordered))
+            .collect::<Result<Vec<_>>>()?,
+        other => vec![jsonl_line(other, ordered)?],
+    };
+    Ok(lines.join("\n"))
+}
+
+/// Render one value as a JSON Lines line (without the newline), honoring
+/// `--field-order`
+pub fn render_jsonl_item(value: &Value) -> Result<String> {
+    jsonl_line(value, field_order_enabled())
+}
+
+fn jsonl_line(value: &Value, ordered: bool) -> Result<String> {
+    Ok(if ordered {
+        serde_json::to_string(&Canonical(value))?
+    } else {
+        serde_json::to_string(value)?
+    })
+}
+
+/// Whether list output must be collected before it is printed because
+/// `--query`, `--sort-by`, `--expect` or `--pipe-through` need the complete result
+pub fn needs_whole_result(query: Option<&str>) -> bool {
+    query.is_some()
+        || SORT_BY.get().is_some()
+        || EXPECTATIONS.get().is_some()
+        || PIPE_THROUGH.get().is_some()
+}
+
+/// Whether a list can be written with [`write_jsonl_items`] a page at a time.
+///
+/// With `--max-items`, or any option that [`needs_whole_result`], the caller
+/// collects the list and uses [`print_output`] instead.
+pub fn streams_jsonl(format: OutputFormat, query: Option<&str>) -> bool {
+    matches!(format, OutputFormat::Jsonl) && !needs_whole_result(query) && LIMIT.get().is_none()
+}
+
+/// Prepare one list item that is written as it arrives instead of going
+/// through [`print_output`]: keep only the `--fields` columns of an object,
+/// then apply the per-value options such as `--exclude`
+pub fn prepare_streamed_item(value: &mut Value) {
+    if let Some(fields) = FIELDS.get() {
+        select_fields(value, fields);
+    }
+    apply_value_options(value);
+}
+
+/// Reduce an object to `fields`, in order; fields it lacks become null.
+/// Other values are left untouched.
+fn select_fields(value: &mut Value, fields: &[String]) {
+    if let Value::Object(obj) = value {
+        let selected = fields
+            .iter()
+            .map(|field| (field.clone(), obj.remove(field).unwrap_or(Value::Null)))
+            .collect();
+        *obj = selected;
+    }
+}
+
+/// Write `items` to stdout as JSON Lines as soon as they are available,
+/// returning how many were written
+///
+/// Handlers that fetch a list in pages call this once per page, so the whole
+/// list is never held in memory.
+pub fn write_jsonl_items<T: Serialize>(items: impl IntoIterator<Item = T>) -> Result<usize> {
+    let ordered = field_order_enabled();
+    let mut out = std::io::BufWriter::new(std::io::stdout().lock());
+    let mut count = 0;
+    for item in items {
+        let mut value = serde_json::to_value(item)?;
+        apply_value_options(&mut value);
+        writeln!(out, "{}", jsonl_line(&value, ordered)?)?;
+        count += 1;
+    }
+    out.flush()?;
+    Ok(count)
 }
 
 /// Apply JMESPath query to JSON data (using extended runtime with 400+ functions)
@@ -134,11 +1124,15 @@ pub fn handle_output(
     _output_format: OutputFormat,
     query: Option<&str>,
 ) -> CliResult<Value> {
-    if let Some(q) = query {
-        apply_jmespath(&data, q)
+    let mut data = if let Some(q) = query {
+        apply_jmespath(&data, q)?
     } else {
-        Ok(data)
-    }
+        data
+    };
+    // Also applied here so commands that render their own tables honor them
+    apply_value_options(&mut data);
+    sort_and_limit(&mut data);
+    Ok(data)
 }
 
 /// Print data in the requested output format, mapping errors to `RedisCtlError::OutputError`.
@@ -150,14 +1144,18 @@ pub fn print_formatted_output(data: Value, output_format: OutputFormat) -> CliRe
     Ok(())
 }
 
-fn print_as_table(value: &Value) -> Result<()> {
+fn render_table(value: &Value) -> String {
     match value {
         Value::Array(arr) if !arr.is_empty() => {
             let mut builder = Builder::default();
 
             // Get headers from first object
             if let Value::Object(first) = &arr[0] {
-                let headers: Vec<String> = first.keys().cloned().collect();
+                let headers: Vec<String> = if field_order_enabled() {
+                    ordered_keys(first).into_iter().cloned().collect()
+                } else {
+                    first.keys().cloned().collect()
+                };
                 builder.push_record(&headers);
 
                 // Add rows
@@ -178,24 +1176,170 @@ fn print_as_table(value: &Value) -> Result<()> {
                 }
             }
 
-            println!("{}", builder.build().with(Style::blank()));
+            builder.build().with(Style::blank()).to_string()
         }
         Value::Object(obj) => {
             let mut builder = Builder::default();
             builder.push_record(["Key", "Value"]);
 
-            for (key, val) in obj {
-                builder.push_record([key.clone(), format_value(val)]);
+            let keys: Vec<&String> = if field_order_enabled() {
+                ordered_keys(obj)
+            } else {
+                obj.keys().collect()
+            };
+            for key in keys {
+                builder.push_record([key.clone(), format_value(&obj[key.as_str()])]);
+            }
+
+            builder.build().with(Style::blank()).to_string()
+        }
+        _ => format_value(value),
+    }
+}
+
+/// Render a table showing only `fields`, in the given order.
+///
+/// Missing or null fields render as blank cells. Values that are not objects
+/// (or arrays of objects) fall back to the regular table.
+fn render_table_fields(value: &Value, fields: &[String]) -> String {
+    let cell = |obj: &Map<String, Value>, field: &str| match obj.get(field) {
+        None | Some(Value::Null) => String::new(),
+        Some(v) => format_value(v),
+    };
+    match value {
+        Value::Array(arr) if !arr.is_empty() && arr.iter().all(Value::is_object) => {
+            let mut builder = Builder::default();
+            builder.push_record(fields);
+            for item in arr {
+                if let Value::Object(obj) = item {
+                    builder.push_record(fields.iter().map(|f| cell(obj, f)));
+                }
             }
+            builder.build().with(Style::blank()).to_string()
+        }
+        Value::Object(obj) => {
+            let mut builder = Builder::default();
+            builder.push_record(["Key", "Value"]);
+            for field in fields {
+                builder.push_record([field.clone(), cell(obj, field)]);
+            }
+            builder.build().with(Style::blank()).to_string()
+        }
+        _ => render_table(value),
+    }
+}
 
-            println!("{}", builder.build().with(Style::blank()));
+/// Render a JSON value as CSV.
+///
+/// Arrays of objects become one row per element, with the union of their keys
+/// (in first-seen order) as the header and empty cells for missing fields. A
+/// single object becomes one row, and an array of scalars a single `value`
+/// column. Nested arrays and objects are JSON-encoded into one cell.
+fn render_csv(value: &Value, options: CsvOptions) -> Result<String> {
+    let single;
+    let rows: &[Value] = match value {
+        Value::Array(arr) => arr,
+        Value::Object(_) => {
+            single = [value.clone()];
+            &single
+        }
+        other => anyhow::bail!(
+            "CSV output needs an array of objects, but the result is {}; adjust -q/--query to select a list",
+            json_type_name(other)
+        ),
+    };
+
+    let mut writer = csv_writer(options);
+    if rows.iter().all(Value::is_object) {
+        let mut headers: Vec<&String> = Vec::new();
+        for row in rows {
+            if let Value::Object(obj) = row {
+                let keys = if field_order_enabled() {
+                    ordered_keys(obj)
+                } else {
+                    obj.keys().collect()
+                };
+                for key in keys {
+                    if !headers.contains(&key) {
+                        headers.push(key);
+                    }
+                }
+            }
+        }
+        if !headers.is_empty() {
+            writer.write_record(&headers)?;
         }
-        _ => {
-            println!("{}", format_value(value));
+        for row in rows {
+            let record: Vec<String> = headers
+                .iter()
+                .map(|h| csv_cell(row.get(h.as_str())))
+                .collect();
+            writer.write_record(&record)?;
+        }
+    } else if rows.iter().any(Value::is_object) {
+        anyhow::bail!(
+            "CSV output needs an array of objects, but the result mixes objects and other values"
+        );
+    } else {
+        writer.write_record(["value"])?;
+        for row in rows {
+            writer.write_record([csv_cell(Some(row))])?;
         }
     }
 
-    Ok(())
+    finish_csv(writer)
+}
+
+/// Render fixed columns as CSV, honoring `--csv-delimiter` and `--csv-quote-all`
+///
+/// For commands that shape their own rows and need the columns in a set order.
+pub fn render_csv_records<R: AsRef<[String]>>(headers: &[&str], rows: &[R]) -> Result<String> {
+    let mut writer = csv_writer(csv_options());
+    writer.write_record(headers)?;
+    for row in rows {
+        writer.write_record(row.as_ref())?;
+    }
+    finish_csv(writer)
+}
+
+fn csv_writer(options: CsvOptions) -> csv::Writer<Vec<u8>> {
+    csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .quote_style(if options.quote_all {
+            csv::QuoteStyle::Always
+        } else {
+            csv::QuoteStyle::Necessary
+        })
+        .from_writer(Vec::new())
+}
+
+fn finish_csv(writer: csv::Writer<Vec<u8>>) -> Result<String> {
+    let bytes = writer.into_inner().context("Failed to write CSV output")?;
+    let rendered = String::from_utf8(bytes).context("CSV output is not valid UTF-8")?;
+    Ok(rendered.trim_end_matches('\n').to_string())
+}
+
+/// Format one CSV cell: strings as-is, nested values JSON-encoded, nulls and
+/// missing fields empty.
+fn csv_cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Bool(b)) => b.to_string(),
+        Some(Value::Number(n)) => n.to_string(),
+        Some(nested) => nested.to_string(),
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
 }
 
 fn format_value(value: &Value) -> String {
@@ -213,6 +1357,23 @@ fn format_value(value: &Value) -> String {
 mod tests {
     use super::*;
 
+    #[cfg(unix)]
+    #[test]
+    fn test_pipe_through_propagates_exit_status() {
+        assert!(pipe_through("grep -q cache", r#"{"name": "cache"}"#).is_ok());
+
+        let err = pipe_through("grep -q missing", r#"{"name": "cache"}"#).unwrap_err();
+        assert!(err.to_string().contains("exited with status 1"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_pipe_through_ignores_early_close() {
+        // `true` exits without reading stdin; that is not a failure
+        let large = "x".repeat(1 << 20);
+        assert!(pipe_through("true", &large).is_ok());
+    }
+
     #[test]
     fn test_normalize_backtick_unquoted_string() {
         // Standard JMESPath backtick literal without quotes
@@ -289,6 +1450,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_query_reports_syntax_error() {
+        assert!(validate_query("[?status==`active`].name").is_ok());
+
+        let err = validate_query("databases[?status==").unwrap_err();
+        assert_eq!(err.kind(), "invalid_query");
+        assert!(err.to_string().contains("databases[?status=="));
+    }
+
+    #[test]
+    fn test_split_expectation() {
+        assert_eq!(split_expectation("name==`prod`"), ("name", Some("`prod`")));
+        assert_eq!(
+            split_expectation("length(nodes[?status=='ok']) == `3`"),
+            ("length(nodes[?status=='ok'])", Some("`3`"))
+        );
+        assert_eq!(split_expectation("tls_mode"), ("tls_mode", None));
+        assert_eq!(split_expectation("a==`x==y`"), ("a", Some("`x==y`")));
+    }
+
+    #[test]
+    fn test_parse_expected_value() {
+        assert_eq!(parse_expected_value("`prod`"), json!("prod"));
+        assert_eq!(parse_expected_value("`3`"), json!(3));
+        assert_eq!(parse_expected_value("true"), json!(true));
+        assert_eq!(parse_expected_value("'3'"), json!("3"));
+        assert_eq!(parse_expected_value("active"), json!("active"));
+        assert_eq!(parse_expected_value("`[1, 2]`"), json!([1, 2]));
+    }
+
+    #[test]
+    fn test_is_truthy() {
+        assert!(is_truthy(&json!("x")));
+        assert!(is_truthy(&json!(0)));
+        assert!(!is_truthy(&json!("")));
+        assert!(!is_truthy(&json!([])));
+        assert!(!is_truthy(&json!(null)));
+        assert!(!is_truthy(&json!(false)));
+    }
+
     #[test]
     fn test_jmespath_backtick_literal_compiles() {
         // The original failing case should now work
@@ -329,4 +1530,332 @@ mod tests {
         let result = compile_jmespath(query);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_ordered_keys_enterprise_database() {
+        let value = serde_json::json!({
+            "port": 12000,
+            "memory_size": 1024,
+            "name": "cache",
+            "uid": 1,
+            "status": "active",
+            "aof_policy": "appendfsync-every-sec"
+        });
+        let keys: Vec<&str> = ordered_keys(value.as_object().unwrap())
+            .into_iter()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            keys,
+            ["uid", "name", "status", "memory_size", "port", "aof_policy"]
+        );
+    }
+
+    #[test]
+    fn test_render_jsonl_writes_one_line_per_element() {
+        let value = serde_json::json!([{"b": 1, "a": [1, 2]}, {"b": 2}]);
+        assert_eq!(
+            render_jsonl(&value, false).unwrap(),
+            "{\"a\":[1,2],\"b\":1}\n{\"b\":2}"
+        );
+        assert_eq!(
+            render_jsonl(&serde_json::json!({"id": 1}), false).unwrap(),
+            "{\"id\":1}"
+        );
+        assert_eq!(render_jsonl(&serde_json::json!([]), false).unwrap(), "");
+    }
+
+    #[test]
+    fn test_canonical_serialization_is_recursive() {
+        let value = serde_json::json!([{
+            "name": "a",
+            "id": 1,
+            "extra": { "status": "x", "id": 2 }
+        }]);
+        let json = serde_json::to_string(&Canonical(&value)).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"id":1,"name":"a","extra":{"id":2,"status":"x"}}]"#
+        );
+    }
+
+    #[test]
+    fn test_coerce_bools_mixed_representations() {
+        let fields: Vec<String> = ["replication", "tls", "enabled"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        let mut value = serde_json::json!({
+            "replication": "true",
+            "tls": 0,
+            "shards": 1,
+            "modules": [
+                {"enabled": "No"},
+                {"enabled": "1"},
+                {"enabled": "maybe"},
+                {"enabled": 2}
+            ]
+        });
+
+        coerce_bools(&mut value, &fields);
+
+        assert_eq!(value["replication"], true);
+        assert_eq!(value["tls"], false);
+        assert_eq!(value["shards"], 1);
+        assert_eq!(value["modules"][0]["enabled"], false);
+        assert_eq!(value["modules"][1]["enabled"], true);
+        assert_eq!(value["modules"][2]["enabled"], "maybe");
+        assert_eq!(value["modules"][3]["enabled"], 2);
+    }
+
+    #[test]
+    fn test_normalize_timestamps_mixed_sources() {
+        let fields: Vec<String> = ["created_time", "last_changed_time", "timestamp", "end_time"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        let mut value = serde_json::json!({
+            "created_time": 1705314600,
+            "last_changed_time": "2024-01-15T11:30:00+01:00",
+            "port": 12000,
+            "tasks": [
+                {"timestamp": 1705314600123_i64, "end_time": "2024-01-15 10:30:00"},
+                {"timestamp": "1705314600", "end_time": "not a time"}
+            ]
+        });
+
+        normalize_timestamps(&mut value, &fields);
+
+        assert_eq!(value["created_time"], "2024-01-15T10:30:00Z");
+        assert_eq!(value["last_changed_time"], "2024-01-15T10:30:00Z");
+        assert_eq!(value["port"], 12000);
+        assert_eq!(value["tasks"][0]["timestamp"], "2024-01-15T10:30:00.123Z");
+        assert_eq!(value["tasks"][0]["end_time"], "2024-01-15T10:30:00Z");
+        assert_eq!(value["tasks"][1]["timestamp"], "2024-01-15T10:30:00Z");
+        assert_eq!(value["tasks"][1]["end_time"], "not a time");
+    }
+
+    #[test]
+    fn test_strip_nulls_recursive() {
+        let mut value = serde_json::json!({
+            "name": "db",
+            "password": null,
+            "endpoints": [{ "host": "h", "port": null }, null],
+            "nested": { "a": null }
+        });
+        strip_nulls(&mut value);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "name": "db",
+                "endpoints": [{ "host": "h" }, null],
+                "nested": {}
+            })
+        );
+    }
+
+    #[test]
+    fn test_exclude_path_strips_from_each_item() {
+        let mut value = serde_json::json!([
+            {"uid": 1, "links": [], "endpoint": {"host": "h", "password": "s"}},
+            {"uid": 2, "endpoints": [{"host": "a", "password": "x"}]},
+            "scalar"
+        ]);
+        for path in parse_fields("links,endpoint.password,endpoints.password,missing.key") {
+            exclude_path(&mut value, &path);
+        }
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {"uid": 1, "endpoint": {"host": "h"}},
+                {"uid": 2, "endpoints": [{"host": "a"}]},
+                "scalar"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_sort_array_by_numeric_aware() {
+        let mut value = serde_json::json!([
+            {"uid": 10, "name": "b"},
+            {"uid": 9, "name": "c"},
+            {"name": "no-uid"},
+            {"uid": "2", "name": "a"}
+        ]);
+        sort_array_by(&mut value, "uid", false);
+        let names: Vec<_> = value
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["a", "c", "b", "no-uid"]);
+
+        sort_array_by(&mut value, "uid", true);
+        let names: Vec<_> = value
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["b", "c", "a", "no-uid"]);
+    }
+
+    #[test]
+    fn test_sort_array_by_nested_field() {
+        let mut value = serde_json::json!([
+            {"name": "x", "region": {"name": "us-east-1"}},
+            {"name": "y", "region": {"name": "eu-west-1"}}
+        ]);
+        sort_array_by(&mut value, "region.name", false);
+        assert_eq!(value[0]["name"], "y");
+
+        // Objects are left alone
+        let mut obj = serde_json::json!({"b": 1, "a": 2});
+        sort_array_by(&mut obj, "a", false);
+        assert_eq!(obj, serde_json::json!({"b": 1, "a": 2}));
+    }
+
+    #[test]
+    fn test_sort_array_by_normalized_timestamps() {
+        // Epoch seconds and ISO strings only sort chronologically once normalized
+        let mut value = serde_json::json!([
+            {"name": "iso", "created": "2024-06-01T00:00:00Z"},
+            {"name": "epoch", "created": 1704067200}
+        ]);
+        normalize_timestamps(&mut value, &["created".to_string()]);
+        sort_array_by(&mut value, "created", false);
+        assert_eq!(value[0]["name"], "epoch");
+        assert_eq!(value[0]["created"], "2024-01-01T00:00:00Z");
+    }
+
+    #[test]
+    fn test_render_csv_delimiter_and_quote_all() {
+        let value = serde_json::json!([{"name": "a;b", "uid": 1}]);
+        let semicolon = CsvOptions {
+            delimiter: b';',
+            quote_all: false,
+        };
+        assert_eq!(
+            render_csv(&value, semicolon).unwrap(),
+            "name;uid\n\"a;b\";1"
+        );
+
+        let tsv_quoted = CsvOptions {
+            delimiter: b'\t',
+            quote_all: true,
+        };
+        assert_eq!(
+            render_csv(&value, tsv_quoted).unwrap(),
+            "\"name\"\t\"uid\"\n\"a;b\"\t\"1\""
+        );
+    }
+
+    #[test]
+    fn test_truncate_list() {
+        let mut value = serde_json::json!([1, 2, 3, 4, 5]);
+        assert_eq!(truncate_list(&mut value, 2), 3);
+        assert_eq!(value, serde_json::json!([1, 2]));
+
+        // Shorter lists and non-lists are untouched
+        assert_eq!(truncate_list(&mut value, 10), 0);
+        let mut obj = serde_json::json!({"a": 1});
+        assert_eq!(truncate_list(&mut obj, 0), 0);
+        assert_eq!(obj, serde_json::json!({"a": 1}));
+    }
+
+    #[test]
+    fn test_wrap_list() {
+        assert_eq!(
+            wrap_list(serde_json::json!([{"uid": 1}, {"uid": 2}]), "databases"),
+            serde_json::json!({"databases": [{"uid": 1}, {"uid": 2}], "count": 2})
+        );
+        assert_eq!(
+            wrap_list(serde_json::json!([]), "items"),
+            serde_json::json!({"items": [], "count": 0})
+        );
+        assert_eq!(
+            wrap_list(serde_json::json!({"uid": 1}), "databases"),
+            serde_json::json!({"uid": 1})
+        );
+    }
+
+    #[test]
+    fn test_parse_fields_trims_and_skips_blanks() {
+        assert_eq!(
+            parse_fields(" uid, status,,shards "),
+            ["uid", "status", "shards"]
+        );
+    }
+
+    #[test]
+    fn test_render_table_fields_selects_and_orders_columns() {
+        let value = serde_json::json!([
+            {"uid": 1, "status": "active", "addr": "10.0.0.1", "shards": 3},
+            {"uid": 2, "addr": "10.0.0.2", "shards": null}
+        ]);
+        let fields = parse_fields("status,uid,shards");
+        let rendered = render_table_fields(&value, &fields);
+        let lines: Vec<Vec<&str>> = rendered
+            .lines()
+            .map(|l| l.split_whitespace().collect())
+            .collect();
+        assert_eq!(lines[0], ["status", "uid", "shards"]);
+        assert_eq!(lines[1], ["active", "1", "3"]);
+        // Missing and null fields are blank
+        assert_eq!(lines[2], ["2"]);
+        assert!(!rendered.contains("addr"));
+    }
+
+    #[test]
+    fn test_render_table_fields_single_object() {
+        let value = serde_json::json!({"uid": 1, "name": "db", "port": 12000});
+        let rendered = render_table_fields(&value, &parse_fields("port,missing"));
+        assert!(rendered.contains("port"));
+        assert!(rendered.contains("12000"));
+        assert!(rendered.contains("missing"));
+        assert!(!rendered.contains("name"));
+    }
+
+    #[test]
+    fn test_render_csv_union_of_keys() {
+        let value = serde_json::json!([
+            {"name": "a", "uid": 1},
+            {"name": "b, c", "status": "active"}
+        ]);
+        assert_eq!(
+            render_csv(&value, CsvOptions::default()).unwrap(),
+            "name,uid,status\na,1,\n\"b, c\",,active"
+        );
+    }
+
+    #[test]
+    fn test_render_csv_encodes_nested_values() {
+        let value = serde_json::json!([
+            {"modules": [{"name": "search"}], "name": "db", "tags": null}
+        ]);
+        assert_eq!(
+            render_csv(&value, CsvOptions::default()).unwrap(),
+            "modules,name,tags\n\"[{\"\"name\"\":\"\"search\"\"}]\",db,"
+        );
+    }
+
+    #[test]
+    fn test_render_csv_single_object_and_scalars() {
+        assert_eq!(
+            render_csv(&serde_json::json!({"uid": 1}), CsvOptions::default()).unwrap(),
+            "uid\n1"
+        );
+        assert_eq!(
+            render_csv(&serde_json::json!(["a", "b"]), CsvOptions::default()).unwrap(),
+            "value\na\nb"
+        );
+    }
+
+    #[test]
+    fn test_render_csv_rejects_scalar() {
+        let err = render_csv(&serde_json::json!(42), CsvOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("needs an array of objects"));
+        assert!(err.to_string().contains("a number"));
+    }
 }
@@ -5,7 +5,14 @@
 //!
 //! TODO: Complete implementation after tower-resilience API stabilizes
 
-use redisctl_core::config::resilience::ResilienceConfig;
+use std::path::PathBuf;
+
+use redisctl_core::config::resilience::{
+    CircuitBreakerConfig, CircuitBreakerMetrics, CircuitBreakerStore, ResilienceConfig, unix_now,
+};
+
+use crate::connection::ConnectionManager;
+use crate::error::{RedisCtlError, Result as CliResult};
 
 /// Wrap a Redis Cloud client with resilience patterns
 ///
@@ -57,6 +64,87 @@ pub fn is_resilience_disabled(
     no_resilience
 }
 
+/// Resilience-related global CLI flags, applied on top of the profile's config
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResilienceFlags {
+    pub no_resilience: bool,
+    pub no_circuit_breaker: bool,
+    pub no_retry: bool,
+    pub retry_attempts: Option<u32>,
+    pub rate_limit: Option<u32>,
+}
+
+impl ResilienceFlags {
+    /// Apply these flags to `config` (flags win over any config file setting)
+    pub fn apply(&self, config: &mut ResilienceConfig) {
+        apply_cli_overrides(
+            config,
+            self.no_resilience,
+            self.no_circuit_breaker,
+            self.no_retry,
+            self.retry_attempts,
+            self.rate_limit,
+        );
+    }
+}
+
+/// Circuit breaker bookkeeping for the profile a command talks to
+///
+/// Profiles opt in with `track = true` in their `resilience.circuit_breaker`
+/// section. The outcome of each command is recorded for `profile show`; the
+/// breaker never stops a command from running. Only network failures and
+/// timeouts count against the circuit; API errors mean the server answered.
+pub struct CircuitBreaker {
+    config_path: PathBuf,
+    profile: String,
+    config: CircuitBreakerConfig,
+}
+
+impl CircuitBreaker {
+    /// Breaker for `profile`, or `None` when the profile doesn't track one or
+    /// there is no config location to keep its state in
+    pub fn for_profile(conn_mgr: &ConnectionManager, profile: &str) -> Option<Self> {
+        let config = conn_mgr.resilience_config(Some(profile)).circuit_breaker;
+        if !config.enabled || !config.track {
+            return None;
+        }
+        let config_path = match &conn_mgr.config_path {
+            Some(path) => path.clone(),
+            None => redisctl_core::Config::config_path().ok()?,
+        };
+        Some(Self {
+            config_path,
+            profile: profile.to_string(),
+            config,
+        })
+    }
+
+    /// Current state, failure counts and last trip time
+    pub fn metrics(&self) -> CircuitBreakerMetrics {
+        CircuitBreakerStore::load(&CircuitBreakerStore::path_for(&self.config_path))
+            .get(&self.profile)
+            .metrics(&self.config, unix_now())
+    }
+
+    /// Record the command's outcome
+    pub fn record<T>(&self, result: &CliResult<T>) {
+        let failed = matches!(
+            result,
+            Err(RedisCtlError::ConnectionError { .. } | RedisCtlError::Timeout { .. })
+        );
+        let recorded = CircuitBreakerStore::update(&self.config_path, |store| {
+            store
+                .profiles
+                .entry(self.profile.clone())
+                .or_default()
+                .record(failed, &self.config, unix_now());
+        });
+        if let Err(e) = recorded {
+            tracing::debug!("Failed to save circuit breaker state: {}", e);
+        }
+    }
+}
+
 /// Apply CLI overrides to resilience configuration
 #[allow(dead_code)]
 pub fn apply_cli_overrides(
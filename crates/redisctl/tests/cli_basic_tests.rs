@@ -35,6 +35,108 @@ fn test_version_flag() {
         .stdout(predicate::str::contains(env!("CARGO_PKG_VERSION")));
 }
 
+#[test]
+fn test_field_order_flag_json_output() {
+    redisctl()
+        .args(["--field-order", "-o", "json", "version"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"version\""));
+}
+
+#[test]
+fn test_omit_null_flag_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--omit-null"));
+}
+
+#[test]
+fn test_sort_by_flags_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--sort-by"))
+        .stdout(predicate::str::contains("--reverse"));
+}
+
+#[test]
+fn test_csv_options_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--csv-delimiter"))
+        .stdout(predicate::str::contains("--csv-quote-all"))
+        .stdout(predicate::str::contains("tsv"));
+}
+
+#[test]
+fn test_csv_delimiter_rejects_multiple_characters() {
+    redisctl()
+        .args(["--csv-delimiter", ";;", "version"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid CSV delimiter"));
+}
+
+#[test]
+fn test_http_timeout_flag_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--http-timeout"));
+}
+
+#[test]
+fn test_max_items_flag_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--max-items"));
+}
+
+#[test]
+fn test_fields_flag_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--fields"));
+}
+
+#[test]
+fn test_reverse_requires_sort_by() {
+    redisctl()
+        .args(["--reverse", "-o", "json", "version"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--sort-by"));
+}
+
+#[test]
+fn test_profile_from_context_flag_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--profile-from-context"));
+}
+
+#[test]
+fn test_preflight_flag_in_help() {
+    redisctl()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--preflight"));
+}
+
 #[test]
 fn test_version_short_flag() {
     redisctl()
@@ -214,6 +316,50 @@ fn test_global_flags_before_subcommand() {
         .success();
 }
 
+#[test]
+fn test_no_color_error_output_is_plain() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    redisctl()
+        .arg("--config-file")
+        .arg(temp_dir.path().join("config.toml"))
+        .arg("--no-color")
+        .args(["profile", "show", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error: "))
+        .stderr(predicate::str::contains("\u{1b}[").not());
+}
+
+#[test]
+fn test_resilience_simulate_prints_backoff_schedule() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    redisctl()
+        .arg("--config-file")
+        .arg(temp_dir.path().join("config.toml"))
+        .args([
+            "resilience",
+            "simulate",
+            "--attempts",
+            "5",
+            "--backoff-ms",
+            "200",
+        ])
+        .args(["--max-backoff-ms", "1000", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"total_delay_ms\": 2400"));
+
+    redisctl()
+        .arg("--config-file")
+        .arg(temp_dir.path().join("config.toml"))
+        .args(["resilience", "simulate", "--attempts", "3", "--jitter"])
+        .args(["--backoff-ms", "100", "--max-backoff-ms", "5000"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0-200ms"))
+        .stdout(predicate::str::contains("up to 300ms"));
+}
+
 #[test]
 fn test_profile_set_missing_required_args() {
     redisctl()
@@ -486,6 +632,28 @@ fn test_api_help_shows_examples() {
         .stdout(predicate::str::contains("api enterprise get /v1/cluster"));
 }
 
+#[test]
+fn test_api_paths_lists_endpoints_without_profile() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    redisctl()
+        .arg("--config-file")
+        .arg(temp_dir.path().join("config.toml"))
+        .args(["api", "enterprise", "paths", "crdbs", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("/v1/crdbs/{guid}"))
+        .stdout(predicate::str::contains("/v1/bdbs\"").not());
+}
+
+#[test]
+fn test_api_request_requires_path() {
+    redisctl()
+        .args(["api", "cloud", "get"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("paths"));
+}
+
 #[test]
 fn test_completions_help() {
     redisctl()
@@ -551,6 +719,37 @@ fn test_cloud_subscription_list_help() {
         .stdout(predicate::str::contains("List all subscriptions"));
 }
 
+#[test]
+fn test_cloud_subscription_delete_help() {
+    redisctl()
+        .arg("cloud")
+        .arg("subscription")
+        .arg("delete")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--force"))
+        .stdout(predicate::str::contains("--delete-databases"))
+        .stdout(predicate::str::contains("--yes"))
+        .stdout(predicate::str::contains(
+            "Delete all databases in the subscription first",
+        ));
+}
+
+#[test]
+fn test_cloud_subscription_wait_help() {
+    redisctl()
+        .arg("cloud")
+        .arg("subscription")
+        .arg("wait")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--id"))
+        .stdout(predicate::str::contains("--timeout"))
+        .stdout(predicate::str::contains("--interval"));
+}
+
 #[test]
 fn test_cloud_database_help() {
     redisctl()
@@ -622,6 +821,19 @@ fn test_cloud_task_get_help() {
         .stdout(predicate::str::contains("Get task status"));
 }
 
+#[test]
+fn test_cloud_task_list_active_help() {
+    redisctl()
+        .arg("cloud")
+        .arg("task")
+        .arg("list")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--active"))
+        .stdout(predicate::str::contains("--watch"));
+}
+
 #[test]
 fn test_cloud_connectivity_help() {
     redisctl()
@@ -738,6 +950,44 @@ fn test_enterprise_cluster_get_help() {
         .stdout(predicate::str::contains("Get cluster configuration"));
 }
 
+#[test]
+fn test_enterprise_cluster_info_brief_help() {
+    redisctl()
+        .arg("enterprise")
+        .arg("cluster")
+        .arg("info")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--brief"));
+}
+
+#[test]
+fn test_enterprise_cluster_time_sync_help() {
+    redisctl()
+        .arg("enterprise")
+        .arg("cluster")
+        .arg("time-sync")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--threshold"))
+        .stdout(predicate::str::contains("clock skew"));
+}
+
+#[test]
+fn test_enterprise_crdb_task_status_wait_help() {
+    redisctl()
+        .arg("enterprise")
+        .arg("crdb-task")
+        .arg("status")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--wait"))
+        .stdout(predicate::str::contains("--wait-timeout"));
+}
+
 #[test]
 fn test_enterprise_database_help() {
     redisctl()
@@ -1047,6 +1297,25 @@ fn test_enterprise_ldap_help() {
         .stdout(predicate::str::contains("LDAP"));
 }
 
+#[test]
+fn test_enterprise_ldap_test_help() {
+    redisctl()
+        .args(["enterprise", "ldap", "test", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--username"))
+        .stdout(predicate::str::contains("--password"));
+}
+
+#[test]
+fn test_enterprise_ldap_test_password_requires_username() {
+    redisctl()
+        .args(["enterprise", "ldap", "test", "--password", "secret"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--username"));
+}
+
 #[test]
 fn test_enterprise_ldap_mappings_help() {
     redisctl()
@@ -1080,6 +1349,19 @@ fn test_enterprise_logs_help() {
         .stdout(predicate::str::contains("Log"));
 }
 
+#[test]
+fn test_enterprise_logs_export_help() {
+    redisctl()
+        .arg("enterprise")
+        .arg("logs")
+        .arg("export")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--compress"))
+        .stdout(predicate::str::contains("json-lines"));
+}
+
 #[test]
 fn test_enterprise_migration_help() {
     redisctl()
@@ -1143,7 +1425,34 @@ fn test_enterprise_status_help() {
         .arg("--help")
         .assert()
         .success()
-        .stdout(predicate::str::contains("status"));
+        .stdout(predicate::str::contains("status"))
+        .stdout(predicate::str::contains("--watch"));
+}
+
+#[test]
+fn test_enterprise_stats_watch_rejects_zero_interval() {
+    redisctl()
+        .args(["enterprise", "stats", "cluster", "--watch", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--watch"));
+}
+
+#[test]
+fn test_enterprise_stats_watch_conflicts_with_follow() {
+    redisctl()
+        .args([
+            "enterprise",
+            "stats",
+            "database",
+            "1",
+            "--watch",
+            "5",
+            "--follow",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
 }
 
 #[test]
@@ -1168,6 +1477,17 @@ fn test_enterprise_usage_report_help() {
         .stdout(predicate::str::contains("Usage report"));
 }
 
+#[test]
+fn test_enterprise_usage_report_schedule_help() {
+    redisctl()
+        .args(["enterprise", "usage-report", "schedule", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--interval"))
+        .stdout(predicate::str::contains("--output-dir"))
+        .stdout(predicate::str::contains("--force"));
+}
+
 // Cloud command help tests
 
 #[test]
@@ -1491,6 +1811,20 @@ fn test_enterprise_node_update_first_class_params_help() {
         .stdout(predicate::str::contains("--data"));
 }
 
+#[test]
+fn test_enterprise_node_remove_help() {
+    redisctl()
+        .arg("enterprise")
+        .arg("node")
+        .arg("remove")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--drain-first"))
+        .stdout(predicate::str::contains("--wait-timeout"))
+        .stdout(predicate::str::contains("master shards"));
+}
+
 #[test]
 fn test_enterprise_node_update_has_examples() {
     redisctl()
@@ -2123,6 +2457,92 @@ fn test_enterprise_database_import_help() {
         .stdout(predicate::str::contains("import"));
 }
 
+#[test]
+fn test_enterprise_database_import_verify_checksum_requires_wait() {
+    redisctl()
+        .arg("enterprise")
+        .arg("database")
+        .arg("import")
+        .arg("1")
+        .arg("--location")
+        .arg("https://example.com/backup.rdb")
+        .arg("--verify-checksum")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--wait"));
+}
+
+#[test]
+fn test_enterprise_database_export_config_help() {
+    redisctl()
+        .args(["enterprise", "database", "export-config", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--file"))
+        .stdout(predicate::str::contains("not exported"));
+}
+
+#[test]
+fn test_enterprise_database_import_config_help() {
+    redisctl()
+        .args(["enterprise", "database", "import-config", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--file"))
+        .stdout(predicate::str::contains("--dry-run"));
+}
+
+#[test]
+fn test_enterprise_database_wait_ready_help() {
+    redisctl()
+        .args(["enterprise", "database", "wait-ready", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--timeout"))
+        .stdout(predicate::str::contains("--interval"));
+}
+
+#[test]
+fn test_enterprise_database_replica_of_help() {
+    redisctl()
+        .args(["enterprise", "database", "replica-of", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--source"))
+        .stdout(predicate::str::contains("--wait"))
+        .stdout(predicate::str::contains("--dry-run"));
+}
+
+#[test]
+fn test_enterprise_database_conns_help() {
+    redisctl()
+        .args(["enterprise", "database", "conns", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CLIENT LIST"))
+        .stdout(predicate::str::contains("--host"))
+        .stdout(predicate::str::contains("--password"))
+        .stdout(predicate::str::contains("--ca-cert"))
+        .stdout(predicate::str::contains("--insecure"));
+}
+
+#[test]
+fn test_enterprise_database_conns_insecure_conflicts_with_ca_cert() {
+    redisctl()
+        .args([
+            "enterprise",
+            "database",
+            "conns",
+            "1",
+            "--insecure",
+            "--ca-cert",
+            "ca.pem",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
 #[test]
 fn test_enterprise_database_export_help() {
     redisctl()
@@ -2147,6 +2567,39 @@ fn test_enterprise_database_restore_help() {
         .stdout(predicate::str::contains("restore"));
 }
 
+#[test]
+fn test_enterprise_database_restore_help_shows_wait_and_force() {
+    redisctl()
+        .args(["enterprise", "database", "restore", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--backup"))
+        .stdout(predicate::str::contains("--wait"))
+        .stdout(predicate::str::contains("--force"));
+}
+
+#[test]
+fn test_enterprise_database_backup_list_help() {
+    redisctl()
+        .args(["enterprise", "database", "backup", "list", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("newest first"));
+}
+
+#[test]
+fn test_enterprise_database_recover_help() {
+    redisctl()
+        .arg("enterprise")
+        .arg("database")
+        .arg("recover")
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("persistence"))
+        .stdout(predicate::str::contains("--wait"));
+}
+
 #[test]
 fn test_enterprise_database_flush_help() {
     redisctl()
@@ -2156,7 +2609,8 @@ fn test_enterprise_database_flush_help() {
         .arg("--help")
         .assert()
         .success()
-        .stdout(predicate::str::contains("flush"));
+        .stdout(predicate::str::contains("flush"))
+        .stdout(predicate::str::contains("--async"));
 }
 
 #[test]
@@ -2252,7 +2706,51 @@ fn test_enterprise_database_slowlog_help() {
         .arg("--help")
         .assert()
         .success()
-        .stdout(predicate::str::contains("slow"));
+        .stdout(predicate::str::contains("slow"))
+        .stdout(predicate::str::contains("--reset"));
+}
+
+#[test]
+fn test_enterprise_database_slowlog_host_requires_reset() {
+    redisctl()
+        .args([
+            "enterprise",
+            "database",
+            "slowlog",
+            "1",
+            "--host",
+            "10.0.0.1",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--reset"));
+}
+
+#[test]
+fn test_enterprise_database_memory_breakdown_help() {
+    redisctl()
+        .args(["enterprise", "database", "memory-breakdown", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("MEMORY STATS"))
+        .stdout(predicate::str::contains("--samples"));
+}
+
+#[test]
+fn test_enterprise_database_tls_help() {
+    redisctl()
+        .args(["enterprise", "database", "tls", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("enable"))
+        .stdout(predicate::str::contains("disable"));
+
+    redisctl()
+        .args(["enterprise", "database", "tls", "enable", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--require-client-cert"))
+        .stdout(predicate::str::contains("--ca"));
 }
 
 #[test]
@@ -2800,7 +3298,9 @@ fn test_enterprise_cluster_join_help() {
         .arg("--help")
         .assert()
         .success()
-        .stdout(predicate::str::contains("join"));
+        .stdout(predicate::str::contains("join"))
+        .stdout(predicate::str::contains("--url"))
+        .stdout(predicate::str::contains("--wait"));
 }
 
 #[test]
@@ -4744,3 +5244,34 @@ fn test_help_shows_prefix_inference_examples() {
             "Commands infer platform from your profile",
         ));
 }
+
+#[test]
+fn test_compare_platforms_help() {
+    redisctl()
+        .args(["compare-platforms", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--cloud-spec"))
+        .stdout(predicate::str::contains("--enterprise-spec"))
+        .stdout(predicate::str::contains("--enterprise-profile"));
+}
+
+#[test]
+fn test_compare_platforms_requires_both_sides() {
+    redisctl()
+        .args(["compare-platforms", "--cloud", "1:2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--enterprise"));
+}
+
+#[test]
+fn test_cloud_acl_bind_role_help() {
+    redisctl()
+        .args(["cloud", "acl", "bind-role", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--subscription"))
+        .stdout(predicate::str::contains("--database"))
+        .stdout(predicate::str::contains("--user"));
+}
@@ -2,7 +2,7 @@ use assert_cmd::Command;
 use predicates::prelude::*;
 use serde_json::json;
 use tempfile::TempDir;
-use wiremock::matchers::{header, method, path};
+use wiremock::matchers::{body_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Helper to create a test command with isolated config
@@ -156,6 +156,156 @@ async fn test_api_enterprise_get_with_basic_auth() {
         .stdout(predicate::str::contains("Test Cluster"));
 }
 
+#[tokio::test]
+async fn test_profile_output_format_applies_without_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    std::fs::write(
+        temp_dir.path().join("config.toml"),
+        format!(
+            r#"
+default_enterprise = "test"
+
+[profiles.test]
+deployment_type = "enterprise"
+url = "{}"
+username = "admin@redis.local"
+password = "test-password"
+insecure = true
+output = "yaml"
+"#,
+            mock_server.uri()
+        ),
+    )
+    .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"name": "prod"})))
+        .mount(&mock_server)
+        .await;
+
+    // The profile's format replaces auto-detection
+    test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/cluster"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("name: prod"));
+
+    // An explicit --output still wins
+    test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/cluster", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"prod\""));
+
+    // So does an explicit `-o auto`, which is JSON when piped
+    test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/cluster", "-o", "auto"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"prod\""));
+}
+
+#[tokio::test]
+async fn test_expect_assertions() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": "prod",
+            "nodes": [{"uid": 1}, {"uid": 2}, {"uid": 3}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/cluster"])
+        .args(["--expect", "name==`prod`", "--expect", "length(nodes)==`3`"])
+        .assert()
+        .success();
+
+    test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/cluster"])
+        .args(["--expect", "name==`staging`"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Expectation failed"))
+        .stderr(predicate::str::contains("\"prod\""));
+
+    // A malformed path is a usage error and nothing is requested
+    test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/cluster"])
+        .args(["--expect", "nodes[==`1`"])
+        .assert()
+        .code(2);
+}
+
+#[tokio::test]
+async fn test_api_custom_headers() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // The user's Accept replaces the default application/json
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .and(header("Accept", "application/problem+json"))
+        .and(header("X-Correlation-Id", "abc-123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"uid": 1})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("enterprise")
+        .arg("get")
+        .arg("/v1/bdbs/1")
+        .arg("-H")
+        .arg("Accept: application/problem+json")
+        .arg("--header")
+        .arg("X-Correlation-Id: abc-123")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"uid\": 1"));
+}
+
+#[tokio::test]
+async fn test_api_header_validation() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    test_cmd(&temp_dir)
+        .args([
+            "api",
+            "enterprise",
+            "get",
+            "/v1/bdbs",
+            "-H",
+            "X-Missing-Colon",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing ':'"));
+
+    // Credentials always come from the profile
+    test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/bdbs"])
+        .args(["-H", "Authorization: Basic Zm9vOmJhcg=="])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot set Authorization"));
+}
+
 #[tokio::test]
 async fn test_api_error_response_401() {
     let temp_dir = TempDir::new().unwrap();
@@ -300,6 +450,38 @@ async fn test_enterprise_database_list() {
         .stdout(predicate::str::contains("db2"));
 }
 
+#[tokio::test]
+async fn test_exclude_strips_fields_from_each_item() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "name": "db1", "authentication_redis_pass": "s1", "tls": {"cert": "c1", "mode": "on"}},
+            {"uid": 2, "name": "db2", "authentication_redis_pass": "s2"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/bdbs", "-o", "json"])
+        .args(["--exclude", "authentication_redis_pass,tls.cert"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let dbs: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        dbs,
+        json!([
+            {"uid": 1, "name": "db1", "tls": {"mode": "on"}},
+            {"uid": 2, "name": "db2"}
+        ])
+    );
+}
+
 #[tokio::test]
 async fn test_api_delete_request() {
     let temp_dir = TempDir::new().unwrap();
@@ -752,346 +934,2683 @@ async fn test_enterprise_backup_operations() {
 }
 
 #[tokio::test]
-async fn test_cloud_account_information() {
+async fn test_enterprise_database_recover_rejects_active_database() {
     let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
-    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
 
-    // Mock account endpoint
     Mock::given(method("GET"))
-        .and(path("/account"))
+        .and(path("/v1/bdbs/1"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "id": 2034806,
-            "name": "Test Account",
-            "key": {
-                "name": "test-key",
-                "accountId": 2034806
-            }
+            "uid": 1,
+            "name": "db1",
+            "status": "active"
         })))
         .expect(1)
         .mount(&mock_server)
         .await;
 
+    // The recovery request must never be sent for a healthy database
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/actions/recover"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
     test_cmd(&temp_dir)
-        .arg("api")
-        .arg("cloud")
-        .arg("get")
-        .arg("/account")
+        .arg("enterprise")
+        .arg("database")
+        .arg("recover")
+        .arg("1")
+        .arg("--force")
         .assert()
-        .success()
-        .stdout(predicate::str::contains("Test Account"))
-        .stdout(predicate::str::contains("2034806"));
+        .failure()
+        .stderr(predicate::str::contains("'recovery' state"));
 }
 
 #[tokio::test]
-async fn test_enterprise_redis_acl_operations() {
+async fn test_enterprise_database_recover_waits_for_active_without_action() {
     let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
     create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
 
-    // Mock Redis ACL list
+    // Still recovering for the pre-check and the first poll, then active
     Mock::given(method("GET"))
-        .and(path("/v1/redis_acls"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-            {
-                "uid": 1,
-                "name": "default-acl",
-                "acl": "+@all"
-            },
-            {
-                "uid": 2,
-                "name": "readonly-acl",
-                "acl": "+@read"
-            }
-        ])))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 1,
+            "name": "db1",
+            "status": "recovery"
+        })))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 1,
+            "name": "db1",
+            "status": "active"
+        })))
         .expect(1)
         .mount(&mock_server)
         .await;
 
-    test_cmd(&temp_dir)
-        .arg("api")
-        .arg("enterprise")
-        .arg("get")
-        .arg("/v1/redis_acls")
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("default-acl"))
-        .stdout(predicate::str::contains("readonly-acl"));
-}
-
-#[tokio::test]
-async fn test_cloud_database_modules() {
-    let temp_dir = TempDir::new().unwrap();
-    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1/actions/recover"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"status": "ready"})))
+        .mount(&mock_server)
+        .await;
 
-    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+    // The response carries no action to follow
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/actions/recover"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
 
-    // Mock database with modules
     Mock::given(method("GET"))
-        .and(path("/subscriptions/123/databases/456"))
+        .and(path("/v1/bdbs/stats/last/1"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "databaseId": 456,
-            "name": "redis-with-modules",
-            "modules": [
-                {
-                    "name": "RedisJSON",
-                    "version": "2.6"
-                },
-                {
-                    "name": "RediSearch",
-                    "version": "2.8"
-                }
-            ]
+            "1": {"no_of_keys": 42}
         })))
-        .expect(1)
         .mount(&mock_server)
         .await;
 
-    test_cmd(&temp_dir)
-        .arg("api")
-        .arg("cloud")
-        .arg("get")
-        .arg("/subscriptions/123/databases/456")
-        .assert()
-        .success()
-        .stdout(predicate::str::contains("RedisJSON"))
-        .stdout(predicate::str::contains("RediSearch"));
+    let output = test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "recover",
+            "1",
+            "--force",
+            "--wait",
+            "--wait-interval",
+            "1",
+            "-o",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["status"], "completed");
+    assert_eq!(result["recovered_keys"], 42);
 }
 
 #[tokio::test]
-async fn test_enterprise_crdb_operations() {
+async fn test_enterprise_node_remove_refuses_node_with_master_shards() {
     let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
     create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
 
-    // Mock CRDB (Active-Active) list
     Mock::given(method("GET"))
-        .and(path("/v1/crdbs"))
+        .and(path("/v1/nodes"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-            {
-                "guid": "crdb-guid-1",
-                "name": "geo-distributed-db",
-                "replication": true,
-                "instances": [
-                    {"cluster": {"url": "cluster1.example.com"}},
-                    {"cluster": {"url": "cluster2.example.com"}}
-                ]
-            }
+            {"uid": 1, "status": "active"},
+            {"uid": 2, "status": "active"},
+            {"uid": 3, "status": "active"},
+            {"uid": 4, "status": "active"}
         ])))
-        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/shards"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": "1", "bdb_uid": 1, "node_uid": "4", "role": "master"},
+            {"uid": "2", "bdb_uid": 1, "node_uid": "2", "role": "slave"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    // The node must not be removed while it still holds master shards
+    Mock::given(method("POST"))
+        .and(path("/v1/nodes/4/actions/remove"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(0)
         .mount(&mock_server)
         .await;
 
     test_cmd(&temp_dir)
-        .arg("api")
         .arg("enterprise")
-        .arg("get")
-        .arg("/v1/crdbs")
+        .arg("node")
+        .arg("remove")
+        .arg("4")
         .assert()
-        .success()
-        .stdout(predicate::str::contains("geo-distributed-db"))
-        .stdout(predicate::str::contains("cluster1.example.com"));
+        .failure()
+        .stderr(predicate::str::contains("--drain-first"));
 }
 
 #[tokio::test]
-async fn test_cloud_payment_methods() {
+async fn test_cloud_subscription_delete_force_dry_run_lists_databases() {
     let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
     create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
 
-    // Mock payment methods endpoint
     Mock::given(method("GET"))
-        .and(path("/payment-methods"))
+        .and(path("/subscriptions/123/databases"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "paymentMethods": [
-                {
-                    "id": 1,
-                    "type": "credit-card",
-                    "last4": "4242"
-                }
-            ]
+            "accountId": 1,
+            "subscription": [{
+                "subscriptionId": 123,
+                "numberOfDatabases": 2,
+                "databases": [
+                    {"databaseId": 1, "name": "cache"},
+                    {"databaseId": 2, "name": "sessions"}
+                ]
+            }]
         })))
         .expect(1)
         .mount(&mock_server)
         .await;
 
+    // A dry run must not delete anything
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
     test_cmd(&temp_dir)
-        .arg("api")
         .arg("cloud")
-        .arg("get")
-        .arg("/payment-methods")
+        .arg("subscription")
+        .arg("delete")
+        .arg("123")
+        .arg("--delete-databases")
+        .arg("--dry-run")
         .assert()
         .success()
-        .stdout(predicate::str::contains("credit-card"))
-        .stdout(predicate::str::contains("4242"));
+        .stderr(predicate::str::contains("Would delete database 1 (cache)"))
+        .stderr(predicate::str::contains(
+            "Would delete database 2 (sessions)",
+        ))
+        .stderr(predicate::str::contains("Would delete subscription 123"));
 }
 
 #[tokio::test]
-async fn test_enterprise_license_info() {
+async fn test_cloud_subscription_delete_without_terminal_requires_confirmation_flags() {
     let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
-    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
 
-    // Mock license endpoint
     Mock::given(method("GET"))
-        .and(path("/v1/license"))
+        .and(path("/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"databaseId": 1, "name": "cache"}
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    // --force alone no longer deletes databases, and the missing prompt is an error
+    test_cmd(&temp_dir)
+        .args(["cloud", "subscription", "delete", "123"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Pass --force to confirm"));
+
+    test_cmd(&temp_dir)
+        .args([
+            "cloud",
+            "subscription",
+            "delete",
+            "123",
+            "--force",
+            "--delete-databases",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Pass --yes to confirm"));
+}
+
+#[tokio::test]
+async fn test_cloud_subscription_wait_follows_pending_task() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "taskId": "db-task",
+                "commandType": "databaseCreateRequest",
+                "status": "processing-in-progress",
+                "response": {"resourceId": 123}
+            },
+            {
+                "taskId": "sub-task",
+                "commandType": "subscriptionCreateRequest",
+                "status": "processing-in-progress",
+                "response": {"resourceId": 123}
+            }
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/sub-task"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "license": "valid",
-            "activation_date": "2024-01-01",
-            "expiration_date": "2025-12-31",
-            "shards_limit": 100
+            "taskId": "sub-task",
+            "status": "processing-completed",
+            "response": {"resourceId": 123}
         })))
         .expect(1)
         .mount(&mock_server)
         .await;
 
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123,
+            "name": "provisioned",
+            "status": "active"
+        })))
+        .mount(&mock_server)
+        .await;
+
     test_cmd(&temp_dir)
-        .arg("api")
-        .arg("enterprise")
-        .arg("get")
-        .arg("/v1/license")
+        .args(["cloud", "subscription", "wait", "--id", "123", "-o", "json"])
+        .args(["--interval", "1"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("valid"))
-        .stdout(predicate::str::contains("shards_limit"));
+        .stdout(predicate::str::contains("provisioned"));
 }
 
 #[tokio::test]
-async fn test_cloud_maintenance_windows() {
+async fn test_cloud_subscription_wait_reports_task_error() {
     let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
     create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
 
-    // Mock maintenance window update
-    Mock::given(method("PUT"))
-        .and(path("/subscriptions/123/maintenance"))
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "taskId": "sub-task",
+            "commandType": "subscriptionCreateRequest",
+            "status": "received",
+            "response": {"resourceId": 123}
+        }])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/sub-task"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "subscriptionId": 123,
-            "maintenanceWindows": [
-                {
-                    "mode": "weekly",
-                    "startHour": 2,
-                    "durationInHours": 4
-                }
-            ]
+            "taskId": "sub-task",
+            "status": "processing-error",
+            "response": {"error": "Insufficient quota"}
         })))
-        .expect(1)
         .mount(&mock_server)
         .await;
 
     test_cmd(&temp_dir)
-        .arg("api")
-        .arg("cloud")
-        .arg("put")
-        .arg("/subscriptions/123/maintenance")
-        .arg("--data")
-        .arg(r#"{"mode":"weekly","startHour":2}"#)
+        .args(["cloud", "subscription", "wait", "--id", "123"])
+        .args(["--interval", "1"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("maintenanceWindows"))
-        .stdout(predicate::str::contains("weekly"));
+        .failure()
+        .stderr(predicate::str::contains("Insufficient quota"));
 }
 
 #[tokio::test]
-async fn test_config_file_overrides_env_vars() {
+async fn test_cloud_database_create_wait_prints_only_database() {
     let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
     create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
 
-    // Mock endpoint expecting credentials from config file
-    // Note: Matches any path to debug what request is being made
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "db-task",
+            "commandType": "databaseCreateRequest",
+            "status": "received"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
     Mock::given(method("GET"))
+        .and(path("/tasks/db-task"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "message": "config file credentials used"
+            "taskId": "db-task",
+            "status": "processing-completed",
+            "response": {"resourceId": 42}
         })))
         .mount(&mock_server)
         .await;
 
-    let mut cmd = Command::cargo_bin("redisctl").unwrap();
-    let config_file = temp_dir.path().join("config.toml");
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 42,
+            "name": "cache",
+            "status": "active"
+        })))
+        .mount(&mock_server)
+        .await;
 
-    // Set environment variables that should be IGNORED when --config-file is specified
-    cmd.env("REDIS_CLOUD_API_KEY", "wrong-env-key");
-    cmd.env("REDIS_CLOUD_SECRET_KEY", "wrong-env-secret");
+    let output = test_cmd(&temp_dir)
+        .args(["cloud", "database", "create", "--subscription", "123"])
+        .args(["--name", "cache", "--memory", "1", "--wait", "-o", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    // Progress is suppressed, so stdout is exactly the database object
+    let db: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(db["databaseId"], 42);
+    assert_eq!(db["status"], "active");
+}
 
-    // The --config-file flag should take precedence over env vars
-    cmd.arg("--config-file")
-        .arg(config_file)
-        .arg("api")
-        .arg("cloud")
-        .arg("get")
-        .arg("/test")
+#[tokio::test]
+async fn test_cloud_database_create_zero_wait_timeout_returns_task() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "db-task",
+            "commandType": "databaseCreateRequest",
+            "status": "received"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/db-task"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["cloud", "database", "create", "--subscription", "123"])
+        .args(["--name", "cache", "--memory", "1", "-o", "json"])
+        .args(["--wait", "--wait-timeout", "0"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("config file credentials used"));
+        .stdout(predicate::str::contains("db-task"));
 }
 
 #[tokio::test]
-async fn test_env_vars_work_without_config_file() {
+async fn test_print_request_shows_cloud_database_create_request() {
+    let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
-    // Mock endpoint expecting credentials from environment variables
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/subscriptions/123/databases"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({"taskId": "db-task"})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args([
+            "--print-request",
+            "cloud",
+            "database",
+            "create",
+            "--subscription",
+            "123",
+        ])
+        .args(["--name", "cache", "--memory", "1", "--wait"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let request: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(request["method"], "POST");
+    assert_eq!(
+        request["url"],
+        format!("{}/subscriptions/123/databases", mock_server.uri())
+    );
+    assert_eq!(request["body"]["name"], "cache");
+    assert_eq!(request["body"]["memoryLimitInGb"], 1.0);
+}
+
+#[tokio::test]
+async fn test_print_request_shows_enterprise_database_update_request() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"uid": 1})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["--print-request", "enterprise", "database", "update", "1"])
+        .args(["--eviction-policy", "allkeys-lru"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"method\": \"PUT\""))
+        .stdout(predicate::str::contains("/v1/bdbs/1"))
+        .stdout(predicate::str::contains(
+            "\"eviction_policy\": \"allkeys-lru\"",
+        ));
+}
+
+#[tokio::test]
+async fn test_cloud_database_get_certificate_writes_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
     Mock::given(method("GET"))
-        .and(path("/test"))
-        .and(header("x-api-key", "env-api-key"))
-        .and(header("x-api-secret-key", "env-api-secret"))
+        .and(path("/subscriptions/123/databases/42/certificate"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "message": "env credentials used"
+            "publicCertificatePEMString": "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----"
         })))
-        .expect(1)
         .mount(&mock_server)
         .await;
 
-    let mut cmd = Command::cargo_bin("redisctl").unwrap();
-
-    // Without --config-file, env vars should work
-    cmd.env("REDIS_CLOUD_API_KEY", "env-api-key");
-    cmd.env("REDIS_CLOUD_SECRET_KEY", "env-api-secret");
-    cmd.env("REDIS_CLOUD_API_URL", mock_server.uri());
-
-    cmd.arg("api")
-        .arg("cloud")
-        .arg("get")
-        .arg("/test")
+    let pem_path = temp_dir.path().join("ca.pem");
+    test_cmd(&temp_dir)
+        .args([
+            "cloud",
+            "database",
+            "get-certificate",
+            "123:42",
+            "-o",
+            "table",
+        ])
+        .args(["--file", pem_path.to_str().unwrap()])
         .assert()
         .success()
-        .stdout(predicate::str::contains("env credentials used"));
+        .stdout(predicate::str::contains("ca.pem"));
+
+    let pem = std::fs::read_to_string(&pem_path).unwrap();
+    assert!(pem.starts_with("-----BEGIN CERTIFICATE-----\n"));
+    assert!(pem.ends_with("-----END CERTIFICATE-----\n"));
 }
 
 #[tokio::test]
-async fn test_cloud_api_secret_alias_works_without_config_file() {
+async fn test_cloud_account_information() {
+    let temp_dir = TempDir::new().unwrap();
     let mock_server = MockServer::start().await;
 
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock account endpoint
     Mock::given(method("GET"))
-        .and(path("/test"))
-        .and(header("x-api-key", "env-api-key"))
-        .and(header("x-api-secret-key", "env-api-secret"))
+        .and(path("/account"))
         .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-            "message": "env alias credentials used"
+            "id": 2034806,
+            "name": "Test Account",
+            "key": {
+                "name": "test-key",
+                "accountId": 2034806
+            }
         })))
         .expect(1)
         .mount(&mock_server)
         .await;
 
-    let mut cmd = Command::cargo_bin("redisctl").unwrap();
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("cloud")
+        .arg("get")
+        .arg("/account")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Test Account"))
+        .stdout(predicate::str::contains("2034806"));
+}
 
-    cmd.env("REDIS_CLOUD_API_KEY", "env-api-key");
-    cmd.env("REDIS_CLOUD_API_SECRET", "env-api-secret");
-    cmd.env_remove("REDIS_CLOUD_SECRET_KEY");
-    cmd.env("REDIS_CLOUD_API_URL", mock_server.uri());
+#[tokio::test]
+async fn test_enterprise_redis_acl_operations() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
 
-    cmd.arg("api")
-        .arg("cloud")
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock Redis ACL list
+    Mock::given(method("GET"))
+        .and(path("/v1/redis_acls"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "uid": 1,
+                "name": "default-acl",
+                "acl": "+@all"
+            },
+            {
+                "uid": 2,
+                "name": "readonly-acl",
+                "acl": "+@read"
+            }
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("enterprise")
         .arg("get")
-        .arg("/test")
+        .arg("/v1/redis_acls")
         .assert()
         .success()
-        .stdout(predicate::str::contains("env alias credentials used"));
+        .stdout(predicate::str::contains("default-acl"))
+        .stdout(predicate::str::contains("readonly-acl"));
+}
+
+#[tokio::test]
+async fn test_cloud_database_modules() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock database with modules
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "name": "redis-with-modules",
+            "modules": [
+                {
+                    "name": "RedisJSON",
+                    "version": "2.6"
+                },
+                {
+                    "name": "RediSearch",
+                    "version": "2.8"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("cloud")
+        .arg("get")
+        .arg("/subscriptions/123/databases/456")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("RedisJSON"))
+        .stdout(predicate::str::contains("RediSearch"));
+}
+
+#[tokio::test]
+async fn test_enterprise_crdb_operations() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock CRDB (Active-Active) list
+    Mock::given(method("GET"))
+        .and(path("/v1/crdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "guid": "crdb-guid-1",
+                "name": "geo-distributed-db",
+                "replication": true,
+                "instances": [
+                    {"cluster": {"url": "cluster1.example.com"}},
+                    {"cluster": {"url": "cluster2.example.com"}}
+                ]
+            }
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("enterprise")
+        .arg("get")
+        .arg("/v1/crdbs")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("geo-distributed-db"))
+        .stdout(predicate::str::contains("cluster1.example.com"));
+}
+
+#[tokio::test]
+async fn test_cloud_payment_methods() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock payment methods endpoint
+    Mock::given(method("GET"))
+        .and(path("/payment-methods"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "paymentMethods": [
+                {
+                    "id": 1,
+                    "type": "credit-card",
+                    "last4": "4242"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("cloud")
+        .arg("get")
+        .arg("/payment-methods")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("credit-card"))
+        .stdout(predicate::str::contains("4242"));
+}
+
+#[tokio::test]
+async fn test_enterprise_license_info() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock license endpoint
+    Mock::given(method("GET"))
+        .and(path("/v1/license"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "license": "valid",
+            "activation_date": "2024-01-01",
+            "expiration_date": "2025-12-31",
+            "shards_limit": 100
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("enterprise")
+        .arg("get")
+        .arg("/v1/license")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("valid"))
+        .stdout(predicate::str::contains("shards_limit"));
+}
+
+#[tokio::test]
+async fn test_cloud_maintenance_windows() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock maintenance window update
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/maintenance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "subscriptionId": 123,
+            "maintenanceWindows": [
+                {
+                    "mode": "weekly",
+                    "startHour": 2,
+                    "durationInHours": 4
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .arg("api")
+        .arg("cloud")
+        .arg("put")
+        .arg("/subscriptions/123/maintenance")
+        .arg("--data")
+        .arg(r#"{"mode":"weekly","startHour":2}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("maintenanceWindows"))
+        .stdout(predicate::str::contains("weekly"));
+}
+
+#[tokio::test]
+async fn test_cloud_subscription_maintenance_window_set_and_show() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("PUT"))
+        .and(path("/subscriptions/123/maintenance-windows"))
+        .and(body_json(json!({
+            "mode": "manual",
+            "windows": [{"days": ["Sunday", "Wednesday"], "startHour": 2, "durationInHours": 4}]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({
+            "taskId": "task-mw-1"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/maintenance-windows"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "mode": "manual",
+            "windows": [{"days": ["Sunday", "Wednesday"], "startHour": 2, "durationInHours": 4}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "cloud",
+            "subscription",
+            "maintenance-window",
+            "set",
+            "123",
+            "--days",
+            "sun,wed",
+            "--start",
+            "02:00",
+            "--duration",
+            "4h",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("task-mw-1"));
+
+    test_cmd(&temp_dir)
+        .args(["cloud", "subscription", "maintenance-window", "show", "123"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Sunday, Wednesday"))
+        .stdout(predicate::str::contains("02:00"))
+        .stdout(predicate::str::contains("4h"));
+
+    // Invalid input is rejected before any request is sent
+    test_cmd(&temp_dir)
+        .args([
+            "cloud",
+            "subscription",
+            "maintenance-window",
+            "set",
+            "123",
+            "--days",
+            "sun",
+            "--start",
+            "02:30",
+            "--duration",
+            "4h",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("on the hour"));
+}
+
+#[tokio::test]
+async fn test_config_file_overrides_env_vars() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    // Mock endpoint expecting credentials from config file
+    // Note: Matches any path to debug what request is being made
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "message": "config file credentials used"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = Command::cargo_bin("redisctl").unwrap();
+    let config_file = temp_dir.path().join("config.toml");
+
+    // Set environment variables that should be IGNORED when --config-file is specified
+    cmd.env("REDIS_CLOUD_API_KEY", "wrong-env-key");
+    cmd.env("REDIS_CLOUD_SECRET_KEY", "wrong-env-secret");
+
+    // The --config-file flag should take precedence over env vars
+    cmd.arg("--config-file")
+        .arg(config_file)
+        .arg("api")
+        .arg("cloud")
+        .arg("get")
+        .arg("/test")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("config file credentials used"));
+}
+
+#[tokio::test]
+async fn test_env_vars_work_without_config_file() {
+    let mock_server = MockServer::start().await;
+
+    // Mock endpoint expecting credentials from environment variables
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("x-api-key", "env-api-key"))
+        .and(header("x-api-secret-key", "env-api-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "message": "env credentials used"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = Command::cargo_bin("redisctl").unwrap();
+
+    // Without --config-file, env vars should work
+    cmd.env("REDIS_CLOUD_API_KEY", "env-api-key");
+    cmd.env("REDIS_CLOUD_SECRET_KEY", "env-api-secret");
+    cmd.env("REDIS_CLOUD_API_URL", mock_server.uri());
+
+    cmd.arg("api")
+        .arg("cloud")
+        .arg("get")
+        .arg("/test")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("env credentials used"));
+}
+
+#[tokio::test]
+async fn test_cloud_api_secret_alias_works_without_config_file() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("x-api-key", "env-api-key"))
+        .and(header("x-api-secret-key", "env-api-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "message": "env alias credentials used"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = Command::cargo_bin("redisctl").unwrap();
+
+    cmd.env("REDIS_CLOUD_API_KEY", "env-api-key");
+    cmd.env("REDIS_CLOUD_API_SECRET", "env-api-secret");
+    cmd.env_remove("REDIS_CLOUD_SECRET_KEY");
+    cmd.env("REDIS_CLOUD_API_URL", mock_server.uri());
+
+    cmd.arg("api")
+        .arg("cloud")
+        .arg("get")
+        .arg("/test")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("env alias credentials used"));
+}
+
+#[tokio::test]
+async fn test_preflight_fails_fast_on_bad_credentials() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // The actual command must never be attempted
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "--preflight",
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("preflight check"))
+        .stderr(predicate::str::contains("Authentication failed"));
+}
+
+#[tokio::test]
+async fn test_preflight_runs_once_before_command() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"name": "c1"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "name": "cache"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "--preflight",
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cache"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_export_config_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 1,
+            "name": "cache",
+            "port": 12000,
+            "status": "active",
+            "memory_size": 1073741824,
+            "authentication_redis_pass": "secret"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs"))
+        .and(wiremock::matchers::body_json(json!({
+            "name": "cache-copy",
+            "memory_size": 1073741824
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 2,
+            "name": "cache-copy"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let config_file = temp_dir.path().join("db.json");
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "export-config", "1", "--file"])
+        .arg(&config_file)
+        .assert()
+        .success();
+
+    let exported = std::fs::read_to_string(&config_file).unwrap();
+    assert!(!exported.contains("secret"));
+    assert!(!exported.contains("12000"));
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "import-config", "--file"])
+        .arg(&config_file)
+        .args(["--name", "cache-copy", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("cache-copy"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_wait_ready() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 1,
+            "name": "cache",
+            "status": "active"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 2,
+            "name": "broken",
+            "status": "creation-failed"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "wait-ready", "1", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"status\": \"active\""));
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "wait-ready",
+            "2",
+            "--timeout",
+            "5",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("creation failed"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_wait_healthy() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    for (uid, name) in [(1, "cache"), (2, "lagging")] {
+        Mock::given(method("GET"))
+            .and(path(format!("/v1/bdbs/{}", uid)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "uid": uid,
+                "name": name,
+                "status": "active"
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    Mock::given(method("GET"))
+        .and(path("/v1/shards"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": "1", "bdb_uid": 1, "role": "master", "assigned_slots": "0-16383",
+             "redis_info": {"master_repl_offset": 1000}},
+            {"uid": "2", "bdb_uid": 1, "role": "slave", "assigned_slots": "0-16383",
+             "redis_info": {"slave_repl_offset": 1000, "master_link_status": "up"}},
+            {"uid": "3", "bdb_uid": 2, "role": "master", "assigned_slots": "0-16383",
+             "redis_info": {"master_repl_offset": 5000000}},
+            {"uid": "4", "bdb_uid": 2, "role": "slave", "assigned_slots": "0-16383",
+             "redis_info": {"slave_repl_offset": 1000, "master_link_status": "up"}}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "wait-healthy", "1", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"lag_bytes\": 0"))
+        .stdout(predicate::str::contains("\"max_lag_bytes\": 1048576"));
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "wait-healthy",
+            "2",
+            "--timeout",
+            "1",
+            "--interval",
+            "1",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("did not catch up"))
+        .stderr(predicate::str::contains("shard 4"));
+}
+
+#[tokio::test]
+async fn test_gzip_response_is_decoded() {
+    use std::io::Write;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    let body = json!([{"uid": 1, "name": "compressed-cache"}]).to_string();
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(body.as_bytes()).unwrap();
+    let gzipped = encoder.finish().unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .and(header("accept-encoding", "gzip, deflate"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-encoding", "gzip")
+                .insert_header("content-type", "application/json")
+                .set_body_bytes(gzipped),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "list", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("compressed-cache"));
+}
+
+#[tokio::test]
+async fn test_no_compression_requests_identity_encoding() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .and(header("accept-encoding", "identity"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "name": "plain-cache"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "--no-compression",
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("plain-cache"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_restore_without_terminal_requires_force() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/restore"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"action_uid": "a-1"})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "restore", "1"])
+        .args(["--backup", "backup-new"])
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Pass --force to confirm"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_backup_list_and_restore() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1/backups"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": "backup-old", "time": "2026-01-01T00:00:00Z", "status": "completed"},
+            {"uid": "backup-new", "time": "2026-02-01T00:00:00Z", "status": "completed"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "backup",
+            "list",
+            "1",
+            "-o",
+            "json",
+            "-q",
+            "[0].uid",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("backup-new"));
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/restore"))
+        .and(wiremock::matchers::body_json(
+            json!({"backup_uid": "backup-new"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"action_uid": "a-1"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/actions/a-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "action_uid": "a-1",
+            "status": "completed",
+            "progress": 100
+        })))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "restore",
+            "1",
+            "--backup",
+            "backup-new",
+            "--force",
+            "--wait",
+            "--wait-interval",
+            "1",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"completed\""))
+        .stdout(predicate::str::contains("backup-new"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_flush_waits_unless_async() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/bdbs/1/flush"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"action_uid": "f-1"})))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    // Only the waiting flush polls the action
+    Mock::given(method("GET"))
+        .and(path("/v1/actions/f-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "action_uid": "f-1",
+            "status": "completed",
+            "progress": 100
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "flush",
+            "1",
+            "--force",
+            "--wait-interval",
+            "1",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Flush completed successfully"));
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "flush",
+            "1",
+            "--force",
+            "--async",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("f-1"))
+        .stdout(predicate::str::contains("completed").not());
+}
+
+#[test]
+fn test_circuit_breaker_opens_and_is_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    // Nothing listens on port 1
+    create_enterprise_profile(&temp_dir, "http://127.0.0.1:1").unwrap();
+    let mut config = std::fs::read_to_string(temp_dir.path().join("config.toml")).unwrap();
+    config.push_str(
+        r#"
+[profiles.test.resilience.circuit_breaker]
+track = true
+window_size = 2
+failure_threshold = 1.0
+"#,
+    );
+    std::fs::write(temp_dir.path().join("config.toml"), config).unwrap();
+
+    // An open circuit is only reported; commands keep trying the network
+    for _ in 0..3 {
+        test_cmd(&temp_dir)
+            .args(["enterprise", "database", "list"])
+            .assert()
+            .code(5)
+            .stderr(predicate::str::contains("circuit breaker").not());
+    }
+
+    test_cmd(&temp_dir)
+        .args(["profile", "show", "test", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"state\": \"open\""))
+        .stdout(predicate::str::contains("\"failures\": 2"));
+}
+
+#[test]
+fn test_circuit_breaker_is_not_tracked_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    create_enterprise_profile(&temp_dir, "http://127.0.0.1:1").unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "list"])
+        .assert()
+        .code(5);
+
+    assert!(!temp_dir.path().join("circuit-breakers.json").exists());
+    test_cmd(&temp_dir)
+        .args(["profile", "show", "test", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("circuit_breaker").not());
+}
+
+#[tokio::test]
+async fn test_notify_webhook_posts_completion_summary() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(wiremock::matchers::body_partial_json(json!({
+            "command": "version",
+            "status": "success"
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "--notify-webhook",
+            &format!("{}/hook", mock_server.uri()),
+            "version",
+        ])
+        .assert()
+        .success();
+}
+
+#[tokio::test]
+async fn test_notify_webhook_reports_failed_api_request() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/99"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"error": "not found"})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(wiremock::matchers::body_partial_json(json!({
+            "status": "failure"
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "--notify-webhook",
+            &format!("{}/hook", mock_server.uri()),
+            "api",
+            "enterprise",
+            "get",
+            "/v1/bdbs/99",
+        ])
+        .assert()
+        .failure();
+}
+
+#[tokio::test]
+async fn test_notify_webhook_failure_does_not_change_exit_status() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "--notify-webhook",
+            &format!("{}/hook", mock_server.uri()),
+            "version",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("notify webhook returned HTTP 500"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_list_csv_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "name": "cache", "status": "active", "module_list": [{"module_name": "search"}]},
+            {"uid": 2, "name": "sessions", "status": "active"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "csv",
+            "-q",
+            "[].{modules: module_list, name: name, uid: uid}",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("modules,name,uid"))
+        .stdout(predicate::str::contains(
+            "\"[{\"\"module_name\"\":\"\"search\"\"}]\",cache,1",
+        ))
+        .stdout(predicate::str::contains(",sessions,2"));
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "csv",
+            "-q",
+            "length(@)",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("needs an array of objects"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_list_limit() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 3, "name": "c"},
+            {"uid": 1, "name": "a"},
+            {"uid": 2, "name": "b"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "json",
+            "--sort-by",
+            "uid",
+            "--max-items",
+            "2",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let names: Vec<String> = serde_json::from_slice::<serde_json::Value>(&output.stdout)
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|db| db["name"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(names, ["a", "b"]);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 more item(s) not shown"));
+}
+
+#[tokio::test]
+async fn test_cloud_slow_log_limit_is_not_a_display_limit() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456/slowlog"))
+        .and(query_param("limit", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "entries": [{"id": 1, "command": "KEYS *"}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args(["cloud", "database", "slow-log", "123:456", "-o", "json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["entries"][0]["command"], "KEYS *");
+
+    // The fixed-database variant has an i32 --limit, which used to clash with the global one
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"entries": []})))
+        .mount(&mock_server)
+        .await;
+    test_cmd(&temp_dir)
+        .args([
+            "cloud",
+            "fixed-database",
+            "slow-log",
+            "123:456",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success();
+}
+
+#[tokio::test]
+async fn test_enterprise_database_list_field_order() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "aof_policy": "appendfsync-every-sec",
+            "memory_size": 1073741824,
+            "name": "cache",
+            "port": 12000,
+            "status": "active",
+            "type": "redis",
+            "uid": 1
+        }])))
+        .mount(&mock_server)
+        .await;
+
+    let key_order = |extra: &[&str]| {
+        let output = test_cmd(&temp_dir)
+            .args(["enterprise", "database", "list", "-o", "json"])
+            .args(extra)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let mut keys: Vec<(usize, &str)> = [
+            "aof_policy",
+            "memory_size",
+            "name",
+            "port",
+            "status",
+            "type",
+            "uid",
+        ]
+        .into_iter()
+        .map(|key| (stdout.find(&format!("\"{}\"", key)).unwrap(), key))
+        .collect();
+        keys.sort();
+        keys.into_iter().map(|(_, key)| key).collect::<Vec<_>>()
+    };
+
+    // Alphabetical by default
+    assert_eq!(
+        key_order(&[]),
+        [
+            "aof_policy",
+            "memory_size",
+            "name",
+            "port",
+            "status",
+            "type",
+            "uid"
+        ]
+    );
+    // Identifying fields first with --field-order, then the rest alphabetically
+    assert_eq!(
+        key_order(&["--field-order"]),
+        [
+            "uid",
+            "name",
+            "status",
+            "type",
+            "memory_size",
+            "port",
+            "aof_policy"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_enterprise_database_list_wrap_lists() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "name": "a"},
+            {"uid": 2, "name": "b"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "json",
+            "--wrap-lists",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["count"], 2);
+    assert_eq!(result["databases"][1]["name"], "b");
+}
+
+#[tokio::test]
+async fn test_wrap_lists_rejects_table_output() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "table",
+            "--wrap-lists",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--wrap-lists only applies to JSON and YAML output",
+        ));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_list_coerce_bools() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "name": "a", "replication": "true", "sharding": 0},
+            {"uid": 2, "name": "b", "replication": false, "sharding": "1"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "list",
+            "-o",
+            "json",
+            "--coerce-bools",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        result,
+        json!([
+            {"uid": 1, "name": "a", "replication": true, "sharding": false},
+            {"uid": 2, "name": "b", "replication": false, "sharding": true}
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_enterprise_database_export_wait_reports_action_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/bdbs/1/export"))
+        .and(wiremock::matchers::body_partial_json(
+            json!({"export_location": "ftp://backup.example.com/db1.rdb"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"action_uid": "a-2"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/actions/a-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "action_uid": "a-2",
+            "status": "failed",
+            "error": "FTP server refused connection"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "export",
+            "1",
+            "--location",
+            "ftp://backup.example.com/db1.rdb",
+            "--wait",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("FTP server refused connection"));
+}
+
+#[tokio::test]
+async fn test_enterprise_database_tls_enable_with_client_cert() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    let cert = "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----";
+    let ca_path = temp_dir.path().join("ca.pem");
+    std::fs::write(&ca_path, format!("{}\n", cert)).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"uid": 1})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/bdbs/1"))
+        .and(wiremock::matchers::body_json(json!({
+            "tls_mode": "enabled",
+            "enforce_client_authentication": "enabled",
+            "authentication_ssl_client_certs": [{"client_cert": cert}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 1,
+            "tls_mode": "enabled"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "tls",
+            "enable",
+            "1",
+            "--require-client-cert",
+            "--ca",
+        ])
+        .arg(format!("@{}", ca_path.display()))
+        .assert()
+        .success();
+
+    // Requiring client certs on a database that trusts none is refused
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "database",
+            "tls",
+            "enable",
+            "1",
+            "--require-client-cert",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--ca"));
+}
+
+#[tokio::test]
+async fn test_enterprise_cluster_join_refuses_clustered_node() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "bootstrap_status": {"state": "completed"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "cluster",
+            "join",
+            "--url",
+            &mock_server.uri(),
+            "--nodes",
+            "10.0.0.1",
+            "--username",
+            "admin@redis.local",
+            "--password",
+            "secret",
+            "--wait",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already belongs to a cluster"));
+}
+
+#[tokio::test]
+async fn test_enterprise_cluster_expand_joins_through_active_node() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "addr": "10.0.0.1", "status": "active"},
+            {"uid": 2, "addr": "10.0.0.2", "status": "active"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bootstrap"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "bootstrap_status": {"state": "idle"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    // The new node (served by the same mock) receives the join request
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "cluster",
+            "expand",
+            "--node-address",
+            &mock_server.uri(),
+            "--insecure",
+            "-o",
+            "json",
+        ])
+        .assert()
+        .success();
+}
+
+#[tokio::test]
+async fn test_enterprise_stats_nodes_prometheus() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/nodes/stats/last"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "stats": [
+                {
+                    "uid": 1,
+                    "intervals": [{"time": "2024-01-15T10:30:00Z", "metrics": {
+                        "cpu_user": 0.25,
+                        "free_memory": 1073741824
+                    }}]
+                },
+                {
+                    "uid": 2,
+                    "intervals": [{"time": "2024-01-15T10:30:00Z", "metrics": {"cpu_user": 0.5}}]
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "stats", "nodes", "--format", "prometheus"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "# TYPE redis_enterprise_node_cpu_user gauge",
+        ))
+        .stdout(predicate::str::contains(
+            "redis_enterprise_node_cpu_user{node=\"2\"} 0.5",
+        ))
+        .stdout(predicate::str::contains(
+            "redis_enterprise_node_free_memory_bytes{node=\"1\"} 1073741824",
+        ));
+}
+
+#[tokio::test]
+async fn test_exit_codes_distinguish_failure_classes() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/99"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error_code": "db_not_found",
+            "description": "Database 99 not found"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+
+    // Not found
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "get", "99"])
+        .assert()
+        .code(4);
+
+    // Authentication
+    test_cmd(&temp_dir)
+        .args(["enterprise", "cluster", "get"])
+        .assert()
+        .code(3);
+
+    // Validation
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "list", "-q", "[?status=="])
+        .assert()
+        .code(2);
+
+    // Network: nothing listens on port 1
+    let offline_dir = TempDir::new().unwrap();
+    create_enterprise_profile(&offline_dir, "http://127.0.0.1:1").unwrap();
+    test_cmd(&offline_dir)
+        .args(["--no-resilience", "enterprise", "database", "list"])
+        .assert()
+        .code(5);
+}
+
+#[tokio::test]
+async fn test_not_found_exit_code_survives_error_context() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/tasks/missing-task"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Task was not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["cloud", "task", "get", "missing-task"])
+        .assert()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "Failed to fetch task missing-task",
+        ));
+}
+
+#[tokio::test]
+async fn test_invalid_query_fails_before_request() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "database", "list", "-q", "[?status=="])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid JMESPath query"));
+}
+
+#[tokio::test]
+async fn test_http_timeout_flag_bounds_slow_requests() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!([]))
+                .set_delay(std::time::Duration::from_secs(5)),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let start = std::time::Instant::now();
+    test_cmd(&temp_dir)
+        .args(["--http-timeout", "1", "enterprise", "database", "list"])
+        .assert()
+        .failure();
+    assert!(start.elapsed() < std::time::Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_api_get_stream_writes_array() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    let bdbs = json!([
+        {"uid": 1, "name": "cache"},
+        {"uid": 2, "name": "sessions"}
+    ]);
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(bdbs.clone()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/bdbs", "--stream"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let streamed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(streamed, bdbs);
+}
+
+#[tokio::test]
+async fn test_api_get_stream_applies_output_filters() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"uid": 1, "name": "cache", "port": 12000},
+            {"uid": 2, "name": "sessions", "port": 12001},
+            {"uid": 3, "name": "queue", "port": 12002}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/bdbs", "--stream"])
+        .args(["--fields", "uid,name", "--max-items", "2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let streamed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        streamed,
+        json!([{"uid": 1, "name": "cache"}, {"uid": 2, "name": "sessions"}])
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 more item(s) not shown"));
+
+    let output = test_cmd(&temp_dir)
+        .args(["api", "enterprise", "get", "/v1/bdbs", "--stream"])
+        .args(["--sort-by", "port", "--reverse"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let sorted: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(sorted[0]["uid"], 3);
+}
+
+#[tokio::test]
+async fn test_api_stream_rejects_query() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    test_cmd(&temp_dir)
+        .args([
+            "api",
+            "enterprise",
+            "get",
+            "/v1/bdbs",
+            "--stream",
+            "-q",
+            "[].name",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--stream cannot be combined"));
+}
+
+#[tokio::test]
+async fn test_enterprise_support_package_resumes_partial_download() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"name": "cluster1"})))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/debuginfo/all"))
+        .and(header("range", "bytes=5-"))
+        .and(header("if-range", "\"v1\""))
+        .respond_with(
+            ResponseTemplate::new(206)
+                .insert_header("content-type", "application/x-gzip")
+                .insert_header("content-range", "bytes 5-9/10")
+                .set_body_bytes(b"world".to_vec()),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let package_path = temp_dir.path().join("package.tar.gz");
+    let part_path = temp_dir.path().join("package.tar.gz.part");
+    let validator_path = temp_dir.path().join("package.tar.gz.part.validator");
+    std::fs::write(&part_path, b"hello").unwrap();
+    std::fs::write(&validator_path, "\"v1\"").unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "support-package", "cluster", "-o", "json"])
+        .args(["--file", package_path.to_str().unwrap()])
+        .args(["--skip-checks", "--resume"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"resumed_bytes\": 5"));
+
+    assert_eq!(std::fs::read(&package_path).unwrap(), b"helloworld");
+    assert!(!part_path.exists());
+    assert!(!validator_path.exists());
+}
+
+#[tokio::test]
+async fn test_enterprise_support_package_restarts_changed_download() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/cluster"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"name": "cluster1"})))
+        .mount(&mock_server)
+        .await;
+
+    // The package changed since the partial download, so If-Range doesn't
+    // match and the server sends all of it
+    Mock::given(method("GET"))
+        .and(path("/v1/debuginfo/all"))
+        .and(header("if-range", "\"v1\""))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/x-gzip")
+                .insert_header("etag", "\"v2\"")
+                .set_body_bytes(b"new package".to_vec()),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let package_path = temp_dir.path().join("package.tar.gz");
+    let part_path = temp_dir.path().join("package.tar.gz.part");
+    std::fs::write(&part_path, b"hello").unwrap();
+    std::fs::write(
+        temp_dir.path().join("package.tar.gz.part.validator"),
+        "\"v1\"",
+    )
+    .unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["enterprise", "support-package", "cluster", "-o", "json"])
+        .args(["--file", package_path.to_str().unwrap()])
+        .args(["--skip-checks", "--resume"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"resumed_bytes\": 0"));
+
+    assert_eq!(std::fs::read(&package_path).unwrap(), b"new package");
+}
+
+#[tokio::test]
+async fn test_enterprise_logs_list_jsonl_writes_one_event_per_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/logs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"time": "2024-01-01T00:00:00Z", "type": "bdb_created"},
+            {"time": "2024-01-01T00:05:00Z", "type": "bdb_updated"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args(["enterprise", "logs", "list", "-o", "jsonl"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("\"type\":\"bdb_created\""));
+    assert!(lines[1].contains("\"type\":\"bdb_updated\""));
+}
+
+#[tokio::test]
+async fn test_enterprise_logs_export_compress_writes_complete_gzip() {
+    use std::io::Read;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/logs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"time": "2024-01-01T00:00:00Z", "type": "bdb_created"},
+            {"time": "2024-01-01T00:05:00Z", "type": "bdb_updated"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let export_path = temp_dir.path().join("logs.jsonl.gz");
+    test_cmd(&temp_dir)
+        .args([
+            "enterprise",
+            "logs",
+            "export",
+            "--since",
+            "2023-12-31T00:00:00Z",
+        ])
+        .arg("--file")
+        .arg(&export_path)
+        .arg("--compress")
+        .assert()
+        .success();
+
+    // A missing gzip trailer makes the decoder fail with UnexpectedEof
+    let mut decoded = String::new();
+    flate2::read::GzDecoder::new(std::fs::File::open(&export_path).unwrap())
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.lines().count(), 2);
+    assert!(decoded.contains("bdb_updated"));
+}
+
+#[tokio::test]
+async fn test_cloud_task_list_jsonl_streams_filtered_fields() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"taskId": "t-1", "status": "processing-completed", "description": "done"},
+            {"taskId": "t-2", "status": "processing-in-progress", "description": "busy"}
+        ])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args(["cloud", "task", "list", "--active", "-o", "jsonl"])
+        .args(["--fields", "taskId,status"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let stdout = String::from_utf8(output).unwrap();
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        lines,
+        vec![json!({"taskId": "t-2", "status": "processing-in-progress"})]
+    );
+}
+
+#[tokio::test]
+async fn test_cloud_task_list_watch_keeps_json_output_parseable() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {"taskId": "t-1", "status": "processing-completed"}
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args(["cloud", "task", "list", "--watch", "-o", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let tasks: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(tasks, json!([]));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("All active tasks have completed"));
+}
+
+#[tokio::test]
+async fn test_cloud_cost_report_download_flatten() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    let report = "\
+SubAccountId,ResourceId,ChargePeriodStart,ChargeDescription,BilledCost,BillingCurrency
+123,db-1,2025-01-31T00:00:00Z,Shard hours,1.5,USD
+123,db-1,2025-01-31T12:00:00Z,Shard hours,1.5,USD
+123,db-1,2025-02-01T00:00:00Z,Shard hours,2,USD
+";
+    Mock::given(method("GET"))
+        .and(path("/cost-report/report-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(report))
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["cloud", "cost-report", "download", "report-1", "--flatten"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "subscription_id,database_id,period,line_item,amount,currency\n",
+        ))
+        .stdout(predicate::str::contains(
+            "123,db-1,2025-01,Shard hours,3,USD",
+        ))
+        .stdout(predicate::str::contains(
+            "123,db-1,2025-02,Shard hours,2,USD",
+        ));
+
+    test_cmd(&temp_dir)
+        .args(["cloud", "cost-report", "download", "report-1", "--flatten"])
+        .args(["-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"period\": \"2025-02\""));
+}
+
+#[tokio::test]
+async fn test_db_open_cloud_database_dry_run() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_cloud_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/subscriptions/123/databases/456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "databaseId": 456,
+            "publicEndpoint": "redis-12345.c1.us-east-1-2.ec2.cloud.redislabs.com:12345",
+            "privateEndpoint": "redis-12345.internal.c1.us-east-1-2.ec2.cloud.rlrcp.com:12345",
+            "security": {"password": "cloud-secret", "enableTls": true}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = test_cmd(&temp_dir)
+        .args([
+            "-o",
+            "json",
+            "db",
+            "open",
+            "--profile",
+            "test",
+            "--subscription",
+            "123",
+            "--database",
+            "456",
+            "--dry-run",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        result["argv"],
+        json!([
+            "redis-cli",
+            "-h",
+            "redis-12345.c1.us-east-1-2.ec2.cloud.redislabs.com",
+            "-p",
+            "12345",
+            "-a",
+            "***",
+            "--tls"
+        ])
+    );
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("cloud-secret"));
+
+    // A cloud profile needs the database to open
+    test_cmd(&temp_dir)
+        .args(["db", "open", "--profile", "test", "--dry-run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--subscription <id> --database <id>",
+        ));
+}
+
+#[tokio::test]
+async fn test_db_open_enterprise_database_dry_run() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 3,
+            "name": "sessions",
+            "authentication_redis_pass": "bdb-secret",
+            "tls_mode": "enabled",
+            "endpoints": [{"dns_name": "redis-12000.cluster.local", "addr": ["10.0.0.1"], "port": 12000}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    test_cmd(&temp_dir)
+        .args(["db", "open", "--profile", "test", "--bdb", "3", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "redis-cli -h redis-12000.cluster.local -p 12000 -a *** --tls --insecure",
+        ))
+        .stdout(predicate::str::contains("bdb-secret").not());
+}
+
+#[tokio::test]
+async fn test_enterprise_database_get_omit_null() {
+    let temp_dir = TempDir::new().unwrap();
+    let mock_server = MockServer::start().await;
+    create_enterprise_profile(&temp_dir, &mock_server.uri()).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/bdbs/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "uid": 1,
+            "name": "cache",
+            "authentication_redis_pass": null,
+            "backup_location": {"type": "s3", "bucket_name": null},
+            "tags": ["a", null]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let get = |extra: &[&str]| {
+        let output = test_cmd(&temp_dir)
+            .args(["enterprise", "database", "get", "1", "-o", "json"])
+            .args(extra)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+
+    // Nulls are kept by default
+    let full = get(&[]);
+    assert!(full["authentication_redis_pass"].is_null());
+    assert!(full.get("authentication_redis_pass").is_some());
+    assert!(full["backup_location"].get("bucket_name").is_some());
+
+    // --omit-null drops null fields at every level but keeps array positions
+    let stripped = get(&["--omit-null"]);
+    assert_eq!(
+        stripped,
+        json!({
+            "uid": 1,
+            "name": "cache",
+            "backup_location": {"type": "s3"},
+            "tags": ["a", null]
+        })
+    );
 }
@@ -90,7 +90,8 @@ fn test_profile_get_nonexistent() {
         .arg("show")
         .arg("nonexistent")
         .assert()
-        .failure();
+        .failure()
+        .code(4);
 }
 
 #[test]
@@ -378,6 +379,95 @@ fn test_profile_validate() {
         .success();
 }
 
+#[test]
+fn test_profile_validate_json() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .arg("profile")
+        .arg("set")
+        .arg("prod")
+        .arg("--deployment")
+        .arg("cloud")
+        .arg("--api-key")
+        .arg("key")
+        .arg("--api-secret")
+        .arg("secret")
+        .assert()
+        .success();
+
+    let output = test_cmd(&temp_dir)
+        .arg("-o")
+        .arg("json")
+        .arg("profile")
+        .arg("validate")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(result["config_path"].is_string());
+    assert_eq!(result["overall_valid"], true);
+
+    let profile = &result["profiles"][0];
+    assert_eq!(profile["name"], "prod");
+    assert_eq!(profile["type"], "cloud");
+    assert_eq!(profile["valid"], true);
+    assert_eq!(profile["errors"], serde_json::json!([]));
+    assert_eq!(profile["default_for"], serde_json::json!(["cloud"]));
+    // Connectivity fields only appear with --connect
+    assert!(profile.get("reachable").is_none());
+}
+
+#[test]
+fn test_profile_validate_reports_broken_base() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("config.toml"),
+        r#"
+[profiles.prod]
+deployment_type = "cloud"
+api_key = "key"
+api_secret = "secret"
+
+[profiles.orphan]
+base = "missing"
+"#,
+    )
+    .unwrap();
+
+    // Other commands still work with a broken base in the file
+    test_cmd(&temp_dir)
+        .arg("profile")
+        .arg("list")
+        .assert()
+        .success();
+
+    let output = test_cmd(&temp_dir)
+        .arg("-o")
+        .arg("json")
+        .arg("profile")
+        .arg("validate")
+        .output()
+        .unwrap();
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["overall_valid"], false);
+
+    let orphan = result["profiles"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|p| p["name"] == "orphan")
+        .unwrap();
+    assert_eq!(orphan["valid"], false);
+    assert!(
+        orphan["errors"][0]
+            .as_str()
+            .unwrap()
+            .contains("profile 'orphan' has base 'missing', which does not exist")
+    );
+}
+
 #[test]
 fn test_profile_set_enterprise_with_insecure() {
     let temp_dir = TempDir::new().unwrap();
@@ -399,6 +489,45 @@ fn test_profile_set_enterprise_with_insecure() {
         .success();
 }
 
+#[test]
+fn test_profile_set_enterprise_kubernetes() {
+    let temp_dir = TempDir::new().unwrap();
+    let secret_dir = temp_dir.path().join("secret");
+    std::fs::create_dir(&secret_dir).unwrap();
+    std::fs::write(secret_dir.join("username"), "admin@redis.local").unwrap();
+    std::fs::write(secret_dir.join("password"), "operator-pass").unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "set", "in-cluster", "--type", "enterprise"])
+        .arg("--kubernetes")
+        .arg("--secret-path")
+        .arg(&secret_dir)
+        .args(["--namespace", "redis", "--cluster", "rec"])
+        .assert()
+        .success();
+
+    let config = std::fs::read_to_string(temp_dir.path().join("config.toml")).unwrap();
+    assert!(config.contains("https://rec.redis.svc.cluster.local:9443"));
+    assert!(config.contains("[profiles.in-cluster.kubernetes]"));
+    assert!(!config.contains("operator-pass"));
+
+    // A missing secret is reported up front rather than on first use
+    test_cmd(&temp_dir)
+        .args([
+            "profile",
+            "set",
+            "broken",
+            "--type",
+            "enterprise",
+            "--kubernetes",
+        ])
+        .arg("--secret-path")
+        .arg(temp_dir.path().join("missing"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("operator secret"));
+}
+
 #[test]
 fn test_profile_set_cloud_with_custom_url() {
     let temp_dir = TempDir::new().unwrap();
@@ -769,6 +898,105 @@ fn test_profile_set_database_requires_port() {
         .stderr(predicate::str::contains("--port"));
 }
 
+#[test]
+fn test_profile_set_rejects_url_without_scheme() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "set", "ent", "--type", "enterprise"])
+        .args(["--url", "cluster.example.com:9443"])
+        .args(["--username", "admin@redis.local", "--password", "secret"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid --url"))
+        .stderr(predicate::str::contains("https://cluster.example.com:9443"));
+
+    // Nothing was saved
+    test_cmd(&temp_dir)
+        .args(["profile", "list", "-o", "json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"ent\"").not());
+}
+
+#[test]
+fn test_profile_set_rejects_url_port_out_of_range() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "set", "ent", "--type", "enterprise"])
+        .args(["--url", "https://cluster.example.com:99443"])
+        .args(["--username", "admin@redis.local", "--password", "secret"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("port outside 1-65535"));
+}
+
+#[test]
+fn test_profile_set_database_rejects_port_in_host() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "set", "cache", "--type", "database"])
+        .args([
+            "--host",
+            "localhost:6380",
+            "--port",
+            "6379",
+            "--password",
+            "x",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --host"))
+        .stderr(predicate::str::contains("--host localhost --port 6380"));
+}
+
+#[test]
+fn test_profile_set_rejects_fields_of_other_type() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "set", "cloud", "--type", "cloud"])
+        .args(["--api-key", "key", "--api-secret", "secret"])
+        .args(["--url", "https://cluster.example.com:9443"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "only applies to enterprise profiles",
+        ));
+}
+
+#[test]
+fn test_profile_set_rejects_insecure_with_ca_cert() {
+    let temp_dir = TempDir::new().unwrap();
+    let ca_cert = temp_dir.path().join("ca.pem");
+    std::fs::write(&ca_cert, "").unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "set", "ent", "--type", "enterprise"])
+        .args(["--url", "https://cluster.example.com:9443"])
+        .args(["--username", "admin@redis.local", "--password", "secret"])
+        .arg("--insecure")
+        .arg("--ca-cert")
+        .arg(&ca_cert)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --ca-cert"));
+}
+
+#[test]
+fn test_profile_set_database_rejects_port_zero() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "set", "cache", "--type", "database"])
+        .args(["--host", "localhost", "--port", "0"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--port"));
+}
+
 #[test]
 fn test_profile_default_database() {
     let temp_dir = TempDir::new().unwrap();
@@ -840,6 +1068,61 @@ fn test_profile_default_database_nonexistent() {
         .failure();
 }
 
+#[test]
+fn test_profile_clear_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.toml");
+
+    test_cmd(&temp_dir)
+        .arg("profile")
+        .arg("set")
+        .arg("my-cache")
+        .arg("--type")
+        .arg("database")
+        .arg("--host")
+        .arg("localhost")
+        .arg("--port")
+        .arg("6379")
+        .assert()
+        .success();
+
+    test_cmd(&temp_dir)
+        .arg("profile")
+        .arg("default-database")
+        .arg("my-cache")
+        .assert()
+        .success();
+    assert!(
+        std::fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("default_database")
+    );
+
+    test_cmd(&temp_dir)
+        .arg("profile")
+        .arg("clear-default")
+        .arg("--type")
+        .arg("database")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("was 'my-cache'"));
+    assert!(
+        !std::fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("default_database")
+    );
+
+    // Clearing again is a no-op
+    test_cmd(&temp_dir)
+        .arg("profile")
+        .arg("unset-default")
+        .arg("--type")
+        .arg("database")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No default database profile"));
+}
+
 #[test]
 fn test_profile_help_shows_database_type() {
     redisctl()
@@ -944,3 +1227,378 @@ fn test_profile_list_shows_database_profiles() {
         .stdout(predicate::str::contains("ent-profile"))
         .stdout(predicate::str::contains("db-profile"));
 }
+
+#[test]
+fn test_config_migrate_legacy_default_profile() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.toml");
+    std::fs::write(
+        &config_path,
+        r#"
+default_profile = "legacy"
+
+[profiles.legacy]
+deployment_type = "cloud"
+api_key = "key"
+api_secret = "secret"
+"#,
+    )
+    .unwrap();
+
+    test_cmd(&temp_dir)
+        .arg("config")
+        .arg("migrate")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("from version 0 to 1"));
+
+    let migrated = std::fs::read_to_string(&config_path).unwrap();
+    assert!(migrated.contains("default_cloud = \"legacy\""));
+    assert!(!migrated.contains("default_profile"));
+    assert!(temp_dir.path().join("config.toml.v0.bak").exists());
+}
+
+#[test]
+fn test_config_migrate_current_is_noop() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .arg("profile")
+        .arg("set")
+        .arg("test-cloud")
+        .arg("--type")
+        .arg("cloud")
+        .arg("--api-key")
+        .arg("test-key")
+        .arg("--api-secret")
+        .arg("test-secret")
+        .assert()
+        .success();
+
+    test_cmd(&temp_dir)
+        .arg("config")
+        .arg("migrate")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("nothing to migrate"));
+}
+
+#[test]
+fn test_db_open_dry_run_json() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("config.toml"),
+        r#"
+[profiles.cache]
+deployment_type = "database"
+host = "redis.example.com"
+port = 12000
+password = "secret123"
+tls = true
+username = "app"
+database = 2
+"#,
+    )
+    .unwrap();
+
+    let output = test_cmd(&temp_dir)
+        .arg("-o")
+        .arg("json")
+        .arg("db")
+        .arg("open")
+        .arg("--profile")
+        .arg("cache")
+        .arg("--dry-run")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["host"], "redis.example.com");
+    assert_eq!(result["port"], 12000);
+    assert_eq!(result["tls"], true);
+    assert_eq!(result["db"], 2);
+    assert_eq!(
+        result["argv"],
+        serde_json::json!([
+            "redis-cli",
+            "-h",
+            "redis.example.com",
+            "-p",
+            "12000",
+            "--user",
+            "app",
+            "-a",
+            "***",
+            "--tls",
+            "-n",
+            "2"
+        ])
+    );
+}
+
+#[test]
+fn test_concurrent_profile_set_keeps_all_profiles() {
+    let temp_dir = TempDir::new().unwrap();
+
+    std::thread::scope(|scope| {
+        for i in 0..8 {
+            let temp_dir = &temp_dir;
+            scope.spawn(move || {
+                test_cmd(temp_dir)
+                    .args(["profile", "set", &format!("cloud-{}", i)])
+                    .args(["--deployment", "cloud"])
+                    .args(["--api-key", "key", "--api-secret", "secret"])
+                    .assert()
+                    .success();
+            });
+        }
+    });
+
+    let config = std::fs::read_to_string(temp_dir.path().join("config.toml")).unwrap();
+    for i in 0..8 {
+        assert!(
+            config.contains(&format!("[profiles.cloud-{}]", i)),
+            "profile cloud-{} was lost:\n{}",
+            i,
+            config
+        );
+    }
+}
+
+#[test]
+fn test_profile_copy() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.toml");
+
+    test_cmd(&temp_dir)
+        .args([
+            "profile",
+            "set",
+            "ent-staging",
+            "--type",
+            "enterprise",
+            "--url",
+            "https://staging:9443",
+            "--username",
+            "admin@redis.local",
+            "--password",
+            "secret",
+        ])
+        .assert()
+        .success();
+    test_cmd(&temp_dir)
+        .args(["profile", "default-enterprise", "ent-staging"])
+        .assert()
+        .success();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "copy", "ent-staging", "ent-prod"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Profile 'ent-staging' copied to 'ent-prod'",
+        ));
+
+    let config = std::fs::read_to_string(&config_path).unwrap();
+    assert!(config.contains("[profiles.ent-prod]"));
+    assert!(config.contains("default_enterprise = \"ent-staging\""));
+
+    // Existing destination needs --force
+    test_cmd(&temp_dir)
+        .args(["profile", "copy", "ent-staging", "ent-prod"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+    test_cmd(&temp_dir)
+        .args(["profile", "cp", "ent-staging", "ent-prod", "--force"])
+        .assert()
+        .success();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "copy", "missing", "other"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_profile_copy_refuses_to_retype_default() {
+    let temp_dir = TempDir::new().unwrap();
+
+    test_cmd(&temp_dir)
+        .args([
+            "profile",
+            "set",
+            "cache",
+            "--type",
+            "database",
+            "--host",
+            "localhost",
+            "--port",
+            "6379",
+        ])
+        .assert()
+        .success();
+    test_cmd(&temp_dir)
+        .args([
+            "profile",
+            "set",
+            "cloud-dev",
+            "--type",
+            "cloud",
+            "--api-key",
+            "key",
+            "--api-secret",
+            "secret",
+        ])
+        .assert()
+        .success();
+    test_cmd(&temp_dir)
+        .args(["profile", "default-database", "cache"])
+        .assert()
+        .success();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "copy", "cloud-dev", "cache", "--force"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("clear-default"));
+}
+
+#[test]
+fn test_profile_export_import_round_trip() {
+    let source_dir = TempDir::new().unwrap();
+    let target_dir = TempDir::new().unwrap();
+    let bundle_path = source_dir.path().join("profiles.json");
+
+    test_cmd(&source_dir)
+        .args([
+            "profile",
+            "set",
+            "ent-prod",
+            "--type",
+            "enterprise",
+            "--url",
+            "https://prod:9443",
+            "--username",
+            "admin@redis.local",
+            "--password",
+            "secret",
+        ])
+        .assert()
+        .success();
+
+    test_cmd(&source_dir)
+        .args(["profile", "export", "--file"])
+        .arg(&bundle_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported 1 profile(s)"));
+
+    // Secrets are replaced with placeholders unless --include-secrets is given
+    let bundle = std::fs::read_to_string(&bundle_path).unwrap();
+    assert!(bundle.contains("${REDISCTL_ENT_PROD_PASSWORD}"));
+    assert!(!bundle.contains("\"secret\""));
+    assert!(bundle.contains("admin@redis.local"));
+
+    test_cmd(&target_dir)
+        .env("REDISCTL_ENT_PROD_PASSWORD", "from-env")
+        .args(["profile", "import"])
+        .arg(&bundle_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Imported 1 profile(s): ent-prod"));
+
+    // The placeholder is kept so the secret stays in the environment
+    let config = std::fs::read_to_string(target_dir.path().join("config.toml")).unwrap();
+    assert!(config.contains("[profiles.ent-prod]"));
+    assert!(config.contains("${REDISCTL_ENT_PROD_PASSWORD}"));
+    assert!(!config.contains("from-env"));
+
+    // Existing names need --overwrite
+    test_cmd(&target_dir)
+        .args(["profile", "import"])
+        .arg(&bundle_path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--overwrite"));
+    test_cmd(&target_dir)
+        .env_remove("REDISCTL_ENT_PROD_PASSWORD")
+        .args(["profile", "import", "--overwrite"])
+        .arg(&bundle_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("REDISCTL_ENT_PROD_PASSWORD"));
+}
+
+#[test]
+fn test_profile_export_with_secrets_is_owner_only() {
+    let temp_dir = TempDir::new().unwrap();
+    let bundle_path = temp_dir.path().join("secrets.json");
+
+    test_cmd(&temp_dir)
+        .args([
+            "profile",
+            "set",
+            "ent-prod",
+            "--type",
+            "enterprise",
+            "--url",
+            "https://prod:9443",
+            "--username",
+            "admin@redis.local",
+            "--password",
+            "secret",
+        ])
+        .assert()
+        .success();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "export", "--include-secrets", "--file"])
+        .arg(&bundle_path)
+        .assert()
+        .success();
+
+    let bundle = std::fs::read_to_string(&bundle_path).unwrap();
+    assert!(bundle.contains("\"secret\""));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&bundle_path)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
+
+#[test]
+fn test_profile_import_rejects_malformed_entries() {
+    let temp_dir = TempDir::new().unwrap();
+    let bundle_path = temp_dir.path().join("profiles.json");
+    std::fs::write(
+        &bundle_path,
+        r#"{
+  "version": 1,
+  "profiles": {
+    "good": {"deployment_type": "database", "host": "localhost", "port": 6379},
+    "no-secret": {"deployment_type": "cloud", "api_key": "key"},
+    "no-user": {"deployment_type": "enterprise", "url": "https://cluster:9443"}
+  }
+}"#,
+    )
+    .unwrap();
+
+    test_cmd(&temp_dir)
+        .args(["profile", "import"])
+        .arg(&bundle_path)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("2 of 3 profile(s) are invalid"))
+        .stderr(predicate::str::contains("no-secret: missing api_secret"))
+        .stderr(predicate::str::contains("no-user: missing username"));
+
+    // Nothing is written when any entry is invalid
+    let config = std::fs::read_to_string(temp_dir.path().join("config.toml")).unwrap_or_default();
+    assert!(!config.contains("[profiles.good]"));
+}